@@ -0,0 +1,2 @@
+-- Drop the 'monitors' table.
+DROP TABLE `monitors`;
@@ -0,0 +1,18 @@
+-- Create the 'monitors' table.
+CREATE TABLE `monitors` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`monitor_created_at` TIMESTAMP NOT NULL DEFAULT current_timestamp(),
+	`name` VARCHAR(1024) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`check_interval` INT(10) UNSIGNED NOT NULL,
+	`check_timeout` INT(10) UNSIGNED NOT NULL,
+	`is_enabled` BIT(1) NOT NULL DEFAULT b'1',
+	`configuration` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `FK_monitors_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_monitors_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `configuration` CHECK (json_valid(`configuration`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,2 @@
+-- Drop the 'check_results' table.
+DROP TABLE `check_results`;
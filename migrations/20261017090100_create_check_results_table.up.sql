@@ -0,0 +1,16 @@
+-- Create the 'check_results' table.
+CREATE TABLE `check_results` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`monitor_id` BIGINT(20) UNSIGNED NOT NULL,
+	`checked_at` TIMESTAMP NOT NULL DEFAULT current_timestamp(),
+	`status` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`latency` INT(10) UNSIGNED NOT NULL,
+	`error_kind` VARCHAR(64) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	`error_message` VARCHAR(2048) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `monitor_id_checked_at` (`monitor_id`, `checked_at`) USING BTREE,
+	CONSTRAINT `FK_check_results_monitors` FOREIGN KEY (`monitor_id`) REFERENCES `monitors` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,4 @@
+-- Remove the 'details' column from the 'check_results' table.
+ALTER TABLE `check_results`
+	DROP CONSTRAINT `details`,
+	DROP COLUMN `details`;
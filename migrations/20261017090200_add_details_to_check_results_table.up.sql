@@ -0,0 +1,4 @@
+-- Add the 'details' column to the 'check_results' table.
+ALTER TABLE `check_results`
+	ADD COLUMN `details` LONGTEXT NOT NULL DEFAULT '{}' COLLATE 'utf8mb4_bin' AFTER `error_message`,
+	ADD CONSTRAINT `details` CHECK (json_valid(`details`));
@@ -0,0 +1,7 @@
+-- Drop the 'heartbeats' table.
+DROP TABLE `heartbeats`;
+
+-- Remove the 'heartbeat_token' column from the 'monitors' table.
+ALTER TABLE `monitors`
+	DROP INDEX `heartbeat_token`,
+	DROP COLUMN `heartbeat_token`;
@@ -0,0 +1,20 @@
+-- Add the 'heartbeat_token' column to the 'monitors' table, so heartbeat monitors can be queried
+-- by their token and no two monitors can share one.
+ALTER TABLE `monitors`
+	ADD COLUMN `heartbeat_token` VARCHAR(64) AS (IF(JSON_VALUE(`configuration`, '$.type') = 'heartbeat', JSON_VALUE(`configuration`, '$.token'), NULL)) PERSISTENT COLLATE 'utf8mb4_bin' AFTER `configuration`,
+	ADD UNIQUE INDEX `heartbeat_token` (`heartbeat_token`) USING BTREE;
+
+-- Create the 'heartbeats' table.
+CREATE TABLE `heartbeats` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`monitor_id` BIGINT(20) UNSIGNED NOT NULL,
+	`received_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`kind` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`exit_code` INT(11) NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `monitor_id_received_at` (`monitor_id`, `received_at`) USING BTREE,
+	CONSTRAINT `FK_heartbeats_monitors` FOREIGN KEY (`monitor_id`) REFERENCES `monitors` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
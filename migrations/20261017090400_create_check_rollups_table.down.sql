@@ -0,0 +1,6 @@
+-- Remove the 'checked_at' index from the 'check_results' table.
+ALTER TABLE `check_results`
+	DROP INDEX `checked_at`;
+
+-- Drop the 'check_rollups' table.
+DROP TABLE `check_rollups`;
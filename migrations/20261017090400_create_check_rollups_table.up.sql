@@ -0,0 +1,30 @@
+-- Create the 'check_rollups' table.
+CREATE TABLE `check_rollups` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`monitor_id` BIGINT(20) UNSIGNED NOT NULL,
+	`period` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`period_start` TIMESTAMP NOT NULL,
+	`check_count` BIGINT(20) UNSIGNED NOT NULL,
+	`up_count` BIGINT(20) UNSIGNED NOT NULL,
+	`degraded_count` BIGINT(20) UNSIGNED NOT NULL,
+	`down_count` BIGINT(20) UNSIGNED NOT NULL,
+	`downtime` BIGINT(20) UNSIGNED NOT NULL,
+	`minimum_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	`average_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	`maximum_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	`p50_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	`p95_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	`p99_latency` INT(10) UNSIGNED NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `monitor_id_period_period_start` (`monitor_id`, `period`, `period_start`) USING BTREE,
+	INDEX `period_period_start` (`period`, `period_start`) USING BTREE,
+	CONSTRAINT `FK_check_rollups_monitors` FOREIGN KEY (`monitor_id`) REFERENCES `monitors` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Index the 'check_results' table by when the checks were performed, so expired check results can
+-- be deleted efficiently.
+ALTER TABLE `check_results`
+	ADD INDEX `checked_at` (`checked_at`) USING BTREE;
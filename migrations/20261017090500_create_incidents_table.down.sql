@@ -0,0 +1,5 @@
+-- Drop the 'incident_updates' table.
+DROP TABLE `incident_updates`;
+
+-- Drop the 'incidents' table.
+DROP TABLE `incidents`;
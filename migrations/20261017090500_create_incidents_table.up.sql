@@ -0,0 +1,32 @@
+-- Create the 'incidents' table.
+CREATE TABLE `incidents` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`monitor_id` BIGINT(20) UNSIGNED NOT NULL,
+	`status` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`started_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`resolved_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	`duration` BIGINT(20) UNSIGNED NULL DEFAULT NULL,
+	`error_kind` VARCHAR(64) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	`error_message` VARCHAR(2048) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `monitor_id_started_at` (`monitor_id`, `started_at`) USING BTREE,
+	CONSTRAINT `FK_incidents_monitors` FOREIGN KEY (`monitor_id`) REFERENCES `monitors` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Create the 'incident_updates' table.
+CREATE TABLE `incident_updates` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`incident_id` BIGINT(20) UNSIGNED NOT NULL,
+	`created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`status` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`message` VARCHAR(4096) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `incident_id_created_at` (`incident_id`, `created_at`) USING BTREE,
+	CONSTRAINT `FK_incident_updates_incidents` FOREIGN KEY (`incident_id`) REFERENCES `incidents` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
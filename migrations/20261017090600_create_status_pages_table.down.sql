@@ -0,0 +1,2 @@
+-- Drop the 'status_pages' table.
+DROP TABLE `status_pages`;
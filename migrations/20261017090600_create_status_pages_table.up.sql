@@ -0,0 +1,21 @@
+-- Create the 'status_pages' table.
+CREATE TABLE `status_pages` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`status_page_created_at` TIMESTAMP NOT NULL DEFAULT current_timestamp(),
+	`slug` VARCHAR(64) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`title` VARCHAR(1024) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`logo_file_id` BIGINT(20) UNSIGNED NULL DEFAULT NULL,
+	`show_uptime_history` BIT(1) NOT NULL DEFAULT b'1',
+	`monitor_groups` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `slug` (`slug`) USING BTREE,
+	INDEX `FK_status_pages_users` (`user_id`) USING BTREE,
+	INDEX `FK_status_pages_files` (`logo_file_id`) USING BTREE,
+	CONSTRAINT `FK_status_pages_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `FK_status_pages_files` FOREIGN KEY (`logo_file_id`) REFERENCES `files` (`id`) ON UPDATE NO ACTION ON DELETE SET NULL,
+	CONSTRAINT `monitor_groups` CHECK (json_valid(`monitor_groups`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,2 @@
+-- Drop the 'maintenance_windows' table.
+DROP TABLE `maintenance_windows`;
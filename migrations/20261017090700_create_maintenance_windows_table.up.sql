@@ -0,0 +1,20 @@
+-- Create the 'maintenance_windows' table.
+CREATE TABLE `maintenance_windows` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`maintenance_window_created_at` TIMESTAMP NOT NULL DEFAULT current_timestamp(),
+	`name` VARCHAR(1024) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`starts_at` TIMESTAMP NOT NULL,
+	`duration` INT(10) UNSIGNED NOT NULL,
+	`frequency` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`recurrence_interval` INT(10) UNSIGNED NOT NULL,
+	`recurrence_ends_at` TIMESTAMP NULL DEFAULT NULL,
+	`monitor_ids` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `FK_maintenance_windows_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_maintenance_windows_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `monitor_ids` CHECK (json_valid(`monitor_ids`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
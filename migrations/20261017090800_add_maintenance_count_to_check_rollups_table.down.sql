@@ -0,0 +1,3 @@
+-- Remove the 'maintenance_count' column from the 'check_rollups' table.
+ALTER TABLE `check_rollups`
+	DROP COLUMN `maintenance_count`;
@@ -0,0 +1,3 @@
+-- Add the 'maintenance_count' column to the 'check_rollups' table.
+ALTER TABLE `check_rollups`
+	ADD COLUMN `maintenance_count` BIGINT(20) UNSIGNED NOT NULL DEFAULT 0 AFTER `down_count`;
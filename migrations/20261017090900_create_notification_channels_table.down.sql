@@ -0,0 +1,8 @@
+-- Drop the 'notification_delivery_attempts' table.
+DROP TABLE `notification_delivery_attempts`;
+
+-- Drop the 'notification_deliveries' table.
+DROP TABLE `notification_deliveries`;
+
+-- Drop the 'notification_channels' table.
+DROP TABLE `notification_channels`;
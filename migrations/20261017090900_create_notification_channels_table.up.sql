@@ -0,0 +1,58 @@
+-- Create the 'notification_channels' table.
+CREATE TABLE `notification_channels` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`notification_channel_created_at` TIMESTAMP NOT NULL DEFAULT current_timestamp(),
+	`name` VARCHAR(1024) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`is_enabled` BIT(1) NOT NULL DEFAULT b'1',
+	`configuration` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	`monitor_ids` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `FK_notification_channels_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_notification_channels_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `configuration` CHECK (json_valid(`configuration`)),
+	CONSTRAINT `monitor_ids` CHECK (json_valid(`monitor_ids`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Create the 'notification_deliveries' table.
+CREATE TABLE `notification_deliveries` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`notification_channel_id` BIGINT(20) UNSIGNED NOT NULL,
+	`monitor_id` BIGINT(20) UNSIGNED NOT NULL,
+	`delivery_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`event` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`payload` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	`status` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`attempt_count` INT(10) UNSIGNED NOT NULL DEFAULT 0,
+	`next_attempt_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `notification_channel_id_delivery_created_at` (`notification_channel_id`, `delivery_created_at`) USING BTREE,
+	INDEX `status_next_attempt_at` (`status`, `next_attempt_at`) USING BTREE,
+	INDEX `FK_notification_deliveries_monitors` (`monitor_id`) USING BTREE,
+	CONSTRAINT `FK_notification_deliveries_notification_channels` FOREIGN KEY (`notification_channel_id`) REFERENCES `notification_channels` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `FK_notification_deliveries_monitors` FOREIGN KEY (`monitor_id`) REFERENCES `monitors` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `payload` CHECK (json_valid(`payload`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Create the 'notification_delivery_attempts' table.
+CREATE TABLE `notification_delivery_attempts` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`notification_delivery_id` BIGINT(20) UNSIGNED NOT NULL,
+	`attempted_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`is_successful` BIT(1) NOT NULL,
+	`status_code` SMALLINT(5) UNSIGNED NULL DEFAULT NULL,
+	`latency` INT(10) UNSIGNED NOT NULL,
+	`error_message` VARCHAR(2048) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `notification_delivery_id_attempted_at` (`notification_delivery_id`, `attempted_at`) USING BTREE,
+	CONSTRAINT `FK_notification_delivery_attempts_notification_deliveries` FOREIGN KEY (`notification_delivery_id`) REFERENCES `notification_deliveries` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
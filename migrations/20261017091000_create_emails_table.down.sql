@@ -0,0 +1,2 @@
+-- Drop the 'emails' table.
+DROP TABLE `emails`;
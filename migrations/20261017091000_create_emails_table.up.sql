@@ -0,0 +1,17 @@
+-- Create the 'emails' table.
+CREATE TABLE `emails` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`email_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`recipient` VARCHAR(256) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`subject` VARCHAR(256) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`body` TEXT NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`status` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`attempt_count` INT(10) UNSIGNED NOT NULL DEFAULT 0,
+	`next_attempt_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	`error_message` VARCHAR(2048) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `status_next_attempt_at` (`status`, `next_attempt_at`) USING BTREE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,2 @@
+-- Drop the 'user_tokens' table.
+DROP TABLE `user_tokens`;
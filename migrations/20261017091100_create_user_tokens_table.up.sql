@@ -0,0 +1,16 @@
+-- Create the 'user_tokens' table.
+CREATE TABLE `user_tokens` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`purpose` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`token_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`token_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`expires_at` TIMESTAMP(3) NOT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `token_hash` (`token_hash`) USING BTREE,
+	INDEX `FK_user_tokens_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_user_tokens_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,6 @@
+-- Drop the 'failed_logins' table.
+DROP TABLE `failed_logins`;
+
+-- Remove the 'account_unlocks_at' column from the 'users' table.
+ALTER TABLE `users`
+	DROP COLUMN `account_unlocks_at`;
@@ -0,0 +1,18 @@
+-- Add the 'account_unlocks_at' column to the 'users' table.
+ALTER TABLE `users`
+	ADD COLUMN `account_unlocks_at` TIMESTAMP(3) NULL DEFAULT NULL AFTER `account_is_locked`;
+
+-- Create the 'failed_logins' table.
+CREATE TABLE `failed_logins` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`username` VARCHAR(128) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`ip_address` VARCHAR(45) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`attempted_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `username_attempted_at` (`username`, `attempted_at`) USING BTREE,
+	INDEX `ip_address_attempted_at` (`ip_address`, `attempted_at`) USING BTREE,
+	INDEX `attempted_at` (`attempted_at`) USING BTREE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
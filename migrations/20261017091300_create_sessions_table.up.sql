@@ -0,0 +1,21 @@
+-- Create the 'sessions' table.
+CREATE TABLE `sessions` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`family_id` CHAR(48) NOT NULL COLLATE 'utf8mb4_bin',
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`refresh_token_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`authenticated_at` TIMESTAMP(3) NOT NULL,
+	`session_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`expires_at` TIMESTAMP(3) NOT NULL,
+	`rotated_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	`revoked_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `refresh_token_hash` (`refresh_token_hash`) USING BTREE,
+	INDEX `family_id` (`family_id`) USING BTREE,
+	INDEX `expires_at` (`expires_at`) USING BTREE,
+	INDEX `FK_sessions_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_sessions_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
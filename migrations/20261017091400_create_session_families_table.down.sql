@@ -0,0 +1,13 @@
+-- Move revocation back to the 'sessions' table.
+ALTER TABLE `sessions`
+	DROP FOREIGN KEY `FK_sessions_session_families`,
+	ADD COLUMN `revoked_at` TIMESTAMP(3) NULL DEFAULT NULL AFTER `rotated_at`;
+
+UPDATE
+	`sessions`
+	INNER JOIN `session_families` ON `session_families`.`id` = `sessions`.`family_id`
+SET
+	`sessions`.`revoked_at` = `session_families`.`revoked_at`;
+
+-- Drop the 'session_families' table.
+DROP TABLE `session_families`;
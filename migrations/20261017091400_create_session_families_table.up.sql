@@ -0,0 +1,44 @@
+-- Create the 'session_families' table.
+CREATE TABLE `session_families` (
+	`id` CHAR(48) NOT NULL COLLATE 'utf8mb4_bin',
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`user_agent` VARCHAR(512) NULL DEFAULT NULL COLLATE 'utf8mb4_unicode_ci',
+	`ip_address` VARCHAR(45) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`family_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`last_seen_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`revoked_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	INDEX `FK_session_families_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_session_families_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB;
+
+-- Create a family for each existing session family, so that existing logins remain valid.
+INSERT INTO `session_families` (
+	`id`,
+	`user_id`,
+	`user_agent`,
+	`ip_address`,
+	`family_created_at`,
+	`last_seen_at`,
+	`revoked_at`
+)
+SELECT
+	`family_id`,
+	MIN(`user_id`),
+	NULL,
+	'',
+	MIN(`authenticated_at`),
+	MAX(`session_created_at`),
+	MAX(`revoked_at`)
+FROM
+	`sessions`
+GROUP BY
+	`family_id`;
+
+-- Move revocation from the 'sessions' table to the 'session_families' table, and make sure every
+-- session belongs to a family.
+ALTER TABLE `sessions`
+	DROP COLUMN `revoked_at`,
+	ADD CONSTRAINT `FK_sessions_session_families` FOREIGN KEY (`family_id`) REFERENCES `session_families` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE;
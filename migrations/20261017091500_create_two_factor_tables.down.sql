@@ -0,0 +1,8 @@
+-- Drop the 'two_factor_challenges' table.
+DROP TABLE `two_factor_challenges`;
+
+-- Drop the 'recovery_codes' table.
+DROP TABLE `recovery_codes`;
+
+-- Drop the 'two_factor_credentials' table.
+DROP TABLE `two_factor_credentials`;
@@ -0,0 +1,43 @@
+-- Create the 'two_factor_credentials' table.
+CREATE TABLE `two_factor_credentials` (
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`secret` VARCHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`credential_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`enabled_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	`last_used_step` BIGINT(20) NULL DEFAULT NULL,
+	PRIMARY KEY (`user_id`) USING BTREE,
+	CONSTRAINT `FK_two_factor_credentials_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB;
+
+-- Create the 'recovery_codes' table.
+CREATE TABLE `recovery_codes` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`code_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`code_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `user_id_code_hash` (`user_id`, `code_hash`) USING BTREE,
+	CONSTRAINT `FK_recovery_codes_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Create the 'two_factor_challenges' table.
+CREATE TABLE `two_factor_challenges` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`challenge_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`challenge_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`expires_at` TIMESTAMP(3) NOT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `challenge_hash` (`challenge_hash`) USING BTREE,
+	INDEX `expires_at` (`expires_at`) USING BTREE,
+	INDEX `FK_two_factor_challenges_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_two_factor_challenges_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,5 @@
+-- Drop the 'oidc_authorization_requests' table.
+DROP TABLE `oidc_authorization_requests`;
+
+-- Drop the 'user_identities' table.
+DROP TABLE `user_identities`;
@@ -0,0 +1,32 @@
+-- Create the 'user_identities' table.
+CREATE TABLE `user_identities` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`provider` VARCHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`subject` VARCHAR(255) NOT NULL COLLATE 'utf8mb4_bin',
+	`identity_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `provider_subject` (`provider`, `subject`) USING BTREE,
+	INDEX `FK_user_identities_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_user_identities_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
+
+-- Create the 'oidc_authorization_requests' table.
+CREATE TABLE `oidc_authorization_requests` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`provider` VARCHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`state_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`code_verifier` VARCHAR(128) NOT NULL COLLATE 'utf8mb4_bin',
+	`nonce` VARCHAR(128) NOT NULL COLLATE 'utf8mb4_bin',
+	`request_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`expires_at` TIMESTAMP(3) NOT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `state_hash` (`state_hash`) USING BTREE,
+	INDEX `expires_at` (`expires_at`) USING BTREE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,2 @@
+-- Drop the 'api_tokens' table.
+DROP TABLE `api_tokens`;
@@ -0,0 +1,19 @@
+-- Create the 'api_tokens' table.
+CREATE TABLE `api_tokens` (
+	`id` BIGINT(20) UNSIGNED NOT NULL AUTO_INCREMENT,
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`name` VARCHAR(256) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`token_hash` CHAR(64) NOT NULL COLLATE 'utf8mb4_bin',
+	`scopes` LONGTEXT NOT NULL COLLATE 'utf8mb4_bin',
+	`token_created_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	`expires_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	`last_used_at` TIMESTAMP(3) NULL DEFAULT NULL,
+	PRIMARY KEY (`id`) USING BTREE,
+	UNIQUE INDEX `token_hash` (`token_hash`) USING BTREE,
+	INDEX `FK_api_tokens_users` (`user_id`) USING BTREE,
+	CONSTRAINT `FK_api_tokens_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE,
+	CONSTRAINT `scopes` CHECK (json_valid(`scopes`))
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB
+AUTO_INCREMENT=1;
@@ -0,0 +1,2 @@
+-- Drop the 'user_roles' table.
+DROP TABLE `user_roles`;
@@ -0,0 +1,10 @@
+-- Create the 'user_roles' table.
+CREATE TABLE `user_roles` (
+	`user_id` BIGINT(20) UNSIGNED NOT NULL,
+	`role` VARCHAR(32) NOT NULL COLLATE 'utf8mb4_unicode_ci',
+	`role_granted_at` TIMESTAMP(3) NOT NULL DEFAULT current_timestamp(3),
+	PRIMARY KEY (`user_id`, `role`) USING BTREE,
+	CONSTRAINT `FK_user_roles_users` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON UPDATE NO ACTION ON DELETE CASCADE
+)
+COLLATE='utf8mb4_unicode_ci'
+ENGINE=InnoDB;
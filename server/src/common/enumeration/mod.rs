@@ -78,6 +78,42 @@ pub(crate) enum AuthenticationResult {
     /// The NotAuthenticated variant will be returned if authentication was not successful.
     NotAuthenticated,
 
+    /// # Description
+    ///
+    /// The Locked variant will be returned if the user's credentials are valid, but their account
+    /// has been locked.
+    Locked,
+
+    /// # Description
+    ///
+    /// The Banned variant will be returned if the user's credentials are valid, but their account
+    /// has been banned.
+    Banned,
+
+    /// # Description
+    ///
+    /// The PasswordResetRequired variant will be returned if the user's credentials are valid, but
+    /// they must reset their password before they can use their account.
+    PasswordResetRequired,
+
+    /// # Description
+    ///
+    /// The Throttled variant will be returned if too many failed logins were attempted from the
+    /// client's ip address, so that the credentials were not checked.
+    Throttled,
+
+    /// # Description
+    ///
+    /// The InsufficientScope variant will be returned if the request was authenticated with an api
+    /// token that does not have the scope the endpoint requires.
+    InsufficientScope,
+
+    /// # Description
+    ///
+    /// The InsufficientPermission variant will be returned if the user was authenticated, but none
+    /// of their roles grant the permission the endpoint requires.
+    InsufficientPermission,
+
     /// # Description
     ///
     /// The Err variant will be returned if an error occurs during the authentication process.
@@ -1,12 +1,45 @@
+#[cfg(test)]
+mod test;
+
+use crate::common::enumeration::AuthenticationResult;
 use actix_web::{
-    http::header::{HeaderValue, AUTHORIZATION},
-    HttpRequest,
+    cookie::{Cookie, SameSite},
+    http::header::{HeaderValue, AUTHORIZATION, USER_AGENT},
+    HttpRequest, HttpResponse,
 };
 use rand::{distr::Alphanumeric, rng, Rng};
 use serde::Serialize;
+use serde_json::json;
 use std::{borrow::Cow, env};
+use time::Duration;
 use validator::ValidationError;
 
+/// The amount of time (in seconds) that is waited before the first retry of a failed operation.
+const RETRY_BASE_DELAY: i64 = 30;
+
+/// The maximum amount of time (in seconds) that is waited between the retries of a failed
+/// operation.
+const MAXIMUM_RETRY_DELAY: i64 = 3600;
+
+/// The name of the cookie that holds the user's access token.
+const ACCESS_TOKEN_COOKIE: &str = "authorization";
+
+/// The name of the cookie that holds the user's refresh token.
+const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+/// The path the refresh token cookie is sent to, since it is only needed by the auth endpoints.
+const REFRESH_TOKEN_COOKIE_PATH: &str = "/auth";
+
+/// The name of the cookie that holds the state of a single sign-on login, which ties the login to
+/// the client that started it.
+const OIDC_STATE_COOKIE: &str = "oidc_state";
+
+/// The path the single sign-on state cookie is sent to.
+const OIDC_STATE_COOKIE_PATH: &str = "/auth/oidc";
+
+/// The maximum amount of characters of a client's user agent that are kept.
+const MAXIMUM_USER_AGENT_LENGTH: usize = 512;
+
 /// # Description
 ///
 /// Get the file path of the server's configuration file.
@@ -117,12 +150,246 @@ pub(crate) fn get_token_from_header(request: &HttpRequest) -> Option<String> {
 /// An option that will contain the token if it was found.
 pub(crate) fn get_token_from_cookie(request: &HttpRequest) -> Option<String> {
     // Get the authorization cookie.
-    return match request.cookie("authorization") {
+    return match request.cookie(ACCESS_TOKEN_COOKIE) {
         Some(cookie) => Some(String::from(cookie.value())),
         None => None,
     };
 }
 
+/// # Description
+///
+/// Get the user's refresh token from an http request's cookies.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// # Returns
+///
+/// An option that will contain the refresh token if it was found.
+pub(crate) fn get_refresh_token_from_cookie(request: &HttpRequest) -> Option<String> {
+    return request
+        .cookie(REFRESH_TOKEN_COOKIE)
+        .map(|cookie| String::from(cookie.value()))
+        .filter(|refresh_token| !refresh_token.is_empty());
+}
+
+/// # Description
+///
+/// Create the cookies that hold a user's access token and refresh token.
+///
+/// # Arguments
+///
+/// `access_token` - The user's access token.
+///
+/// `refresh_token` - The user's refresh token.
+///
+/// # Returns
+///
+/// The cookies that were created.
+pub(crate) fn create_authentication_cookies(
+    access_token: &String,
+    refresh_token: &String,
+) -> Vec<Cookie<'static>> {
+    return vec![
+        create_authentication_cookie(ACCESS_TOKEN_COOKIE, format!("Bearer {}", access_token), "/"),
+        create_authentication_cookie(
+            REFRESH_TOKEN_COOKIE,
+            refresh_token.clone(),
+            REFRESH_TOKEN_COOKIE_PATH,
+        ),
+    ];
+}
+
+/// # Description
+///
+/// Create the cookies that clear a user's access token and refresh token.
+///
+/// # Returns
+///
+/// The cookies that were created.
+pub(crate) fn create_cleared_authentication_cookies() -> Vec<Cookie<'static>> {
+    return vec![
+        create_authentication_cookie(ACCESS_TOKEN_COOKIE, String::new(), "/"),
+        create_authentication_cookie(
+            REFRESH_TOKEN_COOKIE,
+            String::new(),
+            REFRESH_TOKEN_COOKIE_PATH,
+        ),
+    ];
+}
+
+/// # Description
+///
+/// Get the state of a single sign-on login from an http request's cookies.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// # Returns
+///
+/// An option that will contain the state if it was found.
+pub(crate) fn get_oidc_state_from_cookie(request: &HttpRequest) -> Option<String> {
+    return request
+        .cookie(OIDC_STATE_COOKIE)
+        .map(|cookie| String::from(cookie.value()))
+        .filter(|state| !state.is_empty());
+}
+
+/// # Description
+///
+/// Create the cookie that holds the state of a single sign-on login.
+///
+/// # Arguments
+///
+/// `state` - The state of the login.
+///
+/// # Returns
+///
+/// The cookie that was created.
+pub(crate) fn create_oidc_state_cookie(state: &String) -> Cookie<'static> {
+    return create_authentication_cookie(OIDC_STATE_COOKIE, state.clone(), OIDC_STATE_COOKIE_PATH);
+}
+
+/// # Description
+///
+/// Create the cookie that clears the state of a single sign-on login.
+///
+/// # Returns
+///
+/// The cookie that was created.
+pub(crate) fn create_cleared_oidc_state_cookie() -> Cookie<'static> {
+    return create_authentication_cookie(OIDC_STATE_COOKIE, String::new(), OIDC_STATE_COOKIE_PATH);
+}
+
+/// # Description
+///
+/// Create a cookie that holds an authentication secret, which can not be read by scripts or sent
+/// to other sites.
+///
+/// # Arguments
+///
+/// `name` - The name of the cookie.
+///
+/// `value` - The value of the cookie.
+///
+/// `path` - The path the cookie is sent to.
+///
+/// # Returns
+///
+/// The cookie that was created.
+fn create_authentication_cookie(
+    name: &'static str,
+    value: String,
+    path: &'static str,
+) -> Cookie<'static> {
+    return Cookie::build(name, value)
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Strict)
+        .path(path)
+        .expires(None)
+        .finish();
+}
+
+/// # Description
+///
+/// Create the http response for an authentication result that did not authenticate a user.
+///
+/// Users whose credentials are valid but whose account can not be used receive a 403 response
+/// with a machine-readable reason, such as `{"reason": "accountBanned"}`, so that clients can tell
+/// them why. Api tokens that do not have the scope an endpoint requires receive a 403 response with
+/// the `insufficientScope` reason, and users whose roles do not grant the permission an endpoint
+/// requires receive a 403 response with the `insufficientPermission` reason. Clients that
+/// attempted too many failed logins receive a 429 response with the `tooManyFailedLogins` reason.
+///
+/// # Arguments
+///
+/// `result` - The authentication result.
+///
+/// # Returns
+///
+/// The http response that was created. The Ok variant is not a failure, so it is treated as an
+/// unexpected error.
+pub(crate) fn create_authentication_failure_response(result: AuthenticationResult) -> HttpResponse {
+    return match result {
+        AuthenticationResult::NotAuthenticated => HttpResponse::Unauthorized().finish(),
+        AuthenticationResult::Locked => {
+            HttpResponse::Forbidden().json(json!({ "reason": "accountLocked" }))
+        }
+        AuthenticationResult::Banned => {
+            HttpResponse::Forbidden().json(json!({ "reason": "accountBanned" }))
+        }
+        AuthenticationResult::PasswordResetRequired => {
+            HttpResponse::Forbidden().json(json!({ "reason": "passwordResetRequired" }))
+        }
+        AuthenticationResult::Throttled => {
+            HttpResponse::TooManyRequests().json(json!({ "reason": "tooManyFailedLogins" }))
+        }
+        AuthenticationResult::InsufficientScope => {
+            HttpResponse::Forbidden().json(json!({ "reason": "insufficientScope" }))
+        }
+        AuthenticationResult::InsufficientPermission => {
+            HttpResponse::Forbidden().json(json!({ "reason": "insufficientPermission" }))
+        }
+        AuthenticationResult::Ok(_) | AuthenticationResult::Err(_) => {
+            HttpResponse::InternalServerError().finish()
+        }
+    };
+}
+
+/// # Description
+///
+/// Create the http response for a user who must verify their email address before they can use an
+/// endpoint. Like the responses for restricted accounts, it is a 403 response with the
+/// `emailNotVerified` reason, so that clients can tell the user why.
+///
+/// # Returns
+///
+/// The http response that was created.
+pub(crate) fn create_email_not_verified_response() -> HttpResponse {
+    return HttpResponse::Forbidden().json(json!({ "reason": "emailNotVerified" }));
+}
+
+/// # Description
+///
+/// Get the ip address of the client that sent a request. The address of the peer connected to the
+/// server is used rather than forwarding headers, since those can be set by the client.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// # Returns
+///
+/// The ip address of the client, or an empty string if it is not known.
+pub(crate) fn get_client_ip_address(request: &HttpRequest) -> String {
+    return request
+        .peer_addr()
+        .map(|address| address.ip().to_string())
+        .unwrap_or_default();
+}
+
+/// # Description
+///
+/// Get the user agent of the client that sent a request.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// # Returns
+///
+/// The user agent of the client, or None if it did not send a valid one.
+pub(crate) fn get_client_user_agent(request: &HttpRequest) -> Option<String> {
+    return request
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|user_agent| user_agent.to_str().ok())
+        .map(|user_agent| user_agent.chars().take(MAXIMUM_USER_AGENT_LENGTH).collect());
+}
+
 /// # Description
 ///
 /// Map a borrowed vector of type I to an owned vector of type O.
@@ -139,3 +406,46 @@ pub(crate) fn get_token_from_cookie(request: &HttpRequest) -> Option<String> {
 pub(crate) fn map_to_owned<I, O>(vec: &Vec<I>, map_fn: fn(&I) -> O) -> Vec<O> {
     vec.into_iter().map(map_fn).collect::<Vec<O>>()
 }
+
+/// # Description
+///
+/// Get the amount of time to wait before retrying a failed operation, such as a notification
+/// delivery or an email. The delay doubles after each attempt, up to a maximum.
+///
+/// # Arguments
+///
+/// `attempt_count` - The amount of times the operation has been attempted.
+///
+/// # Returns
+///
+/// The amount of time to wait before the next attempt.
+pub(crate) fn get_retry_delay(attempt_count: u32) -> Duration {
+    let exponent: u32 = attempt_count.saturating_sub(1).min(16);
+    let delay: i64 = RETRY_BASE_DELAY.saturating_mul(1 << exponent);
+    return Duration::seconds(delay.min(MAXIMUM_RETRY_DELAY));
+}
+
+/// # Description
+///
+/// Percent-encode a value so that it can be included in a uri. Every character except the
+/// unreserved characters (RFC 3986) is encoded.
+///
+/// # Arguments
+///
+/// `value` - The value to encode.
+///
+/// # Returns
+///
+/// The percent-encoded value.
+pub(crate) fn percent_encode(value: &String) -> String {
+    let mut encoded: String = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    return encoded;
+}
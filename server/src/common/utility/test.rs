@@ -0,0 +1,149 @@
+use crate::common::{
+    enumeration::AuthenticationResult,
+    utility::{
+        create_authentication_failure_response, create_email_not_verified_response,
+        get_client_ip_address, get_client_user_agent, get_retry_delay, percent_encode,
+    },
+};
+use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, HttpResponse};
+use time::Duration;
+
+/// # Description
+///
+/// Test calculating the delay before each retry, and make sure it doubles after each attempt up to
+/// the maximum delay.
+#[test]
+fn retry_delays_back_off_exponentially() {
+    assert_eq!(Duration::seconds(30), get_retry_delay(1));
+    assert_eq!(Duration::seconds(60), get_retry_delay(2));
+    assert_eq!(Duration::seconds(120), get_retry_delay(3));
+    assert_eq!(Duration::seconds(1920), get_retry_delay(7));
+    assert_eq!(Duration::HOUR, get_retry_delay(8));
+    assert_eq!(Duration::HOUR, get_retry_delay(u32::MAX));
+}
+
+/// # Description
+///
+/// Test creating the responses for failed authentication, and make sure restricted accounts are
+/// forbidden with a machine-readable reason.
+#[test]
+fn restricted_accounts_are_forbidden_with_a_reason() {
+    let response: HttpResponse =
+        create_authentication_failure_response(AuthenticationResult::NotAuthenticated);
+    assert_eq!(StatusCode::UNAUTHORIZED, response.status());
+
+    for (result, reason) in [
+        (AuthenticationResult::Locked, "accountLocked"),
+        (AuthenticationResult::Banned, "accountBanned"),
+        (
+            AuthenticationResult::PasswordResetRequired,
+            "passwordResetRequired",
+        ),
+        (AuthenticationResult::InsufficientScope, "insufficientScope"),
+        (
+            AuthenticationResult::InsufficientPermission,
+            "insufficientPermission",
+        ),
+    ] {
+        let response: HttpResponse = create_authentication_failure_response(result);
+        assert_eq!(StatusCode::FORBIDDEN, response.status());
+
+        let body = response
+            .into_body()
+            .try_into_bytes()
+            .expect("Failed to read the response body");
+        assert_eq!(
+            format!("{{\"reason\":\"{}\"}}", reason).as_bytes(),
+            &body[..]
+        );
+    }
+}
+
+/// # Description
+///
+/// Test creating the response for a client that attempted too many failed logins, and make sure it
+/// is rate limited with a machine-readable reason.
+#[test]
+fn throttled_clients_are_rate_limited_with_a_reason() {
+    let response: HttpResponse =
+        create_authentication_failure_response(AuthenticationResult::Throttled);
+    assert_eq!(StatusCode::TOO_MANY_REQUESTS, response.status());
+
+    let body = response
+        .into_body()
+        .try_into_bytes()
+        .expect("Failed to read the response body");
+    assert_eq!(b"{\"reason\":\"tooManyFailedLogins\"}", &body[..]);
+}
+
+/// # Description
+///
+/// Test creating the response for a user who has not verified their email address, and make sure it
+/// is forbidden with a machine-readable reason.
+#[test]
+fn unverified_users_are_forbidden_with_a_reason() {
+    let response: HttpResponse = create_email_not_verified_response();
+    assert_eq!(StatusCode::FORBIDDEN, response.status());
+
+    let body = response
+        .into_body()
+        .try_into_bytes()
+        .expect("Failed to read the response body");
+    assert_eq!(b"{\"reason\":\"emailNotVerified\"}", &body[..]);
+}
+
+/// # Description
+///
+/// Test getting the ip address of a client, and make sure forwarding headers are ignored.
+#[test]
+fn client_ip_addresses_come_from_the_peer_address() {
+    let request = TestRequest::default()
+        .peer_addr("192.0.2.1:4000".parse().expect("Invalid socket address"))
+        .insert_header(("X-Forwarded-For", "198.51.100.1"))
+        .to_http_request();
+    assert_eq!("192.0.2.1", get_client_ip_address(&request));
+
+    let request = TestRequest::default().to_http_request();
+    assert_eq!("", get_client_ip_address(&request));
+}
+
+/// # Description
+///
+/// Test getting the user agent of a client, and make sure long user agents are truncated.
+#[test]
+fn client_user_agents_are_truncated() {
+    let request = TestRequest::default()
+        .insert_header(("User-Agent", "Mozilla/5.0"))
+        .to_http_request();
+    assert_eq!(
+        Some(String::from("Mozilla/5.0")),
+        get_client_user_agent(&request)
+    );
+
+    let request = TestRequest::default()
+        .insert_header(("User-Agent", "a".repeat(1000)))
+        .to_http_request();
+    assert_eq!(
+        Some(512),
+        get_client_user_agent(&request).map(|agent| agent.len())
+    );
+
+    let request = TestRequest::default().to_http_request();
+    assert_eq!(None, get_client_user_agent(&request));
+}
+
+/// # Description
+///
+/// Test percent-encoding values, and make sure only reserved and non-ascii characters are encoded.
+#[test]
+fn reserved_characters_are_percent_encoded() {
+    assert_eq!(
+        "Easy-Tracker_1.0~",
+        percent_encode(&String::from("Easy-Tracker_1.0~"))
+    );
+    assert_eq!(
+        "Easy%20Tracker%3Ajohn%40example.com",
+        percent_encode(&String::from("Easy Tracker:john@example.com"))
+    );
+    assert_eq!("%C3%A9", percent_encode(&String::from("é")));
+}
@@ -19,6 +19,30 @@ pub(crate) struct Config {
 
     /// The server's default value configuration.
     pub(crate) default: DefaultValueConfig,
+
+    /// The server's user account configuration.
+    pub(crate) account: AccountConfig,
+
+    /// The server's login protection configuration.
+    pub(crate) login: LoginConfig,
+
+    /// The server's two-factor authentication configuration.
+    pub(crate) two_factor: TwoFactorConfig,
+
+    /// The server's single sign-on configuration.
+    pub(crate) oidc: OidcConfig,
+
+    /// The server's check scheduler configuration.
+    pub(crate) scheduler: SchedulerConfig,
+
+    /// The server's check result retention configuration.
+    pub(crate) retention: RetentionConfig,
+
+    /// The server's notification delivery configuration.
+    pub(crate) notification: NotificationConfig,
+
+    /// The server's outbound mail configuration.
+    pub(crate) mail: MailConfig,
 }
 
 /// The logging configuration for the server.
@@ -70,13 +94,65 @@ pub(crate) struct DatabaseConfig {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct JwtConfig {
-    /// The private key that will be used to create tokens.
-    pub(crate) private_key_path: String,
+    /// The id of the key that will be used to create tokens.
+    pub(crate) active_key_id: String,
+
+    /// The keys that will be used to create and verify tokens. When the active key is rotated, the
+    /// previous key should be kept until the tokens it created have expired, so that users are not
+    /// logged out.
+    pub(crate) keys: Vec<JwtKeyConfig>,
+
+    /// The amount of time (in seconds) access tokens are valid for.
+    pub(crate) access_token_lifetime: u64,
+
+    /// The amount of time (in seconds) refresh tokens are valid for. Each time a refresh token is
+    /// used, it is replaced by a new one that is valid for this amount of time.
+    pub(crate) refresh_token_lifetime: u64,
+}
+
+/// The configuration of a key that is used to create or verify json web tokens.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JwtKeyConfig {
+    /// The unique identifier of the key, which is included as the `kid` in the header of the tokens
+    /// it creates.
+    pub(crate) id: String,
+
+    /// The algorithm the key is used with.
+    pub(crate) algorithm: JwtAlgorithm,
+
+    /// The private key that will be used to create tokens. This is only required for the active
+    /// key, since retired keys are only used to verify tokens.
+    pub(crate) private_key_path: Option<String>,
 
     /// The public key that will be used to verify tokens.
     pub(crate) public_key_path: String,
 }
 
+/// An enumeration of the algorithms json web tokens can be signed with.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) enum JwtAlgorithm {
+    /// # Description
+    ///
+    /// The Rs256 variant is used for RSA keys, which sign tokens with RSASSA-PKCS1-v1_5 and
+    /// SHA-256.
+    #[serde(rename = "RS256")]
+    Rs256,
+
+    /// # Description
+    ///
+    /// The Es256 variant is used for elliptic curve keys on the P-256 curve, which sign tokens with
+    /// ECDSA and SHA-256.
+    #[serde(rename = "ES256")]
+    Es256,
+
+    /// # Description
+    ///
+    /// The EdDsa variant is used for Ed25519 keys, which sign tokens with EdDSA.
+    #[serde(rename = "EdDSA")]
+    EdDsa,
+}
+
 /// The default value configuration for the server.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -85,6 +161,229 @@ pub(crate) struct DefaultValueConfig {
     pub(crate) user_profile_picture: String,
 }
 
+/// The user account configuration for the server.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccountConfig {
+    /// The url of the page users verify their email address on. The verification token is appended
+    /// to it as the `token` query parameter.
+    pub(crate) email_verification_url: String,
+
+    /// The amount of time (in seconds) email verification links are valid for.
+    pub(crate) email_verification_lifetime: u64,
+
+    /// If users must verify their email address before they can create monitors or notification
+    /// channels.
+    pub(crate) require_verified_email: bool,
+
+    /// The url of the page users reset their password on. The password reset token is appended to
+    /// it as the `token` query parameter.
+    pub(crate) password_reset_url: String,
+
+    /// The amount of time (in seconds) password reset links are valid for.
+    pub(crate) password_reset_lifetime: u64,
+
+    /// The url of the page users unlock their account on after it was locked because of failed
+    /// logins. The unlock token is appended to it as the `token` query parameter.
+    pub(crate) account_unlock_url: String,
+}
+
+/// The login protection configuration for the server.
+///
+/// Failed logins are counted per username and per ip address. Accounts with too many failed logins
+/// are locked temporarily, and ip addresses with too many failed logins can not attempt to login
+/// until their failed logins fall outside of the window.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LoginConfig {
+    /// The amount of time (in seconds) failed logins are counted for.
+    pub(crate) failed_login_window: u64,
+
+    /// The amount of failed logins a username can have within the window before its account is
+    /// locked.
+    pub(crate) maximum_failed_logins_per_user: u64,
+
+    /// The amount of failed logins an ip address can have within the window before it can not
+    /// attempt to login.
+    pub(crate) maximum_failed_logins_per_ip_address: u64,
+
+    /// The amount of time (in seconds) accounts are locked for after too many failed logins.
+    pub(crate) lockout_duration: u64,
+}
+
+/// The two-factor authentication configuration for the server.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TwoFactorConfig {
+    /// The name of the issuer that is shown next to accounts in authenticator apps.
+    pub(crate) issuer: String,
+
+    /// The amount of time (in seconds) users have to enter their second factor after entering their
+    /// password.
+    pub(crate) challenge_lifetime: u64,
+}
+
+/// The single sign-on configuration for the server.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OidcConfig {
+    /// The amount of time (in seconds) users have to login with an identity provider after they
+    /// are sent to it.
+    pub(crate) authorization_lifetime: u64,
+
+    /// The amount of time (in seconds) identity providers are allowed to take to respond.
+    pub(crate) timeout: u64,
+
+    /// The identity providers users can login with.
+    pub(crate) providers: Vec<OidcProviderConfig>,
+}
+
+/// The configuration of an OpenID Connect identity provider users can login with.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OidcProviderConfig {
+    /// The unique name of the identity provider, which is used in the login urls.
+    pub(crate) name: String,
+
+    /// The name of the identity provider that is shown to users.
+    pub(crate) display_name: String,
+
+    /// The issuer identifier of the identity provider, which its discovery document is located
+    /// under.
+    pub(crate) issuer: String,
+
+    /// The client id the server is registered with at the identity provider.
+    pub(crate) client_id: String,
+
+    /// The client secret the server is registered with at the identity provider, if it is a
+    /// confidential client.
+    pub(crate) client_secret: Option<String>,
+
+    /// The uri the identity provider redirects users back to after they login, which must pass the
+    /// code and state it receives to the callback endpoint.
+    pub(crate) redirect_uri: String,
+
+    /// The scopes that are requested from the identity provider.
+    pub(crate) scopes: Vec<String>,
+
+    /// If accounts are created for users that login with the identity provider and do not have one
+    /// yet.
+    pub(crate) allow_user_creation: bool,
+}
+
+/// The check scheduler configuration for the server.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SchedulerConfig {
+    /// The amount of time (in seconds) between each time the scheduler looks for monitors that are
+    /// due to be checked.
+    pub(crate) tick_interval: u64,
+
+    /// The maximum amount of checks that are allowed to be performed at the same time.
+    pub(crate) maximum_concurrent_checks: usize,
+}
+
+/// The check result retention configuration for the server.
+///
+/// Check results are rolled up into hourly and daily summaries before they are deleted, so that
+/// statistics can still be reported for time ranges whose check results no longer exist.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RetentionConfig {
+    /// The amount of time (in seconds) between each time check results are rolled up and expired
+    /// data is deleted.
+    pub(crate) interval: u64,
+
+    /// The amount of days check results are kept for before they are deleted. This must be at least
+    /// 2, since daily rollups are created from the check results once each day has ended.
+    pub(crate) check_result_days: u32,
+
+    /// The amount of days hourly rollups are kept for before they are deleted.
+    pub(crate) hourly_rollup_days: u32,
+
+    /// The amount of days daily rollups are kept for before they are deleted (0 keeps them forever).
+    pub(crate) daily_rollup_days: u32,
+}
+
+/// The notification delivery configuration for the server.
+///
+/// Failed deliveries are retried with an exponential backoff until they succeed or every attempt
+/// has been used.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotificationConfig {
+    /// The amount of time (in seconds) between each time the delivery worker looks for deliveries
+    /// that are due to be attempted.
+    pub(crate) interval: u64,
+
+    /// The amount of time (in seconds) a webhook is allowed to take to respond before the attempt
+    /// is considered to have failed.
+    pub(crate) timeout: u64,
+
+    /// The maximum amount of times a delivery is attempted before it is marked as failed.
+    pub(crate) maximum_attempts: u32,
+}
+
+/// The outbound mail configuration for the server.
+///
+/// Emails are queued and sent by a background worker, and emails that could not be sent are retried
+/// with an exponential backoff until they are sent or every attempt has been used.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MailConfig {
+    /// The host of the smtp server emails will be sent through.
+    pub(crate) host: String,
+
+    /// The port of the smtp server emails will be sent through.
+    pub(crate) port: u16,
+
+    /// The security that will be used for connections to the smtp server.
+    pub(crate) security: MailSecurity,
+
+    /// The username that will be used to authenticate with the smtp server, if it requires
+    /// authentication.
+    pub(crate) username: Option<String>,
+
+    /// The password that will be used to authenticate with the smtp server, if it requires
+    /// authentication.
+    pub(crate) password: Option<String>,
+
+    /// The mailbox emails will be sent from, such as `EasyTracker <noreply@example.com>`.
+    pub(crate) sender: String,
+
+    /// The amount of time (in seconds) the smtp server is allowed to take to respond before the
+    /// attempt is considered to have failed.
+    pub(crate) timeout: u64,
+
+    /// The amount of time (in seconds) between each time the mail worker looks for emails that are
+    /// due to be sent.
+    pub(crate) interval: u64,
+
+    /// The maximum amount of times sending an email is attempted before it is marked as failed.
+    pub(crate) maximum_attempts: u32,
+}
+
+/// An enumeration of the security that can be used for connections to an smtp server.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum MailSecurity {
+    /// # Description
+    ///
+    /// The None variant is used for smtp servers that do not support encryption, such as a local
+    /// relay.
+    None,
+
+    /// # Description
+    ///
+    /// The StartTls variant is used for smtp servers that upgrade plain connections to tls.
+    StartTls,
+
+    /// # Description
+    ///
+    /// The Tls variant is used for smtp servers that only accept tls connections.
+    Tls,
+}
+
 /// An implementation for the Config struct.
 impl Config {
     /// # Description
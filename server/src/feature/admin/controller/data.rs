@@ -0,0 +1,30 @@
+use crate::feature::user::model::{User, UserRole};
+use serde::{Deserialize, Serialize};
+
+/// A get users request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetUsersRequestParams {
+    /// The term the users' usernames and email addresses are searched for. If this is not set,
+    /// every user is returned.
+    pub(super) search: Option<String>,
+
+    /// The maximum amount of users to return.
+    pub(super) limit: Option<u32>,
+
+    /// The amount of matching users to skip.
+    pub(super) offset: Option<u64>,
+}
+
+/// A get user response body struct, which describes a user's account and the roles they have been
+/// granted.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetUserResponseBody {
+    /// The user.
+    #[serde(flatten)]
+    pub(super) user: User,
+
+    /// The roles the user has been granted.
+    pub(super) roles: Vec<UserRole>,
+}
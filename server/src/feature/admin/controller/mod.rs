@@ -0,0 +1,481 @@
+mod data;
+
+use self::data::{GetUserResponseBody, GetUsersRequestParams};
+use crate::{
+    common::{
+        enumeration::{AuthenticationResult, QueryResult, UpdateResult},
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        auth::service::AuthService,
+        user::{
+            model::{AccountAction, Permission, User, UserRole},
+            service::UserService,
+        },
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, post, put,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// The amount of users that will be returned if a limit is not specified.
+const DEFAULT_USER_LIMIT: u32 = 100;
+
+/// The maximum amount of users that can be returned.
+const MAXIMUM_USER_LIMIT: u32 = 1000;
+
+/// # Description
+///
+/// Add the admin controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the admin controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/admin")
+            .service(get_users)
+            .service(get_user)
+            .service(lock_user)
+            .service(unlock_user)
+            .service(ban_user)
+            .service(unban_user)
+            .service(require_password_reset)
+            .service(grant_role)
+            .service(revoke_role),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to list the users, optionally only those whose username or email address
+/// contains a search term.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `params` - The request query parameters.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to search the users.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/users")]
+async fn get_users(
+    request: HttpRequest,
+    params: web::Query<GetUsersRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authorize the user.
+    match auth_service
+        .authorize_request(&request, Permission::UsersRead)
+        .await
+    {
+        AuthenticationResult::Ok(_) => {}
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Determine which users to return.
+    let search_term: String = params.search.clone().unwrap_or_default();
+    let limit: u32 = params
+        .limit
+        .unwrap_or(DEFAULT_USER_LIMIT)
+        .min(MAXIMUM_USER_LIMIT);
+    let offset: u64 = params.offset.unwrap_or(0);
+
+    // Search the users.
+    return match user_service.search(&search_term, &limit, &offset).await {
+        Ok(users) => HttpResponse::Ok().json(users),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get a user, and the roles they have been granted.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to get the user and their roles.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/users/{id}")]
+async fn get_user(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authorize the user.
+    match auth_service
+        .authorize_request(&request, Permission::UsersRead)
+        .await
+    {
+        AuthenticationResult::Ok(_) => {}
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the user.
+    let user: User = match user_service.get_by_id(&id).await {
+        QueryResult::Ok(user) => user,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the user's roles.
+    return match user_service.get_roles(&user.id).await {
+        Ok(roles) => HttpResponse::Ok().json(GetUserResponseBody { user, roles }),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to lock a user's account until it is unlocked by an administrator.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user whose account is being locked.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to lock the user's account.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/users/{id}/lock")]
+async fn lock_user(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    return perform_account_action(
+        &request,
+        &id,
+        &AccountAction::Lock,
+        &auth_service,
+        &user_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to unlock a user's account, whether it was locked by an administrator or
+/// temporarily because of failed logins.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user whose account is being unlocked.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to unlock the user's account.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/users/{id}/unlock")]
+async fn unlock_user(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    return perform_account_action(
+        &request,
+        &id,
+        &AccountAction::Unlock,
+        &auth_service,
+        &user_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to ban a user's account.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user whose account is being banned.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to ban the user's account.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/users/{id}/ban")]
+async fn ban_user(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    return perform_account_action(
+        &request,
+        &id,
+        &AccountAction::Ban,
+        &auth_service,
+        &user_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to lift the ban of a user's account.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user whose account is being unbanned.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to unban the user's account.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/users/{id}/unban")]
+async fn unban_user(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    return perform_account_action(
+        &request,
+        &id,
+        &AccountAction::Unban,
+        &auth_service,
+        &user_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to require a user to reset their password before they can use their account.
+/// The user is emailed a password reset link.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user who must reset their password.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to require the password reset.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/users/{id}/require-password-reset")]
+async fn require_password_reset(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    return perform_account_action(
+        &request,
+        &id,
+        &AccountAction::RequirePasswordReset,
+        &auth_service,
+        &user_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to grant a role to a user.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `path` - The id of the user, and the role being granted to them.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to grant the role.
+///
+/// # Returns
+///
+/// An http response.
+#[put("/users/{id}/roles/{role}")]
+async fn grant_role(
+    request: HttpRequest,
+    path: web::Path<(u64, UserRole)>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authorize the user.
+    match auth_service
+        .authorize_request(&request, Permission::RolesWrite)
+        .await
+    {
+        AuthenticationResult::Ok(_) => {}
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Make sure the user the role is being granted to exists.
+    let (id, role) = path.into_inner();
+    match user_service.get_by_id(&id).await {
+        QueryResult::Ok(_) => {}
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Grant the role, and return the user's roles.
+    return match user_service.grant_role(&id, &role).await {
+        Ok(roles) => HttpResponse::Ok().json(roles),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to revoke a role from a user. Users can not revoke their own roles, so that
+/// there is always an administrator who can manage roles.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `path` - The id of the user, and the role being revoked from them.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to revoke the role.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/users/{id}/roles/{role}")]
+async fn revoke_role(
+    request: HttpRequest,
+    path: web::Path<(u64, UserRole)>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authorize the user.
+    let user: User = match auth_service
+        .authorize_request(&request, Permission::RolesWrite)
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Users can not revoke their own roles.
+    let (id, role) = path.into_inner();
+    if user.id == id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Make sure the user the role is being revoked from exists.
+    match user_service.get_by_id(&id).await {
+        QueryResult::Ok(_) => {}
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Revoke the role, and return the user's remaining roles.
+    return match user_service.revoke_role(&id, &role).await {
+        Ok(roles) => HttpResponse::Ok().json(roles),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// Authorize a request to manage a user's account, and perform an action on the account. Users can
+/// not perform actions on their own account, so that administrators can not lock themselves out.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the user whose account the action is being performed on.
+///
+/// `action` - The action being performed.
+///
+/// `auth_service` - The authentication service that will be used to authorize the user sending the
+/// request.
+///
+/// `user_service` - The user service that will be used to perform the action.
+///
+/// # Returns
+///
+/// An http response.
+async fn perform_account_action(
+    request: &HttpRequest,
+    id: &u64,
+    action: &AccountAction,
+    auth_service: &Inject<DependencyInjector, dyn AuthService>,
+    user_service: &Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authorize the user.
+    let user: User = match auth_service
+        .authorize_request(request, Permission::UsersWrite)
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Users can not perform actions on their own account.
+    if user.id == *id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Perform the action, and return the updated user.
+    return match user_service.perform_account_action(id, action).await {
+        UpdateResult::Ok(updated_user) => HttpResponse::Ok().json(updated_user),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
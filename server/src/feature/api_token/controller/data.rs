@@ -0,0 +1,84 @@
+use crate::feature::api_token::model::{ApiToken, ApiTokenScope};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+
+/// A create api token request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateApiTokenRequestBody {
+    /// The api token's name, which describes what it is used for.
+    pub(super) name: String,
+
+    /// The scopes that determine which endpoints the api token can be used with.
+    pub(super) scopes: Vec<ApiTokenScope>,
+
+    /// The date and time the api token expires at. If this is not set, the api token does not
+    /// expire.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) expires_at: Option<OffsetDateTime>,
+}
+
+/// An Into<ApiToken> implementation for the CreateApiTokenRequestBody struct.
+impl Into<ApiToken> for CreateApiTokenRequestBody {
+    fn into(self) -> ApiToken {
+        return ApiToken {
+            name: self.name,
+            scopes: Json(self.scopes),
+            expires_at: self.expires_at,
+            ..Default::default()
+        };
+    }
+}
+
+/// A get api token response body struct, which describes an api token without its secret.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetApiTokenResponseBody {
+    /// The api token's unique identifier.
+    pub(super) id: u64,
+
+    /// The api token's name.
+    pub(super) name: String,
+
+    /// The scopes that determine which endpoints the api token can be used with.
+    pub(super) scopes: Vec<ApiTokenScope>,
+
+    /// The date and time the api token was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(super) created_at: OffsetDateTime,
+
+    /// The date and time the api token expires at, if it expires.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(super) expires_at: Option<OffsetDateTime>,
+
+    /// The date and time the api token was last used at, if it has been used.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(super) last_used_at: Option<OffsetDateTime>,
+}
+
+/// An Into<GetApiTokenResponseBody> implementation for the ApiToken struct.
+impl Into<GetApiTokenResponseBody> for ApiToken {
+    fn into(self) -> GetApiTokenResponseBody {
+        return GetApiTokenResponseBody {
+            id: self.id,
+            name: self.name,
+            scopes: self.scopes.0,
+            created_at: self.token_created_at,
+            expires_at: self.expires_at,
+            last_used_at: self.last_used_at,
+        };
+    }
+}
+
+/// A create api token response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateApiTokenResponseBody {
+    /// The api token's secret, which can not be shown again.
+    pub(super) token: String,
+
+    /// The api token that was created.
+    #[serde(flatten)]
+    pub(super) api_token: GetApiTokenResponseBody,
+}
@@ -0,0 +1,171 @@
+mod data;
+
+use self::data::{CreateApiTokenRequestBody, CreateApiTokenResponseBody, GetApiTokenResponseBody};
+use crate::{
+    common::{
+        enumeration::{AuthenticationResult, DeletionResult, InsertionResult},
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        api_token::{model::ApiToken, service::ApiTokenService},
+        auth::service::AuthService,
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the api token controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the api token controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/api-tokens")
+            .service(create_api_token)
+            .service(get_api_tokens)
+            .service(delete_api_token),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to create an api token for the user that sent the request. The api token's
+/// secret is only returned in this response.
+///
+/// Api tokens can not be used to manage api tokens, so the request must be authenticated with an
+/// access token.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains the api token's name, scopes and expiry.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `api_token_service` - The api token service that will be used to create the api token.
+///
+/// # Returns
+///
+/// An http response.
+#[post("")]
+async fn create_api_token(
+    request: HttpRequest,
+    body: web::Json<CreateApiTokenRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    api_token_service: Inject<DependencyInjector, dyn ApiTokenService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Convert the request body into an api token.
+    let mut api_token: ApiToken = body.into_inner().into();
+    api_token.user_id = user.id;
+
+    // Create the api token.
+    return match api_token_service.insert(&api_token).await {
+        InsertionResult::Ok((token, created_api_token)) => {
+            HttpResponse::Ok().json(CreateApiTokenResponseBody {
+                token,
+                api_token: created_api_token.into(),
+            })
+        }
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the api tokens of the user that sent the request, without their secrets.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `api_token_service` - The api token service that will be used to get the user's api tokens.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_api_tokens(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    api_token_service: Inject<DependencyInjector, dyn ApiTokenService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the user's api tokens, and convert them into the response body format.
+    let api_tokens: Vec<GetApiTokenResponseBody> =
+        match api_token_service.get_by_user_id(&user.id).await {
+            Ok(api_tokens) => api_tokens
+                .into_iter()
+                .map(|api_token| api_token.into())
+                .collect(),
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // Return the api tokens.
+    return HttpResponse::Ok().json(api_tokens);
+}
+
+/// # Description
+///
+/// An api endpoint to delete one of the api tokens of the user that sent the request, which
+/// revokes it immediately.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the api token that is being deleted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `api_token_service` - The api token service that will be used to delete the api token.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn delete_api_token(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    api_token_service: Inject<DependencyInjector, dyn ApiTokenService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Delete the api token.
+    return match api_token_service.delete(&id, &user.id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
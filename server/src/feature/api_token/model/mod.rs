@@ -0,0 +1,169 @@
+use crate::common::utility::create_value_validation_error;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+use validator::{Validate, ValidationError};
+
+/// The prefix of api token secrets, which lets them be told apart from access tokens when they are
+/// sent as Bearer tokens.
+pub(crate) const API_TOKEN_PREFIX: &str = "et_";
+
+/// An api token struct, which holds the hash of a secret that scripts and other automated clients
+/// can use to authenticate as the user that created it.
+#[derive(Clone, Debug, Validate)]
+pub(crate) struct ApiToken {
+    /// The api token's unique identifier.
+    pub(crate) id: u64,
+
+    /// The id of the user the api token belongs to.
+    pub(crate) user_id: u64,
+
+    /// The api token's name, which describes what it is used for.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) name: String,
+
+    /// The sha-256 hash of the api token's secret, encoded as hex.
+    pub(crate) token_hash: String,
+
+    /// The scopes that determine which endpoints the api token can be used with.
+    #[validate(custom(function = "validate_scopes"))]
+    pub(crate) scopes: Json<Vec<ApiTokenScope>>,
+
+    /// The date and time the api token was created at.
+    pub(crate) token_created_at: OffsetDateTime,
+
+    /// The date and time the api token expires at, if it expires.
+    #[validate(custom(function = "validate_expires_at"))]
+    pub(crate) expires_at: Option<OffsetDateTime>,
+
+    /// The date and time the api token was last used to authenticate a request at, if it has been
+    /// used.
+    pub(crate) last_used_at: Option<OffsetDateTime>,
+}
+
+/// A Default implementation for the ApiToken struct.
+impl Default for ApiToken {
+    fn default() -> Self {
+        return ApiToken {
+            id: 0,
+            user_id: 0,
+            name: String::from(""),
+            token_hash: String::from(""),
+            scopes: Json(Vec::new()),
+            token_created_at: OffsetDateTime::now_utc(),
+            expires_at: None,
+            last_used_at: None,
+        };
+    }
+}
+
+/// An implementation for the ApiToken struct.
+impl ApiToken {
+    /// # Description
+    ///
+    /// Check if the api token has a scope.
+    ///
+    /// # Arguments
+    ///
+    /// `scope` - The scope being checked.
+    ///
+    /// # Returns
+    ///
+    /// If the api token has the scope.
+    pub(crate) fn has_scope(&self, scope: &ApiTokenScope) -> bool {
+        return self.scopes.contains(scope);
+    }
+}
+
+/// An enumeration of all of the scopes an api token can have, which each allow it to be used with
+/// a group of endpoints.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) enum ApiTokenScope {
+    /// # Description
+    ///
+    /// The MonitorsRead variant allows monitors, their checks and their maintenance windows to be
+    /// read.
+    #[serde(rename = "monitors:read")]
+    MonitorsRead,
+
+    /// # Description
+    ///
+    /// The MonitorsWrite variant allows monitors and their maintenance windows to be created,
+    /// updated and deleted.
+    #[serde(rename = "monitors:write")]
+    MonitorsWrite,
+
+    /// # Description
+    ///
+    /// The IncidentsRead variant allows incidents and their updates to be read.
+    #[serde(rename = "incidents:read")]
+    IncidentsRead,
+
+    /// # Description
+    ///
+    /// The IncidentsWrite variant allows updates to be posted to incidents.
+    #[serde(rename = "incidents:write")]
+    IncidentsWrite,
+
+    /// # Description
+    ///
+    /// The FilesRead variant allows files to be read.
+    #[serde(rename = "files:read")]
+    FilesRead,
+
+    /// # Description
+    ///
+    /// The FilesWrite variant allows files to be uploaded, updated and deleted.
+    #[serde(rename = "files:write")]
+    FilesWrite,
+}
+
+/// # Description
+///
+/// Validate the scopes of an api token.
+///
+/// # Arguments
+///
+/// `scopes` - The scopes that are being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the scopes are valid, the Ok variant will be returned.
+/// - If there are no scopes, or a scope appears more than once, the Err variant will be returned
+/// with a validation error.
+fn validate_scopes(scopes: &Json<Vec<ApiTokenScope>>) -> Result<(), ValidationError> {
+    if scopes.is_empty() {
+        return Err(create_value_validation_error("length", &scopes.len()));
+    }
+
+    for (index, scope) in scopes.iter().enumerate() {
+        if scopes[..index].contains(scope) {
+            return Err(create_value_validation_error("duplicate", scope));
+        }
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Validate the date and time an api token expires at.
+///
+/// # Arguments
+///
+/// `expires_at` - The date and time that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the date and time is in the future, the Ok variant will be returned.
+/// - If the date and time has already passed, the Err variant will be returned with a validation
+/// error.
+fn validate_expires_at(expires_at: &OffsetDateTime) -> Result<(), ValidationError> {
+    if *expires_at <= OffsetDateTime::now_utc() {
+        return Err(ValidationError::new("past"));
+    }
+
+    return Ok(());
+}
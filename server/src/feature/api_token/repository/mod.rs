@@ -0,0 +1,307 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::api_token::model::{ApiToken, ApiTokenScope},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// An api token repository trait.
+#[async_trait]
+pub(crate) trait ApiTokenRepository: Interface {
+    /// # Description
+    ///
+    /// Insert an api token into the api token repository.
+    ///
+    /// # Arguments
+    ///
+    /// `api_token` - The api token to insert into the api token repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the api
+    /// token that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, api_token: &ApiToken, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query an api token from the api token repository by the hash of its secret.
+    ///
+    /// # Arguments
+    ///
+    /// `token_hash` - The hash of the api token's secret.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the api token if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_token_hash(
+        &self,
+        token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<ApiToken>, Error>;
+
+    /// # Description
+    ///
+    /// Query the api tokens of a user from the api token repository, ordered from the most to the
+    /// least recently created.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the api tokens belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the api tokens that were
+    /// found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<ApiToken>, Error>;
+
+    /// # Description
+    ///
+    /// Update the date and time an api token was last used at.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the api token.
+    ///
+    /// `last_used_at` - The date and time the api token was last used at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update_last_used_at(
+        &self,
+        id: &u64,
+        last_used_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete an api token that belongs to a user from the api token repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the api token that will be deleted.
+    ///
+    /// `user_id` - The id of the user the api token must belong to.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted, which will be 0 if the api token does not exist or belongs to another user.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete(
+        &self,
+        id: &u64,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+}
+
+/// An ApiTokenRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = ApiTokenRepository)]
+pub(crate) struct ApiTokenRepositoryImpl;
+
+/// An ApiTokenRepository implementation for the ApiTokenRepositoryImpl struct.
+#[async_trait]
+impl ApiTokenRepository for ApiTokenRepositoryImpl {
+    async fn insert(&self, api_token: &ApiToken, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `api_tokens` (
+                    `user_id`,
+                    `name`,
+                    `token_hash`,
+                    `scopes`,
+                    `token_created_at`,
+                    `expires_at`,
+                    `last_used_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            api_token.user_id,
+            api_token.name,
+            api_token.token_hash,
+            &api_token.scopes,
+            api_token.token_created_at,
+            api_token.expires_at,
+            api_token.last_used_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_by_token_hash(
+        &self,
+        token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<ApiToken>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            ApiToken,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `name` AS `name: String`,
+                    `token_hash` AS `token_hash: String`,
+                    `scopes` AS `scopes: Json<Vec<ApiTokenScope>>`,
+                    `token_created_at` AS `token_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`,
+                    `last_used_at` AS `last_used_at: OffsetDateTime`
+                FROM
+                    `api_tokens`
+                WHERE
+                    `token_hash` = ?;
+            "#,
+            token_hash
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<ApiToken>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            ApiToken,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `name` AS `name: String`,
+                    `token_hash` AS `token_hash: String`,
+                    `scopes` AS `scopes: Json<Vec<ApiTokenScope>>`,
+                    `token_created_at` AS `token_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`,
+                    `last_used_at` AS `last_used_at: OffsetDateTime`
+                FROM
+                    `api_tokens`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `token_created_at` DESC,
+                    `id` DESC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update_last_used_at(
+        &self,
+        id: &u64,
+        last_used_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `api_tokens`
+                SET
+                    `last_used_at` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            last_used_at,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete(
+        &self,
+        id: &u64,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `api_tokens`
+                WHERE
+                    `id` = ? AND `user_id` = ?;
+            "#,
+            id,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        api_token::{
+            model::{ApiToken, ApiTokenScope},
+            repository::ApiTokenRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, types::Json, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting api tokens, and make sure they can be queried by the hash of their secret and by
+/// their owner, and that they can only be deleted by their owner.
+#[actix_web::test]
+async fn api_tokens_can_only_be_deleted_by_their_owner() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an api token repository instance.
+    let api_token_repository: Arc<dyn ApiTokenRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert two test users, and an api token for the first one.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let other_user: User = insert_test_user(&injector, &mut context).await;
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut api_token: ApiToken = ApiToken {
+        id: 0,
+        user_id: user.id,
+        name: generate_random_string(8),
+        token_hash: generate_random_string(64),
+        scopes: Json(vec![
+            ApiTokenScope::MonitorsRead,
+            ApiTokenScope::IncidentsWrite,
+        ]),
+        token_created_at: now,
+        expires_at: Some(now + Duration::DAY),
+        last_used_at: None,
+    };
+    api_token.id = api_token_repository
+        .insert(&api_token, &mut context)
+        .await
+        .expect("Failed to insert api token");
+
+    // Make sure the api token can be queried by the hash of its secret.
+    let queried_api_token: ApiToken = api_token_repository
+        .get_by_token_hash(&api_token.token_hash, &mut context)
+        .await
+        .expect("Failed to query api token")
+        .expect("The api token could not be found");
+    assert_eq!(api_token.id, queried_api_token.id);
+    assert_eq!(api_token.user_id, queried_api_token.user_id);
+    assert_eq!(api_token.name, queried_api_token.name);
+    assert_eq!(api_token.scopes.0, queried_api_token.scopes.0);
+    assert!(queried_api_token.last_used_at.is_none());
+
+    // Record that the api token was used, and make sure it was recorded.
+    api_token_repository
+        .update_last_used_at(&api_token.id, &now, &mut context)
+        .await
+        .expect("Failed to update the date and time the api token was last used at");
+    let api_tokens: Vec<ApiToken> = api_token_repository
+        .get_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query api tokens");
+    assert_eq!(1, api_tokens.len());
+    assert!(api_tokens[0].last_used_at.is_some());
+
+    // Make sure the api token can not be deleted by another user.
+    let rows_deleted: u64 = api_token_repository
+        .delete(&api_token.id, &other_user.id, &mut context)
+        .await
+        .expect("Failed to delete api token");
+    assert_eq!(0, rows_deleted);
+
+    // Delete the api token, and make sure it can only be deleted once.
+    for expected_rows_deleted in [1, 0] {
+        let rows_deleted: u64 = api_token_repository
+            .delete(&api_token.id, &user.id, &mut context)
+            .await
+            .expect("Failed to delete api token");
+        assert_eq!(expected_rows_deleted, rows_deleted);
+    }
+    assert!(api_token_repository
+        .get_by_token_hash(&api_token.token_hash, &mut context)
+        .await
+        .expect("Failed to query api token")
+        .is_none());
+
+    // Delete the test users.
+    delete_test_user(&user, &injector, &mut context).await;
+    delete_test_user(&other_user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
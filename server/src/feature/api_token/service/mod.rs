@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult},
+    database::DatabaseConnectionFactory,
+    feature::{
+        api_token::{
+            model::{ApiToken, API_TOKEN_PREFIX},
+            repository::ApiTokenRepository,
+        },
+        crypto::service::CryptoService,
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::Acquire;
+use std::{error::Error, sync::Arc};
+use time::{Duration, OffsetDateTime};
+use validator::{Validate, ValidationErrors};
+
+/// How often (in seconds) the date and time an api token was last used at is updated, so that
+/// every authenticated request does not have to write to the database.
+const LAST_USED_AT_UPDATE_INTERVAL: i64 = 60;
+
+/// An api token service trait.
+#[async_trait(?Send)]
+pub(crate) trait ApiTokenService: Interface {
+    /// # Description
+    ///
+    /// Create an api token with a new secret, and store the secret's hash.
+    ///
+    /// # Arguments
+    ///
+    /// `api_token` - The api token being created. Its secret, hash and timestamps are generated.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the api token was created, the Ok variant will be returned with the secret, which is not
+    /// stored and can not be retrieved again, and the api token that was stored.
+    /// - If the api token is invalid, the Invalid variant will be returned with the validation
+    /// errors.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        api_token: &ApiToken,
+    ) -> InsertionResult<(String, ApiToken), ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Create an api token with a new secret, and store the secret's hash, in the context
+    /// specified.
+    ///
+    /// # Arguments
+    ///
+    /// `api_token` - The api token being created. Its secret, hash and timestamps are generated.
+    ///
+    /// `context` - The context the api token will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the api token was created, the Ok variant will be returned with the secret, which is not
+    /// stored and can not be retrieved again, and the api token that was stored.
+    /// - If the api token is invalid, the Invalid variant will be returned with the validation
+    /// errors.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        api_token: &ApiToken,
+        context: &mut QueryContext,
+    ) -> InsertionResult<(String, ApiToken), ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the api tokens of a user.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the api tokens belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the api tokens, from the
+    /// most to the least recently created.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id(&self, user_id: &u64) -> Result<Vec<ApiToken>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Delete one of a user's api tokens, which revokes it immediately.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the api token that will be deleted.
+    ///
+    /// `user_id` - The id of the user the api token belongs to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the api token was deleted, the Ok variant will be returned.
+    /// - If the api token does not exist or belongs to another user, the NotFound variant will be
+    /// returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete(&self, id: &u64, user_id: &u64) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the api token a secret belongs to, and record that it was just used to authenticate a
+    /// request.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The api token's secret.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the api token was found and has not expired, the Ok variant will be returned with the
+    /// api token.
+    /// - If the api token does not exist or has expired, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn authenticate(&self, token: &String) -> QueryResult<ApiToken, Box<dyn Error>>;
+}
+
+/// An ApiTokenServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = ApiTokenService)]
+pub(crate) struct ApiTokenServiceImpl {
+    /// The crypto service that will be used to generate and hash api token secrets.
+    #[shaku(inject)]
+    crypto_service: Arc<dyn CryptoService>,
+
+    /// The api token repository that will be used to manage persistent api token data.
+    #[shaku(inject)]
+    api_token_repository: Arc<dyn ApiTokenRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// An ApiTokenService implementation for the ApiTokenServiceImpl struct.
+#[async_trait(?Send)]
+impl ApiTokenService for ApiTokenServiceImpl {
+    async fn insert(
+        &self,
+        api_token: &ApiToken,
+    ) -> InsertionResult<(String, ApiToken), ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self.insert_with_context(api_token, &mut context).await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        api_token: &ApiToken,
+        context: &mut QueryContext,
+    ) -> InsertionResult<(String, ApiToken), ValidationErrors, Box<dyn Error>> {
+        // Validate the api token.
+        if let Err(validation_errors) = api_token.validate() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Create a secret with a prefix, so that it can be told apart from access tokens, and store
+        // its hash.
+        let token: String = format!(
+            "{}{}",
+            API_TOKEN_PREFIX,
+            __self.crypto_service.generate_secret_token()
+        );
+        let mut created_api_token: ApiToken = ApiToken {
+            id: 0,
+            token_hash: __self.crypto_service.hash_secret_token(&token),
+            token_created_at: OffsetDateTime::now_utc(),
+            last_used_at: None,
+            ..api_token.clone()
+        };
+        created_api_token.id = match __self
+            .api_token_repository
+            .insert(&created_api_token, context)
+            .await
+        {
+            Ok(id) => id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Return the secret.
+        return InsertionResult::Ok((token, created_api_token));
+    }
+
+    async fn get_by_user_id(&self, user_id: &u64) -> Result<Vec<ApiToken>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the api tokens.
+        return Ok(__self
+            .api_token_repository
+            .get_by_user_id(user_id, &mut context)
+            .await?);
+    }
+
+    async fn delete(&self, id: &u64, user_id: &u64) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the api token, as long as it belongs to the user.
+        return match __self
+            .api_token_repository
+            .delete(id, user_id, &mut context)
+            .await
+        {
+            Ok(0) => DeletionResult::NotFound,
+            Ok(_) => DeletionResult::Ok,
+            Err(error) => DeletionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn authenticate(&self, token: &String) -> QueryResult<ApiToken, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the api token the secret belongs to.
+        let token_hash: String = __self.crypto_service.hash_secret_token(token);
+        let mut api_token: ApiToken = match __self
+            .api_token_repository
+            .get_by_token_hash(&token_hash, &mut context)
+            .await
+        {
+            Ok(Some(api_token)) => api_token,
+            Ok(None) => return QueryResult::NotFound,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Make sure the api token has not expired.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        if api_token
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= now)
+        {
+            return QueryResult::NotFound;
+        }
+
+        // Record that the api token was just used, unless that was recorded recently.
+        if api_token.last_used_at.is_none_or(|last_used_at| {
+            now - last_used_at >= Duration::seconds(LAST_USED_AT_UPDATE_INTERVAL)
+        }) {
+            if let Err(error) = __self
+                .api_token_repository
+                .update_last_used_at(&api_token.id, &now, &mut context)
+                .await
+            {
+                return QueryResult::Err(Box::new(error));
+            }
+            api_token.last_used_at = Some(now);
+        }
+
+        return QueryResult::Ok(api_token);
+    }
+}
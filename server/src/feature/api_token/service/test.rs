@@ -0,0 +1,292 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        api_token::{
+            model::{ApiToken, ApiTokenScope, API_TOKEN_PREFIX},
+            repository::ApiTokenRepository,
+            service::ApiTokenService,
+        },
+        crypto::service::CryptoService,
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use sqlx::types::Json;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// A function to create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `user_service` - The user service the user will be inserted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(user_service: &Arc<dyn UserService>) -> User {
+    return match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `user_service` - The user service the user will be deleted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user.
+async fn delete_test_user(user: &User, user_service: &Arc<dyn UserService>) {
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    return Config::load_config(String::from("config.json")).expect("Failed to load config");
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    return DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to load dependency injector");
+}
+
+/// # Description
+///
+/// Test creating an api token, and make sure its secret authenticates it and records that it was
+/// used, and that it can no longer be used once it is deleted.
+#[actix_web::test]
+async fn api_tokens_are_authenticated_by_their_secret() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let api_token_service: Arc<dyn ApiTokenService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&user_service).await;
+
+    // Make sure an api token without any scopes is rejected.
+    match api_token_service
+        .insert(&ApiToken {
+            user_id: user.id,
+            name: generate_random_string(8),
+            ..Default::default()
+        })
+        .await
+    {
+        InsertionResult::Invalid(_) => {}
+        _ => panic!("An api token without any scopes was created"),
+    };
+
+    // Create an api token.
+    let (token, api_token): (String, ApiToken) = match api_token_service
+        .insert(&ApiToken {
+            user_id: user.id,
+            name: generate_random_string(8),
+            scopes: Json(vec![ApiTokenScope::MonitorsWrite]),
+            expires_at: Some(OffsetDateTime::now_utc() + Duration::DAY),
+            ..Default::default()
+        })
+        .await
+    {
+        InsertionResult::Ok(result) => result,
+        InsertionResult::Invalid(details) => panic!("Failed to create api token: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to create api token: {}", error),
+    };
+    assert!(token.starts_with(API_TOKEN_PREFIX));
+    assert_ne!(token, api_token.token_hash);
+
+    // Authenticate the api token, and make sure it was recorded as used.
+    let authenticated_api_token: ApiToken = match api_token_service.authenticate(&token).await {
+        QueryResult::Ok(api_token) => api_token,
+        QueryResult::NotFound => panic!("The api token could not be authenticated"),
+        QueryResult::Err(error) => panic!("Failed to authenticate the api token: {}", error),
+    };
+    assert_eq!(api_token.id, authenticated_api_token.id);
+    assert!(authenticated_api_token.has_scope(&ApiTokenScope::MonitorsWrite));
+    assert!(!authenticated_api_token.has_scope(&ApiTokenScope::FilesWrite));
+    let api_tokens: Vec<ApiToken> = api_token_service
+        .get_by_user_id(&user.id)
+        .await
+        .expect("Failed to get api tokens");
+    assert_eq!(1, api_tokens.len());
+    assert!(api_tokens[0].last_used_at.is_some());
+
+    // Make sure an unknown secret is not authenticated.
+    match api_token_service
+        .authenticate(&format!(
+            "{}{}",
+            API_TOKEN_PREFIX,
+            generate_random_string(48)
+        ))
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("An unknown api token was authenticated"),
+    };
+
+    // Delete the api token, and make sure it can no longer be used.
+    match api_token_service.delete(&api_token.id, &user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete api token: Api token not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete api token: {}", error),
+    };
+    match api_token_service.authenticate(&token).await {
+        QueryResult::NotFound => {}
+        _ => panic!("A deleted api token was authenticated"),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
+
+/// # Description
+///
+/// Test authenticating an api token that has expired, and make sure it is rejected.
+#[actix_web::test]
+async fn expired_api_tokens_are_rejected() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service and repository instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let api_token_service: Arc<dyn ApiTokenService> = injector.resolve();
+    let api_token_repository: Arc<dyn ApiTokenRepository> = injector.resolve();
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&user_service).await;
+
+    // Make sure an api token can not be created with an expiry in the past.
+    match api_token_service
+        .insert(&ApiToken {
+            user_id: user.id,
+            name: generate_random_string(8),
+            scopes: Json(vec![ApiTokenScope::MonitorsRead]),
+            expires_at: Some(OffsetDateTime::now_utc() - Duration::DAY),
+            ..Default::default()
+        })
+        .await
+    {
+        InsertionResult::Invalid(_) => {}
+        _ => panic!("An api token that has already expired was created"),
+    };
+
+    // Store an api token that has expired with the repository, since the service will not create
+    // one.
+    let token: String = format!("{}{}", API_TOKEN_PREFIX, generate_random_string(48));
+    let mut context: QueryContext = QueryContext::Connection(
+        connection_factory
+            .get_connection()
+            .await
+            .expect("Failed to acquire a database connection"),
+    );
+    api_token_repository
+        .insert(
+            &ApiToken {
+                user_id: user.id,
+                name: generate_random_string(8),
+                token_hash: crypto_service.hash_secret_token(&token),
+                scopes: Json(vec![ApiTokenScope::MonitorsRead]),
+                expires_at: Some(OffsetDateTime::now_utc() - Duration::MINUTE),
+                ..Default::default()
+            },
+            &mut context,
+        )
+        .await
+        .expect("Failed to insert api token");
+
+    // Make sure the expired api token is not authenticated.
+    match api_token_service.authenticate(&token).await {
+        QueryResult::NotFound => {}
+        _ => panic!("An expired api token was authenticated"),
+    };
+
+    // Delete the test user, which deletes their api tokens as well.
+    delete_test_user(&user, &user_service).await;
+}
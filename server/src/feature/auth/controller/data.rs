@@ -10,3 +10,80 @@ pub(super) struct LoginRequestBody {
     /// The user's password.
     pub(super) password: String,
 }
+
+/// A two-factor login request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoFactorLoginRequestBody {
+    /// The challenge token the user received from the login endpoint.
+    pub(super) challenge_token: String,
+
+    /// A code from the user's authenticator app, or one of their recovery codes.
+    pub(super) code: String,
+}
+
+/// A two-factor challenge response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoFactorChallengeResponseBody {
+    /// The challenge token the user must send to the two-factor login endpoint with their second
+    /// factor.
+    pub(super) challenge_token: String,
+}
+
+/// A password reset request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PasswordResetRequestBody {
+    /// The email address of the user whose password is being reset.
+    pub(super) email: String,
+}
+
+/// A password reset confirmation request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct PasswordResetConfirmationRequestBody {
+    /// The password reset token that was emailed to the user.
+    pub(super) token: String,
+
+    /// The user's new password.
+    pub(super) password: String,
+}
+
+/// An account unlock request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AccountUnlockRequestBody {
+    /// The account unlock token that was emailed to the user.
+    pub(super) token: String,
+}
+
+/// An identity provider response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OidcProviderResponseBody {
+    /// The unique name of the identity provider, which is used in the login urls.
+    pub(super) name: String,
+
+    /// The name of the identity provider that is shown to users.
+    pub(super) display_name: String,
+}
+
+/// A single sign-on authorization response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OidcAuthorizationResponseBody {
+    /// The url of the identity provider the user must be sent to.
+    pub(super) authorization_url: String,
+}
+
+/// A single sign-on callback request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OidcCallbackRequestBody {
+    /// The authorization code the identity provider sent the user back with.
+    pub(super) code: String,
+
+    /// The state the identity provider sent the user back with.
+    pub(super) state: String,
+}
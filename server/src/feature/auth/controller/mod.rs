@@ -1,16 +1,35 @@
 mod data;
 
 use crate::{
-    common::enumeration::AuthenticationResult,
+    common::{
+        enumeration::{AuthenticationResult, QueryResult, UpdateResult},
+        utility::{
+            create_authentication_cookies, create_authentication_failure_response,
+            create_cleared_authentication_cookies, create_cleared_oidc_state_cookie,
+            create_oidc_state_cookie, get_client_ip_address, get_client_user_agent,
+            get_oidc_state_from_cookie, get_refresh_token_from_cookie,
+        },
+    },
+    config::Config,
     feature::{
-        auth::{controller::data::LoginRequestBody, service::AuthService},
+        auth::{
+            controller::data::{
+                AccountUnlockRequestBody, LoginRequestBody, OidcAuthorizationResponseBody,
+                OidcCallbackRequestBody, OidcProviderResponseBody,
+                PasswordResetConfirmationRequestBody, PasswordResetRequestBody,
+                TwoFactorChallengeResponseBody, TwoFactorLoginRequestBody,
+            },
+            service::AuthService,
+        },
         crypto::service::CryptoService,
-        user::model::User,
+        oidc::service::OidcService,
+        session::service::SessionService,
+        two_factor::service::TwoFactorService,
+        user::{model::User, service::UserService},
     },
     injector::DependencyInjector,
 };
 use actix_web::{
-    cookie::{Cookie, SameSite},
     get, post,
     web::{self, ServiceConfig},
     HttpRequest, HttpResponse,
@@ -28,82 +47,352 @@ pub(crate) fn configure(config: &mut ServiceConfig) {
     config.service(
         web::scope("/auth")
             .service(login)
+            .service(login_two_factor)
+            .service(oidc_providers)
+            .service(oidc_authorize)
+            .service(oidc_callback)
+            .service(refresh)
             .service(logout)
             .service(status)
-            .service(current_user),
+            .service(current_user)
+            .service(request_password_reset)
+            .service(confirm_password_reset)
+            .service(unlock_account),
     );
 }
 
 /// # Description
 ///
-/// An api endpoint to authenticate a user.
+/// An api endpoint to authenticate a user. Failed logins are counted per username and per client
+/// ip address, so that accounts are locked and clients are throttled when too many are attempted.
+///
+/// The user is issued a short-lived access token and a refresh token, which can be used to get a
+/// new access token from the refresh endpoint once it expires. If the user has enabled two-factor
+/// authentication, they are issued a challenge token instead, which must be sent to the two-factor
+/// login endpoint with their second factor before they are issued any other tokens.
 ///
 /// # Arguments
 ///
+/// `request` - The http request.
+///
 /// `body` - The request body that will be used to attempt to authenticate the user.
 ///
 /// `auth_service` - The authentication service that will be used to authenticate the user.
 ///
 /// `crypto_service` - The crypto service that will be used to create a token for the user.
 ///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
+/// `two_factor_service` - The two-factor service that will be used to create a challenge for the
+/// user, if they have enabled two-factor authentication.
+///
 /// # Returns
 ///
 /// An http response.
 #[post("/login")]
 async fn login(
+    request: HttpRequest,
     body: web::Json<LoginRequestBody>,
     auth_service: Inject<DependencyInjector, dyn AuthService>,
     crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_credentials(
+            &body.username,
+            &body.password,
+            &get_client_ip_address(&request),
+        )
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    return create_two_factor_or_login_response(
+        &request,
+        user,
+        &*crypto_service,
+        &*session_service,
+        &*two_factor_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint to finish authenticating a user that has enabled two-factor authentication,
+/// with the challenge token they were issued by the login endpoint and their second factor.
+/// Incorrect codes count as failed logins.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains the challenge token and the second factor.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user.
+///
+/// `crypto_service` - The crypto service that will be used to create a token for the user.
+///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/login/two-factor")]
+async fn login_two_factor(
+    request: HttpRequest,
+    body: web::Json<TwoFactorLoginRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
 ) -> HttpResponse {
     // Authenticate the user.
     let user: User = match auth_service
-        .authenticate_credentials(&body.username, &body.password)
+        .authenticate_two_factor(
+            &body.challenge_token,
+            &body.code,
+            &get_client_ip_address(&request),
+        )
         .await
     {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
+    };
+
+    return create_login_response(&request, user, &*crypto_service, &*session_service).await;
+}
+
+/// # Description
+///
+/// An api endpoint to list the identity providers users can login with.
+///
+/// # Arguments
+///
+/// `config` - The server's configuration, which contains the identity providers.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/oidc/providers")]
+async fn oidc_providers(config: web::Data<Config>) -> HttpResponse {
+    let providers: Vec<OidcProviderResponseBody> = config
+        .oidc
+        .providers
+        .iter()
+        .map(|provider| OidcProviderResponseBody {
+            name: provider.name.clone(),
+            display_name: provider.display_name.clone(),
+        })
+        .collect();
+    return HttpResponse::Ok().json(providers);
+}
+
+/// # Description
+///
+/// An api endpoint to start logging a user in with an identity provider. The response contains the
+/// url the user must be sent to, and sets a cookie with the state of the login so that it can only
+/// be finished by the client that started it.
+///
+/// # Arguments
+///
+/// `provider` - The name of the identity provider.
+///
+/// `oidc_service` - The oidc service that will be used to start the login.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/oidc/{provider}/authorize")]
+async fn oidc_authorize(
+    provider: web::Path<String>,
+    oidc_service: Inject<DependencyInjector, dyn OidcService>,
+) -> HttpResponse {
+    // Start the login.
+    return match oidc_service.begin_login(&provider).await {
+        QueryResult::Ok((state, authorization_url)) => HttpResponse::Ok()
+            .cookie(create_oidc_state_cookie(&state))
+            .json(OidcAuthorizationResponseBody { authorization_url }),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to finish logging a user in with an identity provider, with the authorization
+/// code and state the identity provider sent them back with. The state must match the state cookie
+/// that was set when the login was started, and the cookie is cleared either way.
+///
+/// The user is issued the same tokens as they are by the login endpoint. If the user has enabled
+/// two-factor authentication, they are issued a challenge token instead, which must be sent to the
+/// two-factor login endpoint with their second factor.
+///
+/// # Arguments
+///
+/// `request` - The http request, which contains the state cookie.
+///
+/// `provider` - The name of the identity provider.
+///
+/// `body` - The request body which contains the authorization code and the state.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user.
+///
+/// `crypto_service` - The crypto service that will be used to create a token for the user.
+///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
+/// `two_factor_service` - The two-factor service that will be used to create a challenge for the
+/// user, if they have enabled two-factor authentication.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/oidc/{provider}/callback")]
+async fn oidc_callback(
+    request: HttpRequest,
+    provider: web::Path<String>,
+    body: web::Json<OidcCallbackRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Make sure the login is being finished by the client that started it, then authenticate the
+    // user.
+    let mut response: HttpResponse = match get_oidc_state_from_cookie(&request) {
+        Some(state) if state == body.state => {
+            match auth_service
+                .authenticate_oidc(&provider, &body.code, &body.state)
+                .await
+            {
+                AuthenticationResult::Ok(user) => {
+                    create_two_factor_or_login_response(
+                        &request,
+                        user,
+                        &*crypto_service,
+                        &*session_service,
+                        &*two_factor_service,
+                    )
+                    .await
+                }
+                result => create_authentication_failure_response(result),
+            }
+        }
+        _ => HttpResponse::Unauthorized().finish(),
+    };
+
+    // Clear the state cookie, since the login can not be finished again.
+    if response
+        .add_cookie(&create_cleared_oidc_state_cookie())
+        .is_err()
+    {
+        return HttpResponse::InternalServerError().finish();
+    }
+    return response;
+}
+
+/// # Description
+///
+/// An api endpoint to issue a new access token to a user with their refresh token. The refresh
+/// token is rotated, so it can only be used once. If a refresh token that was already used is sent
+/// again, every refresh token issued since the user logged in is revoked.
+///
+/// # Arguments
+///
+/// `request` - The http request, which contains the refresh token cookie.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user the
+/// session belongs to.
+///
+/// `crypto_service` - The crypto service that will be used to create a token for the user.
+///
+/// `session_service` - The session service that will be used to rotate the refresh token.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/refresh")]
+async fn refresh(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+) -> HttpResponse {
+    // Get the refresh token from the request.
+    let refresh_token: String = match get_refresh_token_from_cookie(&request) {
+        Some(refresh_token) => refresh_token,
+        None => return HttpResponse::Unauthorized().finish(),
+    };
+
+    // Rotate the refresh token.
+    let (refresh_token, session) = match session_service.rotate(&refresh_token).await {
+        QueryResult::Ok(result) => result,
+        QueryResult::NotFound => {
+            let mut response = HttpResponse::Unauthorized();
+            for cookie in create_cleared_authentication_cookies() {
+                response.cookie(cookie);
+            }
+            return response.finish();
+        }
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Make sure the user the session belongs to can still use their account.
+    let user: User = match auth_service.authenticate_session(&session).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
     };
 
     // Create an authentication token for the user.
-    let token: String = match crypto_service.create_token(&user) {
+    let token: String = match crypto_service.create_token(&user, &session.family_id) {
         Ok(token) => token,
         Err(_) => return HttpResponse::InternalServerError().finish(),
     };
 
-    // Create an authentication cookie for the user.
-    let auth_cookie: Cookie = Cookie::build("authorization", format!("Bearer {}", token))
-        .http_only(true)
-        .secure(true)
-        .same_site(SameSite::Strict)
-        .path("/")
-        .expires(None)
-        .finish();
-
-    // Send the response.
-    return HttpResponse::Ok().cookie(auth_cookie).json(user);
+    // Send the response with the user's new authentication cookies.
+    let mut response = HttpResponse::Ok();
+    for cookie in create_authentication_cookies(&token, &refresh_token) {
+        response.cookie(cookie);
+    }
+    return response.json(user);
 }
 
 /// # Description
 ///
-/// An api endpoint to unauthenticate a user.
+/// An api endpoint to unauthenticate a user. The user's refresh token is revoked, so that it can
+/// not be used to get new access tokens.
+///
+/// # Arguments
+///
+/// `request` - The http request, which contains the refresh token cookie.
+///
+/// `session_service` - The session service that will be used to revoke the refresh token.
 ///
 /// # Returns
 ///
 /// An http response.
 #[post("/logout")]
-async fn logout() -> HttpResponse {
-    // Clear the user's authentication token.
-    let auth_cookie: Cookie = Cookie::build("authorization", "")
-        .http_only(true)
-        .secure(true)
-        .same_site(SameSite::Strict)
-        .path("/")
-        .expires(None)
-        .finish();
-
-    // Send the response.
-    return HttpResponse::Ok().cookie(auth_cookie).finish();
+async fn logout(
+    request: HttpRequest,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+) -> HttpResponse {
+    // Revoke the user's refresh token.
+    if let Some(refresh_token) = get_refresh_token_from_cookie(&request) {
+        if session_service.revoke(&refresh_token).await.is_err() {
+            return HttpResponse::InternalServerError().finish();
+        }
+    }
+
+    // Clear the user's authentication cookies.
+    let mut response = HttpResponse::Ok();
+    for cookie in create_cleared_authentication_cookies() {
+        response.cookie(cookie);
+    }
+    return response.finish();
 }
 
 /// # Description
@@ -125,10 +414,10 @@ async fn status(
     auth_service: Inject<DependencyInjector, dyn AuthService>,
 ) -> HttpResponse {
     // Check if the user is authenticated.
-    return match auth_service.authenticate_request(&request).await {
+    return match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(_) => HttpResponse::Ok().json(true),
         AuthenticationResult::NotAuthenticated => HttpResponse::Ok().json(false),
-        AuthenticationResult::Err(_) => HttpResponse::InternalServerError().finish(),
+        result => create_authentication_failure_response(result),
     };
 }
 
@@ -152,9 +441,191 @@ async fn current_user(
     auth_service: Inject<DependencyInjector, dyn AuthService>,
 ) -> HttpResponse {
     // Attempt to get the user that sent the request.
-    return match auth_service.authenticate_request(&request).await {
+    return match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(user) => HttpResponse::Ok().json(user),
-        AuthenticationResult::NotAuthenticated => HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => HttpResponse::InternalServerError().finish(),
+        result => create_authentication_failure_response(result),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to email a user a link they can use to reset their password. The response is
+/// the same whether or not a user has the email address, so that it can not be used to find out
+/// which email addresses are in use.
+///
+/// # Arguments
+///
+/// `body` - The request body which contains the email address of the user.
+///
+/// `user_service` - The user service that will be used to send the password reset email.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/password-reset/request")]
+async fn request_password_reset(
+    body: web::Json<PasswordResetRequestBody>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Send the password reset email.
+    return match user_service.send_password_reset(&body.email).await {
+        Ok(()) => HttpResponse::Accepted().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
     };
 }
+
+/// # Description
+///
+/// An api endpoint to reset a user's password with the token that was emailed to them. Resetting
+/// the password signs the user out everywhere, so the user must login again afterwards.
+///
+/// # Arguments
+///
+/// `body` - The request body which contains the password reset token and the new password.
+///
+/// `user_service` - The user service that will be used to reset the password.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/password-reset/confirm")]
+async fn confirm_password_reset(
+    body: web::Json<PasswordResetConfirmationRequestBody>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Reset the password.
+    return match user_service
+        .reset_password(&body.token, &body.password)
+        .await
+    {
+        UpdateResult::Ok(_) => HttpResponse::Ok().finish(),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to unlock an account that was locked because of failed logins, with the token
+/// that was emailed to its user.
+///
+/// # Arguments
+///
+/// `body` - The request body which contains the account unlock token.
+///
+/// `user_service` - The user service that will be used to unlock the account.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/unlock")]
+async fn unlock_account(
+    body: web::Json<AccountUnlockRequestBody>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Unlock the account.
+    return match user_service.unlock_account(&body.token).await {
+        UpdateResult::Ok(_) => HttpResponse::Ok().finish(),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// Log a user in whose credentials have been verified, by creating a session for them and sending
+/// their authentication cookies.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `user` - The user that is logging in.
+///
+/// `crypto_service` - The crypto service that will be used to create a token for the user.
+///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
+/// # Returns
+///
+/// An http response.
+async fn create_login_response(
+    request: &HttpRequest,
+    user: User,
+    crypto_service: &dyn CryptoService,
+    session_service: &dyn SessionService,
+) -> HttpResponse {
+    // Create a session for the user.
+    let (refresh_token, session) = match session_service
+        .create(
+            &user,
+            &get_client_user_agent(request),
+            &get_client_ip_address(request),
+        )
+        .await
+    {
+        Ok(result) => result,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Create an authentication token for the user.
+    let token: String = match crypto_service.create_token(&user, &session.family_id) {
+        Ok(token) => token,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Send the response with the user's authentication cookies.
+    let mut response = HttpResponse::Ok();
+    for cookie in create_authentication_cookies(&token, &refresh_token) {
+        response.cookie(cookie);
+    }
+    return response.json(user);
+}
+
+/// # Description
+///
+/// Log a user in whose first factor has been verified. If the user has enabled two-factor
+/// authentication, a challenge is created for them instead, which they must send back with their
+/// second factor before they are logged in.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `user` - The user that is logging in.
+///
+/// `crypto_service` - The crypto service that will be used to create a token for the user.
+///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
+/// `two_factor_service` - The two-factor service that will be used to create a challenge for the
+/// user.
+///
+/// # Returns
+///
+/// An http response.
+async fn create_two_factor_or_login_response(
+    request: &HttpRequest,
+    user: User,
+    crypto_service: &dyn CryptoService,
+    session_service: &dyn SessionService,
+    two_factor_service: &dyn TwoFactorService,
+) -> HttpResponse {
+    // If the user has enabled two-factor authentication, they must verify their second factor
+    // before they are logged in.
+    match two_factor_service.is_enabled(&user.id).await {
+        Ok(true) => {
+            return match two_factor_service.create_challenge(&user).await {
+                Ok(challenge_token) => HttpResponse::Accepted()
+                    .json(TwoFactorChallengeResponseBody { challenge_token }),
+                Err(_) => HttpResponse::InternalServerError().finish(),
+            }
+        }
+        Ok(false) => {}
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    return create_login_response(request, user, crypto_service, session_service).await;
+}
@@ -0,0 +1,17 @@
+use time::OffsetDateTime;
+
+/// A failed login struct, which is a login attempt that used incorrect credentials.
+#[derive(Clone, Debug)]
+pub(crate) struct FailedLogin {
+    /// The failed login's unique identifier.
+    pub(crate) id: u64,
+
+    /// The username the login was attempted with.
+    pub(crate) username: String,
+
+    /// The ip address of the client that attempted the login.
+    pub(crate) ip_address: String,
+
+    /// The date and time the login was attempted at.
+    pub(crate) attempted_at: OffsetDateTime,
+}
@@ -0,0 +1,287 @@
+#[cfg(test)]
+mod test;
+
+use crate::{common::enumeration::QueryContext, feature::auth::model::FailedLogin};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// A failed login repository trait.
+#[async_trait]
+pub(crate) trait FailedLoginRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a failed login into the failed login repository.
+    ///
+    /// # Arguments
+    ///
+    /// `failed_login` - The failed login to insert into the failed login repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the failed
+    /// login that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(
+        &self,
+        failed_login: &FailedLogin,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Count the failed logins that were attempted with a username since a specific time.
+    ///
+    /// # Arguments
+    ///
+    /// `username` - The username the logins were attempted with.
+    ///
+    /// `since` - The date and time to count failed logins from.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the amount of failed
+    /// logins.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn count_by_username(
+        &self,
+        username: &String,
+        since: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Count the failed logins that were attempted from an ip address since a specific time.
+    ///
+    /// # Arguments
+    ///
+    /// `ip_address` - The ip address the logins were attempted from.
+    ///
+    /// `since` - The date and time to count failed logins from.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the amount of failed
+    /// logins.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn count_by_ip_address(
+        &self,
+        ip_address: &String,
+        since: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the failed logins that were attempted with a username from the failed login
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `username` - The username the logins were attempted with.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_by_username(
+        &self,
+        username: &String,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the failed logins that were attempted before a specific time from the failed login
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `before` - The date and time failed logins must have been attempted before to be deleted.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_before(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+}
+
+/// A FailedLoginRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = FailedLoginRepository)]
+pub(crate) struct FailedLoginRepositoryImpl;
+
+/// A FailedLoginRepository implementation for the FailedLoginRepositoryImpl struct.
+#[async_trait]
+impl FailedLoginRepository for FailedLoginRepositoryImpl {
+    async fn insert(
+        &self,
+        failed_login: &FailedLogin,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `failed_logins` (
+                    `id`,
+                    `username`,
+                    `ip_address`,
+                    `attempted_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            failed_login.id,
+            failed_login.username,
+            failed_login.ip_address,
+            failed_login.attempted_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn count_by_username(
+        &self,
+        username: &String,
+        since: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT
+                    COUNT(*) AS `count: u64`
+                FROM
+                    `failed_logins`
+                WHERE
+                    `username` = ? AND `attempted_at` >= ?;
+            "#,
+            username,
+            since
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.count);
+    }
+
+    async fn count_by_ip_address(
+        &self,
+        ip_address: &String,
+        since: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT
+                    COUNT(*) AS `count: u64`
+                FROM
+                    `failed_logins`
+                WHERE
+                    `ip_address` = ? AND `attempted_at` >= ?;
+            "#,
+            ip_address,
+            since
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.count);
+    }
+
+    async fn delete_by_username(
+        &self,
+        username: &String,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `failed_logins`
+                WHERE
+                    `username` = ?;
+            "#,
+            username
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_before(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `failed_logins`
+                WHERE
+                    `attempted_at` < ?;
+            "#,
+            before
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
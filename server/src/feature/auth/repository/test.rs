@@ -0,0 +1,198 @@
+use crate::{
+    common::enumeration::QueryContext,
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::auth::{model::FailedLogin, repository::FailedLoginRepository},
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a failed login that can be used for testing.
+///
+/// # Arguments
+///
+/// `username` - The username the login was attempted with.
+///
+/// `ip_address` - The ip address the login was attempted from.
+///
+/// `attempted_at` - The date and time the login was attempted at.
+///
+/// # Returns
+///
+/// The failed login that was created.
+fn create_test_failed_login(
+    username: &str,
+    ip_address: &str,
+    attempted_at: OffsetDateTime,
+) -> FailedLogin {
+    return FailedLogin {
+        id: 0,
+        username: String::from(username),
+        ip_address: String::from(ip_address),
+        attempted_at,
+    };
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting failed logins, and make sure they are counted by username and ip address within
+/// the window until they are deleted.
+#[actix_web::test]
+async fn failed_logins_are_counted_until_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a failed login repository instance.
+    let failed_login_repository: Arc<dyn FailedLoginRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert two recent failed logins and one old failed login.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let username: String = String::from("failed-login-test-user");
+    let ip_address: String = String::from("192.0.2.1");
+    for failed_login in [
+        create_test_failed_login(&username, &ip_address, now),
+        create_test_failed_login(&username, "192.0.2.2", now),
+        create_test_failed_login(&username, &ip_address, now - Duration::DAY),
+    ] {
+        let id = failed_login_repository
+            .insert(&failed_login, &mut context)
+            .await
+            .expect("Failed to insert failed login");
+        assert!(id > 0);
+    }
+
+    // Make sure only the recent failed logins are counted.
+    let since: OffsetDateTime = now - Duration::HOUR;
+    assert_eq!(
+        2,
+        failed_login_repository
+            .count_by_username(&username, &since, &mut context)
+            .await
+            .expect("Failed to count failed logins")
+    );
+    assert_eq!(
+        1,
+        failed_login_repository
+            .count_by_ip_address(&ip_address, &since, &mut context)
+            .await
+            .expect("Failed to count failed logins")
+    );
+
+    // Delete the old failed login, and make sure the recent ones remain.
+    failed_login_repository
+        .delete_before(&since, &mut context)
+        .await
+        .expect("Failed to delete old failed logins");
+    assert_eq!(
+        2,
+        failed_login_repository
+            .count_by_username(&username, &(now - Duration::WEEK), &mut context)
+            .await
+            .expect("Failed to count failed logins")
+    );
+
+    // Delete the failed logins of the username, and make sure none remain.
+    let rows_deleted = failed_login_repository
+        .delete_by_username(&username, &mut context)
+        .await
+        .expect("Failed to delete failed logins");
+    assert_eq!(2, rows_deleted);
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
@@ -3,18 +3,33 @@ mod test;
 
 use crate::{
     common::{
-        enumeration::{AuthenticationResult, QueryResult},
+        enumeration::{AuthenticationResult, QueryContext, QueryResult, UpdateResult},
         utility::{get_token_from_cookie, get_token_from_header},
     },
+    config::LoginConfig,
+    database::DatabaseConnectionFactory,
     feature::{
+        api_token::{
+            model::{ApiToken, ApiTokenScope, API_TOKEN_PREFIX},
+            service::ApiTokenService,
+        },
+        auth::{model::FailedLogin, repository::FailedLoginRepository},
         crypto::{model::UserClaims, service::CryptoService},
-        user::{model::User, service::UserService},
+        oidc::service::OidcService,
+        session::{model::Session, service::SessionService},
+        two_factor::{model::TwoFactorChallenge, service::TwoFactorService},
+        user::{
+            model::{Permission, User, UserRole},
+            service::UserService,
+        },
     },
 };
 use actix_web::HttpRequest;
 use async_trait::async_trait;
 use shaku::{Component, Interface};
-use std::sync::Arc;
+use sqlx::Acquire;
+use std::{error::Error, sync::Arc};
+use time::{Duration, OffsetDateTime};
 
 /// The prefix for Bearer tokens.
 const BEARER: &str = "Bearer ";
@@ -24,7 +39,12 @@ const BEARER: &str = "Bearer ";
 pub(crate) trait AuthService: Interface {
     /// # Description
     ///
-    /// Authenticate a user with login credentials.
+    /// Authenticate a user with login credentials. Failed logins are recorded, and the user's
+    /// account is locked temporarily if too many of them are attempted with its username.
+    ///
+    /// If the user has enabled two-factor authentication, the login is not finished until their
+    /// second factor is verified with `authenticate_two_factor`, so their previous failed logins
+    /// are kept until then.
     ///
     /// # Arguments
     ///
@@ -32,23 +52,31 @@ pub(crate) trait AuthService: Interface {
     ///
     /// `password` - The password being authenticated.
     ///
+    /// `ip_address` - The ip address of the client attempting to login.
+    ///
     /// # Returns
     ///
     /// This function returns an authentication result:
     /// - If authentication is successful, the Ok variant will be returned with the User that was
     /// authenticated.
     /// - If authentication is not successful, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If too many failed logins were attempted from the ip address, the Throttled variant will
+    /// be returned.
     /// - If an error occurs during the authentication process, the Err variant will be returned
     /// with the error that occurred.
     async fn authenticate_credentials(
         &self,
         username: &String,
         password: &String,
+        ip_address: &String,
     ) -> AuthenticationResult;
 
     /// # Description
     ///
-    /// Authenticate a user with a user token.
+    /// Authenticate a user with a user token. The token is only valid while the session family it
+    /// was issued for has not been revoked.
     ///
     /// # Arguments
     ///
@@ -60,27 +88,171 @@ pub(crate) trait AuthService: Interface {
     /// - If authentication is successful, the Ok variant will be returned with the User that was
     /// authenticated.
     /// - If authentication is not successful, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
     /// - If an error occurs during the authentication process, the Err variant will be returned
     /// with the error that occurred.
     async fn authenticate_token(&self, token: &String) -> AuthenticationResult;
 
     /// # Description
     ///
-    /// Authenticate a user with http request data.
+    /// Authenticate a user that has entered their password with their second factor, which
+    /// finishes their login. Incorrect codes are recorded as failed logins, so that they count
+    /// toward locking the user's account.
+    ///
+    /// # Arguments
+    ///
+    /// `challenge_token` - The challenge token the user received after entering their password.
+    ///
+    /// `code` - A code from the user's authenticator app, or one of their recovery codes.
+    ///
+    /// `ip_address` - The ip address of the client attempting to login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If authentication is successful, the Ok variant will be returned with the User that was
+    /// authenticated, and the challenge can not be used again.
+    /// - If the challenge does not exist or has expired, or the code is not correct, the
+    /// NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If too many failed logins were attempted from the ip address, the Throttled variant will
+    /// be returned.
+    /// - If an error occurs during the authentication process, the Err variant will be returned
+    /// with the error that occurred.
+    async fn authenticate_two_factor(
+        &self,
+        challenge_token: &String,
+        code: &String,
+        ip_address: &String,
+    ) -> AuthenticationResult;
+
+    /// # Description
+    ///
+    /// Authenticate a user that was sent back by an identity provider, with the authorization code
+    /// and the state of their single sign-on login.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The name of the identity provider.
+    ///
+    /// `code` - The authorization code the identity provider sent the user back with.
+    ///
+    /// `state` - The secret state of the login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If authentication is successful, the Ok variant will be returned with the User that was
+    /// authenticated. If the user has enabled two-factor authentication, they must still verify
+    /// their second factor.
+    /// - If the login could not be finished, or the account at the identity provider can not be
+    /// linked to a user, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If an error occurs during the authentication process, the Err variant will be returned
+    /// with the error that occurred.
+    async fn authenticate_oidc(
+        &self,
+        provider: &String,
+        code: &String,
+        state: &String,
+    ) -> AuthenticationResult;
+
+    /// # Description
+    ///
+    /// Authenticate a user with http request data. Requests can be authenticated with an access
+    /// token, or with one of the user's api tokens if the endpoint accepts them.
     ///
     /// # Arguments
     ///
     /// `request` - The http request being authenticated.
     ///
+    /// `scope` - The scope an api token must have to be used with the endpoint, or None if the
+    /// endpoint does not accept api tokens.
+    ///
     /// # Returns
     ///
     /// This function returns an authentication result:
     /// - If authentication is successful, the Ok variant will be returned with the User that was
     /// authenticated.
     /// - If authentication is not successful, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If the request was authenticated with an api token that does not have the scope, the
+    /// InsufficientScope variant will be returned.
     /// - If an error occurs during the authentication process, the Err variant will be returned
     /// with the error that occurred.
-    async fn authenticate_request(&self, request: &HttpRequest) -> AuthenticationResult;
+    async fn authenticate_request(
+        &self,
+        request: &HttpRequest,
+        scope: Option<ApiTokenScope>,
+    ) -> AuthenticationResult;
+
+    /// # Description
+    ///
+    /// Authenticate a user with http request data, and make sure one of their roles grants a
+    /// permission. Api tokens are not accepted, so permissions can only be used by users who have
+    /// logged in.
+    ///
+    /// # Arguments
+    ///
+    /// `request` - The http request being authorized.
+    ///
+    /// `permission` - The permission the endpoint requires.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If the user was authenticated and has the permission, the Ok variant will be returned with
+    /// the User that was authenticated.
+    /// - If authentication is not successful, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If none of the user's roles grant the permission, the InsufficientPermission variant will
+    /// be returned.
+    /// - If an error occurs during the authorization process, the Err variant will be returned with
+    /// the error that occurred.
+    async fn authorize_request(
+        &self,
+        request: &HttpRequest,
+        permission: Permission,
+    ) -> AuthenticationResult;
+
+    /// # Description
+    ///
+    /// Authenticate the user a session belongs to, so that they can be issued a new access token.
+    ///
+    /// # Arguments
+    ///
+    /// `session` - The session being authenticated.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If authentication is successful, the Ok variant will be returned with the User that was
+    /// authenticated.
+    /// - If the user no longer exists or has reset their password since they logged in, the
+    /// NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If an error occurs during the authentication process, the Err variant will be returned
+    /// with the error that occurred.
+    async fn authenticate_session(&self, session: &Session) -> AuthenticationResult;
+
+    /// # Description
+    ///
+    /// Delete the failed logins that were attempted before the failed login window, since they no
+    /// longer count toward locking accounts or throttling clients.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of failed
+    /// logins deleted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_expired_failed_logins(&self) -> Result<u64, Box<dyn Error>>;
 }
 
 /// An AuthServiceImpl struct.
@@ -94,6 +266,33 @@ pub(crate) struct AuthServiceImpl {
     /// The user service that will be used for authentication.
     #[shaku(inject)]
     user_service: Arc<dyn UserService>,
+
+    /// The session service that will be used to make sure tokens have not been revoked.
+    #[shaku(inject)]
+    session_service: Arc<dyn SessionService>,
+
+    /// The two-factor service that will be used to verify second factors.
+    #[shaku(inject)]
+    two_factor_service: Arc<dyn TwoFactorService>,
+
+    /// The oidc service that will be used to finish single sign-on logins.
+    #[shaku(inject)]
+    oidc_service: Arc<dyn OidcService>,
+
+    /// The api token service that will be used to authenticate api tokens.
+    #[shaku(inject)]
+    api_token_service: Arc<dyn ApiTokenService>,
+
+    /// The failed login repository that will be used to record failed logins.
+    #[shaku(inject)]
+    failed_login_repository: Arc<dyn FailedLoginRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The login configuration that determines when accounts are locked and clients are throttled.
+    login_config: LoginConfig,
 }
 
 /// An AuthService implementation for the AuthServiceImpl struct.
@@ -103,22 +302,61 @@ impl AuthService for AuthServiceImpl {
         &self,
         username: &String,
         password: &String,
+        ip_address: &String,
     ) -> AuthenticationResult {
+        // Make sure the client has not attempted too many failed logins.
+        let mut context = match __self.connection_factory.get_connection().await {
+            Ok(connection) => QueryContext::Connection(connection),
+            Err(error) => return AuthenticationResult::Err(Box::new(error)),
+        };
+        match self.is_throttled(ip_address, &mut context).await {
+            Ok(true) => return AuthenticationResult::Throttled,
+            Ok(false) => {}
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+
         // Query the user from persistent storage.
         let user: User = match __self.user_service.get_by_username(username).await {
             QueryResult::Ok(user) => user,
-            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::NotFound => {
+                return match self.record_failed_login(username, ip_address, None).await {
+                    Ok(()) => AuthenticationResult::NotAuthenticated,
+                    Err(error) => AuthenticationResult::Err(error),
+                }
+            }
             QueryResult::Err(error) => return AuthenticationResult::Err(error),
         };
 
+        // Passwords are not checked while the account is locked, so that they can not be guessed
+        // until the lock is lifted.
+        if self.lock_is_in_effect(&user) {
+            return AuthenticationResult::Locked;
+        }
+
         // Make sure the password is correct.
-        return match __self
+        if __self
             .crypto_service
             .verify_password(password, &user.password)
+            .is_err()
         {
-            Ok(()) => AuthenticationResult::Ok(user),
-            Err(_) => AuthenticationResult::NotAuthenticated,
+            return match self
+                .record_failed_login(&user.username, ip_address, Some(&user))
+                .await
+            {
+                Ok(()) => AuthenticationResult::NotAuthenticated,
+                Err(error) => AuthenticationResult::Err(error),
+            };
+        }
+
+        // If the user has enabled two-factor authentication, the login is finished once their
+        // second factor is verified.
+        match __self.two_factor_service.is_enabled(&user.id).await {
+            Ok(true) => return self.check_account_status(user),
+            Ok(false) => {}
+            Err(error) => return AuthenticationResult::Err(error),
         };
+
+        return self.finish_login(user, &mut context).await;
     }
 
     async fn authenticate_token(&self, token: &String) -> AuthenticationResult {
@@ -136,14 +374,135 @@ impl AuthService for AuthServiceImpl {
         };
 
         // Make sure the token has not expired (this happens when the user resets their password).
-        if claims.password_last_reset == user.password_reset_at.unix_timestamp() {
-            return AuthenticationResult::Ok(user);
-        } else {
+        if claims.password_last_reset != user.password_reset_at.unix_timestamp() {
             return AuthenticationResult::NotAuthenticated;
         }
+
+        // Make sure the session family the token was issued for has not been revoked.
+        match __self.session_service.mark_family_seen(&claims.jti).await {
+            QueryResult::Ok(family) if family.user_id == user.id => {}
+            QueryResult::Ok(_) | QueryResult::NotFound => {
+                return AuthenticationResult::NotAuthenticated
+            }
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        return self.check_account_status(user);
     }
 
-    async fn authenticate_request(&self, request: &HttpRequest) -> AuthenticationResult {
+    async fn authenticate_two_factor(
+        &self,
+        challenge_token: &String,
+        code: &String,
+        ip_address: &String,
+    ) -> AuthenticationResult {
+        // Make sure the client has not attempted too many failed logins.
+        let mut context = match __self.connection_factory.get_connection().await {
+            Ok(connection) => QueryContext::Connection(connection),
+            Err(error) => return AuthenticationResult::Err(Box::new(error)),
+        };
+        match self.is_throttled(ip_address, &mut context).await {
+            Ok(true) => return AuthenticationResult::Throttled,
+            Ok(false) => {}
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Query the challenge, and the user it was created for.
+        let challenge: TwoFactorChallenge = match __self
+            .two_factor_service
+            .get_challenge(challenge_token)
+            .await
+        {
+            QueryResult::Ok(challenge) => challenge,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+        let user: User = match __self.user_service.get_by_id(&challenge.user_id).await {
+            QueryResult::Ok(user) => user,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Codes are not checked while the account is locked, so that they can not be guessed
+        // until the lock is lifted.
+        if self.lock_is_in_effect(&user) {
+            return AuthenticationResult::Locked;
+        }
+
+        // Make sure the code is correct. The challenge can be used again until it expires, so
+        // that the user can correct a typo.
+        match __self.two_factor_service.verify(&user.id, code).await {
+            Ok(true) => {}
+            Ok(false) => {
+                return match self
+                    .record_failed_login(&user.username, ip_address, Some(&user))
+                    .await
+                {
+                    Ok(()) => AuthenticationResult::NotAuthenticated,
+                    Err(error) => AuthenticationResult::Err(error),
+                }
+            }
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Make sure the challenge is only used once.
+        match __self
+            .two_factor_service
+            .delete_challenge(&challenge.id)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => return AuthenticationResult::NotAuthenticated,
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        return self.finish_login(user, &mut context).await;
+    }
+
+    async fn authenticate_oidc(
+        &self,
+        provider: &String,
+        code: &String,
+        state: &String,
+    ) -> AuthenticationResult {
+        // Finish the login, and query the user the account at the identity provider belongs to.
+        let user: User = match __self
+            .oidc_service
+            .complete_login(provider, code, state)
+            .await
+        {
+            QueryResult::Ok(user) => user,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Logging in with an identity provider does not get around a lock that is in effect.
+        if self.lock_is_in_effect(&user) {
+            return AuthenticationResult::Locked;
+        }
+
+        // If the user has enabled two-factor authentication, the login is finished once their
+        // second factor is verified.
+        match __self.two_factor_service.is_enabled(&user.id).await {
+            Ok(true) => return self.check_account_status(user),
+            Ok(false) => {}
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Acquire a database connection.
+        let mut context = match __self.connection_factory.get_connection().await {
+            Ok(connection) => QueryContext::Connection(connection),
+            Err(error) => return AuthenticationResult::Err(Box::new(error)),
+        };
+
+        return self.finish_login(user, &mut context).await;
+    }
+
+    async fn authenticate_request(
+        &self,
+        request: &HttpRequest,
+        scope: Option<ApiTokenScope>,
+    ) -> AuthenticationResult {
         // Attempt to locate the user's authentication token from the request.
         // This will attempt to locate the token in the request headers before checking cookies
         // to allow overriding cookies when sending requests.
@@ -158,9 +517,401 @@ impl AuthService for AuthServiceImpl {
             return AuthenticationResult::NotAuthenticated;
         }
 
+        // Api tokens are told apart from access tokens by their prefix, and can only be used with
+        // endpoints that accept them.
+        let token: String = token.trim_start_matches(BEARER).to_owned();
+        if token.starts_with(API_TOKEN_PREFIX) {
+            return match scope {
+                Some(scope) => self.authenticate_api_token(&token, &scope).await,
+                None => AuthenticationResult::NotAuthenticated,
+            };
+        }
+
         // Now that we have the token, we can authenticate the user.
-        return __self
-            .authenticate_token(&token.trim_start_matches(BEARER).to_owned())
+        return self.authenticate_token(&token).await;
+    }
+
+    async fn authorize_request(
+        &self,
+        request: &HttpRequest,
+        permission: Permission,
+    ) -> AuthenticationResult {
+        // Authenticate the user.
+        let user: User = match self.authenticate_request(request, None).await {
+            AuthenticationResult::Ok(user) => user,
+            result => return result,
+        };
+
+        // Make sure one of the user's roles grants the permission.
+        let roles: Vec<UserRole> = match __self.user_service.get_roles(&user.id).await {
+            Ok(roles) => roles,
+            Err(error) => return AuthenticationResult::Err(error),
+        };
+        if !roles
+            .iter()
+            .any(|role| role.permissions().contains(&permission))
+        {
+            return AuthenticationResult::InsufficientPermission;
+        }
+
+        return AuthenticationResult::Ok(user);
+    }
+
+    async fn authenticate_session(&self, session: &Session) -> AuthenticationResult {
+        // Query the user from persistent storage.
+        let user: User = match __self.user_service.get_by_id(&session.user_id).await {
+            QueryResult::Ok(user) => user,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Make sure the user has not reset their password since they logged in.
+        if user.password_reset_at > session.authenticated_at {
+            return AuthenticationResult::NotAuthenticated;
+        }
+
+        return self.check_account_status(user);
+    }
+
+    async fn delete_expired_failed_logins(&self) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the failed logins that are outside of the window.
+        return Ok(__self
+            .failed_login_repository
+            .delete_before(&self.get_failed_login_window_start(), &mut context)
+            .await?);
+    }
+}
+
+/// An implementation for the AuthServiceImpl struct.
+impl AuthServiceImpl {
+    /// # Description
+    ///
+    /// Create auth service parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `login_config` - The login configuration that determines when accounts are locked and
+    /// clients are throttled.
+    ///
+    /// # Returns
+    ///
+    /// The auth service parameters that were created.
+    pub(crate) fn create_parameters(login_config: LoginConfig) -> AuthServiceImplParameters {
+        return AuthServiceImplParameters { login_config };
+    }
+
+    /// # Description
+    ///
+    /// Authenticate the user an api token belongs to, and make sure the api token has the scope
+    /// the endpoint requires.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The api token's secret.
+    ///
+    /// `scope` - The scope the api token must have.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If authentication is successful, the Ok variant will be returned with the User that was
+    /// authenticated.
+    /// - If the api token does not exist, has expired or its user no longer exists, the
+    /// NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If the api token does not have the scope, the InsufficientScope variant will be returned.
+    /// - If an error occurs during the authentication process, the Err variant will be returned
+    /// with the error that occurred.
+    async fn authenticate_api_token(
+        &self,
+        token: &String,
+        scope: &ApiTokenScope,
+    ) -> AuthenticationResult {
+        // Query the api token.
+        let api_token: ApiToken = match self.api_token_service.authenticate(token).await {
+            QueryResult::Ok(api_token) => api_token,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Query the user the api token belongs to.
+        let user: User = match self.user_service.get_by_id(&api_token.user_id).await {
+            QueryResult::Ok(user) => user,
+            QueryResult::NotFound => return AuthenticationResult::NotAuthenticated,
+            QueryResult::Err(error) => return AuthenticationResult::Err(error),
+        };
+
+        // Make sure the user can use their account, and that the api token has the scope.
+        return match self.check_account_status(user) {
+            AuthenticationResult::Ok(_) if !api_token.has_scope(scope) => {
+                AuthenticationResult::InsufficientScope
+            }
+            result => result,
+        };
+    }
+
+    /// # Description
+    ///
+    /// Check if too many failed logins have been attempted from an ip address within the window.
+    ///
+    /// # Arguments
+    ///
+    /// `ip_address` - The ip address of the client attempting to login.
+    ///
+    /// `context` - The context the failed logins will be counted in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the check was performed, the Ok variant will be returned with if the client is
+    /// throttled.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn is_throttled(
+        &self,
+        ip_address: &String,
+        context: &mut QueryContext<'_>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let failed_login_count: u64 = self
+            .failed_login_repository
+            .count_by_ip_address(ip_address, &self.get_failed_login_window_start(), context)
+            .await?;
+        return Ok(failed_login_count >= self.login_config.maximum_failed_logins_per_ip_address);
+    }
+
+    /// # Description
+    ///
+    /// Finish the login of a user whose credentials (and second factor, if they enabled two-factor
+    /// authentication) have been verified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is logging in.
+    ///
+    /// `context` - The context the user's failed logins will be deleted in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If the user is allowed to use their account, the Ok variant will be returned with the
+    /// user.
+    /// - If the user no longer exists, the NotAuthenticated variant will be returned.
+    /// - If the user's account is banned, locked or requires a password reset, the Banned, Locked
+    /// or PasswordResetRequired variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn finish_login(
+        &self,
+        mut user: User,
+        context: &mut QueryContext<'_>,
+    ) -> AuthenticationResult {
+        // Now that the user has logged in, their previous failed logins no longer count toward
+        // locking their account.
+        if let Err(error) = self
+            .failed_login_repository
+            .delete_by_username(&user.username, context)
+            .await
+        {
+            return AuthenticationResult::Err(Box::new(error));
+        }
+
+        // If the user's account was locked temporarily and the lock has expired, lift it.
+        if user.account_is_locked {
+            user.account_is_locked = false;
+            user.account_unlocks_at = None;
+            user = match self.user_service.update(&user).await {
+                UpdateResult::Ok(user) => user,
+                UpdateResult::NotFound => return AuthenticationResult::NotAuthenticated,
+                UpdateResult::Invalid(errors) => {
+                    return AuthenticationResult::Err(Box::new(errors))
+                }
+                UpdateResult::Err(error) => return AuthenticationResult::Err(error),
+            };
+        }
+
+        return self.check_account_status(user);
+    }
+
+    /// # Description
+    ///
+    /// Get the date and time failed logins start counting from.
+    ///
+    /// # Returns
+    ///
+    /// The start of the failed login window.
+    fn get_failed_login_window_start(&self) -> OffsetDateTime {
+        return OffsetDateTime::now_utc()
+            - Duration::seconds(self.login_config.failed_login_window as i64);
+    }
+
+    /// # Description
+    ///
+    /// Record a failed login, and lock the user's account if too many failed logins have been
+    /// attempted with its username within the window. This is performed in a transaction, so that
+    /// the account is only locked if the failed login was recorded and the unlock email was queued.
+    ///
+    /// # Arguments
+    ///
+    /// `username` - The username the login was attempted with.
+    ///
+    /// `ip_address` - The ip address of the client that attempted the login.
+    ///
+    /// `user` - The user with the username, if one exists.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the failed login was recorded, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn record_failed_login(
+        &self,
+        username: &String,
+        ip_address: &String,
+        user: Option<&User>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = self.connection_factory.get_connection().await?;
+
+        // Start a transaction.
+        let transaction = connection.begin().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Record the failed login.
+        let result = self
+            .record_failed_login_with_context(username, ip_address, user, &mut context)
             .await;
+
+        // If the failed login was recorded, commit the transaction, otherwise roll it back.
+        match result {
+            Ok(()) => context.commit_if_transaction().await?,
+            Err(_) => context.rollback_if_transaction().await?,
+        };
+
+        // Return the result.
+        return result;
+    }
+
+    /// # Description
+    ///
+    /// Record a failed login in the context specified, and lock the user's account if too many
+    /// failed logins have been attempted with its username within the window.
+    ///
+    /// # Arguments
+    ///
+    /// `username` - The username the login was attempted with.
+    ///
+    /// `ip_address` - The ip address of the client that attempted the login.
+    ///
+    /// `user` - The user with the username, if one exists.
+    ///
+    /// `context` - The context the failed login will be recorded in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the failed login was recorded, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn record_failed_login_with_context(
+        &self,
+        username: &String,
+        ip_address: &String,
+        user: Option<&User>,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Record the failed login.
+        let failed_login: FailedLogin = FailedLogin {
+            id: 0,
+            username: username.clone(),
+            ip_address: ip_address.clone(),
+            attempted_at: OffsetDateTime::now_utc(),
+        };
+        self.failed_login_repository
+            .insert(&failed_login, context)
+            .await?;
+
+        // Failed logins for usernames that do not belong to a user only count toward throttling
+        // the client.
+        let user: &User = match user {
+            Some(user) => user,
+            None => return Ok(()),
+        };
+
+        // If too many failed logins were attempted with the username, lock the account.
+        let failed_login_count: u64 = self
+            .failed_login_repository
+            .count_by_username(username, &self.get_failed_login_window_start(), context)
+            .await?;
+        if failed_login_count >= self.login_config.maximum_failed_logins_per_user {
+            self.user_service
+                .lock_account_with_context(user, self.login_config.lockout_duration, context)
+                .await?;
+            self.failed_login_repository
+                .delete_by_username(username, context)
+                .await?;
+        }
+
+        return Ok(());
+    }
+
+    /// # Description
+    ///
+    /// Check if a user's account is locked. Accounts that were locked temporarily are no longer
+    /// locked once the lock has expired.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose account is being checked.
+    ///
+    /// # Returns
+    ///
+    /// If the user's account is locked.
+    fn lock_is_in_effect(&self, user: &User) -> bool {
+        return user.account_is_locked
+            && user
+                .account_unlocks_at
+                .is_none_or(|unlocks_at| unlocks_at > OffsetDateTime::now_utc());
+    }
+
+    /// # Description
+    ///
+    /// Make sure a user whose credentials have been verified is allowed to use their account. The
+    /// account status is only checked after the credentials are verified, so that it is not revealed
+    /// to anyone who does not know them.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose credentials have been verified.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an authentication result:
+    /// - If the user's account has been banned, the Banned variant will be returned.
+    /// - If the user's account has been locked, the Locked variant will be returned.
+    /// - If the user must reset their password, the PasswordResetRequired variant will be returned.
+    /// - Otherwise, the Ok variant will be returned with the user.
+    fn check_account_status(&self, user: User) -> AuthenticationResult {
+        if user.account_is_banned {
+            return AuthenticationResult::Banned;
+        }
+
+        if self.lock_is_in_effect(&user) {
+            return AuthenticationResult::Locked;
+        }
+
+        if user.password_reset_is_required {
+            return AuthenticationResult::PasswordResetRequired;
+        }
+
+        return AuthenticationResult::Ok(user);
     }
 }
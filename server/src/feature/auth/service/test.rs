@@ -7,14 +7,26 @@ use crate::{
     },
     config::Config,
     feature::{
+        api_token::{
+            model::{ApiToken, ApiTokenScope},
+            service::ApiTokenService,
+        },
         auth::service::AuthService,
         crypto::service::CryptoService,
-        user::{model::User, service::UserService},
+        session::{model::Session, service::SessionService},
+        two_factor::{model::TwoFactorEnrollment, service::TwoFactorService},
+        user::{
+            model::{Permission, User, UserRole},
+            service::UserService,
+        },
     },
     injector::DependencyInjector,
 };
+use actix_web::test::TestRequest;
+use rand::random;
 use shaku::HasComponent;
-use std::{sync::Arc, thread, time::Duration};
+use sqlx::types::Json;
+use std::{net::Ipv4Addr, sync::Arc, thread, time::Duration};
 use time::OffsetDateTime;
 
 /// # Description
@@ -44,10 +56,48 @@ fn create_test_user() -> User {
         email_is_verified: false,
         password_reset_is_required: false,
         account_is_locked: false,
+        account_unlocks_at: None,
         account_is_banned: false,
     };
 }
 
+/// # Description
+///
+/// Create a session for a test user, so that tokens can be created for the session family.
+///
+/// # Arguments
+///
+/// `user` - The user the session is being created for.
+///
+/// `session_service` - The session service that will be used to create the session.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to create the session.
+///
+/// # Returns
+///
+/// The session that was created.
+async fn create_test_session(user: &User, session_service: &Arc<dyn SessionService>) -> Session {
+    let (_, session) = session_service
+        .create(user, &None, &create_test_ip_address())
+        .await
+        .expect("Failed to create session");
+    return session;
+}
+
+/// # Description
+///
+/// A function to create a random ip address that can be used for testing, so that failed logins
+/// from other tests do not throttle it.
+///
+/// # Returns
+///
+/// The ip address that was created.
+fn create_test_ip_address() -> String {
+    return Ipv4Addr::from(random::<[u8; 4]>()).to_string();
+}
+
 /// # Description
 ///
 /// A utility function to load the server's configuration file.
@@ -117,7 +167,7 @@ async fn authenticating_a_user_that_does_not_exist_via_credentials_fails() {
 
     // Attempt to authenticate the user.
     match auth_service
-        .authenticate_credentials(&user.username, &user.password)
+        .authenticate_credentials(&user.username, &user.password, &create_test_ip_address())
         .await
     {
         AuthenticationResult::Ok(user) => panic!(
@@ -125,6 +175,14 @@ async fn authenticating_a_user_that_does_not_exist_via_credentials_fails() {
             user
         ),
         AuthenticationResult::NotAuthenticated => {}
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to perform authentication: {:?}",
             error
@@ -174,11 +232,19 @@ async fn authenticating_a_user_that_exists_via_credentials_succeeds() {
 
     // Attempt to authenticate the user.
     let authenticated_user: User = match auth_service
-        .authenticate_credentials(&user.username, &user.password)
+        .authenticate_credentials(&user.username, &user.password, &create_test_ip_address())
         .await
     {
         AuthenticationResult::Ok(user) => user,
         AuthenticationResult::NotAuthenticated => panic!("Failed to authenticate the user."),
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to perform authentication: {:?}",
             error
@@ -235,7 +301,7 @@ async fn authenticating_a_user_that_does_not_exit_via_token_fails() {
 
     // Generate a token for the user.
     let token: String = crypto_service
-        .create_token(&user)
+        .create_token(&user, &generate_random_string(48))
         .expect("Failed to create token for user");
 
     // Attempt to authenticate the user.
@@ -245,6 +311,14 @@ async fn authenticating_a_user_that_does_not_exit_via_token_fails() {
             user
         ),
         AuthenticationResult::NotAuthenticated => {}
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to authenticate a user token: {:?}",
             error
@@ -266,6 +340,9 @@ async fn authenticating_a_user_that_exists_via_token_succeeds() {
     // Get a crypto service instance.
     let crypto_service: Arc<dyn CryptoService> = injector.resolve();
 
+    // Get a session service instance.
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+
     // Get an auth service instance.
     let auth_service: Arc<dyn AuthService> = injector.resolve();
 
@@ -295,14 +372,23 @@ async fn authenticating_a_user_that_exists_via_token_succeeds() {
     }
 
     // Generate a token for the user.
+    let session: Session = create_test_session(&user, &session_service).await;
     let token: String = crypto_service
-        .create_token(&user)
+        .create_token(&user, &session.family_id)
         .expect("Failed to create token for user");
 
     // Attempt to authenticate the user.
     let user: User = match auth_service.authenticate_token(&token).await {
         AuthenticationResult::Ok(user) => user,
         AuthenticationResult::NotAuthenticated => panic!("Failed to authenticate the user"),
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to authenticate a user token: {:?}",
             error
@@ -321,6 +407,268 @@ async fn authenticating_a_user_that_exists_via_token_succeeds() {
     }
 }
 
+/// # Description
+///
+/// Make sure tokens can no longer be used once the session family they were issued for is revoked.
+#[actix_web::test]
+async fn revoking_a_session_family_invalidates_its_tokens() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Create two sessions for the user, and a token for each of them.
+    let session: Session = create_test_session(&user, &session_service).await;
+    let other_session: Session = create_test_session(&user, &session_service).await;
+    let token: String = crypto_service
+        .create_token(&user, &session.family_id)
+        .expect("Failed to create token for user");
+    let other_token: String = crypto_service
+        .create_token(&user, &other_session.family_id)
+        .expect("Failed to create token for user");
+    assert_eq!(
+        2,
+        session_service
+            .get_active_families(&user.id)
+            .await
+            .expect("Failed to query session families")
+            .len()
+    );
+
+    // Make sure a user can not revoke a session family that belongs to another user.
+    match session_service
+        .revoke_family(&(user.id + 1), &session.family_id)
+        .await
+    {
+        DeletionResult::NotFound => {}
+        _ => panic!("A session family was revoked by a user it does not belong to"),
+    };
+
+    // Revoke one of the session families, and make sure only its token was invalidated.
+    match session_service
+        .revoke_family(&user.id, &session.family_id)
+        .await
+    {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("The session family could not be found"),
+        DeletionResult::Err(error) => panic!("Failed to revoke session family: {:?}", error),
+    };
+    match auth_service.authenticate_token(&token).await {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("A token of a revoked session family was authenticated"),
+    };
+    match auth_service.authenticate_token(&other_token).await {
+        AuthenticationResult::Ok(_) => {}
+        _ => panic!("A token of a session family that was not revoked was not authenticated"),
+    };
+
+    // Revoke every session family, and make sure the other token was invalidated as well.
+    session_service
+        .revoke_all(&user.id)
+        .await
+        .expect("Failed to revoke session families");
+    match auth_service.authenticate_token(&other_token).await {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("A token of a revoked session family was authenticated"),
+    };
+    assert!(session_service
+        .get_active_families(&user.id)
+        .await
+        .expect("Failed to query session families")
+        .is_empty());
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
+/// # Description
+///
+/// Test authenticating requests with an api token, and make sure it is only accepted by endpoints
+/// that require one of its scopes.
+#[actix_web::test]
+async fn api_tokens_are_limited_to_their_scopes() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let api_token_service: Arc<dyn ApiTokenService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Create an api token that can only read monitors, and a request that uses it.
+    let (token, _): (String, ApiToken) = match api_token_service
+        .insert(&ApiToken {
+            user_id: user.id,
+            name: generate_random_string(8),
+            scopes: Json(vec![ApiTokenScope::MonitorsRead]),
+            ..Default::default()
+        })
+        .await
+    {
+        InsertionResult::Ok(result) => result,
+        InsertionResult::Invalid(details) => panic!("Failed to create api token: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to create api token: {:?}", error),
+    };
+    let request = TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_http_request();
+
+    // Make sure the api token authenticates the user for endpoints that require its scope.
+    match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(authenticated_user) => assert_eq!(user.id, authenticated_user.id),
+        _ => panic!("The api token was not authenticated"),
+    };
+
+    // Make sure the api token is rejected by endpoints that require another scope, and by
+    // endpoints that do not accept api tokens.
+    match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::InsufficientScope => {}
+        _ => panic!("The api token was not rejected by an endpoint that requires another scope"),
+    };
+    match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("The api token was not rejected by an endpoint that does not accept it"),
+    };
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
+/// # Description
+///
+/// Test authorizing requests, and make sure they are only authorized once one of the user's roles
+/// grants the permission the endpoint requires.
+#[actix_web::test]
+async fn requests_are_only_authorized_with_a_granted_permission() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Create a request that is authenticated with an access token for the user.
+    let session: Session = create_test_session(&user, &session_service).await;
+    let token: String = crypto_service
+        .create_token(&user, &session.family_id)
+        .expect("Failed to create token for user");
+    let request = TestRequest::default()
+        .insert_header(("Authorization", format!("Bearer {}", token)))
+        .to_http_request();
+
+    // Make sure the request is not authorized while the user has no roles.
+    match auth_service
+        .authorize_request(&request, Permission::UsersRead)
+        .await
+    {
+        AuthenticationResult::InsufficientPermission => {}
+        _ => panic!("A user without roles was authorized"),
+    };
+
+    // Grant the user the admin role, and make sure the request is authorized.
+    let roles: Vec<UserRole> = user_service
+        .grant_role(&user.id, &UserRole::Admin)
+        .await
+        .expect("Failed to grant role");
+    assert_eq!(vec![UserRole::Admin], roles);
+    match auth_service
+        .authorize_request(&request, Permission::UsersRead)
+        .await
+    {
+        AuthenticationResult::Ok(authorized_user) => assert_eq!(user.id, authorized_user.id),
+        _ => panic!("A user with the permission was not authorized"),
+    };
+
+    // Revoke the role, and make sure the request is no longer authorized.
+    let roles: Vec<UserRole> = user_service
+        .revoke_role(&user.id, &UserRole::Admin)
+        .await
+        .expect("Failed to revoke role");
+    assert!(roles.is_empty());
+    match auth_service
+        .authorize_request(&request, Permission::UsersRead)
+        .await
+    {
+        AuthenticationResult::InsufficientPermission => {}
+        _ => panic!("A user whose role was revoked was authorized"),
+    };
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
 /// # Description
 ///
 /// Make sure existing tokens are expired after a user resets their password.
@@ -335,6 +683,9 @@ async fn updating_a_users_password_expires_existing_tokens() {
     // Get a crypto service instance.
     let crypto_service: Arc<dyn CryptoService> = injector.resolve();
 
+    // Get a session service instance.
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+
     // Get an auth service instance.
     let auth_service: Arc<dyn AuthService> = injector.resolve();
 
@@ -364,14 +715,23 @@ async fn updating_a_users_password_expires_existing_tokens() {
     }
 
     // Generate a token for the user.
+    let session: Session = create_test_session(&user, &session_service).await;
     let token: String = crypto_service
-        .create_token(&user)
+        .create_token(&user, &session.family_id)
         .expect("Failed to create token for user");
 
     // Attempt to authenticate the user.
     match auth_service.authenticate_token(&token).await {
         AuthenticationResult::Ok(user) => user,
         AuthenticationResult::NotAuthenticated => panic!("Failed to authenticate the user"),
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to authenticate a user token: {:?}",
             error
@@ -406,6 +766,12 @@ async fn updating_a_users_password_expires_existing_tokens() {
             user
         ),
         AuthenticationResult::NotAuthenticated => {},
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => panic!("The test user's account should not be restricted"),
         AuthenticationResult::Err(error) => panic!(
             "An error occurred while attempting to authenticate a user token: {:?}",
             error
@@ -423,3 +789,421 @@ async fn updating_a_users_password_expires_existing_tokens() {
         }
     }
 }
+
+/// # Description
+///
+/// Make sure users whose account is banned, locked or requires a password reset can not login or
+/// use their existing tokens.
+#[actix_web::test]
+async fn restricted_accounts_can_not_be_authenticated() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    for restriction in ["banned", "locked", "passwordResetRequired"] {
+        // Create a test user with the restriction.
+        let mut user: User = create_test_user();
+        let password: String = user.password.clone();
+        user.account_is_banned = restriction == "banned";
+        user.account_is_locked = restriction == "locked";
+        user.password_reset_is_required = restriction == "passwordResetRequired";
+
+        // Insert the user.
+        let user: User = match user_service.insert(&user).await {
+            InsertionResult::Ok(user) => user,
+            InsertionResult::Invalid(details) => panic!(
+                "Inserting a test user failed, generated fields failed validation: {:?}",
+                details
+            ),
+            InsertionResult::Err(error) => {
+                panic!("Failed to insert test user, an error occurred: {:?}", error)
+            }
+        };
+
+        // Make sure the user can not login or use a token, and the restriction is reported.
+        let session: Session = create_test_session(&user, &session_service).await;
+        let token: String = crypto_service
+            .create_token(&user, &session.family_id)
+            .expect("Failed to create token for user");
+        for result in [
+            auth_service
+                .authenticate_credentials(&user.username, &password, &create_test_ip_address())
+                .await,
+            auth_service.authenticate_token(&token).await,
+        ] {
+            match (restriction, result) {
+                ("banned", AuthenticationResult::Banned) => {}
+                ("locked", AuthenticationResult::Locked) => {}
+                ("passwordResetRequired", AuthenticationResult::PasswordResetRequired) => {}
+                _ => panic!("The {} user was not restricted", restriction),
+            };
+        }
+
+        // Delete the test user.
+        match user_service.delete(&user.id).await {
+            DeletionResult::Ok => {}
+            DeletionResult::NotFound => {
+                panic!("Failed to delete test user: The user could not be found.")
+            }
+            DeletionResult::Err(error) => {
+                panic!("Failed to delete test user, an error occurred: {:?}", error)
+            }
+        }
+    }
+}
+
+/// # Description
+///
+/// Make sure a user's account is locked after too many failed logins are attempted with their
+/// username, and that their password is not checked while it is locked.
+#[actix_web::test]
+async fn too_many_failed_logins_lock_the_account() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = create_test_user();
+    let password: String = user.password.clone();
+    let user: User = match user_service.insert(&user).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Attempt to login with an incorrect password until the account is locked.
+    let incorrect_password: String = generate_random_string(16);
+    for _ in 0..load_config().login.maximum_failed_logins_per_user {
+        match auth_service
+            .authenticate_credentials(
+                &user.username,
+                &incorrect_password,
+                &create_test_ip_address(),
+            )
+            .await
+        {
+            AuthenticationResult::NotAuthenticated => {}
+            _ => panic!("A login with an incorrect password was not rejected"),
+        };
+    }
+
+    // Make sure the account was locked temporarily.
+    let locked_user: User = match user_service.get_by_id(&user.id).await {
+        QueryResult::Ok(user) => user,
+        QueryResult::NotFound => panic!("A user that should exist could not be found."),
+        QueryResult::Err(error) => panic!("Failed to query the test user: {:?}", error),
+    };
+    assert!(locked_user.account_is_locked);
+    assert!(locked_user
+        .account_unlocks_at
+        .is_some_and(|unlocks_at| unlocks_at > OffsetDateTime::now_utc()));
+
+    // Make sure the correct password is not accepted while the account is locked.
+    match auth_service
+        .authenticate_credentials(&user.username, &password, &create_test_ip_address())
+        .await
+    {
+        AuthenticationResult::Locked => {}
+        _ => panic!("The locked user was able to login"),
+    };
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
+/// # Description
+///
+/// Make sure locked accounts give the same response whether the password is correct or not, so
+/// that passwords can not be guessed while the lock is in effect.
+#[actix_web::test]
+async fn locked_accounts_give_the_same_response_for_any_password() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user whose account is locked.
+    let mut user: User = create_test_user();
+    let password: String = user.password.clone();
+    user.account_is_locked = true;
+    user.account_unlocks_at = Some(OffsetDateTime::now_utc() + time::Duration::HOUR);
+    let user: User = match user_service.insert(&user).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Make sure logins with the correct and an incorrect password both report the lock.
+    for attempted_password in [password, generate_random_string(16)] {
+        match auth_service
+            .authenticate_credentials(
+                &user.username,
+                &attempted_password,
+                &create_test_ip_address(),
+            )
+            .await
+        {
+            AuthenticationResult::Locked => {}
+            _ => panic!("A login to a locked account was not rejected as locked"),
+        };
+    }
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
+/// # Description
+///
+/// Make sure users whose account was locked temporarily can login once the lock has expired, and
+/// that the lock is lifted when they do.
+#[actix_web::test]
+async fn expired_account_locks_are_lifted_when_logging_in() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user whose lock has expired.
+    let mut user: User = create_test_user();
+    let password: String = user.password.clone();
+    user.account_is_locked = true;
+    user.account_unlocks_at = Some(OffsetDateTime::now_utc() - time::Duration::MINUTE);
+    let user: User = match user_service.insert(&user).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Make sure the user can login, and their account is no longer locked.
+    match auth_service
+        .authenticate_credentials(&user.username, &password, &create_test_ip_address())
+        .await
+    {
+        AuthenticationResult::Ok(authenticated_user) => {
+            assert!(!authenticated_user.account_is_locked);
+            assert!(authenticated_user.account_unlocks_at.is_none());
+        }
+        _ => panic!("The user could not login after their lock expired"),
+    };
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
+
+/// # Description
+///
+/// Make sure clients that attempt too many failed logins are throttled, even if each login uses a
+/// different username.
+#[actix_web::test]
+async fn too_many_failed_logins_throttle_the_client() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an auth service instance.
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Attempt to login with usernames that do not exist until the client is throttled.
+    let ip_address: String = create_test_ip_address();
+    for _ in 0..load_config().login.maximum_failed_logins_per_ip_address {
+        match auth_service
+            .authenticate_credentials(
+                &generate_random_string(16),
+                &generate_random_string(16),
+                &ip_address,
+            )
+            .await
+        {
+            AuthenticationResult::NotAuthenticated => {}
+            _ => panic!("A login for a user that does not exist was not rejected"),
+        };
+    }
+
+    // Make sure the client is throttled.
+    match auth_service
+        .authenticate_credentials(
+            &generate_random_string(16),
+            &generate_random_string(16),
+            &ip_address,
+        )
+        .await
+    {
+        AuthenticationResult::Throttled => {}
+        _ => panic!("The client was not throttled"),
+    };
+
+    // Make sure other clients are not throttled.
+    match auth_service
+        .authenticate_credentials(
+            &generate_random_string(16),
+            &generate_random_string(16),
+            &create_test_ip_address(),
+        )
+        .await
+    {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("A client that did not attempt any failed logins was throttled"),
+    };
+}
+
+/// # Description
+///
+/// Test logging in as a user that has enabled two-factor authentication, and make sure their
+/// challenge is only accepted with a correct code and can only be used once.
+#[actix_web::test]
+async fn two_factor_challenges_require_a_correct_code() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let two_factor_service: Arc<dyn TwoFactorService> = injector.resolve();
+    let auth_service: Arc<dyn AuthService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!(
+            "Inserting a test user failed, generated fields failed validation: {:?}",
+            details
+        ),
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert test user, an error occurred: {:?}", error)
+        }
+    };
+
+    // Enroll the user in two-factor authentication.
+    let enrollment: TwoFactorEnrollment = match two_factor_service.begin_enrollment(&user).await {
+        InsertionResult::Ok(enrollment) => enrollment,
+        InsertionResult::Invalid(details) => panic!("Failed to begin enrollment: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to begin enrollment: {:?}", error),
+    };
+    let code: String = crypto_service
+        .create_totp_code(&enrollment.secret, &OffsetDateTime::now_utc())
+        .expect("Failed to create totp code");
+    match two_factor_service.confirm_enrollment(&user, &code).await {
+        UpdateResult::Ok(_) => {}
+        UpdateResult::NotFound => panic!("Failed to confirm enrollment: Enrollment not found"),
+        UpdateResult::Invalid(details) => panic!("Failed to confirm enrollment: {:?}", details),
+        UpdateResult::Err(error) => panic!("Failed to confirm enrollment: {:?}", error),
+    };
+
+    // Create a challenge for the user, as if they had entered their password.
+    let challenge_token: String = two_factor_service
+        .create_challenge(&user)
+        .await
+        .expect("Failed to create challenge");
+    let ip_address: String = create_test_ip_address();
+
+    // Make sure the challenge is not accepted with an incorrect code or an unknown token.
+    match auth_service
+        .authenticate_two_factor(&challenge_token, &String::from("abcde-fghij"), &ip_address)
+        .await
+    {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("A challenge was accepted with an incorrect code"),
+    };
+    let next_code: String = crypto_service
+        .create_totp_code(
+            &enrollment.secret,
+            &(OffsetDateTime::now_utc() + time::Duration::seconds(30)),
+        )
+        .expect("Failed to create totp code");
+    match auth_service
+        .authenticate_two_factor(&generate_random_string(48), &next_code, &ip_address)
+        .await
+    {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("An unknown challenge token was accepted"),
+    };
+
+    // Make sure the challenge is accepted with a correct code, but only once.
+    match auth_service
+        .authenticate_two_factor(&challenge_token, &next_code, &ip_address)
+        .await
+    {
+        AuthenticationResult::Ok(authenticated_user) => assert_eq!(user.id, authenticated_user.id),
+        AuthenticationResult::NotAuthenticated => panic!("Failed to authenticate the user."),
+        AuthenticationResult::Locked
+        | AuthenticationResult::Banned
+        | AuthenticationResult::PasswordResetRequired
+        | AuthenticationResult::Throttled
+        | AuthenticationResult::InsufficientScope
+        | AuthenticationResult::InsufficientPermission => {
+            panic!("The test user's account should not be restricted")
+        }
+        AuthenticationResult::Err(error) => panic!(
+            "An error occurred while attempting to perform authentication: {:?}",
+            error
+        ),
+    };
+    match auth_service
+        .authenticate_two_factor(&challenge_token, &next_code, &ip_address)
+        .await
+    {
+        AuthenticationResult::NotAuthenticated => {}
+        _ => panic!("A challenge was accepted twice"),
+    };
+
+    // Delete the test user.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete test user: The user could not be found.")
+        }
+        DeletionResult::Err(error) => {
+            panic!("Failed to delete test user, an error occurred: {:?}", error)
+        }
+    }
+}
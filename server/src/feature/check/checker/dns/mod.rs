@@ -0,0 +1,314 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::{
+        checker::{
+            create_error_message, get_elapsed_milliseconds, get_timeout, truncate_error_message,
+        },
+        model::{CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{DnsAssertion, DnsMonitorConfiguration, DnsRecordType, Monitor},
+};
+use hickory_resolver::{
+    config::{NameServerConfig, ResolveHosts, ResolverConfig, ResolverOpts},
+    name_server::TokioConnectionProvider,
+    proto::{
+        rr::{Name, RData, RecordType},
+        xfer::Protocol,
+        ProtoErrorKind,
+    },
+    ResolveError, Resolver, TokioResolver,
+};
+use std::{net::SocketAddr, time::Instant};
+use time::OffsetDateTime;
+use tokio::time::timeout;
+
+/// # Description
+///
+/// Perform a dns check by querying the monitor's resolver for the monitored host name's records,
+/// and asserting on the records that are returned.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor the check is being performed for.
+///
+/// `configuration` - The monitor's dns configuration.
+///
+/// # Returns
+///
+/// The result of the check.
+pub(crate) async fn check(
+    monitor: &Monitor,
+    configuration: &DnsMonitorConfiguration,
+) -> CheckResult {
+    // Create the check result.
+    let mut check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: OffsetDateTime::now_utc(),
+        ..Default::default()
+    };
+
+    // Create the resolver.
+    let resolver: TokioResolver = match create_resolver(monitor, configuration) {
+        Ok(resolver) => resolver,
+        Err(error) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Resolution);
+            check_result.error_message = Some(truncate_error_message(error));
+            return check_result;
+        }
+    };
+
+    // Parse the host name, treating it as fully qualified so no search domains are appended.
+    let mut name: Name = match Name::from_utf8(&configuration.host) {
+        Ok(name) => name,
+        Err(error) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Resolution);
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+    };
+    name.set_fqdn(true);
+
+    // Perform the query.
+    let record_type: RecordType = get_record_type(&configuration.record_type);
+    let start: Instant = Instant::now();
+    let lookup_result = timeout(get_timeout(monitor), resolver.lookup(name, record_type)).await;
+    check_result.latency = get_elapsed_milliseconds(&start);
+
+    // If the query failed, determine why.
+    let records: Vec<String> = match lookup_result {
+        Ok(Ok(lookup)) => lookup
+            .record_iter()
+            .filter(|record| record.record_type() == record_type)
+            .filter_map(|record| format_record(record.data()))
+            .collect(),
+        Ok(Err(error)) if error.is_no_records_found() => Vec::new(),
+        Ok(Err(error)) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(get_error_kind(&error));
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+        Err(_) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Timeout);
+            check_result.error_message = Some(String::from("Timed out while resolving"));
+            return check_result;
+        }
+    };
+    check_result.details.records = Some(records.clone());
+
+    // If no records were returned, the host name could not be resolved.
+    if records.is_empty() {
+        check_result.status = CheckStatus::Down;
+        check_result.error_kind = Some(CheckErrorKind::Resolution);
+        check_result.error_message = Some(truncate_error_message(format!(
+            "No {} records were found for {}",
+            record_type, configuration.host
+        )));
+        return check_result;
+    }
+
+    // Make sure the records that were returned satisfy the monitor's assertion.
+    let normalized_records: Vec<String> = records
+        .iter()
+        .map(|record| normalize_record(&configuration.record_type, record))
+        .collect();
+    let normalized_expected_records: Vec<String> = configuration
+        .expected_records
+        .iter()
+        .map(|record| normalize_record(&configuration.record_type, record))
+        .collect();
+    let is_satisfied: bool = match configuration.assertion {
+        DnsAssertion::Resolves => true,
+        DnsAssertion::Exact => {
+            normalized_expected_records
+                .iter()
+                .all(|record| normalized_records.contains(record))
+                && normalized_records
+                    .iter()
+                    .all(|record| normalized_expected_records.contains(record))
+        }
+        DnsAssertion::Contains => normalized_expected_records
+            .iter()
+            .all(|record| normalized_records.contains(record)),
+    };
+
+    // If the assertion was not satisfied, the service is not operational.
+    if !is_satisfied {
+        check_result.status = CheckStatus::Down;
+        check_result.error_kind = Some(CheckErrorKind::RecordMismatch);
+        check_result.error_message = Some(truncate_error_message(format!(
+            "Received records {:?}, expected them to {} {:?}",
+            records,
+            match configuration.assertion {
+                DnsAssertion::Contains => "contain",
+                _ => "be",
+            },
+            configuration.expected_records
+        )));
+    }
+
+    // Return the check result.
+    return check_result;
+}
+
+/// # Description
+///
+/// Create a resolver that only sends queries to a dns monitor's resolver.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor the resolver is being created for.
+///
+/// `configuration` - The monitor's dns configuration.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the resolver was created successfully, the Ok variant will be returned with the resolver.
+/// - If the resolver's address is not valid, the Err variant will be returned with a description of
+/// the error.
+fn create_resolver(
+    monitor: &Monitor,
+    configuration: &DnsMonitorConfiguration,
+) -> Result<TokioResolver, String> {
+    // Parse the resolver's address.
+    let address: SocketAddr = match configuration.resolver.parse() {
+        Ok(address) => address,
+        Err(_) => {
+            return Err(format!(
+                "Invalid resolver address {:?}",
+                configuration.resolver
+            ))
+        }
+    };
+
+    // Configure the resolver to make a single, uncached attempt at each query.
+    let mut options: ResolverOpts = ResolverOpts::default();
+    options.timeout = get_timeout(monitor);
+    options.attempts = 1;
+    options.cache_size = 0;
+    options.use_hosts_file = ResolveHosts::Never;
+
+    // Create the resolver.
+    let resolver_config: ResolverConfig = ResolverConfig::from_parts(
+        None,
+        Vec::new(),
+        vec![NameServerConfig::new(address, Protocol::Udp)],
+    );
+    return Ok(
+        Resolver::builder_with_config(resolver_config, TokioConnectionProvider::default())
+            .with_options(options)
+            .build(),
+    );
+}
+
+/// # Description
+///
+/// Get the query record type that corresponds to a dns monitor's record type.
+///
+/// # Arguments
+///
+/// `record_type` - The dns monitor's record type.
+///
+/// # Returns
+///
+/// The query record type.
+fn get_record_type(record_type: &DnsRecordType) -> RecordType {
+    return match record_type {
+        DnsRecordType::A => RecordType::A,
+        DnsRecordType::Aaaa => RecordType::AAAA,
+        DnsRecordType::Cname => RecordType::CNAME,
+        DnsRecordType::Mx => RecordType::MX,
+        DnsRecordType::Txt => RecordType::TXT,
+    };
+}
+
+/// # Description
+///
+/// Get the kind of error that caused a query to fail.
+///
+/// # Arguments
+///
+/// `error` - The error that caused the query to fail.
+///
+/// # Returns
+///
+/// The kind of error that caused the query to fail.
+fn get_error_kind(error: &ResolveError) -> CheckErrorKind {
+    return match error.proto().map(|error| error.kind()) {
+        Some(ProtoErrorKind::Timeout) => CheckErrorKind::Timeout,
+        _ => CheckErrorKind::Resolution,
+    };
+}
+
+/// # Description
+///
+/// Format a record's data as text.
+///
+/// # Arguments
+///
+/// `data` - The record's data.
+///
+/// # Returns
+///
+/// An option that will contain the record's text if the record is of a supported type.
+fn format_record(data: &RData) -> Option<String> {
+    return match data {
+        RData::A(address) => Some(address.to_string()),
+        RData::AAAA(address) => Some(address.to_string()),
+        RData::CNAME(name) => Some(format_name(&name.0)),
+        RData::MX(mx) => Some(format!(
+            "{} {}",
+            mx.preference(),
+            format_name(mx.exchange())
+        )),
+        RData::TXT(txt) => Some(
+            txt.txt_data()
+                .iter()
+                .map(|data| String::from_utf8_lossy(data))
+                .collect(),
+        ),
+        _ => None,
+    };
+}
+
+/// # Description
+///
+/// Format a domain name as text, without its trailing dot.
+///
+/// # Arguments
+///
+/// `name` - The domain name.
+///
+/// # Returns
+///
+/// The domain name's text.
+fn format_name(name: &Name) -> String {
+    return name.to_utf8().trim_end_matches('.').to_string();
+}
+
+/// # Description
+///
+/// Normalize a record's text so that it can be compared with other records. Text records are
+/// compared exactly, while the case and trailing dots of other records are ignored.
+///
+/// # Arguments
+///
+/// `record_type` - The type of the record.
+///
+/// `record` - The record's text.
+///
+/// # Returns
+///
+/// The normalized text.
+fn normalize_record(record_type: &DnsRecordType, record: &str) -> String {
+    return match record_type {
+        DnsRecordType::Txt => String::from(record),
+        _ => record.trim().trim_end_matches('.').to_lowercase(),
+    };
+}
@@ -0,0 +1,327 @@
+use crate::feature::{
+    check::{
+        checker::Checker,
+        model::{CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{
+        DnsAssertion, DnsMonitorConfiguration, DnsRecordType, Monitor, MonitorConfiguration,
+    },
+};
+use hickory_resolver::proto::{
+    op::{Message, MessageType, ResponseCode},
+    rr::{
+        rdata::{A, AAAA, CNAME, MX, TXT},
+        Name, RData, Record, RecordType,
+    },
+};
+use std::{net::Ipv4Addr, str::FromStr};
+use tokio::net::UdpSocket;
+
+/// # Description
+///
+/// Get the records the stand-in dns server will answer a query with.
+///
+/// # Arguments
+///
+/// `name` - The name that was queried.
+///
+/// `record_type` - The type of records that were queried.
+///
+/// # Returns
+///
+/// An option that will contain the records if the name exists.
+fn get_stand_in_records(name: &str, record_type: RecordType) -> Option<Vec<RData>> {
+    if name != "example.test." {
+        return None;
+    }
+
+    let records: Vec<RData> = match record_type {
+        RecordType::A => vec![
+            RData::A(A::new(192, 0, 2, 1)),
+            RData::A(A::new(192, 0, 2, 2)),
+        ],
+        RecordType::AAAA => vec![RData::AAAA(AAAA::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))],
+        RecordType::CNAME => vec![RData::CNAME(CNAME(
+            Name::from_str("Target.Example.Test.").unwrap(),
+        ))],
+        RecordType::MX => vec![RData::MX(MX::new(
+            10,
+            Name::from_str("mail.example.test.").unwrap(),
+        ))],
+        RecordType::TXT => vec![RData::TXT(TXT::new(vec![String::from("v=spf1 -all")]))],
+        _ => Vec::new(),
+    };
+
+    return Some(records);
+}
+
+/// # Description
+///
+/// Start a local stand-in dns server that monitors can use as their resolver.
+///
+/// The server only knows about the `example.test` host name, and responds to queries for any other
+/// host name as if it does not exist.
+///
+/// # Panics
+///
+/// This function will panic if the server could not be bound to a local port.
+///
+/// # Returns
+///
+/// The address of the server.
+async fn start_stand_in_server() -> String {
+    // Bind a socket to a random local port.
+    let socket: UdpSocket = UdpSocket::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let address: String = socket
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .to_string();
+
+    // Answer queries in the background.
+    actix_web::rt::spawn(async move {
+        let mut buffer: [u8; 512] = [0; 512];
+        while let Ok((length, peer)) = socket.recv_from(&mut buffer).await {
+            let request: Message = match Message::from_vec(&buffer[..length]) {
+                Ok(request) => request,
+                Err(_) => continue,
+            };
+
+            // Create the response.
+            let mut response: Message = Message::new();
+            response
+                .set_id(request.id())
+                .set_message_type(MessageType::Response)
+                .set_op_code(request.op_code())
+                .set_recursion_desired(request.recursion_desired())
+                .set_recursion_available(true)
+                .add_queries(request.queries().to_vec());
+
+            // Answer the query.
+            let query = &request.queries()[0];
+            match get_stand_in_records(&query.name().to_lowercase().to_utf8(), query.query_type()) {
+                Some(records) => {
+                    for record in records {
+                        response.add_answer(Record::from_rdata(query.name().clone(), 60, record));
+                    }
+                }
+                None => {
+                    response.set_response_code(ResponseCode::NXDomain);
+                }
+            }
+
+            let _ = socket.send_to(&response.to_vec().unwrap(), peer).await;
+        }
+    });
+
+    // Return the server's address.
+    return address;
+}
+
+/// # Description
+///
+/// Create a dns monitor that can be used for testing.
+///
+/// # Arguments
+///
+/// `resolver` - The address of the resolver the monitor will query.
+///
+/// `record_type` - The type of records the monitor will query.
+///
+/// `assertion` - The assertion the monitor will make.
+///
+/// `expected_records` - The records the assertion will be made with.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor(
+    resolver: String,
+    record_type: DnsRecordType,
+    assertion: DnsAssertion,
+    expected_records: Vec<&str>,
+) -> Monitor {
+    return Monitor {
+        id: 1,
+        check_timeout: 1,
+        configuration: MonitorConfiguration::Dns(DnsMonitorConfiguration {
+            host: String::from("example.test"),
+            record_type,
+            resolver,
+            assertion,
+            expected_records: expected_records.into_iter().map(String::from).collect(),
+        }),
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Test checking a host name that resolves, and make sure it is considered to be up and the records
+/// are recorded.
+#[actix_web::test]
+async fn resolving_host_is_up() {
+    // Start the stand-in server.
+    let resolver: String = start_stand_in_server().await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor =
+        create_test_monitor(resolver, DnsRecordType::A, DnsAssertion::Resolves, vec![]);
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded, and recorded the records.
+    assert_eq!(monitor.id, check_result.monitor_id);
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+    assert_eq!(
+        Some(vec![String::from("192.0.2.1"), String::from("192.0.2.2")]),
+        check_result.details.records
+    );
+}
+
+/// # Description
+///
+/// Test checking a host name whose records exactly match the expected records, and make sure it is
+/// considered to be up regardless of the order and case of the records.
+#[actix_web::test]
+async fn exact_records_are_up() {
+    // Start the stand-in server.
+    let resolver: String = start_stand_in_server().await;
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+
+    // Check the a records.
+    let monitor: Monitor = create_test_monitor(
+        resolver.clone(),
+        DnsRecordType::A,
+        DnsAssertion::Exact,
+        vec!["192.0.2.2", "192.0.2.1"],
+    );
+    assert_eq!(CheckStatus::Up, checker.check(&monitor).await.status);
+
+    // Check the cname record.
+    let monitor: Monitor = create_test_monitor(
+        resolver,
+        DnsRecordType::Cname,
+        DnsAssertion::Exact,
+        vec!["target.example.test."],
+    );
+    assert_eq!(CheckStatus::Up, checker.check(&monitor).await.status);
+}
+
+/// # Description
+///
+/// Test checking a host name whose records are not exactly the expected records, and make sure it
+/// is considered to be down.
+#[actix_web::test]
+async fn inexact_records_are_down() {
+    // Start the stand-in server.
+    let resolver: String = start_stand_in_server().await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(
+        resolver,
+        DnsRecordType::A,
+        DnsAssertion::Exact,
+        vec!["192.0.2.1"],
+    );
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the records.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::RecordMismatch),
+        check_result.error_kind
+    );
+    assert!(check_result.error_message.is_some());
+}
+
+/// # Description
+///
+/// Test checking a host name whose records contain the expected records, and make sure it is
+/// considered to be up, and that it is considered to be down once they do not.
+#[actix_web::test]
+async fn contained_records_are_up() {
+    // Start the stand-in server.
+    let resolver: String = start_stand_in_server().await;
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+
+    // Check the mx records.
+    let monitor: Monitor = create_test_monitor(
+        resolver.clone(),
+        DnsRecordType::Mx,
+        DnsAssertion::Contains,
+        vec!["10 mail.example.test"],
+    );
+    assert_eq!(CheckStatus::Up, checker.check(&monitor).await.status);
+
+    // Check the txt records, which must match exactly.
+    let monitor: Monitor = create_test_monitor(
+        resolver,
+        DnsRecordType::Txt,
+        DnsAssertion::Contains,
+        vec!["V=SPF1 -ALL"],
+    );
+    let check_result: CheckResult = checker.check(&monitor).await;
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::RecordMismatch),
+        check_result.error_kind
+    );
+    assert_eq!(
+        Some(vec![String::from("v=spf1 -all")]),
+        check_result.details.records
+    );
+}
+
+/// # Description
+///
+/// Test checking a host name that does not exist, and make sure it is considered to be down.
+#[actix_web::test]
+async fn nonexistent_host_is_down() {
+    // Start the stand-in server.
+    let resolver: String = start_stand_in_server().await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let mut monitor: Monitor =
+        create_test_monitor(resolver, DnsRecordType::A, DnsAssertion::Resolves, vec![]);
+    if let MonitorConfiguration::Dns(configuration) = &mut monitor.configuration {
+        configuration.host = String::from("missing.test");
+    }
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the resolution.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Resolution), check_result.error_kind);
+    assert_eq!(Some(Vec::new()), check_result.details.records);
+}
+
+/// # Description
+///
+/// Test checking a host name with a resolver that never responds, and make sure it is considered to
+/// be down once the monitor's timeout elapses.
+#[actix_web::test]
+async fn unresponsive_resolver_times_out() {
+    // Bind a socket that never responds to queries.
+    let socket: UdpSocket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0))
+        .await
+        .expect("Failed to bind socket");
+    let resolver: String = socket
+        .local_addr()
+        .expect("Failed to get socket address")
+        .to_string();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor =
+        create_test_monitor(resolver, DnsRecordType::A, DnsAssertion::Resolves, vec![]);
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the timeout.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Timeout), check_result.error_kind);
+    assert!(check_result.latency >= 1000);
+}
@@ -0,0 +1,361 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::{
+        checker::{
+            create_error_message, get_elapsed_milliseconds, get_timeout, truncate_error_message,
+        },
+        model::{CheckErrorKind, CheckResult, CheckStatus, FailedAssertion},
+    },
+    monitor::model::{
+        HttpAssertion, HttpMethod, HttpMonitorConfiguration, JsonPathComparison, Monitor,
+    },
+};
+use regex::Regex;
+use reqwest::{header::HeaderMap, Client, Method, Response};
+use serde_json::Value;
+use serde_json_path::JsonPath;
+use std::time::Instant;
+use time::OffsetDateTime;
+
+/// The maximum amount of bytes of a response body that will be read to evaluate assertions.
+const MAXIMUM_BODY_LENGTH: usize = 1048576;
+
+/// # Description
+///
+/// Perform an http check by sending a request to the monitored url.
+///
+/// # Arguments
+///
+/// `client` - The http client that will be used to send the request.
+///
+/// `monitor` - The monitor the check is being performed for.
+///
+/// `configuration` - The monitor's http configuration.
+///
+/// # Returns
+///
+/// The result of the check.
+pub(crate) async fn check(
+    client: &Client,
+    monitor: &Monitor,
+    configuration: &HttpMonitorConfiguration,
+) -> CheckResult {
+    // Create the check result.
+    let mut check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: OffsetDateTime::now_utc(),
+        ..Default::default()
+    };
+
+    // Send the request.
+    let start: Instant = Instant::now();
+    let response_result = client
+        .request(
+            get_request_method(&configuration.method),
+            &configuration.url,
+        )
+        .timeout(get_timeout(monitor))
+        .send()
+        .await;
+    check_result.latency = get_elapsed_milliseconds(&start);
+
+    // If the request failed, determine why it failed.
+    let response = match response_result {
+        Ok(response) => response,
+        Err(error) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(get_error_kind(&error));
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+    };
+
+    // If the response's status code is not expected, the service is not operational.
+    let status_code: u16 = response.status().as_u16();
+    if !configuration.expected_status_codes.contains(&status_code) {
+        check_result.status = CheckStatus::Down;
+        check_result.error_kind = Some(CheckErrorKind::UnexpectedStatusCode);
+        check_result.error_message = Some(format!(
+            "Received status code {}, expected one of {:?}",
+            status_code, configuration.expected_status_codes
+        ));
+        return check_result;
+    }
+
+    // If the monitor does not make any assertions, the service is operational.
+    if configuration.assertions.is_empty() {
+        return check_result;
+    }
+
+    // Capture the response's headers, and read its body if an assertion requires it.
+    let headers: HeaderMap = response.headers().clone();
+    let mut body: String = String::new();
+    if requires_body(&configuration.assertions) {
+        body = match read_body(response).await {
+            Ok(body) => body,
+            Err(error) => {
+                check_result.status = CheckStatus::Down;
+                check_result.error_kind = Some(get_error_kind(&error));
+                check_result.error_message = Some(create_error_message(&error));
+                return check_result;
+            }
+        };
+    }
+
+    // Evaluate each of the assertions, recording the ones that are not satisfied.
+    for assertion in &configuration.assertions {
+        if let Err(message) = evaluate_assertion(assertion, &headers, &body, check_result.latency) {
+            check_result
+                .details
+                .failed_assertions
+                .push(FailedAssertion {
+                    assertion: assertion.clone(),
+                    message,
+                });
+        }
+    }
+
+    // If any of the assertions were not satisfied, the service is not operational.
+    if !check_result.details.failed_assertions.is_empty() {
+        let messages: Vec<&str> = check_result
+            .details
+            .failed_assertions
+            .iter()
+            .map(|failed_assertion| failed_assertion.message.as_str())
+            .collect();
+        check_result.status = CheckStatus::Down;
+        check_result.error_kind = Some(CheckErrorKind::AssertionFailed);
+        check_result.error_message = Some(truncate_error_message(format!(
+            "{} of {} assertions failed: {}",
+            messages.len(),
+            configuration.assertions.len(),
+            messages.join("; ")
+        )));
+    }
+
+    // Return the check result.
+    return check_result;
+}
+
+/// # Description
+///
+/// Determine if any of an http monitor's assertions require the response body to be read.
+///
+/// # Arguments
+///
+/// `assertions` - The http monitor's assertions.
+///
+/// # Returns
+///
+/// If the response body must be read.
+fn requires_body(assertions: &[HttpAssertion]) -> bool {
+    return assertions.iter().any(|assertion| {
+        return matches!(
+            assertion,
+            HttpAssertion::BodyContains { .. }
+                | HttpAssertion::BodyNotContains { .. }
+                | HttpAssertion::BodyMatches { .. }
+                | HttpAssertion::JsonPath { .. }
+        );
+    });
+}
+
+/// # Description
+///
+/// Read a response's body as text, up to the maximum body length.
+///
+/// # Arguments
+///
+/// `response` - The response whose body will be read.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the body was read successfully, the Ok variant will be returned with the body.
+/// - If an error occurs while reading the body, the Err variant will be returned with the error
+/// that occurred.
+async fn read_body(mut response: Response) -> Result<String, reqwest::Error> {
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        let remaining_length: usize = MAXIMUM_BODY_LENGTH - body.len();
+        body.extend_from_slice(&chunk[..chunk.len().min(remaining_length)]);
+
+        // If the maximum body length has been reached, stop reading.
+        if body.len() >= MAXIMUM_BODY_LENGTH {
+            break;
+        }
+    }
+
+    return Ok(String::from_utf8_lossy(&body).into_owned());
+}
+
+/// # Description
+///
+/// Evaluate an http assertion against a response.
+///
+/// # Arguments
+///
+/// `assertion` - The assertion that will be evaluated.
+///
+/// `headers` - The response's headers.
+///
+/// `body` - The response's body (this is empty if the assertion does not require it).
+///
+/// `latency` - The amount of time (in milliseconds) it took to receive the response.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the assertion is satisfied, the Ok variant will be returned.
+/// - If the assertion is not satisfied, the Err variant will be returned with a description of why.
+fn evaluate_assertion(
+    assertion: &HttpAssertion,
+    headers: &HeaderMap,
+    body: &str,
+    latency: u32,
+) -> Result<(), String> {
+    match assertion {
+        HttpAssertion::BodyContains { keyword } => {
+            if !body.contains(keyword.as_str()) {
+                return Err(format!("Response body does not contain {:?}", keyword));
+            }
+        }
+        HttpAssertion::BodyNotContains { keyword } => {
+            if body.contains(keyword.as_str()) {
+                return Err(format!("Response body contains {:?}", keyword));
+            }
+        }
+        HttpAssertion::BodyMatches { pattern } => {
+            let regex: Regex = Regex::new(pattern).map_err(|error| error.to_string())?;
+            if !regex.is_match(body) {
+                return Err(format!("Response body does not match {:?}", pattern));
+            }
+        }
+        HttpAssertion::JsonPath {
+            path,
+            comparison,
+            value,
+        } => {
+            // Select the value from the response body.
+            let json: Value = serde_json::from_str(body)
+                .map_err(|_| String::from("Response body is not valid json"))?;
+            let json_path: JsonPath = JsonPath::parse(path).map_err(|error| error.to_string())?;
+            let selected_value: &Value = match json_path.query(&json).first() {
+                Some(selected_value) => selected_value,
+                None => return Err(format!("Json path {:?} did not select a value", path)),
+            };
+
+            // Compare the selected value to the expected value.
+            let is_satisfied: bool = match comparison {
+                JsonPathComparison::Equals => selected_value == value,
+                JsonPathComparison::NotEquals => selected_value != value,
+                JsonPathComparison::GreaterThan => {
+                    matches!((selected_value.as_f64(), value.as_f64()), (Some(selected), Some(expected)) if selected > expected)
+                }
+                JsonPathComparison::LessThan => {
+                    matches!((selected_value.as_f64(), value.as_f64()), (Some(selected), Some(expected)) if selected < expected)
+                }
+            };
+            if !is_satisfied {
+                return Err(format!(
+                    "Json path {:?} selected {}, expected it to be {} {}",
+                    path,
+                    selected_value,
+                    get_comparison_description(comparison),
+                    value
+                ));
+            }
+        }
+        HttpAssertion::HeaderMatches { name, pattern } => {
+            let header_value: &str = match headers.get(name.as_str()) {
+                Some(header_value) => header_value
+                    .to_str()
+                    .map_err(|_| format!("Response header {:?} is not valid text", name))?,
+                None => return Err(format!("Response header {:?} is missing", name)),
+            };
+            let regex: Regex = Regex::new(pattern).map_err(|error| error.to_string())?;
+            if !regex.is_match(header_value) {
+                return Err(format!(
+                    "Response header {:?} has the value {:?}, expected it to match {:?}",
+                    name, header_value, pattern
+                ));
+            }
+        }
+        HttpAssertion::LatencyBelow { threshold } => {
+            if latency >= *threshold {
+                return Err(format!(
+                    "Response took {}ms, expected it to take less than {}ms",
+                    latency, threshold
+                ));
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Get a human readable description of a json path comparison.
+///
+/// # Arguments
+///
+/// `comparison` - The json path comparison.
+///
+/// # Returns
+///
+/// The description of the comparison.
+fn get_comparison_description(comparison: &JsonPathComparison) -> &'static str {
+    return match comparison {
+        JsonPathComparison::Equals => "equal to",
+        JsonPathComparison::NotEquals => "not equal to",
+        JsonPathComparison::GreaterThan => "greater than",
+        JsonPathComparison::LessThan => "less than",
+    };
+}
+
+/// # Description
+///
+/// Get the request method that corresponds to a monitor's http method.
+///
+/// # Arguments
+///
+/// `method` - The monitor's http method.
+///
+/// # Returns
+///
+/// The request method.
+fn get_request_method(method: &HttpMethod) -> Method {
+    return match method {
+        HttpMethod::Get => Method::GET,
+        HttpMethod::Head => Method::HEAD,
+        HttpMethod::Post => Method::POST,
+        HttpMethod::Put => Method::PUT,
+        HttpMethod::Patch => Method::PATCH,
+        HttpMethod::Delete => Method::DELETE,
+        HttpMethod::Options => Method::OPTIONS,
+    };
+}
+
+/// # Description
+///
+/// Determine the kind of error that caused a request to fail.
+///
+/// # Arguments
+///
+/// `error` - The error that caused the request to fail.
+///
+/// # Returns
+///
+/// The kind of error that caused the request to fail.
+fn get_error_kind(error: &reqwest::Error) -> CheckErrorKind {
+    if error.is_timeout() {
+        return CheckErrorKind::Timeout;
+    } else if error.is_connect() {
+        return CheckErrorKind::Connection;
+    } else {
+        return CheckErrorKind::Request;
+    }
+}
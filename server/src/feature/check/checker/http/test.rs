@@ -0,0 +1,368 @@
+use crate::feature::{
+    check::{
+        checker::Checker,
+        model::{CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{
+        HttpAssertion, HttpMethod, HttpMonitorConfiguration, JsonPathComparison, Monitor,
+        MonitorConfiguration,
+    },
+};
+use actix_web::{rt::time::sleep, web, App, HttpResponse, HttpServer};
+use serde_json::json;
+use std::{net::TcpListener, time::Duration};
+
+/// # Description
+///
+/// Start a local stand-in http server that monitors can be pointed at.
+///
+/// The server responds to the following routes:
+/// - `/ok` - Responds with a 200 status code.
+/// - `/error` - Responds with a 500 status code.
+/// - `/slow` - Responds with a 200 status code after 5 seconds.
+/// - `/health` - Responds with a 200 status code, a version header, and a json body.
+///
+/// # Panics
+///
+/// This function will panic if the server could not be bound to a local port.
+///
+/// # Returns
+///
+/// The base url of the server that was started.
+fn start_stand_in_server() -> String {
+    // Bind a listener to a random local port.
+    let listener: TcpListener =
+        TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in server");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .port();
+
+    // Create and start the server.
+    let server = HttpServer::new(|| {
+        App::new()
+            .route("/ok", web::to(|| async { HttpResponse::Ok().finish() }))
+            .route(
+                "/error",
+                web::to(|| async { HttpResponse::InternalServerError().finish() }),
+            )
+            .route(
+                "/health",
+                web::to(|| async {
+                    return HttpResponse::Ok()
+                        .insert_header(("X-Version", "1.2.3"))
+                        .content_type("application/json")
+                        .body(r#"{"status":"ok","db":"down","connections":12}"#);
+                }),
+            )
+            .route(
+                "/slow",
+                web::to(|| async {
+                    sleep(Duration::from_secs(5)).await;
+                    return HttpResponse::Ok().finish();
+                }),
+            )
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to start stand-in server")
+    .run();
+    actix_web::rt::spawn(server);
+
+    // Return the server's base url.
+    return format!("http://127.0.0.1:{}", port);
+}
+
+/// # Description
+///
+/// Create an http monitor that can be used for testing.
+///
+/// # Arguments
+///
+/// `url` - The url the monitor will send requests to.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor(url: String) -> Monitor {
+    return Monitor {
+        id: 1,
+        check_timeout: 1,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url,
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Test checking an endpoint that responds with an expected status code, and make sure it is
+/// considered to be up.
+#[actix_web::test]
+async fn expected_status_code_is_up() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(format!("{}/ok", base_url));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(monitor.id, check_result.monitor_id);
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+    assert_eq!(None, check_result.error_message);
+}
+
+/// # Description
+///
+/// Test checking an endpoint that responds with an unexpected status code, and make sure it is
+/// considered to be down.
+#[actix_web::test]
+async fn unexpected_status_code_is_down() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(format!("{}/error", base_url));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the status code.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::UnexpectedStatusCode),
+        check_result.error_kind
+    );
+    assert!(check_result.error_message.is_some());
+}
+
+/// # Description
+///
+/// Test that the expected status codes configured are respected, and make sure a non-200 status
+/// code can be considered to be up.
+#[actix_web::test]
+async fn configured_status_code_is_up() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Create a monitor that expects the server to error.
+    let mut monitor: Monitor = create_test_monitor(format!("{}/error", base_url));
+    monitor.configuration = MonitorConfiguration::Http(HttpMonitorConfiguration {
+        url: format!("{}/error", base_url),
+        method: HttpMethod::Get,
+        expected_status_codes: vec![500],
+        assertions: Vec::new(),
+    });
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(CheckStatus::Up, check_result.status);
+}
+
+/// # Description
+///
+/// Test checking an endpoint that takes longer than the monitor's timeout to respond, and make sure
+/// it is considered to be down.
+#[actix_web::test]
+async fn slow_response_times_out() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(format!("{}/slow", base_url));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the timeout.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Timeout), check_result.error_kind);
+    assert!(check_result.latency >= 1000);
+    assert!(check_result.latency < 5000);
+}
+
+/// # Description
+///
+/// Test checking an endpoint that is not accepting connections, and make sure it is considered to
+/// be down.
+#[actix_web::test]
+async fn refused_connection_is_down() {
+    // Find a local port that nothing is listening on.
+    let port: u16 = TcpListener::bind("127.0.0.1:0")
+        .expect("Failed to bind listener")
+        .local_addr()
+        .expect("Failed to get listener address")
+        .port();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(format!("http://127.0.0.1:{}/", port));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the connection.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Connection), check_result.error_kind);
+    assert!(check_result.error_message.is_some());
+}
+
+/// # Description
+///
+/// Create an http monitor that makes assertions, that can be used for testing.
+///
+/// # Arguments
+///
+/// `url` - The url the monitor will send requests to.
+///
+/// `assertions` - The assertions the monitor will make.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor_with_assertions(url: String, assertions: Vec<HttpAssertion>) -> Monitor {
+    let mut monitor: Monitor = create_test_monitor(url.clone());
+    monitor.configuration = MonitorConfiguration::Http(HttpMonitorConfiguration {
+        url,
+        method: HttpMethod::Get,
+        expected_status_codes: vec![200],
+        assertions,
+    });
+
+    return monitor;
+}
+
+/// # Description
+///
+/// Test checking an endpoint whose response satisfies all of the monitor's assertions, and make sure
+/// it is considered to be up.
+#[actix_web::test]
+async fn satisfied_assertions_are_up() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Create a monitor that makes each kind of assertion.
+    let monitor: Monitor = create_test_monitor_with_assertions(
+        format!("{}/health", base_url),
+        vec![
+            HttpAssertion::BodyContains {
+                keyword: String::from("\"status\":\"ok\""),
+            },
+            HttpAssertion::BodyNotContains {
+                keyword: String::from("error"),
+            },
+            HttpAssertion::BodyMatches {
+                pattern: String::from(r#""connections":\d+"#),
+            },
+            HttpAssertion::JsonPath {
+                path: String::from("$.status"),
+                comparison: JsonPathComparison::Equals,
+                value: json!("ok"),
+            },
+            HttpAssertion::JsonPath {
+                path: String::from("$.connections"),
+                comparison: JsonPathComparison::GreaterThan,
+                value: json!(10),
+            },
+            HttpAssertion::HeaderMatches {
+                name: String::from("x-version"),
+                pattern: String::from(r"^1\."),
+            },
+            HttpAssertion::LatencyBelow { threshold: 1000 },
+        ],
+    );
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+    assert!(check_result.details.failed_assertions.is_empty());
+}
+
+/// # Description
+///
+/// Test checking an endpoint whose response does not satisfy some of the monitor's assertions, and
+/// make sure it is considered to be down and the failed assertions are recorded.
+#[actix_web::test]
+async fn failed_assertions_are_down() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Create a monitor that makes assertions the response does not satisfy.
+    let db_assertion: HttpAssertion = HttpAssertion::JsonPath {
+        path: String::from("$.db"),
+        comparison: JsonPathComparison::Equals,
+        value: json!("up"),
+    };
+    let header_assertion: HttpAssertion = HttpAssertion::HeaderMatches {
+        name: String::from("X-Missing"),
+        pattern: String::from(".*"),
+    };
+    let monitor: Monitor = create_test_monitor_with_assertions(
+        format!("{}/health", base_url),
+        vec![
+            db_assertion.clone(),
+            HttpAssertion::BodyContains {
+                keyword: String::from("ok"),
+            },
+            header_assertion.clone(),
+        ],
+    );
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the assertions, and recorded the ones that failed.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::AssertionFailed),
+        check_result.error_kind
+    );
+    let failed_assertions: Vec<HttpAssertion> = check_result
+        .details
+        .failed_assertions
+        .into_iter()
+        .map(|failed_assertion| failed_assertion.assertion)
+        .collect();
+    assert_eq!(vec![db_assertion, header_assertion], failed_assertions);
+}
+
+/// # Description
+///
+/// Test checking an endpoint that does not respond with json using a json path assertion, and make
+/// sure it is considered to be down.
+#[actix_web::test]
+async fn json_path_assertion_on_non_json_body_is_down() {
+    // Start the stand-in server.
+    let base_url: String = start_stand_in_server();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor_with_assertions(
+        format!("{}/ok", base_url),
+        vec![HttpAssertion::JsonPath {
+            path: String::from("$.status"),
+            comparison: JsonPathComparison::Equals,
+            value: json!("ok"),
+        }],
+    );
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the assertion.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::AssertionFailed),
+        check_result.error_kind
+    );
+    assert_eq!(1, check_result.details.failed_assertions.len());
+}
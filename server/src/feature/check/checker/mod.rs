@@ -0,0 +1,162 @@
+pub(crate) mod dns;
+pub(crate) mod http;
+pub(crate) mod tcp;
+pub(crate) mod tls;
+
+use crate::feature::{
+    check::model::{CheckResult, CheckStatus},
+    monitor::model::{Monitor, MonitorConfiguration},
+};
+use openssl::ssl::{SslConnector, SslMethod};
+use reqwest::Client;
+use std::{
+    error::Error,
+    time::{Duration, Instant},
+};
+use time::OffsetDateTime;
+
+/// A checker struct, which performs the checks configured by monitors.
+pub(crate) struct Checker {
+    /// The http client that will be used to perform http checks.
+    http_client: Client,
+
+    /// The tls connector that will be used to perform tls checks.
+    tls_connector: SslConnector,
+}
+
+/// An implementation for the Checker struct.
+impl Checker {
+    /// # Description
+    ///
+    /// Create a new checker.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the checker was created successfully, the Ok variant will be returned with the checker.
+    /// - If an error occurs while creating the checker's http client or tls connector, the Err
+    /// variant will be returned with the error that occurred.
+    pub(crate) fn new() -> Result<Self, Box<dyn Error>> {
+        // Create the http client.
+        let http_client: Client = Client::builder()
+            .user_agent(concat!("EasyTracker/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        // Create the tls connector, which trusts the system's root certificates.
+        let tls_connector: SslConnector = SslConnector::builder(SslMethod::tls_client())?.build();
+
+        // Return the checker.
+        return Ok(Checker {
+            http_client,
+            tls_connector,
+        });
+    }
+
+    /// # Description
+    ///
+    /// Perform the check a monitor is configured to perform.
+    ///
+    /// Heartbeat monitors do not perform checks, they are evaluated by the heartbeat service instead.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor whose check will be performed.
+    ///
+    /// # Returns
+    ///
+    /// The result of the check.
+    pub(crate) async fn check(&self, monitor: &Monitor) -> CheckResult {
+        return match &monitor.configuration {
+            MonitorConfiguration::Http(configuration) => {
+                http::check(&self.http_client, monitor, configuration).await
+            }
+            MonitorConfiguration::Tcp(configuration) => tcp::check(monitor, configuration).await,
+            MonitorConfiguration::Tls(configuration) => {
+                tls::check(&self.tls_connector, monitor, configuration).await
+            }
+            MonitorConfiguration::Dns(configuration) => dns::check(monitor, configuration).await,
+            MonitorConfiguration::Heartbeat(_) => CheckResult {
+                monitor_id: monitor.id,
+                checked_at: OffsetDateTime::now_utc(),
+                status: CheckStatus::Down,
+                error_message: Some(String::from(
+                    "Heartbeat monitors cannot be actively checked",
+                )),
+                ..Default::default()
+            },
+        };
+    }
+}
+
+/// The maximum amount of characters a check result's error message may contain.
+const MAXIMUM_ERROR_MESSAGE_LENGTH: usize = 2048;
+
+/// # Description
+///
+/// Create a check result error message from an error, including the errors that caused it.
+///
+/// # Arguments
+///
+/// `error` - The error the message will be created from.
+///
+/// # Returns
+///
+/// The error message that was created, truncated to the maximum length a check result allows.
+pub(crate) fn create_error_message(error: &dyn Error) -> String {
+    // Describe the error, followed by each of the errors that caused it.
+    let mut message: String = error.to_string();
+    let mut source: Option<&dyn Error> = error.source();
+    while let Some(cause) = source {
+        message.push_str(": ");
+        message.push_str(&cause.to_string());
+        source = cause.source();
+    }
+
+    // Truncate the message to the maximum length allowed.
+    return truncate_error_message(message);
+}
+
+/// # Description
+///
+/// Truncate an error message to the maximum length a check result allows.
+///
+/// # Arguments
+///
+/// `message` - The error message that will be truncated.
+///
+/// # Returns
+///
+/// The error message that was truncated.
+pub(crate) fn truncate_error_message(message: String) -> String {
+    return message.chars().take(MAXIMUM_ERROR_MESSAGE_LENGTH).collect();
+}
+
+/// # Description
+///
+/// Get the amount of time that has elapsed since an instant, in milliseconds.
+///
+/// # Arguments
+///
+/// `start` - The instant the elapsed time will be measured from.
+///
+/// # Returns
+///
+/// The amount of milliseconds that have elapsed, capped at the maximum value of a u32.
+pub(crate) fn get_elapsed_milliseconds(start: &Instant) -> u32 {
+    return u32::try_from(start.elapsed().as_millis()).unwrap_or(u32::MAX);
+}
+
+/// # Description
+///
+/// Get the amount of time a monitor's checks are allowed to take.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor to get the timeout of.
+///
+/// # Returns
+///
+/// The monitor's timeout.
+pub(crate) fn get_timeout(monitor: &Monitor) -> Duration {
+    return Duration::from_secs(u64::from(monitor.check_timeout));
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::{
+        checker::{
+            create_error_message, get_elapsed_milliseconds, get_timeout, truncate_error_message,
+        },
+        model::{CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{Monitor, TcpMonitorConfiguration},
+};
+use regex::Regex;
+use std::time::Instant;
+use time::OffsetDateTime;
+use tokio::{
+    io::AsyncReadExt,
+    net::TcpStream,
+    time::{timeout_at, Instant as TokioInstant},
+};
+
+/// The maximum amount of bytes that will be read while waiting for a banner.
+const MAXIMUM_BANNER_LENGTH: usize = 1024;
+
+/// # Description
+///
+/// Perform a tcp check by opening a connection to the monitored host and port, and optionally
+/// matching the banner the service sends against the monitor's banner pattern.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor the check is being performed for.
+///
+/// `configuration` - The monitor's tcp configuration.
+///
+/// # Returns
+///
+/// The result of the check.
+pub(crate) async fn check(
+    monitor: &Monitor,
+    configuration: &TcpMonitorConfiguration,
+) -> CheckResult {
+    // Create the check result.
+    let mut check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: OffsetDateTime::now_utc(),
+        ..Default::default()
+    };
+
+    // Determine when the check must be completed by.
+    let start: Instant = Instant::now();
+    let deadline: TokioInstant = TokioInstant::now() + get_timeout(monitor);
+
+    // Open the connection.
+    let connection_result = timeout_at(
+        deadline,
+        TcpStream::connect((configuration.host.as_str(), configuration.port)),
+    )
+    .await;
+
+    // If the connection could not be opened, determine why.
+    let mut stream: TcpStream = match connection_result {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(error)) => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Connection);
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+        Err(_) => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Timeout);
+            check_result.error_message = Some(String::from("Timed out while connecting"));
+            return check_result;
+        }
+    };
+
+    // If the monitor does not expect a banner, the service is operational.
+    let banner_pattern: &String = match &configuration.banner_pattern {
+        Some(banner_pattern) => banner_pattern,
+        None => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            return check_result;
+        }
+    };
+
+    // Compile the banner pattern.
+    let banner_regex: Regex = match Regex::new(banner_pattern) {
+        Ok(banner_regex) => banner_regex,
+        Err(error) => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::BannerMismatch);
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+    };
+
+    // Read the banner until it matches the pattern, the connection is closed, the maximum banner
+    // length is reached, or the check times out.
+    let mut banner: Vec<u8> = Vec::new();
+    let mut buffer: [u8; MAXIMUM_BANNER_LENGTH] = [0; MAXIMUM_BANNER_LENGTH];
+    loop {
+        let read_result = timeout_at(deadline, stream.read(&mut buffer)).await;
+        check_result.latency = get_elapsed_milliseconds(&start);
+
+        // Add the bytes that were read to the banner.
+        match read_result {
+            Ok(Ok(0)) => break,
+            Ok(Ok(amount)) => banner.extend_from_slice(&buffer[..amount]),
+            Ok(Err(error)) => {
+                check_result.status = CheckStatus::Down;
+                check_result.error_kind = Some(CheckErrorKind::Connection);
+                check_result.error_message = Some(create_error_message(&error));
+                return check_result;
+            }
+            Err(_) if banner.is_empty() => {
+                check_result.status = CheckStatus::Down;
+                check_result.error_kind = Some(CheckErrorKind::Timeout);
+                check_result.error_message =
+                    Some(String::from("Timed out while waiting for a banner"));
+                return check_result;
+            }
+            Err(_) => break,
+        }
+
+        // If the banner matches the pattern, the service is operational.
+        if banner_regex.is_match(&String::from_utf8_lossy(&banner)) {
+            return check_result;
+        }
+
+        // If the maximum banner length has been reached, stop reading.
+        if banner.len() >= MAXIMUM_BANNER_LENGTH {
+            break;
+        }
+    }
+
+    // The banner did not match the pattern, so the service is not operational.
+    check_result.status = CheckStatus::Down;
+    check_result.error_kind = Some(CheckErrorKind::BannerMismatch);
+    check_result.error_message = Some(truncate_error_message(format!(
+        "Received banner {:?}, expected it to match {:?}",
+        String::from_utf8_lossy(&banner),
+        banner_pattern
+    )));
+
+    // Return the check result.
+    return check_result;
+}
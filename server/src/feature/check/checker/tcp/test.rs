@@ -0,0 +1,182 @@
+use crate::feature::{
+    check::{
+        checker::Checker,
+        model::{CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{Monitor, MonitorConfiguration, TcpMonitorConfiguration},
+};
+use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+/// # Description
+///
+/// Start a local stand-in tcp server that monitors can be pointed at.
+///
+/// # Arguments
+///
+/// `banner` - The banner the server will send to each connection it accepts, if any.
+///
+/// # Panics
+///
+/// This function will panic if the server could not be bound to a local port.
+///
+/// # Returns
+///
+/// The port the server is listening on.
+async fn start_stand_in_server(banner: Option<&'static str>) -> u16 {
+    // Bind a listener to a random local port.
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .port();
+
+    // Accept connections in the background, sending the banner to each of them.
+    actix_web::rt::spawn(async move {
+        let mut streams = Vec::new();
+        while let Ok((mut stream, _)) = listener.accept().await {
+            if let Some(banner) = banner {
+                let _ = stream.write_all(banner.as_bytes()).await;
+            }
+
+            // Keep the connection open until the server is dropped.
+            streams.push(stream);
+        }
+    });
+
+    // Return the server's port.
+    return port;
+}
+
+/// # Description
+///
+/// Create a tcp monitor that can be used for testing.
+///
+/// # Arguments
+///
+/// `port` - The local port the monitor will connect to.
+///
+/// `banner_pattern` - The banner pattern the monitor expects, if any.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor(port: u16, banner_pattern: Option<&str>) -> Monitor {
+    return Monitor {
+        id: 1,
+        check_timeout: 1,
+        configuration: MonitorConfiguration::Tcp(TcpMonitorConfiguration {
+            host: String::from("127.0.0.1"),
+            port,
+            banner_pattern: banner_pattern.map(String::from),
+        }),
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Test checking a port that is accepting connections, and make sure it is considered to be up.
+#[actix_web::test]
+async fn open_port_is_up() {
+    // Start the stand-in server.
+    let port: u16 = start_stand_in_server(None).await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(port, None);
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(monitor.id, check_result.monitor_id);
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+}
+
+/// # Description
+///
+/// Test checking a port that is not accepting connections, and make sure it is considered to be
+/// down.
+#[actix_web::test]
+async fn closed_port_is_down() {
+    // Find a local port that nothing is listening on.
+    let port: u16 = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind listener")
+        .local_addr()
+        .expect("Failed to get listener address")
+        .port();
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(port, None);
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the connection.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Connection), check_result.error_kind);
+    assert!(check_result.error_message.is_some());
+}
+
+/// # Description
+///
+/// Test checking a service whose banner matches the monitor's banner pattern, and make sure it is
+/// considered to be up.
+#[actix_web::test]
+async fn matching_banner_is_up() {
+    // Start the stand-in server.
+    let port: u16 = start_stand_in_server(Some("SSH-2.0-OpenSSH_9.6\r\n")).await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(port, Some(r"^SSH-2\.0-"));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+}
+
+/// # Description
+///
+/// Test checking a service whose banner does not match the monitor's banner pattern, and make sure
+/// it is considered to be down.
+#[actix_web::test]
+async fn mismatched_banner_is_down() {
+    // Start the stand-in server.
+    let port: u16 = start_stand_in_server(Some("220 smtp.example.com ESMTP\r\n")).await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(port, Some(r"^SSH-2\.0-"));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the banner.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::BannerMismatch),
+        check_result.error_kind
+    );
+    assert!(check_result.error_message.is_some());
+}
+
+/// # Description
+///
+/// Test checking a service that never sends a banner, and make sure it is considered to be down
+/// once the monitor's timeout elapses.
+#[actix_web::test]
+async fn missing_banner_times_out() {
+    // Start the stand-in server.
+    let port: u16 = start_stand_in_server(None).await;
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let monitor: Monitor = create_test_monitor(port, Some(r"^SSH-2\.0-"));
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the timeout.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Timeout), check_result.error_kind);
+    assert!(check_result.latency >= 1000);
+}
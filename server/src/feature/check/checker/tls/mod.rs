@@ -0,0 +1,288 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::{
+        checker::{create_error_message, get_elapsed_milliseconds, get_timeout},
+        model::{CertificateDetails, CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{Monitor, TlsMonitorConfiguration},
+};
+use openssl::{
+    asn1::{Asn1Time, Asn1TimeRef},
+    error::ErrorStack,
+    ssl::{Ssl, SslConnector, SslVerifyMode},
+    x509::{X509Ref, X509VerifyResult},
+};
+use std::{net::IpAddr, pin::Pin, time::Instant};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+use tokio::{
+    net::TcpStream,
+    time::{timeout_at, Instant as TokioInstant},
+};
+use tokio_openssl::SslStream;
+
+/// # Description
+///
+/// Perform a tls check by completing a handshake with the monitored host and port, and inspecting
+/// the certificate the service presents.
+///
+/// # Arguments
+///
+/// `connector` - The tls connector that will be used to perform the handshake.
+///
+/// `monitor` - The monitor the check is being performed for.
+///
+/// `configuration` - The monitor's tls configuration.
+///
+/// # Returns
+///
+/// The result of the check.
+pub(crate) async fn check(
+    connector: &SslConnector,
+    monitor: &Monitor,
+    configuration: &TlsMonitorConfiguration,
+) -> CheckResult {
+    // Create the check result.
+    let mut check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: OffsetDateTime::now_utc(),
+        ..Default::default()
+    };
+
+    // Determine when the check must be completed by.
+    let start: Instant = Instant::now();
+    let deadline: TokioInstant = TokioInstant::now() + get_timeout(monitor);
+
+    // Open the connection.
+    let connection_result = timeout_at(
+        deadline,
+        TcpStream::connect((configuration.host.as_str(), configuration.port)),
+    )
+    .await;
+
+    // If the connection could not be opened, determine why.
+    let stream: TcpStream = match connection_result {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(error)) => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Connection);
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+        Err(_) => {
+            check_result.latency = get_elapsed_milliseconds(&start);
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Timeout);
+            check_result.error_message = Some(String::from("Timed out while connecting"));
+            return check_result;
+        }
+    };
+
+    // Create the tls stream.
+    let server_name: &str = match &configuration.server_name {
+        Some(server_name) => server_name,
+        None => &configuration.host,
+    };
+    let mut stream: SslStream<TcpStream> =
+        match create_ssl(connector, server_name).and_then(|ssl| SslStream::new(ssl, stream)) {
+            Ok(stream) => stream,
+            Err(error) => {
+                check_result.latency = get_elapsed_milliseconds(&start);
+                check_result.status = CheckStatus::Down;
+                check_result.error_kind = Some(CheckErrorKind::Handshake);
+                check_result.error_message = Some(create_error_message(&error));
+                return check_result;
+            }
+        };
+
+    // Perform the handshake.
+    let handshake_result = timeout_at(deadline, Pin::new(&mut stream).connect()).await;
+    check_result.latency = get_elapsed_milliseconds(&start);
+
+    // If the handshake could not be completed, determine why.
+    match handshake_result {
+        Ok(Ok(())) => {}
+        Ok(Err(error)) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Handshake);
+            check_result.error_message = Some(create_error_message(&error));
+            return check_result;
+        }
+        Err(_) => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Timeout);
+            check_result.error_message =
+                Some(String::from("Timed out while performing the handshake"));
+            return check_result;
+        }
+    }
+
+    // Get the certificate the service presented.
+    let certificate = match stream.ssl().peer_certificate() {
+        Some(certificate) => certificate,
+        None => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::Handshake);
+            check_result.error_message = Some(String::from("No certificate was presented"));
+            return check_result;
+        }
+    };
+
+    // Record the details of the certificate.
+    let verify_result: X509VerifyResult = stream.ssl().verify_result();
+    let certificate_details: CertificateDetails =
+        match create_certificate_details(&certificate, verify_result) {
+            Ok(certificate_details) => certificate_details,
+            Err(error) => {
+                check_result.status = CheckStatus::Down;
+                check_result.error_kind = Some(CheckErrorKind::CertificateVerification);
+                check_result.error_message = Some(create_error_message(&error));
+                return check_result;
+            }
+        };
+    check_result.details.certificate = Some(certificate_details.clone());
+
+    // If the certificate could not be verified, the service is not operational.
+    if !certificate_details.is_chain_valid {
+        check_result.status = CheckStatus::Down;
+        check_result.error_kind = Some(CheckErrorKind::CertificateVerification);
+        check_result.error_message = Some(format!(
+            "Certificate verification failed: {}",
+            verify_result.error_string()
+        ));
+        return check_result;
+    }
+
+    // If the certificate expires within the expiry threshold, the service is degraded.
+    let expiry_threshold: Duration = Duration::days(i64::from(configuration.expiry_threshold));
+    if certificate_details.not_after - OffsetDateTime::now_utc() < expiry_threshold {
+        check_result.status = CheckStatus::Degraded;
+        check_result.error_kind = Some(CheckErrorKind::CertificateExpiring);
+        check_result.error_message = Some(format!(
+            "Certificate expires at {}, which is within {} days",
+            certificate_details
+                .not_after
+                .format(&Rfc3339)
+                .unwrap_or_default(),
+            configuration.expiry_threshold
+        ));
+    }
+
+    // Return the check result.
+    return check_result;
+}
+
+/// # Description
+///
+/// Create the ssl state that will be used to perform a handshake with a service.
+///
+/// The handshake is allowed to complete even if the certificate cannot be verified, so that the
+/// certificate's details can still be recorded. The outcome of the verification is determined
+/// after the handshake.
+///
+/// # Arguments
+///
+/// `connector` - The tls connector the ssl state will be created from.
+///
+/// `server_name` - The server name that will be sent during the handshake and that the
+/// certificate will be verified against.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the ssl state was created successfully, the Ok variant will be returned with the ssl state.
+/// - If an error occurs, the Err variant will be returned with the error that occurred.
+fn create_ssl(connector: &SslConnector, server_name: &str) -> Result<Ssl, ErrorStack> {
+    let mut configuration = connector.configure()?;
+    configuration.set_verify(SslVerifyMode::NONE);
+    return configuration.into_ssl(server_name);
+}
+
+/// # Description
+///
+/// Create the details of a certificate that was presented by a service.
+///
+/// # Arguments
+///
+/// `certificate` - The certificate the details will be created from.
+///
+/// `verify_result` - The result of verifying the certificate chain.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the details were created successfully, the Ok variant will be returned with the details.
+/// - If an error occurs, the Err variant will be returned with the error that occurred.
+fn create_certificate_details(
+    certificate: &X509Ref,
+    verify_result: X509VerifyResult,
+) -> Result<CertificateDetails, ErrorStack> {
+    // Describe the certificate's issuer.
+    let issuer: String = certificate
+        .issuer_name()
+        .entries()
+        .map(|entry| {
+            return format!(
+                "{}={}",
+                entry.object().nid().short_name().unwrap_or("?"),
+                String::from_utf8_lossy(entry.data().as_slice())
+            );
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    // Get the dns names and ip addresses the certificate is valid for.
+    let mut subject_alternative_names: Vec<String> = Vec::new();
+    if let Some(names) = certificate.subject_alt_names() {
+        for name in names {
+            if let Some(dns_name) = name.dnsname() {
+                subject_alternative_names.push(String::from(dns_name));
+            } else if let Some(ip_address) = name.ipaddress() {
+                if let Ok(octets) = <[u8; 4]>::try_from(ip_address) {
+                    subject_alternative_names.push(IpAddr::from(octets).to_string());
+                } else if let Ok(octets) = <[u8; 16]>::try_from(ip_address) {
+                    subject_alternative_names.push(IpAddr::from(octets).to_string());
+                }
+            }
+        }
+    }
+
+    // Determine if the certificate chain was verified.
+    let is_chain_valid: bool = verify_result == X509VerifyResult::OK;
+    let chain_validation_error: Option<String> = if is_chain_valid {
+        None
+    } else {
+        Some(String::from(verify_result.error_string()))
+    };
+
+    // Return the certificate's details.
+    return Ok(CertificateDetails {
+        not_after: convert_asn1_time(certificate.not_after())?,
+        issuer,
+        subject_alternative_names,
+        is_chain_valid,
+        chain_validation_error,
+    });
+}
+
+/// # Description
+///
+/// Convert an asn1 time to a date and time.
+///
+/// # Arguments
+///
+/// `asn1_time` - The asn1 time that will be converted.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the conversion was successful, the Ok variant will be returned with the date and time.
+/// - If an error occurs, the Err variant will be returned with the error that occurred.
+fn convert_asn1_time(asn1_time: &Asn1TimeRef) -> Result<OffsetDateTime, ErrorStack> {
+    let difference = Asn1Time::from_unix(0)?.diff(asn1_time)?;
+    return Ok(OffsetDateTime::UNIX_EPOCH
+        + Duration::days(i64::from(difference.days))
+        + Duration::seconds(i64::from(difference.secs)));
+}
@@ -0,0 +1,353 @@
+use crate::feature::{
+    check::{
+        checker::{tls::check, Checker},
+        model::{CertificateDetails, CheckErrorKind, CheckResult, CheckStatus},
+    },
+    monitor::model::{Monitor, MonitorConfiguration, TlsMonitorConfiguration},
+};
+use openssl::{
+    asn1::Asn1Time,
+    bn::BigNum,
+    ec::{EcGroup, EcKey},
+    hash::MessageDigest,
+    nid::Nid,
+    pkey::{PKey, Private},
+    ssl::{SslAcceptor, SslConnector, SslConnectorBuilder, SslMethod},
+    x509::{
+        extension::{BasicConstraints, SubjectAlternativeName},
+        X509Name, X509NameBuilder, X509,
+    },
+};
+use std::{fs, pin::Pin, sync::Arc};
+use tokio::{io::AsyncWriteExt, net::TcpListener};
+use tokio_openssl::SslStream;
+
+/// # Description
+///
+/// Load the repository's self-signed test certificate and its private key.
+///
+/// # Panics
+///
+/// This function will panic if the certificate or private key could not be loaded.
+///
+/// # Returns
+///
+/// The certificate and its private key.
+fn load_test_certificate() -> (X509, PKey<Private>) {
+    let certificate: X509 = X509::from_pem(
+        &fs::read("tls_certificate.pem").expect("Failed to read the test certificate"),
+    )
+    .expect("Failed to parse the test certificate");
+    let key: PKey<Private> = PKey::private_key_from_pem(
+        &fs::read("tls_certificate_key.pem").expect("Failed to read the test certificate key"),
+    )
+    .expect("Failed to parse the test certificate key");
+
+    return (certificate, key);
+}
+
+/// # Description
+///
+/// Create a self-signed certificate for localhost that expires after a number of days.
+///
+/// # Arguments
+///
+/// `days` - The amount of days until the certificate expires.
+///
+/// # Panics
+///
+/// This function will panic if the certificate could not be created.
+///
+/// # Returns
+///
+/// The certificate and its private key.
+fn create_test_certificate(days: u32) -> (X509, PKey<Private>) {
+    // Generate the certificate's private key.
+    let group: EcGroup =
+        EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).expect("Failed to create curve");
+    let key: PKey<Private> =
+        PKey::from_ec_key(EcKey::generate(&group).expect("Failed to generate key"))
+            .expect("Failed to create key");
+
+    // Create the certificate's subject.
+    let mut name_builder: X509NameBuilder = X509Name::builder().expect("Failed to create name");
+    name_builder
+        .append_entry_by_text("CN", "localhost")
+        .expect("Failed to set common name");
+    let name: X509Name = name_builder.build();
+
+    // Create and sign the certificate.
+    let mut builder = X509::builder().expect("Failed to create certificate");
+    builder.set_version(2).unwrap();
+    builder
+        .set_serial_number(&BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap())
+        .unwrap();
+    builder.set_subject_name(&name).unwrap();
+    builder.set_issuer_name(&name).unwrap();
+    builder.set_pubkey(&key).unwrap();
+    builder
+        .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+        .unwrap();
+    builder
+        .set_not_after(&Asn1Time::days_from_now(days).unwrap())
+        .unwrap();
+    builder
+        .append_extension(BasicConstraints::new().critical().ca().build().unwrap())
+        .unwrap();
+    let subject_alternative_name = SubjectAlternativeName::new()
+        .dns("localhost")
+        .ip("127.0.0.1")
+        .build(&builder.x509v3_context(None, None))
+        .unwrap();
+    builder.append_extension(subject_alternative_name).unwrap();
+    builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+    return (builder.build(), key);
+}
+
+/// # Description
+///
+/// Start a local stand-in tls server that monitors can be pointed at.
+///
+/// # Arguments
+///
+/// `certificate` - The certificate the server will present.
+///
+/// `key` - The certificate's private key.
+///
+/// # Panics
+///
+/// This function will panic if the server could not be created or bound to a local port.
+///
+/// # Returns
+///
+/// The port the server is listening on.
+async fn start_stand_in_server(certificate: &X509, key: &PKey<Private>) -> u16 {
+    // Create the tls acceptor.
+    let mut acceptor_builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls_server())
+        .expect("Failed to create acceptor");
+    acceptor_builder.set_certificate(certificate).unwrap();
+    acceptor_builder.set_private_key(key).unwrap();
+    let acceptor: Arc<SslAcceptor> = Arc::new(acceptor_builder.build());
+
+    // Bind a listener to a random local port.
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .port();
+
+    // Accept connections in the background, performing a handshake with each of them.
+    actix_web::rt::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let acceptor: Arc<SslAcceptor> = Arc::clone(&acceptor);
+            actix_web::rt::spawn(async move {
+                let ssl = openssl::ssl::Ssl::new(acceptor.context()).unwrap();
+                let mut stream = SslStream::new(ssl, stream).unwrap();
+                let _ = Pin::new(&mut stream).accept().await;
+            });
+        }
+    });
+
+    // Return the server's port.
+    return port;
+}
+
+/// # Description
+///
+/// Create a tls connector that trusts a certificate.
+///
+/// # Arguments
+///
+/// `certificate` - The certificate the connector will trust.
+///
+/// # Returns
+///
+/// The tls connector that was created.
+fn create_trusting_connector(certificate: &X509) -> SslConnector {
+    let mut builder: SslConnectorBuilder =
+        SslConnector::builder(SslMethod::tls_client()).expect("Failed to create connector");
+    builder
+        .cert_store_mut()
+        .add_cert(certificate.clone())
+        .expect("Failed to trust certificate");
+
+    return builder.build();
+}
+
+/// # Description
+///
+/// Create a tls monitor that can be used for testing.
+///
+/// # Arguments
+///
+/// `port` - The local port the monitor will connect to.
+///
+/// `server_name` - The server name the certificate will be verified against.
+///
+/// # Returns
+///
+/// The monitor that was created, and its tls configuration.
+fn create_test_monitor(port: u16, server_name: &str) -> (Monitor, TlsMonitorConfiguration) {
+    let configuration: TlsMonitorConfiguration = TlsMonitorConfiguration {
+        host: String::from("127.0.0.1"),
+        port,
+        server_name: Some(String::from(server_name)),
+        expiry_threshold: 30,
+    };
+    let monitor: Monitor = Monitor {
+        id: 1,
+        check_timeout: 2,
+        configuration: MonitorConfiguration::Tls(configuration.clone()),
+        ..Default::default()
+    };
+
+    return (monitor, configuration);
+}
+
+/// # Description
+///
+/// Test checking a service that presents a trusted certificate, and make sure it is considered to
+/// be up and the certificate's details are recorded.
+#[actix_web::test]
+async fn trusted_certificate_is_up() {
+    // Start the stand-in server.
+    let (certificate, key) = load_test_certificate();
+    let port: u16 = start_stand_in_server(&certificate, &key).await;
+
+    // Perform the check.
+    let connector: SslConnector = create_trusting_connector(&certificate);
+    let (monitor, configuration) = create_test_monitor(port, "localhost");
+    let check_result: CheckResult = check(&connector, &monitor, &configuration).await;
+
+    // Make sure the check succeeded.
+    assert_eq!(monitor.id, check_result.monitor_id);
+    assert_eq!(CheckStatus::Up, check_result.status);
+    assert_eq!(None, check_result.error_kind);
+
+    // Make sure the certificate's details were recorded.
+    let certificate_details: CertificateDetails = check_result
+        .details
+        .certificate
+        .expect("Certificate details were not recorded");
+    assert!(certificate_details.is_chain_valid);
+    assert_eq!(None, certificate_details.chain_validation_error);
+    assert!(certificate_details.issuer.contains("CN=localhost"));
+    assert_eq!(2123, certificate_details.not_after.year());
+}
+
+/// # Description
+///
+/// Test checking a service that presents an untrusted certificate, and make sure it is considered
+/// to be down.
+#[actix_web::test]
+async fn untrusted_certificate_is_down() {
+    // Start the stand-in server.
+    let (certificate, key) = load_test_certificate();
+    let port: u16 = start_stand_in_server(&certificate, &key).await;
+
+    // Perform the check using the system's root certificates.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let (monitor, _) = create_test_monitor(port, "localhost");
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the verification, but still recorded the details.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::CertificateVerification),
+        check_result.error_kind
+    );
+    let certificate_details: CertificateDetails = check_result
+        .details
+        .certificate
+        .expect("Certificate details were not recorded");
+    assert!(!certificate_details.is_chain_valid);
+    assert!(certificate_details.chain_validation_error.is_some());
+}
+
+/// # Description
+///
+/// Test checking a service whose certificate is not valid for the server name, and make sure it is
+/// considered to be down.
+#[actix_web::test]
+async fn mismatched_server_name_is_down() {
+    // Start the stand-in server.
+    let (certificate, key) = load_test_certificate();
+    let port: u16 = start_stand_in_server(&certificate, &key).await;
+
+    // Perform the check.
+    let connector: SslConnector = create_trusting_connector(&certificate);
+    let (monitor, configuration) = create_test_monitor(port, "example.com");
+    let check_result: CheckResult = check(&connector, &monitor, &configuration).await;
+
+    // Make sure the check failed because of the verification.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::CertificateVerification),
+        check_result.error_kind
+    );
+}
+
+/// # Description
+///
+/// Test checking a service whose certificate expires within the expiry threshold, and make sure it
+/// is considered to be degraded.
+#[actix_web::test]
+async fn expiring_certificate_is_degraded() {
+    // Start the stand-in server with a certificate that expires soon.
+    let (certificate, key) = create_test_certificate(5);
+    let port: u16 = start_stand_in_server(&certificate, &key).await;
+
+    // Perform the check.
+    let connector: SslConnector = create_trusting_connector(&certificate);
+    let (monitor, configuration) = create_test_monitor(port, "localhost");
+    let check_result: CheckResult = check(&connector, &monitor, &configuration).await;
+
+    // Make sure the check determined the service to be degraded.
+    assert_eq!(CheckStatus::Degraded, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::CertificateExpiring),
+        check_result.error_kind
+    );
+    let certificate_details: CertificateDetails = check_result
+        .details
+        .certificate
+        .expect("Certificate details were not recorded");
+    assert!(certificate_details.is_chain_valid);
+    assert_eq!(
+        vec![String::from("localhost"), String::from("127.0.0.1")],
+        certificate_details.subject_alternative_names
+    );
+}
+
+/// # Description
+///
+/// Test checking a service that does not speak tls, and make sure it is considered to be down.
+#[actix_web::test]
+async fn failed_handshake_is_down() {
+    // Start a plain tcp server that responds with a banner instead of a handshake.
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in server");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .port();
+    actix_web::rt::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let _ = stream.write_all(b"220 smtp.example.com ESMTP\r\n").await;
+        }
+    });
+
+    // Perform the check.
+    let checker: Checker = Checker::new().expect("Failed to create checker");
+    let (monitor, _) = create_test_monitor(port, "localhost");
+    let check_result: CheckResult = checker.check(&monitor).await;
+
+    // Make sure the check failed because of the handshake.
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(Some(CheckErrorKind::Handshake), check_result.error_kind);
+    assert_eq!(None, check_result.details.certificate);
+}
@@ -0,0 +1,7 @@
+pub(crate) mod checker;
+pub(crate) mod model;
+pub(crate) mod repository;
+pub(crate) mod retention;
+pub(crate) mod scheduler;
+pub(crate) mod service;
+pub(crate) mod statistics;
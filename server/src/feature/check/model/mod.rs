@@ -0,0 +1,485 @@
+use crate::feature::monitor::model::HttpAssertion;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::{Duration, OffsetDateTime};
+use validator::Validate;
+
+/// A check result struct.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckResult {
+    /// The check result's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the monitor that performed the check.
+    pub(crate) monitor_id: u64,
+
+    /// The date and time the check was performed at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) checked_at: OffsetDateTime,
+
+    /// The status the check determined the monitored service to be in.
+    pub(crate) status: CheckStatus,
+
+    /// The amount of time (in milliseconds) the check took to complete.
+    pub(crate) latency: u32,
+
+    /// The kind of error that caused the check to fail, if it failed.
+    pub(crate) error_kind: Option<CheckErrorKind>,
+
+    /// A human readable description of the error that caused the check to fail, if it failed.
+    #[validate(length(max = 2048))]
+    pub(crate) error_message: Option<String>,
+
+    /// Additional check type specific details that were gathered while performing the check.
+    pub(crate) details: CheckDetails,
+}
+
+/// A Default implementation for the CheckResult struct.
+impl Default for CheckResult {
+    fn default() -> Self {
+        return CheckResult {
+            id: 0,
+            monitor_id: 0,
+            checked_at: OffsetDateTime::now_utc(),
+            status: CheckStatus::Up,
+            latency: 0,
+            error_kind: None,
+            error_message: None,
+            details: CheckDetails::default(),
+        };
+    }
+}
+
+/// A PartialEq implementation for the CheckResult struct.
+impl PartialEq for CheckResult {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.monitor_id == other.monitor_id
+            && self.checked_at.date() == other.checked_at.date()
+            && self.checked_at.hour() == other.checked_at.hour()
+            && self.checked_at.minute() == other.checked_at.minute()
+            && self.checked_at.second() == other.checked_at.second()
+            && self.status == other.status
+            && self.latency == other.latency
+            && self.error_kind == other.error_kind
+            && self.error_message == other.error_message
+            && self.details == other.details;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the statuses a check can determine a monitored service to be in.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum CheckStatus {
+    /// # Description
+    ///
+    /// The Up variant is used when the monitored service is operational.
+    Up,
+
+    /// # Description
+    ///
+    /// The Degraded variant is used when the monitored service is operational, but requires
+    /// attention.
+    Degraded,
+
+    /// # Description
+    ///
+    /// The Down variant is used when the monitored service is not operational.
+    Down,
+
+    /// # Description
+    ///
+    /// The Maintenance variant is used when the monitored service is not operational during one of
+    /// its monitor's maintenance windows.
+    Maintenance,
+}
+
+/// An enumeration of all of the kinds of errors that can cause a check to fail.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum CheckErrorKind {
+    /// # Description
+    ///
+    /// The Timeout variant is used when the check did not complete within the monitor's timeout.
+    Timeout,
+
+    /// # Description
+    ///
+    /// The Connection variant is used when a connection to the monitored service could not be
+    /// established.
+    Connection,
+
+    /// # Description
+    ///
+    /// The UnexpectedStatusCode variant is used when the monitored service responded with a status
+    /// code the monitor does not expect.
+    UnexpectedStatusCode,
+
+    /// # Description
+    ///
+    /// The BannerMismatch variant is used when the first bytes sent by the monitored service do
+    /// not match the banner pattern the monitor expects.
+    BannerMismatch,
+
+    /// # Description
+    ///
+    /// The Handshake variant is used when a tls handshake with the monitored service could not be
+    /// completed.
+    Handshake,
+
+    /// # Description
+    ///
+    /// The CertificateVerification variant is used when the certificate presented by the monitored
+    /// service could not be verified.
+    CertificateVerification,
+
+    /// # Description
+    ///
+    /// The CertificateExpiring variant is used when the certificate presented by the monitored
+    /// service expires within the monitor's expiry threshold.
+    CertificateExpiring,
+
+    /// # Description
+    ///
+    /// The Resolution variant is used when the monitored host name's records could not be
+    /// resolved.
+    Resolution,
+
+    /// # Description
+    ///
+    /// The RecordMismatch variant is used when the records returned by the resolver do not satisfy
+    /// the monitor's assertion.
+    RecordMismatch,
+
+    /// # Description
+    ///
+    /// The AssertionFailed variant is used when the response received from the monitored service
+    /// does not satisfy one or more of the monitor's assertions.
+    AssertionFailed,
+
+    /// # Description
+    ///
+    /// The Request variant is used when an error occurs while sending a request to, or reading a
+    /// response from, the monitored service.
+    Request,
+
+    /// # Description
+    ///
+    /// The HeartbeatFailed variant is used when a job reports to its heartbeat monitor that it has
+    /// failed.
+    HeartbeatFailed,
+
+    /// # Description
+    ///
+    /// The HeartbeatMissed variant is used when a heartbeat monitor does not receive a heartbeat
+    /// within its expected period and grace period.
+    HeartbeatMissed,
+}
+
+/// A check details struct, which contains the check type specific details gathered by a check.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckDetails {
+    /// The details of the certificate presented by the monitored service, if one was inspected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) certificate: Option<CertificateDetails>,
+
+    /// The dns records that were returned by the resolver, if a dns query was performed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) records: Option<Vec<String>>,
+
+    /// The http assertions that were not satisfied by the response, if any.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) failed_assertions: Vec<FailedAssertion>,
+}
+
+/// A From<Json<CheckDetails>> implementation for the CheckDetails struct.
+///
+/// This allows check details to be queried directly from their json column.
+impl From<Json<CheckDetails>> for CheckDetails {
+    fn from(json: Json<CheckDetails>) -> Self {
+        return json.0;
+    }
+}
+
+/// A certificate details struct.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CertificateDetails {
+    /// The date and time the certificate expires at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) not_after: OffsetDateTime,
+
+    /// The distinguished name of the certificate's issuer.
+    pub(crate) issuer: String,
+
+    /// The subject alternative names the certificate is valid for.
+    pub(crate) subject_alternative_names: Vec<String>,
+
+    /// If the certificate chain was successfully verified.
+    pub(crate) is_chain_valid: bool,
+
+    /// A human readable description of why the certificate chain could not be verified, if it
+    /// could not be verified.
+    pub(crate) chain_validation_error: Option<String>,
+}
+
+/// A failed assertion struct, which describes an http assertion that a response did not satisfy.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FailedAssertion {
+    /// The assertion that was not satisfied.
+    pub(crate) assertion: HttpAssertion,
+
+    /// A human readable description of why the assertion was not satisfied.
+    pub(crate) message: String,
+}
+
+/// A check sample struct, which contains the parts of a check result that statistics are calculated
+/// from.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct CheckSample {
+    /// The date and time the check was performed at.
+    pub(crate) checked_at: OffsetDateTime,
+
+    /// The status the check determined the monitored service to be in.
+    pub(crate) status: CheckStatus,
+
+    /// The amount of time (in milliseconds) the check took to complete.
+    pub(crate) latency: u32,
+}
+
+/// A check statistics struct, which summarizes the check results a monitor produced within a time
+/// range.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckStatistics {
+    /// The date and time the time range starts at (inclusive).
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) from: OffsetDateTime,
+
+    /// The date and time the time range ends at (exclusive).
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) to: OffsetDateTime,
+
+    /// The amount of checks that were performed.
+    pub(crate) check_count: u64,
+
+    /// The amount of checks that determined the monitored service to be up.
+    pub(crate) up_count: u64,
+
+    /// The amount of checks that determined the monitored service to be degraded.
+    pub(crate) degraded_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down.
+    pub(crate) down_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down during maintenance.
+    pub(crate) maintenance_count: u64,
+
+    /// The percentage of checks that determined the monitored service to be operational (up or
+    /// degraded), if any checks were performed outside of maintenance.
+    pub(crate) uptime_percentage: Option<f64>,
+
+    /// The amount of time (in seconds) the monitored service was determined to be down for. Each
+    /// check that determined the service to be down accounts for the time until the next check.
+    pub(crate) downtime: u64,
+
+    /// The latency statistics of the checks that determined the monitored service to be
+    /// operational.
+    pub(crate) latency: LatencyStatistics,
+}
+
+/// A check series bucket struct, which summarizes the check results a monitor produced within one
+/// bucket of a time series.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckSeriesBucket {
+    /// The date and time the bucket starts at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) start: OffsetDateTime,
+
+    /// The amount of checks that were performed.
+    pub(crate) check_count: u64,
+
+    /// The amount of checks that determined the monitored service to be up.
+    pub(crate) up_count: u64,
+
+    /// The amount of checks that determined the monitored service to be degraded.
+    pub(crate) degraded_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down.
+    pub(crate) down_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down during maintenance.
+    pub(crate) maintenance_count: u64,
+
+    /// The percentage of checks that determined the monitored service to be operational (up or
+    /// degraded), if any checks were performed outside of maintenance.
+    pub(crate) uptime_percentage: Option<f64>,
+
+    /// The amount of time (in seconds) within the bucket the monitored service was determined to be
+    /// down for.
+    pub(crate) downtime: u64,
+
+    /// The latency statistics of the checks that determined the monitored service to be
+    /// operational.
+    pub(crate) latency: LatencyStatistics,
+}
+
+/// A latency statistics struct.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LatencyStatistics {
+    /// The minimum latency (in milliseconds), if any latencies were measured.
+    pub(crate) minimum: Option<u32>,
+
+    /// The average latency (in milliseconds), if any latencies were measured.
+    pub(crate) average: Option<u32>,
+
+    /// The maximum latency (in milliseconds), if any latencies were measured.
+    pub(crate) maximum: Option<u32>,
+
+    /// The median latency (in milliseconds), if any latencies were measured.
+    pub(crate) p50: Option<u32>,
+
+    /// The 95th percentile latency (in milliseconds), if any latencies were measured.
+    pub(crate) p95: Option<u32>,
+
+    /// The 99th percentile latency (in milliseconds), if any latencies were measured.
+    pub(crate) p99: Option<u32>,
+}
+
+/// An enumeration of all of the widths the buckets of a time series can have.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum SeriesBucketWidth {
+    /// # Description
+    ///
+    /// The Minute variant is used for buckets that are one minute wide.
+    Minute,
+
+    /// # Description
+    ///
+    /// The Hour variant is used for buckets that are one hour wide.
+    Hour,
+
+    /// # Description
+    ///
+    /// The Day variant is used for buckets that are one day wide.
+    Day,
+}
+
+/// An implementation for the SeriesBucketWidth enum.
+impl SeriesBucketWidth {
+    /// # Description
+    ///
+    /// Get the amount of time a bucket covers.
+    ///
+    /// # Returns
+    ///
+    /// The amount of time a bucket covers.
+    pub(crate) fn get_duration(&self) -> Duration {
+        return match self {
+            SeriesBucketWidth::Minute => Duration::MINUTE,
+            SeriesBucketWidth::Hour => Duration::HOUR,
+            SeriesBucketWidth::Day => Duration::DAY,
+        };
+    }
+}
+
+/// A check rollup struct, which summarizes the check results a monitor produced within an hour or a
+/// day, so that they can be reported on after the check results themselves have been deleted.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CheckRollup {
+    /// The check rollup's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the monitor that produced the check results.
+    pub(crate) monitor_id: u64,
+
+    /// The period of time the rollup covers.
+    pub(crate) period: RollupPeriod,
+
+    /// The date and time the period starts at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) period_start: OffsetDateTime,
+
+    /// The amount of checks that were performed.
+    pub(crate) check_count: u64,
+
+    /// The amount of checks that determined the monitored service to be up.
+    pub(crate) up_count: u64,
+
+    /// The amount of checks that determined the monitored service to be degraded.
+    pub(crate) degraded_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down.
+    pub(crate) down_count: u64,
+
+    /// The amount of checks that determined the monitored service to be down during maintenance.
+    pub(crate) maintenance_count: u64,
+
+    /// The amount of time (in seconds) within the period the monitored service was determined to be
+    /// down for.
+    pub(crate) downtime: u64,
+
+    /// The minimum latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) minimum_latency: Option<u32>,
+
+    /// The average latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) average_latency: Option<u32>,
+
+    /// The maximum latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) maximum_latency: Option<u32>,
+
+    /// The median latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) p50_latency: Option<u32>,
+
+    /// The 95th percentile latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) p95_latency: Option<u32>,
+
+    /// The 99th percentile latency (in milliseconds) of the operational checks, if there were any.
+    pub(crate) p99_latency: Option<u32>,
+}
+
+/// An enumeration of all of the periods of time a check rollup can cover.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum RollupPeriod {
+    /// # Description
+    ///
+    /// The Hour variant is used for rollups that cover one hour.
+    Hour,
+
+    /// # Description
+    ///
+    /// The Day variant is used for rollups that cover one day.
+    Day,
+}
+
+/// An implementation for the RollupPeriod enum.
+impl RollupPeriod {
+    /// # Description
+    ///
+    /// Get the series bucket width that covers the same period of time.
+    ///
+    /// # Returns
+    ///
+    /// The series bucket width.
+    pub(crate) fn get_bucket_width(&self) -> SeriesBucketWidth {
+        return match self {
+            RollupPeriod::Hour => SeriesBucketWidth::Hour,
+            RollupPeriod::Day => SeriesBucketWidth::Day,
+        };
+    }
+}
@@ -0,0 +1,749 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::check::model::{
+        CheckDetails, CheckErrorKind, CheckResult, CheckRollup, CheckSample, CheckStatus,
+        RollupPeriod,
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// A check result repository trait.
+#[async_trait]
+pub(crate) trait CheckResultRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a check result into the check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `check_result` - The check result to insert into the check result repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// check result that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(
+        &self,
+        check_result: &CheckResult,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a check result from the check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the check result to get from the check result repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional check result.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext)
+        -> Result<Option<CheckResult>, Error>;
+
+    /// # Description
+    ///
+    /// Get the most recent check results that were produced by a monitor from the check result
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that produced the check results.
+    ///
+    /// `limit` - The maximum amount of check results to get.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the check results that
+    /// were found, ordered from newest to oldest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckResult>, Error>;
+
+    /// # Description
+    ///
+    /// Get the samples of the check results that were produced by a monitor within a time range
+    /// from the check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the samples that were
+    /// found, ordered from oldest to newest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_samples_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckSample>, Error>;
+
+    /// # Description
+    ///
+    /// Get the ids of all of the monitors that have check results in the check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the monitor ids.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_monitor_ids(&self, context: &mut QueryContext) -> Result<Vec<u64>, Error>;
+
+    /// # Description
+    ///
+    /// Get the date and time a monitor's oldest check result was produced at from the check result
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that produced the check results.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional date and
+    /// time.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_earliest_checked_at(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<OffsetDateTime>, Error>;
+
+    /// # Description
+    ///
+    /// Delete a batch of the check results that were produced before a date and time from the
+    /// check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `before` - The date and time the check results were produced before.
+    ///
+    /// `limit` - The maximum amount of check results to delete.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the number of rows
+    /// affected.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_before(
+        &self,
+        before: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a check rollup into the check result repository, replacing the rollup of the same
+    /// monitor and period if one already exists.
+    ///
+    /// # Arguments
+    ///
+    /// `rollup` - The check rollup to insert into the check result repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the number of rows
+    /// affected.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn upsert_rollup(
+        &self,
+        rollup: &CheckRollup,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get the check rollups of a monitor whose periods start within a time range from the check
+    /// result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that produced the check results.
+    ///
+    /// `period` - The period of time the rollups cover.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the rollups that were
+    /// found, ordered from oldest to newest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_rollups_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        period: &RollupPeriod,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckRollup>, Error>;
+
+    /// # Description
+    ///
+    /// Get the most recent check rollup of a monitor from the check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that produced the check results.
+    ///
+    /// `period` - The period of time the rollup covers.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional rollup.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_latest_rollup_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        period: &RollupPeriod,
+        context: &mut QueryContext,
+    ) -> Result<Option<CheckRollup>, Error>;
+
+    /// # Description
+    ///
+    /// Delete a batch of the check rollups whose periods started before a date and time from the
+    /// check result repository.
+    ///
+    /// # Arguments
+    ///
+    /// `period` - The period of time the rollups cover.
+    ///
+    /// `before` - The date and time the rollups' periods started before.
+    ///
+    /// `limit` - The maximum amount of rollups to delete.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the number of rows
+    /// affected.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_rollups_before(
+        &self,
+        period: &RollupPeriod,
+        before: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+}
+
+/// A CheckResultRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = CheckResultRepository)]
+pub(crate) struct CheckResultRepositoryImpl;
+
+/// A CheckResultRepository implementation for the CheckResultRepositoryImpl struct.
+#[async_trait]
+impl CheckResultRepository for CheckResultRepositoryImpl {
+    async fn insert(
+        &self,
+        check_result: &CheckResult,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `check_results` (
+                    `id`,
+                    `monitor_id`,
+                    `checked_at`,
+                    `status`,
+                    `latency`,
+                    `error_kind`,
+                    `error_message`,
+                    `details`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            check_result.id,
+            check_result.monitor_id,
+            check_result.checked_at,
+            check_result.status,
+            check_result.latency,
+            check_result.error_kind,
+            check_result.error_message,
+            Json(&check_result.details)
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<CheckResult>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            CheckResult,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `checked_at` AS `checked_at: OffsetDateTime`,
+                    `status` AS `status: CheckStatus`,
+                    `latency` AS `latency: u32`,
+                    `error_kind` AS `error_kind: CheckErrorKind`,
+                    `error_message` AS `error_message: String`,
+                    `details` AS `details: Json<CheckDetails>`
+                FROM
+                    `check_results`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckResult>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            CheckResult,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `checked_at` AS `checked_at: OffsetDateTime`,
+                    `status` AS `status: CheckStatus`,
+                    `latency` AS `latency: u32`,
+                    `error_kind` AS `error_kind: CheckErrorKind`,
+                    `error_message` AS `error_message: String`,
+                    `details` AS `details: Json<CheckDetails>`
+                FROM
+                    `check_results`
+                WHERE
+                    `monitor_id` = ?
+                ORDER BY
+                    `checked_at` DESC,
+                    `id` DESC
+                LIMIT ?;
+            "#,
+            monitor_id,
+            limit
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_samples_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckSample>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            CheckSample,
+            r#"
+                SELECT
+                    `checked_at` AS `checked_at: OffsetDateTime`,
+                    `status` AS `status: CheckStatus`,
+                    `latency` AS `latency: u32`
+                FROM
+                    `check_results`
+                WHERE
+                    `monitor_id` = ?
+                    AND `checked_at` >= ?
+                    AND `checked_at` < ?
+                ORDER BY
+                    `checked_at` ASC,
+                    `id` ASC;
+            "#,
+            monitor_id,
+            from,
+            to
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_monitor_ids(&self, context: &mut QueryContext) -> Result<Vec<u64>, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT DISTINCT
+                    `monitor_id` AS `monitor_id: u64`
+                FROM
+                    `check_results`;
+            "#
+        );
+
+        // Execute the query.
+        let records = match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        }?;
+
+        return Ok(records
+            .into_iter()
+            .map(|record| record.monitor_id)
+            .collect());
+    }
+
+    async fn get_earliest_checked_at(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<OffsetDateTime>, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT
+                    MIN(`checked_at`) AS `checked_at: OffsetDateTime`
+                FROM
+                    `check_results`
+                WHERE
+                    `monitor_id` = ?;
+            "#,
+            monitor_id
+        );
+
+        // Execute the query.
+        let record = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return Ok(record.checked_at);
+    }
+
+    async fn delete_before(
+        &self,
+        before: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `check_results`
+                WHERE
+                    `checked_at` < ?
+                LIMIT ?;
+            "#,
+            before,
+            limit
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn upsert_rollup(
+        &self,
+        rollup: &CheckRollup,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `check_rollups` (
+                    `monitor_id`,
+                    `period`,
+                    `period_start`,
+                    `check_count`,
+                    `up_count`,
+                    `degraded_count`,
+                    `down_count`,
+                    `maintenance_count`,
+                    `downtime`,
+                    `minimum_latency`,
+                    `average_latency`,
+                    `maximum_latency`,
+                    `p50_latency`,
+                    `p95_latency`,
+                    `p99_latency`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) ON DUPLICATE KEY UPDATE
+                    `check_count` = VALUES(`check_count`),
+                    `up_count` = VALUES(`up_count`),
+                    `degraded_count` = VALUES(`degraded_count`),
+                    `down_count` = VALUES(`down_count`),
+                    `maintenance_count` = VALUES(`maintenance_count`),
+                    `downtime` = VALUES(`downtime`),
+                    `minimum_latency` = VALUES(`minimum_latency`),
+                    `average_latency` = VALUES(`average_latency`),
+                    `maximum_latency` = VALUES(`maximum_latency`),
+                    `p50_latency` = VALUES(`p50_latency`),
+                    `p95_latency` = VALUES(`p95_latency`),
+                    `p99_latency` = VALUES(`p99_latency`);
+            "#,
+            rollup.monitor_id,
+            rollup.period,
+            rollup.period_start,
+            rollup.check_count,
+            rollup.up_count,
+            rollup.degraded_count,
+            rollup.down_count,
+            rollup.maintenance_count,
+            rollup.downtime,
+            rollup.minimum_latency,
+            rollup.average_latency,
+            rollup.maximum_latency,
+            rollup.p50_latency,
+            rollup.p95_latency,
+            rollup.p99_latency
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn get_rollups_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        period: &RollupPeriod,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<CheckRollup>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            CheckRollup,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `period` AS `period: RollupPeriod`,
+                    `period_start` AS `period_start: OffsetDateTime`,
+                    `check_count` AS `check_count: u64`,
+                    `up_count` AS `up_count: u64`,
+                    `degraded_count` AS `degraded_count: u64`,
+                    `down_count` AS `down_count: u64`,
+                    `maintenance_count` AS `maintenance_count: u64`,
+                    `downtime` AS `downtime: u64`,
+                    `minimum_latency` AS `minimum_latency: u32`,
+                    `average_latency` AS `average_latency: u32`,
+                    `maximum_latency` AS `maximum_latency: u32`,
+                    `p50_latency` AS `p50_latency: u32`,
+                    `p95_latency` AS `p95_latency: u32`,
+                    `p99_latency` AS `p99_latency: u32`
+                FROM
+                    `check_rollups`
+                WHERE
+                    `monitor_id` = ?
+                    AND `period` = ?
+                    AND `period_start` >= ?
+                    AND `period_start` < ?
+                ORDER BY
+                    `period_start` ASC;
+            "#,
+            monitor_id,
+            period,
+            from,
+            to
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_latest_rollup_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        period: &RollupPeriod,
+        context: &mut QueryContext,
+    ) -> Result<Option<CheckRollup>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            CheckRollup,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `period` AS `period: RollupPeriod`,
+                    `period_start` AS `period_start: OffsetDateTime`,
+                    `check_count` AS `check_count: u64`,
+                    `up_count` AS `up_count: u64`,
+                    `degraded_count` AS `degraded_count: u64`,
+                    `down_count` AS `down_count: u64`,
+                    `maintenance_count` AS `maintenance_count: u64`,
+                    `downtime` AS `downtime: u64`,
+                    `minimum_latency` AS `minimum_latency: u32`,
+                    `average_latency` AS `average_latency: u32`,
+                    `maximum_latency` AS `maximum_latency: u32`,
+                    `p50_latency` AS `p50_latency: u32`,
+                    `p95_latency` AS `p95_latency: u32`,
+                    `p99_latency` AS `p99_latency: u32`
+                FROM
+                    `check_rollups`
+                WHERE
+                    `monitor_id` = ?
+                    AND `period` = ?
+                ORDER BY
+                    `period_start` DESC
+                LIMIT 1;
+            "#,
+            monitor_id,
+            period
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn delete_rollups_before(
+        &self,
+        period: &RollupPeriod,
+        before: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `check_rollups`
+                WHERE
+                    `period` = ?
+                    AND `period_start` < ?
+                LIMIT ?;
+            "#,
+            period,
+            before,
+            limit
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
@@ -0,0 +1,699 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::{
+            model::{
+                CertificateDetails, CheckDetails, CheckErrorKind, CheckResult, CheckRollup,
+                CheckSample, CheckStatus, FailedAssertion, RollupPeriod,
+            },
+            repository::CheckResultRepository,
+        },
+        monitor::{
+            model::{
+                HttpAssertion, HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration,
+            },
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a check result that can be used for testing.
+///
+/// # Returns
+///
+/// The check result that was created.
+fn create_test_check_result() -> CheckResult {
+    return CheckResult {
+        id: 0,
+        monitor_id: 0,
+        checked_at: OffsetDateTime::now_utc(),
+        status: CheckStatus::Down,
+        latency: 250,
+        error_kind: Some(CheckErrorKind::UnexpectedStatusCode),
+        error_message: Some(generate_random_string(32)),
+        details: CheckDetails {
+            certificate: Some(CertificateDetails {
+                not_after: OffsetDateTime::now_utc(),
+                issuer: generate_random_string(32),
+                subject_alternative_names: vec![generate_random_string(32)],
+                is_chain_valid: true,
+                chain_validation_error: None,
+            }),
+            records: Some(vec![generate_random_string(32)]),
+            failed_assertions: vec![FailedAssertion {
+                assertion: HttpAssertion::BodyContains {
+                    keyword: generate_random_string(32),
+                },
+                message: generate_random_string(32),
+            }],
+        },
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a check result into the repository, and validate the id that was returned.
+#[actix_web::test]
+async fn check_result_id_is_returned_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result repository instance.
+    let check_result_repository: Arc<dyn CheckResultRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Create a test check result.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = monitor.id;
+
+    // Insert the check result.
+    let id = check_result_repository
+        .insert(&check_result, &mut context)
+        .await
+        .expect("Failed to insert check result");
+
+    // Make sure the check result id was returned.
+    assert!(id > 0);
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying a check result by id after it has been inserted, and make sure the correct data is
+/// returned.
+#[actix_web::test]
+async fn check_result_is_queryable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result repository instance.
+    let check_result_repository: Arc<dyn CheckResultRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Create a test check result.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = monitor.id;
+
+    // Insert the check result.
+    let id = check_result_repository
+        .insert(&check_result, &mut context)
+        .await
+        .expect("Failed to insert check result");
+
+    check_result.id = id;
+
+    // Query the check result.
+    let queried_check_result: CheckResult = check_result_repository
+        .get(&id, &mut context)
+        .await
+        .expect("Failed to query check result after insertion: An error occurred")
+        .expect("Failed to query check result after insertion: Check result not found");
+
+    // Make sure the check result contains the correct data.
+    assert_eq!(check_result, queried_check_result);
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Make sure the check result was deleted with the monitor.
+    let deleted_query_result: Option<CheckResult> = check_result_repository
+        .get(&id, &mut context)
+        .await
+        .expect("Failed to query check result after deletion: An error occurred");
+    assert!(deleted_query_result.is_none());
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying the check results a monitor produced, and make sure they are returned from newest
+/// to oldest, and respect the limit specified.
+#[actix_web::test]
+async fn check_results_are_queryable_by_monitor_id() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result repository instance.
+    let check_result_repository: Arc<dyn CheckResultRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let other_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert three check results for the first monitor, and one for the second monitor.
+    let mut check_results: Vec<CheckResult> = Vec::new();
+    for (index, monitor_id) in [monitor.id, monitor.id, monitor.id, other_monitor.id]
+        .into_iter()
+        .enumerate()
+    {
+        let mut check_result: CheckResult = create_test_check_result();
+        check_result.monitor_id = monitor_id;
+        check_result.checked_at = OffsetDateTime::now_utc() - Duration::minutes(10 - index as i64);
+        check_result.id = check_result_repository
+            .insert(&check_result, &mut context)
+            .await
+            .expect("Failed to insert check result");
+        check_results.push(check_result);
+    }
+
+    // Query the two most recent check results of the first monitor.
+    let queried_check_results: Vec<CheckResult> = check_result_repository
+        .get_by_monitor_id(&monitor.id, &2, &mut context)
+        .await
+        .expect("Failed to query check results by monitor id");
+
+    // Make sure only the first monitor's most recent check results were returned.
+    assert_eq!(
+        vec![check_results[2].clone(), check_results[1].clone()],
+        queried_check_results
+    );
+
+    // Delete the test user (their monitors and check results will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying the samples of the check results a monitor produced within a time range, and make
+/// sure only the samples within the time range are returned from oldest to newest.
+#[actix_web::test]
+async fn check_samples_are_queryable_by_time_range() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result repository instance.
+    let check_result_repository: Arc<dyn CheckResultRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert a check result each minute for the last five minutes.
+    let now: OffsetDateTime = OffsetDateTime::now_utc().replace_nanosecond(0).unwrap();
+    let mut check_results: Vec<CheckResult> = Vec::new();
+    for minutes in (1..=5).rev() {
+        let mut check_result: CheckResult = create_test_check_result();
+        check_result.monitor_id = monitor.id;
+        check_result.checked_at = now - Duration::minutes(minutes);
+        check_result_repository
+            .insert(&check_result, &mut context)
+            .await
+            .expect("Failed to insert check result");
+        check_results.push(check_result);
+    }
+
+    // Query the samples of the check results from the second to the fourth minute.
+    let samples: Vec<CheckSample> = check_result_repository
+        .get_samples_by_monitor_id(
+            &monitor.id,
+            &check_results[1].checked_at,
+            &check_results[4].checked_at,
+            &mut context,
+        )
+        .await
+        .expect("Failed to query check samples by monitor id");
+
+    // Make sure only the samples within the time range were returned, in order.
+    assert_eq!(
+        vec![
+            check_results[1].checked_at,
+            check_results[2].checked_at,
+            check_results[3].checked_at,
+        ],
+        samples
+            .iter()
+            .map(|sample| sample.checked_at)
+            .collect::<Vec<OffsetDateTime>>()
+    );
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting check rollups, and make sure they are replaced, queryable and deletable by the
+/// start of their periods.
+#[actix_web::test]
+async fn check_rollups_are_upserted_queried_and_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result repository instance.
+    let check_result_repository: Arc<dyn CheckResultRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert an hourly rollup for each of the last three hours.
+    let now: OffsetDateTime = OffsetDateTime::now_utc()
+        .replace_minute(0)
+        .unwrap()
+        .replace_second(0)
+        .unwrap()
+        .replace_nanosecond(0)
+        .unwrap();
+    let mut rollup: CheckRollup = CheckRollup {
+        id: 0,
+        monitor_id: monitor.id,
+        period: RollupPeriod::Hour,
+        period_start: now,
+        check_count: 60,
+        up_count: 60,
+        degraded_count: 0,
+        down_count: 0,
+        maintenance_count: 0,
+        downtime: 0,
+        minimum_latency: Some(100),
+        average_latency: Some(150),
+        maximum_latency: Some(200),
+        p50_latency: Some(150),
+        p95_latency: Some(190),
+        p99_latency: Some(200),
+    };
+    for hours in 1..=3 {
+        rollup.period_start = now - Duration::hours(hours);
+        check_result_repository
+            .upsert_rollup(&rollup, &mut context)
+            .await
+            .expect("Failed to insert check rollup");
+    }
+
+    // Replace the most recent rollup.
+    rollup.period_start = now - Duration::hours(1);
+    rollup.up_count = 30;
+    rollup.down_count = 30;
+    check_result_repository
+        .upsert_rollup(&rollup, &mut context)
+        .await
+        .expect("Failed to replace check rollup");
+
+    // Make sure the latest rollup was replaced.
+    let latest_rollup: CheckRollup = check_result_repository
+        .get_latest_rollup_by_monitor_id(&monitor.id, &RollupPeriod::Hour, &mut context)
+        .await
+        .expect("Failed to query the latest check rollup")
+        .expect("Check rollup could not be found");
+    assert_eq!(rollup.period_start, latest_rollup.period_start);
+    assert_eq!(30, latest_rollup.down_count);
+
+    // Make sure the rollups within a time range are returned in order.
+    let rollups: Vec<CheckRollup> = check_result_repository
+        .get_rollups_by_monitor_id(
+            &monitor.id,
+            &RollupPeriod::Hour,
+            &(now - Duration::hours(3)),
+            &(now - Duration::hours(1)),
+            &mut context,
+        )
+        .await
+        .expect("Failed to query check rollups by monitor id");
+    assert_eq!(
+        vec![now - Duration::hours(3), now - Duration::hours(2)],
+        rollups
+            .iter()
+            .map(|rollup| rollup.period_start)
+            .collect::<Vec<OffsetDateTime>>()
+    );
+
+    // Delete the rollups before the most recent one, and make sure only it remains.
+    check_result_repository
+        .delete_rollups_before(
+            &RollupPeriod::Hour,
+            &(now - Duration::hours(1)),
+            &100,
+            &mut context,
+        )
+        .await
+        .expect("Failed to delete check rollups");
+    let rollups: Vec<CheckRollup> = check_result_repository
+        .get_rollups_by_monitor_id(
+            &monitor.id,
+            &RollupPeriod::Hour,
+            &(now - Duration::hours(3)),
+            &now,
+            &mut context,
+        )
+        .await
+        .expect("Failed to query check rollups by monitor id");
+    assert_eq!(1, rollups.len());
+
+    // Delete the test user (their monitor and check rollups will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
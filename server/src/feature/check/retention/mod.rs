@@ -0,0 +1,116 @@
+use crate::{
+    config::RetentionConfig,
+    feature::{
+        auth::service::AuthService, check::service::CheckResultService, oidc::service::OidcService,
+        session::service::SessionService, two_factor::service::TwoFactorService,
+    },
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use std::{sync::Arc, time::Duration};
+use tokio::time::{interval, MissedTickBehavior};
+
+/// A retention worker struct, which periodically rolls check results up into hourly and daily
+/// rollups and deletes the check results and rollups that are older than their retention periods.
+/// Failed logins that no longer count toward locking accounts, expired sessions, expired
+/// two-factor challenges and the authorization requests of expired single sign-on logins are
+/// deleted as well.
+pub(crate) struct RetentionWorker {
+    /// The dependency injector that will be used to acquire service instances.
+    dependency_injector: Arc<DependencyInjector>,
+
+    /// The retention configuration.
+    config: RetentionConfig,
+}
+
+/// An implementation for the RetentionWorker struct.
+impl RetentionWorker {
+    /// # Description
+    ///
+    /// Create a new retention worker.
+    ///
+    /// # Arguments
+    ///
+    /// `dependency_injector` - The dependency injector that will be used to acquire service
+    /// instances.
+    ///
+    /// `config` - The retention configuration.
+    ///
+    /// # Returns
+    ///
+    /// The retention worker that was created.
+    pub(crate) fn new(
+        dependency_injector: Arc<DependencyInjector>,
+        config: RetentionConfig,
+    ) -> Self {
+        return RetentionWorker {
+            dependency_injector,
+            config,
+        };
+    }
+
+    /// # Description
+    ///
+    /// Run the retention worker. Each interval, the worker will roll up the check results of each
+    /// completed hour and day, and delete the data that has expired.
+    ///
+    /// ### Note: This function never returns, it should be spawned as a background task.
+    pub(crate) async fn run(self) {
+        // Create the interval.
+        let mut ticks = interval(Duration::from_secs(self.config.interval));
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        log::info!("Starting the retention worker");
+
+        loop {
+            ticks.tick().await;
+
+            // Get a check result service instance.
+            let check_result_service: &dyn CheckResultService =
+                self.dependency_injector.resolve_ref();
+
+            // Apply the retention.
+            if let Err(error) = check_result_service.apply_retention().await {
+                log::error!("Failed to apply the check result retention: {}", error);
+            }
+
+            // Get an auth service instance.
+            let auth_service: &dyn AuthService = self.dependency_injector.resolve_ref();
+
+            // Delete the expired failed logins.
+            if let Err(error) = auth_service.delete_expired_failed_logins().await {
+                log::error!("Failed to delete the expired failed logins: {}", error);
+            }
+
+            // Get a session service instance.
+            let session_service: &dyn SessionService = self.dependency_injector.resolve_ref();
+
+            // Delete the expired sessions.
+            if let Err(error) = session_service.delete_expired().await {
+                log::error!("Failed to delete the expired sessions: {}", error);
+            }
+
+            // Get a two-factor service instance.
+            let two_factor_service: &dyn TwoFactorService = self.dependency_injector.resolve_ref();
+
+            // Delete the expired two-factor challenges.
+            if let Err(error) = two_factor_service.delete_expired_challenges().await {
+                log::error!(
+                    "Failed to delete the expired two-factor challenges: {}",
+                    error
+                );
+            }
+
+            // Get an oidc service instance.
+            let oidc_service: &dyn OidcService = self.dependency_injector.resolve_ref();
+
+            // Delete the expired authorization requests.
+            if let Err(error) = oidc_service.delete_expired_authorization_requests().await {
+                log::error!(
+                    "Failed to delete the expired authorization requests: {}",
+                    error
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,262 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::{InsertionResult, QueryResult},
+    config::SchedulerConfig,
+    feature::{
+        check::{checker::Checker, model::CheckResult, service::CheckResultService},
+        heartbeat::service::HeartbeatService,
+        monitor::{
+            model::{Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+    },
+    injector::DependencyInjector,
+};
+use actix_web::rt::task::JoinHandle;
+use shaku::HasComponent;
+use std::{
+    collections::HashMap,
+    error::Error,
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::Semaphore,
+    time::{interval, MissedTickBehavior},
+};
+
+/// A check scheduler struct, which periodically performs the checks that monitors are configured to
+/// perform and persists their results.
+pub(crate) struct CheckScheduler {
+    /// The dependency injector that will be used to acquire service instances.
+    dependency_injector: Arc<DependencyInjector>,
+
+    /// The scheduler's configuration.
+    config: SchedulerConfig,
+
+    /// The checker that will be used to perform checks.
+    checker: Rc<Checker>,
+
+    /// The semaphore that will be used to limit the amount of checks performed at the same time.
+    semaphore: Arc<Semaphore>,
+
+    /// The instant each monitor is next due to be checked at, keyed by the monitor's id.
+    next_check_instants: HashMap<u64, Instant>,
+}
+
+/// An implementation for the CheckScheduler struct.
+impl CheckScheduler {
+    /// # Description
+    ///
+    /// Create a new check scheduler.
+    ///
+    /// # Arguments
+    ///
+    /// `dependency_injector` - The dependency injector that will be used to acquire service
+    /// instances.
+    ///
+    /// `config` - The scheduler's configuration.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the scheduler was created successfully, the Ok variant will be returned with the
+    /// scheduler.
+    /// - If an error occurs while creating the scheduler's checker, the Err variant will be returned
+    /// with the error that occurred.
+    pub(crate) fn new(
+        dependency_injector: Arc<DependencyInjector>,
+        config: SchedulerConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        return Ok(CheckScheduler {
+            dependency_injector,
+            semaphore: Arc::new(Semaphore::new(config.maximum_concurrent_checks)),
+            config,
+            checker: Rc::new(Checker::new()?),
+            next_check_instants: HashMap::new(),
+        });
+    }
+
+    /// # Description
+    ///
+    /// Run the scheduler. Each tick, the scheduler will perform the checks of all of the enabled
+    /// monitors that are due to be checked.
+    ///
+    /// ### Note: This function never returns, it should be spawned as a background task.
+    pub(crate) async fn run(mut self) {
+        // Create the tick interval.
+        let mut ticks = interval(Duration::from_secs(self.config.tick_interval));
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        log::info!("Starting the check scheduler");
+
+        loop {
+            ticks.tick().await;
+            self.tick().await;
+        }
+    }
+
+    /// # Description
+    ///
+    /// Start performing the checks of all of the enabled monitors that are due to be checked.
+    ///
+    /// # Returns
+    ///
+    /// The handles of the checks that were started.
+    pub(crate) async fn tick(&mut self) -> Vec<JoinHandle<()>> {
+        // Get a monitor service instance.
+        let monitor_service: &dyn MonitorService = self.dependency_injector.resolve_ref();
+
+        // Query the monitors that are enabled.
+        let monitors: Vec<Monitor> = match monitor_service.get_enabled().await {
+            QueryResult::Ok(monitors) => monitors,
+            QueryResult::NotFound => Vec::new(),
+            QueryResult::Err(error) => {
+                log::error!("Failed to query the enabled monitors: {}", error);
+                return Vec::new();
+            }
+        };
+
+        // Forget about monitors that no longer exist or have been disabled.
+        self.next_check_instants
+            .retain(|id, _| monitors.iter().any(|monitor| monitor.id == *id));
+
+        // Start the checks of all of the monitors that are due to be checked.
+        let now: Instant = Instant::now();
+        let mut handles: Vec<JoinHandle<()>> = Vec::new();
+        for monitor in monitors {
+            // If the monitor is not due to be checked, skip it.
+            if let Some(next_check_instant) = self.next_check_instants.get(&monitor.id) {
+                if now < *next_check_instant {
+                    continue;
+                }
+            }
+
+            // Schedule the monitor's next check.
+            self.next_check_instants
+                .insert(monitor.id, now + get_evaluation_interval(&monitor));
+
+            // Wait until the concurrency limit allows another check to be performed.
+            let permit = match Arc::clone(&self.semaphore).acquire_owned().await {
+                Ok(permit) => permit,
+                Err(error) => {
+                    log::error!("Failed to acquire a check permit: {}", error);
+                    break;
+                }
+            };
+
+            // Perform the check in the background.
+            let dependency_injector: Arc<DependencyInjector> =
+                Arc::clone(&self.dependency_injector);
+            let checker: Rc<Checker> = Rc::clone(&self.checker);
+            handles.push(actix_web::rt::spawn(async move {
+                match &monitor.configuration {
+                    MonitorConfiguration::Heartbeat(_) => {
+                        evaluate_heartbeat_monitor(&dependency_injector, &monitor).await
+                    }
+                    _ => {
+                        let check_result: CheckResult = checker.check(&monitor).await;
+                        persist_check_result(&dependency_injector, &check_result).await;
+                    }
+                }
+                drop(permit);
+            }));
+        }
+
+        // Return the handles of the checks that were started.
+        return handles;
+    }
+}
+
+/// # Description
+///
+/// Get the amount of time between the evaluations of a monitor.
+///
+/// Heartbeat monitors are evaluated at least once per grace period, so that missed heartbeats are
+/// detected soon after the grace period elapses.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor being evaluated.
+///
+/// # Returns
+///
+/// The amount of time between the evaluations of the monitor.
+fn get_evaluation_interval(monitor: &Monitor) -> Duration {
+    let seconds: u32 = match &monitor.configuration {
+        MonitorConfiguration::Heartbeat(configuration) => monitor
+            .check_interval
+            .min(configuration.grace_period)
+            .max(1),
+        _ => monitor.check_interval,
+    };
+
+    return Duration::from_secs(u64::from(seconds));
+}
+
+/// # Description
+///
+/// Determine if a heartbeat monitor has missed a heartbeat, and persist the check result that
+/// records it if it has, logging any errors that occur.
+///
+/// # Arguments
+///
+/// `dependency_injector` - The dependency injector that will be used to acquire service instances.
+///
+/// `monitor` - The heartbeat monitor that will be evaluated.
+async fn evaluate_heartbeat_monitor(dependency_injector: &DependencyInjector, monitor: &Monitor) {
+    // Get a heartbeat service instance.
+    let heartbeat_service: &dyn HeartbeatService = dependency_injector.resolve_ref();
+
+    // Determine if the monitor has missed a heartbeat.
+    match heartbeat_service
+        .get_missed_heartbeat_check_result(monitor)
+        .await
+    {
+        QueryResult::Ok(check_result) => {
+            persist_check_result(dependency_injector, &check_result).await
+        }
+        QueryResult::NotFound => {}
+        QueryResult::Err(error) => log::error!(
+            "Failed to evaluate the heartbeats of monitor {}: {}",
+            monitor.id,
+            error
+        ),
+    }
+}
+
+/// # Description
+///
+/// Persist the result of a check, logging any errors that occur.
+///
+/// # Arguments
+///
+/// `dependency_injector` - The dependency injector that will be used to acquire a check result
+/// service instance.
+///
+/// `check_result` - The check result that will be persisted.
+async fn persist_check_result(
+    dependency_injector: &DependencyInjector,
+    check_result: &CheckResult,
+) {
+    // Get a check result service instance.
+    let check_result_service: &dyn CheckResultService = dependency_injector.resolve_ref();
+
+    // Insert the check result.
+    match check_result_service.insert(check_result).await {
+        InsertionResult::Ok(_) => {}
+        InsertionResult::Invalid(details) => log::warn!(
+            "Discarded the check result of monitor {}: {}",
+            check_result.monitor_id,
+            details
+        ),
+        InsertionResult::Err(error) => log::error!(
+            "Failed to persist the check result of monitor {}: {}",
+            check_result.monitor_id,
+            error
+        ),
+    }
+}
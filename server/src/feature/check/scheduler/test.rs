@@ -0,0 +1,352 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::{Config, SchedulerConfig},
+    feature::{
+        check::{
+            model::{CheckResult, CheckStatus},
+            scheduler::CheckScheduler,
+            service::CheckResultService,
+        },
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{web, App, HttpResponse, HttpServer};
+use core::panic;
+use shaku::HasComponent;
+use std::{net::TcpListener, sync::Arc};
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Start a local stand-in http server that responds to every request with a 200 status code.
+///
+/// # Panics
+///
+/// This function will panic if the server could not be bound to a local port.
+///
+/// # Returns
+///
+/// The base url of the server that was started.
+fn start_stand_in_server() -> String {
+    // Bind a listener to a random local port.
+    let listener: TcpListener =
+        TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in server");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in server address")
+        .port();
+
+    // Create and start the server.
+    let server = HttpServer::new(|| {
+        App::new().default_service(web::to(|| async { HttpResponse::Ok().finish() }))
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to start stand-in server")
+    .run();
+    actix_web::rt::spawn(server);
+
+    // Return the server's base url.
+    return format!("http://127.0.0.1:{}", port);
+}
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `url` - The url the monitor will send requests to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, url: String, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let monitor: Monitor = Monitor {
+        user_id: user.id,
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 5,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url,
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+        ..Default::default()
+    };
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Query the check results a monitor has produced with the check result service.
+///
+/// # Arguments
+///
+/// `monitor` - The monitor that produced the check results.
+///
+/// `injector` - The dependency injector that will be used to acquire a check result service
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to query the check results.
+///
+/// # Returns
+///
+/// The check results that were found.
+async fn get_check_results(monitor: &Monitor, injector: &DependencyInjector) -> Vec<CheckResult> {
+    // Get a check result service instance.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Perform the query.
+    return match check_result_service
+        .get_by_monitor_id(&monitor.id, &100)
+        .await
+    {
+        QueryResult::Ok(check_results) => check_results,
+        QueryResult::NotFound => Vec::new(),
+        QueryResult::Err(error) => panic!("Failed to query check results: {}", error),
+    };
+}
+
+/// # Description
+///
+/// Test that a scheduler tick checks a monitor that is due to be checked, and that the result of
+/// the check is persisted.
+#[actix_web::test]
+async fn due_monitor_is_checked_and_persisted() {
+    // Create a dependency injector.
+    let injector: Arc<DependencyInjector> = Arc::new(create_dependency_injector().await);
+
+    // Insert a test user, and a monitor that points at a stand-in server.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor =
+        insert_test_monitor(&user, format!("{}/", start_stand_in_server()), &injector).await;
+
+    // Create the scheduler.
+    let mut scheduler: CheckScheduler = CheckScheduler::new(
+        Arc::clone(&injector),
+        SchedulerConfig {
+            tick_interval: 1,
+            maximum_concurrent_checks: 4,
+        },
+    )
+    .expect("Failed to create scheduler");
+
+    // Perform a tick, and wait for the checks to complete.
+    for handle in scheduler.tick().await {
+        handle.await.expect("Failed to perform check");
+    }
+
+    // Make sure the check result was persisted.
+    let check_results: Vec<CheckResult> = get_check_results(&monitor, &injector).await;
+    assert_eq!(1, check_results.len());
+    assert_eq!(CheckStatus::Up, check_results[0].status);
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test that a monitor is not checked again before its check interval has elapsed.
+#[actix_web::test]
+async fn monitor_is_not_checked_before_interval_elapses() {
+    // Create a dependency injector.
+    let injector: Arc<DependencyInjector> = Arc::new(create_dependency_injector().await);
+
+    // Insert a test user, and a monitor that points at a stand-in server.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor =
+        insert_test_monitor(&user, format!("{}/", start_stand_in_server()), &injector).await;
+
+    // Create the scheduler.
+    let mut scheduler: CheckScheduler = CheckScheduler::new(
+        Arc::clone(&injector),
+        SchedulerConfig {
+            tick_interval: 1,
+            maximum_concurrent_checks: 4,
+        },
+    )
+    .expect("Failed to create scheduler");
+
+    // Perform two ticks, and wait for the checks to complete.
+    for _ in 0..2 {
+        for handle in scheduler.tick().await {
+            handle.await.expect("Failed to perform check");
+        }
+    }
+
+    // Make sure the monitor was only checked once.
+    let check_results: Vec<CheckResult> = get_check_results(&monitor, &injector).await;
+    assert_eq!(1, check_results.len());
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
@@ -0,0 +1,1014 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext, QueryResult},
+        utility::create_value_validation_error,
+    },
+    config::RetentionConfig,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::{
+            model::{
+                CheckResult, CheckRollup, CheckSample, CheckSeriesBucket, CheckStatistics,
+                CheckStatus, RollupPeriod, SeriesBucketWidth,
+            },
+            repository::CheckResultRepository,
+            statistics,
+        },
+        incident::{model::Incident, service::IncidentService},
+        maintenance::service::MaintenanceWindowService,
+        monitor::{model::Monitor, service::MonitorService},
+        notification::service::NotificationChannelService,
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use time::{Duration, OffsetDateTime};
+use validator::{Validate, ValidationErrors};
+
+/// The maximum amount of check results or rollups that will be deleted by each deletion query, so
+/// that expired data is deleted without holding locks on large parts of the tables.
+const RETENTION_DELETION_BATCH_SIZE: u32 = 10000;
+
+/// A check result service trait.
+#[async_trait(?Send)]
+pub(crate) trait CheckResultService: Interface {
+    /// # Description
+    ///
+    /// Insert a check result into persistent storage, and open or resolve the incident of the
+    /// monitor that produced it and notify its notification channels if its status changed.
+    ///
+    /// A failed check that was performed during one of the monitor's maintenance windows is
+    /// recorded with the maintenance status instead.
+    ///
+    /// # Arguments
+    ///
+    /// `check_result` - The check result that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the check result that
+    /// was inserted.
+    /// - If the check result being inserted contains validation errors, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        check_result: &CheckResult,
+    ) -> InsertionResult<CheckResult, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a check result into persistent storage, and open or resolve the incident of the
+    /// monitor that produced it and notify its notification channels if its status changed.
+    ///
+    /// A failed check that was performed during one of the monitor's maintenance windows is
+    /// recorded with the maintenance status instead.
+    ///
+    /// # Arguments
+    ///
+    /// `check_result` - The check result that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the check result that
+    /// was inserted.
+    /// - If the check result being inserted contains validation errors, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        check_result: &CheckResult,
+        context: &mut QueryContext,
+    ) -> InsertionResult<CheckResult, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the most recent check results that were produced by a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `limit` - The maximum amount of check results that will be queried.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the check results that
+    /// were found, ordered from newest to oldest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<CheckResult>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the most recent check results that were produced by a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `limit` - The maximum amount of check results that will be queried.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the check results that
+    /// were found, ordered from newest to oldest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<CheckResult>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Calculate the statistics of the check results a monitor produced within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the calculation is successful, the Ok variant will be returned with the statistics.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_statistics(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+    ) -> QueryResult<CheckStatistics, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Calculate the statistics of the check results a monitor produced within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the calculation is successful, the Ok variant will be returned with the statistics.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_statistics_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> QueryResult<CheckStatistics, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Calculate a time series of the check results a monitor produced within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `width` - The width of each bucket of the time series.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the calculation is successful, the Ok variant will be returned with the buckets of the
+    /// time series, ordered from oldest to newest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_series(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        width: &SeriesBucketWidth,
+    ) -> QueryResult<Vec<CheckSeriesBucket>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Calculate a time series of the check results a monitor produced within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `width` - The width of each bucket of the time series.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the calculation is successful, the Ok variant will be returned with the buckets of the
+    /// time series, ordered from oldest to newest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_series_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        width: &SeriesBucketWidth,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<CheckSeriesBucket>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Roll the check results of each completed hour and day up into check rollups, and delete the
+    /// check results and check rollups that are older than their retention periods.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the retention was applied successfully, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn apply_retention(&self) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Roll the check results of each completed hour and day up into check rollups, and delete the
+    /// check results and check rollups that are older than their retention periods.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the retention was applied successfully, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn apply_retention_with_context(
+        &self,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>>;
+}
+
+/// A CheckResultServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = CheckResultService)]
+pub(crate) struct CheckResultServiceImpl {
+    /// The check result repository that will be used to manage persistent check result data.
+    #[shaku(inject)]
+    check_result_repository: Arc<dyn CheckResultRepository>,
+
+    /// The monitor service that will be used to validate monitor data.
+    #[shaku(inject)]
+    monitor_service: Arc<dyn MonitorService>,
+
+    /// The incident service that will be used to open and resolve incidents.
+    #[shaku(inject)]
+    incident_service: Arc<dyn IncidentService>,
+
+    /// The maintenance window service that will be used to determine if a monitor is in
+    /// maintenance.
+    #[shaku(inject)]
+    maintenance_window_service: Arc<dyn MaintenanceWindowService>,
+
+    /// The notification channel service that will be used to notify users when their monitors'
+    /// states change.
+    #[shaku(inject)]
+    notification_channel_service: Arc<dyn NotificationChannelService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The retention configuration that determines how long check results and rollups are kept.
+    retention_config: RetentionConfig,
+}
+
+/// A CheckResultService implementation for the CheckResultServiceImpl struct.
+#[async_trait(?Send)]
+impl CheckResultService for CheckResultServiceImpl {
+    async fn insert(
+        &self,
+        check_result: &CheckResult,
+    ) -> InsertionResult<CheckResult, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self.insert_with_context(check_result, &mut context).await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        check_result: &CheckResult,
+        context: &mut QueryContext,
+    ) -> InsertionResult<CheckResult, ValidationErrors, Box<dyn Error>> {
+        // Validate the check result.
+        let mut validation_errors = match check_result.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Check if the monitor id specified exists.
+        let monitor_option: Option<Monitor> = match __self
+            .monitor_service
+            .get_with_context(&check_result.monitor_id, context)
+            .await
+        {
+            QueryResult::Ok(monitor) => Some(monitor),
+            QueryResult::NotFound => {
+                validation_errors.add(
+                    name_of!(monitor_id in CheckResult),
+                    create_value_validation_error("not_found", &check_result.monitor_id),
+                );
+                None
+            }
+            QueryResult::Err(error) => return InsertionResult::Err(error),
+        };
+
+        // If any validation errors exist, return them.
+        let monitor: Monitor = match monitor_option {
+            Some(monitor) if validation_errors.is_empty() => monitor,
+            _ => return InsertionResult::Invalid(validation_errors),
+        };
+
+        // If the check failed during one of the monitor's maintenance windows, record it as
+        // maintenance, so that it does not count as downtime or open an incident.
+        let mut check_result: CheckResult = check_result.clone();
+        if check_result.status == CheckStatus::Down {
+            match __self
+                .maintenance_window_service
+                .get_occurrences_by_monitor_id_with_context(
+                    &check_result.monitor_id,
+                    &check_result.checked_at,
+                    &(check_result.checked_at + Duration::SECOND),
+                    context,
+                )
+                .await
+            {
+                QueryResult::Ok(occurrences) if !occurrences.is_empty() => {
+                    check_result.status = CheckStatus::Maintenance
+                }
+                QueryResult::Ok(_) | QueryResult::NotFound => {}
+                QueryResult::Err(error) => return InsertionResult::Err(error),
+            }
+        }
+
+        // Query the check result the monitor produced before this one.
+        let previous_check_results: Vec<CheckResult> = match __self
+            .check_result_repository
+            .get_by_monitor_id(&check_result.monitor_id, &1, context)
+            .await
+        {
+            Ok(previous_check_results) => previous_check_results,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Perform the insertion.
+        let check_result_id = match __self
+            .check_result_repository
+            .insert(&check_result, context)
+            .await
+        {
+            Ok(check_result_id) => check_result_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the check result that was inserted.
+        let inserted_check_result: CheckResult = match __self
+            .check_result_repository
+            .get(&check_result_id, context)
+            .await
+        {
+            Ok(Some(inserted_check_result)) => inserted_check_result,
+            Ok(None) => {
+                return InsertionResult::Err(Box::new(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "Check result could not be found after insertion",
+                )))
+            }
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Open or resolve the monitor's incident if its status changed.
+        let incident_option: Option<Incident> = match __self
+            .incident_service
+            .process_check_result_with_context(
+                &inserted_check_result,
+                previous_check_results.first(),
+                context,
+            )
+            .await
+        {
+            QueryResult::Ok(incident) => Some(incident),
+            QueryResult::NotFound => None,
+            QueryResult::Err(error) => return InsertionResult::Err(error),
+        };
+
+        // Notify the monitor's notification channels if its state changed.
+        if let Err(error) = __self
+            .notification_channel_service
+            .notify_with_context(
+                &monitor,
+                &inserted_check_result,
+                previous_check_results.first(),
+                incident_option.as_ref(),
+                context,
+            )
+            .await
+        {
+            return InsertionResult::Err(error);
+        }
+
+        // Return the check result that was inserted.
+        return InsertionResult::Ok(inserted_check_result);
+    }
+
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<CheckResult>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_monitor_id_with_context(monitor_id, limit, &mut context)
+            .await;
+    }
+
+    async fn get_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<CheckResult>, Box<dyn Error>> {
+        // Perform the query and return the check results that were found.
+        return match __self
+            .check_result_repository
+            .get_by_monitor_id(monitor_id, limit, context)
+            .await
+        {
+            Ok(check_results) => QueryResult::Ok(check_results),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_statistics(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+    ) -> QueryResult<CheckStatistics, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the calculation.
+        return self
+            .get_statistics_with_context(monitor_id, from, to, &mut context)
+            .await;
+    }
+
+    async fn get_statistics_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> QueryResult<CheckStatistics, Box<dyn Error>> {
+        // Determine the dates and times before which check results and hourly rollups are deleted.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let check_result_boundary: OffsetDateTime = self.get_check_result_boundary(now);
+        let hourly_rollup_boundary: OffsetDateTime = self.get_hourly_rollup_boundary(now);
+
+        // Query the samples of the check results within the part of the time range that check
+        // results are still kept for.
+        let samples_from: OffsetDateTime = (*from).max(check_result_boundary);
+        let samples: Vec<CheckSample> = if samples_from < *to {
+            match __self
+                .check_result_repository
+                .get_samples_by_monitor_id(monitor_id, &samples_from, to, context)
+                .await
+            {
+                Ok(samples) => samples,
+                Err(error) => return QueryResult::Err(Box::new(error)),
+            }
+        } else {
+            Vec::new()
+        };
+
+        // Calculate the statistics of the check results.
+        let check_result_statistics: CheckStatistics =
+            statistics::calculate_statistics(&samples, samples_from.min(*to), *to);
+
+        // If the time range does not extend past the check results that are still kept, return the
+        // statistics of the check results.
+        if *from >= check_result_boundary {
+            return QueryResult::Ok(check_result_statistics);
+        }
+
+        // Query the rollups of the older part of the time range, using hourly rollups where they are
+        // still kept and daily rollups before that.
+        let rollups_to: OffsetDateTime = (*to).min(check_result_boundary);
+        let hourly_rollups_from: OffsetDateTime = (*from).max(hourly_rollup_boundary);
+        let mut rollups: Vec<CheckRollup> = Vec::new();
+        if *from < hourly_rollup_boundary {
+            match __self
+                .check_result_repository
+                .get_rollups_by_monitor_id(
+                    monitor_id,
+                    &RollupPeriod::Day,
+                    &statistics::get_bucket_start(*from, SeriesBucketWidth::Day),
+                    &rollups_to.min(hourly_rollup_boundary),
+                    context,
+                )
+                .await
+            {
+                Ok(daily_rollups) => rollups.extend(daily_rollups),
+                Err(error) => return QueryResult::Err(Box::new(error)),
+            }
+        }
+        if hourly_rollups_from < rollups_to {
+            match __self
+                .check_result_repository
+                .get_rollups_by_monitor_id(
+                    monitor_id,
+                    &RollupPeriod::Hour,
+                    &statistics::get_bucket_start(hourly_rollups_from, SeriesBucketWidth::Hour),
+                    &rollups_to,
+                    context,
+                )
+                .await
+            {
+                Ok(hourly_rollups) => rollups.extend(hourly_rollups),
+                Err(error) => return QueryResult::Err(Box::new(error)),
+            }
+        }
+
+        // Combine the statistics of the rollups and the check results, and return them.
+        let buckets: Vec<CheckSeriesBucket> =
+            rollups.iter().map(statistics::convert_rollup).collect();
+        return QueryResult::Ok(statistics::combine_statistics(
+            &buckets,
+            &check_result_statistics,
+            *from,
+            *to,
+        ));
+    }
+
+    async fn get_series(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        width: &SeriesBucketWidth,
+    ) -> QueryResult<Vec<CheckSeriesBucket>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the calculation.
+        return self
+            .get_series_with_context(monitor_id, from, to, width, &mut context)
+            .await;
+    }
+
+    async fn get_series_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        width: &SeriesBucketWidth,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<CheckSeriesBucket>, Box<dyn Error>> {
+        // Determine the date and time before which check results are deleted. Minute buckets are
+        // only ever calculated from check results, since there are no rollups that fine.
+        let check_result_boundary: OffsetDateTime =
+            self.get_check_result_boundary(OffsetDateTime::now_utc());
+        let period: RollupPeriod = match width {
+            SeriesBucketWidth::Minute => {
+                return self
+                    .get_check_result_series(monitor_id, from, to, width, context)
+                    .await;
+            }
+            SeriesBucketWidth::Hour => RollupPeriod::Hour,
+            SeriesBucketWidth::Day => RollupPeriod::Day,
+        };
+
+        // If the time range does not extend past the check results that are still kept, calculate
+        // the time series from the check results.
+        if *from >= check_result_boundary {
+            return self
+                .get_check_result_series(monitor_id, from, to, width, context)
+                .await;
+        }
+
+        // Create the buckets of the older part of the time range, and fill them in with the rollups
+        // that cover them.
+        let rollups_to: OffsetDateTime = (*to).min(check_result_boundary);
+        let mut buckets: Vec<CheckSeriesBucket> =
+            statistics::calculate_series(&[], *from, rollups_to, *width);
+        let rollups: Vec<CheckRollup> = match __self
+            .check_result_repository
+            .get_rollups_by_monitor_id(
+                monitor_id,
+                &period,
+                &statistics::get_bucket_start(*from, *width),
+                &rollups_to,
+                context,
+            )
+            .await
+        {
+            Ok(rollups) => rollups,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+        for rollup in &rollups {
+            if let Some(bucket) = buckets
+                .iter_mut()
+                .find(|bucket| bucket.start == rollup.period_start)
+            {
+                *bucket = statistics::convert_rollup(rollup);
+            }
+        }
+
+        // Calculate the buckets of the part of the time range that check results are still kept for.
+        if rollups_to < *to {
+            match self
+                .get_check_result_series(monitor_id, &rollups_to, to, width, context)
+                .await
+            {
+                QueryResult::Ok(check_result_buckets) => buckets.extend(check_result_buckets),
+                QueryResult::NotFound => {}
+                QueryResult::Err(error) => return QueryResult::Err(error),
+            }
+        }
+
+        // Return the time series.
+        return QueryResult::Ok(buckets);
+    }
+
+    async fn apply_retention(&self) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Apply the retention.
+        return self
+            .apply_retention_with_context(&OffsetDateTime::now_utc(), &mut context)
+            .await;
+    }
+
+    async fn apply_retention_with_context(
+        &self,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>> {
+        // Roll up the check results of each monitor's completed hours and days.
+        let monitor_ids: Vec<u64> = __self
+            .check_result_repository
+            .get_monitor_ids(context)
+            .await?;
+        for monitor_id in &monitor_ids {
+            for period in [RollupPeriod::Hour, RollupPeriod::Day] {
+                self.create_rollups(monitor_id, &period, now, context)
+                    .await?;
+            }
+        }
+
+        // Delete the check results that are older than their retention period.
+        let check_result_boundary: OffsetDateTime = self.get_check_result_boundary(*now);
+        while __self
+            .check_result_repository
+            .delete_before(
+                &check_result_boundary,
+                &RETENTION_DELETION_BATCH_SIZE,
+                context,
+            )
+            .await?
+            > 0
+        {}
+
+        // Delete the hourly rollups that are older than their retention period.
+        let hourly_rollup_boundary: OffsetDateTime = self.get_hourly_rollup_boundary(*now);
+        while __self
+            .check_result_repository
+            .delete_rollups_before(
+                &RollupPeriod::Hour,
+                &hourly_rollup_boundary,
+                &RETENTION_DELETION_BATCH_SIZE,
+                context,
+            )
+            .await?
+            > 0
+        {}
+
+        // Delete the daily rollups that are older than their retention period, unless they are kept
+        // forever.
+        if __self.retention_config.daily_rollup_days > 0 {
+            let daily_rollup_boundary: OffsetDateTime = get_retention_boundary(
+                *now,
+                __self.retention_config.daily_rollup_days,
+                SeriesBucketWidth::Day,
+            );
+            while __self
+                .check_result_repository
+                .delete_rollups_before(
+                    &RollupPeriod::Day,
+                    &daily_rollup_boundary,
+                    &RETENTION_DELETION_BATCH_SIZE,
+                    context,
+                )
+                .await?
+                > 0
+            {}
+        }
+
+        return Ok(());
+    }
+}
+
+/// An implementation for the CheckResultServiceImpl struct.
+impl CheckResultServiceImpl {
+    /// # Description
+    ///
+    /// Create check result service parameters that can be used to override the default values
+    /// injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `retention_config` - The retention configuration that determines how long check results and
+    /// rollups are kept.
+    ///
+    /// # Returns
+    ///
+    /// The check result service parameters that were created.
+    pub(crate) fn create_parameters(
+        retention_config: RetentionConfig,
+    ) -> CheckResultServiceImplParameters {
+        return CheckResultServiceImplParameters { retention_config };
+    }
+
+    /// # Description
+    ///
+    /// Get the date and time before which check results are deleted.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// # Returns
+    ///
+    /// The date and time before which check results are deleted.
+    fn get_check_result_boundary(&self, now: OffsetDateTime) -> OffsetDateTime {
+        return get_retention_boundary(
+            now,
+            self.retention_config.check_result_days,
+            SeriesBucketWidth::Day,
+        );
+    }
+
+    /// # Description
+    ///
+    /// Get the date and time before which hourly rollups are deleted.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// # Returns
+    ///
+    /// The date and time before which hourly rollups are deleted.
+    fn get_hourly_rollup_boundary(&self, now: OffsetDateTime) -> OffsetDateTime {
+        return get_retention_boundary(
+            now,
+            self.retention_config.hourly_rollup_days,
+            SeriesBucketWidth::Day,
+        );
+    }
+
+    /// # Description
+    ///
+    /// Calculate a time series of the check results a monitor produced within a time range, without
+    /// reading any rollups.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `width` - The width of each bucket of the time series.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the calculation is successful, the Ok variant will be returned with the buckets of the
+    /// time series, ordered from oldest to newest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_check_result_series(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        width: &SeriesBucketWidth,
+        context: &mut QueryContext<'_>,
+    ) -> QueryResult<Vec<CheckSeriesBucket>, Box<dyn Error>> {
+        // Query the samples of the check results within the time range.
+        let samples: Vec<CheckSample> = match self
+            .check_result_repository
+            .get_samples_by_monitor_id(monitor_id, from, to, context)
+            .await
+        {
+            Ok(samples) => samples,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Calculate and return the time series.
+        return QueryResult::Ok(statistics::calculate_series(&samples, *from, *to, *width));
+    }
+
+    /// # Description
+    ///
+    /// Roll up the check results of each of a monitor's completed periods that have not been rolled
+    /// up yet.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that produced the check results.
+    ///
+    /// `period` - The period of time each rollup will cover.
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the rollups were created successfully, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create_rollups(
+        &self,
+        monitor_id: &u64,
+        period: &RollupPeriod,
+        now: &OffsetDateTime,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        let width: SeriesBucketWidth = period.get_bucket_width();
+
+        // Start after the latest rollup, or at the monitor's oldest check result if there are none.
+        let from: OffsetDateTime = match self
+            .check_result_repository
+            .get_latest_rollup_by_monitor_id(monitor_id, period, context)
+            .await?
+        {
+            Some(rollup) => rollup.period_start + width.get_duration(),
+            None => match self
+                .check_result_repository
+                .get_earliest_checked_at(monitor_id, context)
+                .await?
+            {
+                Some(checked_at) => statistics::get_bucket_start(checked_at, width),
+                None => return Ok(()),
+            },
+        };
+
+        // Only roll up the periods that have been completed.
+        let to: OffsetDateTime = statistics::get_bucket_start(*now, width);
+        if from >= to {
+            return Ok(());
+        }
+
+        // Query the samples of the check results within the completed periods.
+        let samples: Vec<CheckSample> = self
+            .check_result_repository
+            .get_samples_by_monitor_id(monitor_id, &from, &to, context)
+            .await?;
+
+        // Insert a rollup for each of the periods that any checks were performed in.
+        for bucket in statistics::calculate_series(&samples, from, to, width) {
+            if bucket.check_count == 0 {
+                continue;
+            }
+
+            self.check_result_repository
+                .upsert_rollup(
+                    &statistics::create_rollup(*monitor_id, *period, &bucket),
+                    context,
+                )
+                .await?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// # Description
+///
+/// Get the date and time before which data is deleted, given the amount of days it is kept for. The
+/// date and time is aligned to the start of a bucket, so that whole buckets are deleted at once.
+///
+/// # Arguments
+///
+/// `now` - The current date and time.
+///
+/// `days` - The amount of days the data is kept for.
+///
+/// `width` - The width of the buckets the date and time will be aligned to.
+///
+/// # Returns
+///
+/// The date and time before which data is deleted.
+fn get_retention_boundary(
+    now: OffsetDateTime,
+    days: u32,
+    width: SeriesBucketWidth,
+) -> OffsetDateTime {
+    return statistics::get_bucket_start(now - Duration::days(i64::from(days)), width);
+}
@@ -0,0 +1,426 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        check::{
+            model::{CheckDetails, CheckErrorKind, CheckResult, CheckStatus},
+            service::CheckResultService,
+        },
+        incident::service::IncidentService,
+        maintenance::{
+            model::{MaintenanceFrequency, MaintenanceWindow},
+            service::MaintenanceWindowService,
+        },
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::types::Json;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a check result that can be used for testing.
+///
+/// # Returns
+///
+/// The check result that was created.
+fn create_test_check_result() -> CheckResult {
+    return CheckResult {
+        id: 0,
+        monitor_id: 0,
+        checked_at: OffsetDateTime::now_utc(),
+        status: CheckStatus::Down,
+        latency: 250,
+        error_kind: Some(CheckErrorKind::UnexpectedStatusCode),
+        error_message: Some(generate_random_string(32)),
+        details: CheckDetails::default(),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting a check result and make sure the correct data is returned and queryable.
+#[actix_web::test]
+async fn check_result_is_queryable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result service instance.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Create a test check result.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = monitor.id;
+
+    // Insert the check result.
+    let inserted_check_result = match check_result_service.insert(&check_result).await {
+        InsertionResult::Ok(inserted_check_result) => inserted_check_result,
+        InsertionResult::Invalid(details) => panic!(
+            "Failed to insert check result, the check result was invalid: {}",
+            details
+        ),
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert check result, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the check result id with the one that was generated.
+    check_result.id = inserted_check_result.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(check_result, inserted_check_result);
+
+    // Query the check results the monitor produced.
+    let queried_check_results = match check_result_service
+        .get_by_monitor_id(&monitor.id, &10)
+        .await
+    {
+        QueryResult::Ok(queried_check_results) => queried_check_results,
+        QueryResult::NotFound => panic!("Failed to query check results: Not found"),
+        QueryResult::Err(error) => panic!(
+            "Failed to query check results, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure only the check result that was inserted was queried.
+    assert_eq!(vec![check_result], queried_check_results);
+
+    // Delete the test user (their monitor and check results will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting a failed check result during one of the monitor's maintenance windows, and make
+/// sure it is recorded as maintenance without opening an incident.
+#[actix_web::test]
+async fn failed_check_during_maintenance_is_recorded_as_maintenance() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+    let maintenance_window_service: Arc<dyn MaintenanceWindowService> = injector.resolve();
+    let incident_service: Arc<dyn IncidentService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Insert a maintenance window that is currently occurring.
+    let maintenance_window: MaintenanceWindow = MaintenanceWindow {
+        user_id: user.id,
+        name: generate_random_string(16),
+        starts_at: OffsetDateTime::now_utc() - Duration::MINUTE,
+        duration: 3600,
+        frequency: MaintenanceFrequency::Once,
+        monitor_ids: Json(vec![monitor.id]),
+        ..Default::default()
+    };
+    if let InsertionResult::Invalid(details) =
+        maintenance_window_service.insert(&maintenance_window).await
+    {
+        panic!("Failed to insert maintenance window: {}", details);
+    }
+
+    // Insert a failed check result.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = monitor.id;
+    let inserted_check_result = match check_result_service.insert(&check_result).await {
+        InsertionResult::Ok(inserted_check_result) => inserted_check_result,
+        InsertionResult::Invalid(details) => panic!(
+            "Failed to insert check result, the check result was invalid: {}",
+            details
+        ),
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert check result, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the check result was recorded as maintenance, and no incident was opened.
+    assert_eq!(CheckStatus::Maintenance, inserted_check_result.status);
+    match incident_service.get_by_monitor_id(&monitor.id, &10).await {
+        QueryResult::Ok(incidents) => assert!(incidents.is_empty()),
+        QueryResult::NotFound => panic!("Failed to query incidents: Not found"),
+        QueryResult::Err(error) => panic!("Failed to query incidents: {}", error),
+    };
+
+    // Delete the test user (their monitor, maintenance window and check results will be deleted
+    // with them).
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting a check result for a monitor that does not exist and make sure it does not
+/// succeed.
+#[actix_web::test]
+async fn inserting_a_check_result_for_a_missing_monitor_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result service instance.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Create a test check result for a monitor that does not exist.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = u64::MAX;
+
+    // Insert the check result, and make sure the validation errors are detected.
+    match check_result_service.insert(&check_result).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert check result, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+}
+
+/// # Description
+///
+/// Test inserting a check result with validation errors and make sure it does not succeed.
+#[actix_web::test]
+async fn inserting_a_check_result_with_validation_errors_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a check result service instance.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Create a test check result with an error message that is too long.
+    let mut check_result: CheckResult = create_test_check_result();
+    check_result.monitor_id = monitor.id;
+    check_result.error_message = Some(generate_random_string(4096));
+
+    // Insert the check result, and make sure the validation errors are detected.
+    match check_result_service.insert(&check_result).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert check result, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
@@ -0,0 +1,468 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::check::model::{
+    CheckRollup, CheckSample, CheckSeriesBucket, CheckStatistics, CheckStatus, LatencyStatistics,
+    RollupPeriod, SeriesBucketWidth,
+};
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Calculate the statistics of the check results a monitor produced within a time range.
+///
+/// # Arguments
+///
+/// `samples` - The samples of the check results that were produced within the time range, ordered
+/// from oldest to newest.
+///
+/// `from` - The date and time the time range starts at.
+///
+/// `to` - The date and time the time range ends at.
+///
+/// # Returns
+///
+/// The statistics that were calculated.
+pub(crate) fn calculate_statistics(
+    samples: &[CheckSample],
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+) -> CheckStatistics {
+    // Count the checks by status.
+    let (up_count, degraded_count, down_count, maintenance_count) = count_statuses(samples);
+
+    // Each check that determined the service to be down accounts for the time until the next
+    // check, or until the end of the time range if it was the last check.
+    let end: OffsetDateTime = to.min(OffsetDateTime::now_utc());
+    let mut downtime: Duration = Duration::ZERO;
+    for (index, sample) in samples.iter().enumerate() {
+        if sample.status != CheckStatus::Down {
+            continue;
+        }
+
+        let next_checked_at: OffsetDateTime = match samples.get(index + 1) {
+            Some(next_sample) => next_sample.checked_at.min(end),
+            None => end,
+        };
+        if next_checked_at > sample.checked_at {
+            downtime += next_checked_at - sample.checked_at;
+        }
+    }
+
+    // Return the statistics.
+    return CheckStatistics {
+        from,
+        to,
+        check_count: samples.len() as u64,
+        up_count,
+        degraded_count,
+        down_count,
+        maintenance_count,
+        uptime_percentage: calculate_uptime_percentage(up_count, degraded_count, down_count),
+        downtime: downtime.whole_seconds().max(0) as u64,
+        latency: calculate_latency_statistics(samples),
+    };
+}
+
+/// # Description
+///
+/// Calculate a time series of the check results a monitor produced within a time range.
+///
+/// The buckets are aligned to multiples of their width since the unix epoch (in utc), and a bucket
+/// is returned for every part of the time range, even if no checks were performed within it.
+///
+/// # Arguments
+///
+/// `samples` - The samples of the check results that were produced within the time range, ordered
+/// from oldest to newest.
+///
+/// `from` - The date and time the time range starts at.
+///
+/// `to` - The date and time the time range ends at.
+///
+/// `width` - The width of each bucket.
+///
+/// # Returns
+///
+/// The buckets of the time series, ordered from oldest to newest.
+pub(crate) fn calculate_series(
+    samples: &[CheckSample],
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+    width: SeriesBucketWidth,
+) -> Vec<CheckSeriesBucket> {
+    let first_bucket_start: OffsetDateTime = get_bucket_start(from, width);
+    let bucket_count: usize = count_buckets(from, to, width);
+    let mut bucket_samples: Vec<Vec<CheckSample>> = vec![Vec::new(); bucket_count];
+    let mut bucket_downtimes: Vec<Duration> = vec![Duration::ZERO; bucket_count];
+
+    // Group the samples by the bucket they fall within.
+    let end: OffsetDateTime = to.min(OffsetDateTime::now_utc());
+    for (index, sample) in samples.iter().enumerate() {
+        let bucket_index: usize = match usize::try_from(
+            (sample.checked_at - first_bucket_start)
+                .whole_seconds()
+                .div_euclid(width.get_duration().whole_seconds()),
+        ) {
+            Ok(bucket_index) if bucket_index < bucket_count => bucket_index,
+            _ => continue,
+        };
+        bucket_samples[bucket_index].push(sample.clone());
+
+        // If the check determined the service to be down, it accounts for the time until the next
+        // check, without extending past the end of its bucket.
+        if sample.status == CheckStatus::Down {
+            let bucket_end: OffsetDateTime =
+                first_bucket_start + width.get_duration() * (bucket_index as u32 + 1);
+            let next_checked_at: OffsetDateTime = match samples.get(index + 1) {
+                Some(next_sample) => next_sample.checked_at.min(bucket_end).min(end),
+                None => bucket_end.min(end),
+            };
+            if next_checked_at > sample.checked_at {
+                bucket_downtimes[bucket_index] += next_checked_at - sample.checked_at;
+            }
+        }
+    }
+
+    // Summarize each bucket.
+    return bucket_samples
+        .iter()
+        .zip(bucket_downtimes)
+        .enumerate()
+        .map(|(index, (samples, downtime))| {
+            let (up_count, degraded_count, down_count, maintenance_count) = count_statuses(samples);
+            return CheckSeriesBucket {
+                start: first_bucket_start + width.get_duration() * index as u32,
+                check_count: samples.len() as u64,
+                up_count,
+                degraded_count,
+                down_count,
+                maintenance_count,
+                uptime_percentage: calculate_uptime_percentage(
+                    up_count,
+                    degraded_count,
+                    down_count,
+                ),
+                downtime: downtime.whole_seconds().max(0) as u64,
+                latency: calculate_latency_statistics(samples),
+            };
+        })
+        .collect();
+}
+
+/// # Description
+///
+/// Combine the statistics of check results with the series buckets that summarize older check
+/// results, to produce the statistics of a time range that spans both.
+///
+/// Latency percentiles can not be combined exactly, so the combined percentiles are approximated
+/// by averaging the percentiles of each part, weighted by their amount of operational checks.
+///
+/// # Arguments
+///
+/// `buckets` - The series buckets that summarize the older check results.
+///
+/// `statistics` - The statistics of the newer check results.
+///
+/// `from` - The date and time the combined time range starts at.
+///
+/// `to` - The date and time the combined time range ends at.
+///
+/// # Returns
+///
+/// The combined statistics.
+pub(crate) fn combine_statistics(
+    buckets: &[CheckSeriesBucket],
+    statistics: &CheckStatistics,
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+) -> CheckStatistics {
+    // Sum the counts and downtime of each part.
+    let mut combined_statistics: CheckStatistics = CheckStatistics {
+        from,
+        to,
+        ..statistics.clone()
+    };
+    for bucket in buckets {
+        combined_statistics.check_count += bucket.check_count;
+        combined_statistics.up_count += bucket.up_count;
+        combined_statistics.degraded_count += bucket.degraded_count;
+        combined_statistics.down_count += bucket.down_count;
+        combined_statistics.maintenance_count += bucket.maintenance_count;
+        combined_statistics.downtime += bucket.downtime;
+    }
+    combined_statistics.uptime_percentage = calculate_uptime_percentage(
+        combined_statistics.up_count,
+        combined_statistics.degraded_count,
+        combined_statistics.down_count,
+    );
+
+    // Combine the latency statistics of each part, weighted by their amount of operational checks.
+    let mut parts: Vec<(u64, &LatencyStatistics)> = buckets
+        .iter()
+        .map(|bucket| (bucket.up_count + bucket.degraded_count, &bucket.latency))
+        .collect();
+    parts.push((
+        statistics.up_count + statistics.degraded_count,
+        &statistics.latency,
+    ));
+    let get_weighted_average = |get_value: fn(&LatencyStatistics) -> Option<u32>| -> Option<u32> {
+        let (mut total, mut weight) = (0_u64, 0_u64);
+        for (count, latency) in &parts {
+            if let Some(value) = get_value(latency) {
+                total += u64::from(value) * count;
+                weight += count;
+            }
+        }
+        if weight == 0 {
+            return None;
+        }
+
+        return Some((total / weight) as u32);
+    };
+    combined_statistics.latency = LatencyStatistics {
+        minimum: parts
+            .iter()
+            .filter_map(|(_, latency)| latency.minimum)
+            .min(),
+        average: get_weighted_average(|latency| latency.average),
+        maximum: parts
+            .iter()
+            .filter_map(|(_, latency)| latency.maximum)
+            .max(),
+        p50: get_weighted_average(|latency| latency.p50),
+        p95: get_weighted_average(|latency| latency.p95),
+        p99: get_weighted_average(|latency| latency.p99),
+    };
+
+    // Return the combined statistics.
+    return combined_statistics;
+}
+
+/// # Description
+///
+/// Create a check rollup from the series bucket that summarizes the same period.
+///
+/// # Arguments
+///
+/// `monitor_id` - The unique identifier of the monitor that produced the check results.
+///
+/// `period` - The period of time the rollup covers.
+///
+/// `bucket` - The series bucket that summarizes the period.
+///
+/// # Returns
+///
+/// The check rollup that was created.
+pub(crate) fn create_rollup(
+    monitor_id: u64,
+    period: RollupPeriod,
+    bucket: &CheckSeriesBucket,
+) -> CheckRollup {
+    return CheckRollup {
+        id: 0,
+        monitor_id,
+        period,
+        period_start: bucket.start,
+        check_count: bucket.check_count,
+        up_count: bucket.up_count,
+        degraded_count: bucket.degraded_count,
+        down_count: bucket.down_count,
+        maintenance_count: bucket.maintenance_count,
+        downtime: bucket.downtime,
+        minimum_latency: bucket.latency.minimum,
+        average_latency: bucket.latency.average,
+        maximum_latency: bucket.latency.maximum,
+        p50_latency: bucket.latency.p50,
+        p95_latency: bucket.latency.p95,
+        p99_latency: bucket.latency.p99,
+    };
+}
+
+/// # Description
+///
+/// Convert a check rollup into the series bucket that summarizes the same period.
+///
+/// # Arguments
+///
+/// `rollup` - The check rollup that will be converted.
+///
+/// # Returns
+///
+/// The series bucket.
+pub(crate) fn convert_rollup(rollup: &CheckRollup) -> CheckSeriesBucket {
+    return CheckSeriesBucket {
+        start: rollup.period_start,
+        check_count: rollup.check_count,
+        up_count: rollup.up_count,
+        degraded_count: rollup.degraded_count,
+        down_count: rollup.down_count,
+        maintenance_count: rollup.maintenance_count,
+        uptime_percentage: calculate_uptime_percentage(
+            rollup.up_count,
+            rollup.degraded_count,
+            rollup.down_count,
+        ),
+        downtime: rollup.downtime,
+        latency: LatencyStatistics {
+            minimum: rollup.minimum_latency,
+            average: rollup.average_latency,
+            maximum: rollup.maximum_latency,
+            p50: rollup.p50_latency,
+            p95: rollup.p95_latency,
+            p99: rollup.p99_latency,
+        },
+    };
+}
+
+/// # Description
+///
+/// Count the amount of buckets a time series of a time range will contain.
+///
+/// # Arguments
+///
+/// `from` - The date and time the time range starts at.
+///
+/// `to` - The date and time the time range ends at.
+///
+/// `width` - The width of each bucket.
+///
+/// # Returns
+///
+/// The amount of buckets the time series will contain.
+pub(crate) fn count_buckets(
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+    width: SeriesBucketWidth,
+) -> usize {
+    // If the time range is empty, there are no buckets.
+    if to <= from {
+        return 0;
+    }
+
+    // Count the buckets, including the bucket that is partially covered at the end.
+    let covered_seconds: i64 = (to - get_bucket_start(from, width)).whole_seconds();
+    let width_seconds: i64 = width.get_duration().whole_seconds();
+    return ((covered_seconds + width_seconds - 1) / width_seconds) as usize;
+}
+
+/// # Description
+///
+/// Get the start of the bucket a date and time falls within.
+///
+/// # Arguments
+///
+/// `at` - The date and time.
+///
+/// `width` - The width of each bucket.
+///
+/// # Returns
+///
+/// The date and time the bucket starts at.
+pub(crate) fn get_bucket_start(at: OffsetDateTime, width: SeriesBucketWidth) -> OffsetDateTime {
+    let timestamp: i64 = at.unix_timestamp();
+    let width_seconds: i64 = width.get_duration().whole_seconds();
+    return OffsetDateTime::UNIX_EPOCH
+        + Duration::seconds(timestamp - timestamp.rem_euclid(width_seconds));
+}
+
+/// # Description
+///
+/// Count the checks that determined the monitored service to be in each status.
+///
+/// # Arguments
+///
+/// `samples` - The samples of the checks that will be counted.
+///
+/// # Returns
+///
+/// The amount of checks that determined the service to be up, degraded, down and down during
+/// maintenance.
+fn count_statuses(samples: &[CheckSample]) -> (u64, u64, u64, u64) {
+    let mut counts: (u64, u64, u64, u64) = (0, 0, 0, 0);
+    for sample in samples {
+        match sample.status {
+            CheckStatus::Up => counts.0 += 1,
+            CheckStatus::Degraded => counts.1 += 1,
+            CheckStatus::Down => counts.2 += 1,
+            CheckStatus::Maintenance => counts.3 += 1,
+        }
+    }
+
+    return counts;
+}
+
+/// # Description
+///
+/// Calculate the percentage of checks that determined the monitored service to be operational.
+///
+/// Checks that were performed during maintenance are not counted, so planned downtime does not
+/// affect uptime.
+///
+/// # Arguments
+///
+/// `up_count` - The amount of checks that determined the service to be up.
+///
+/// `degraded_count` - The amount of checks that determined the service to be degraded.
+///
+/// `down_count` - The amount of checks that determined the service to be down.
+///
+/// # Returns
+///
+/// An option that will contain the percentage if any checks were performed.
+fn calculate_uptime_percentage(up_count: u64, degraded_count: u64, down_count: u64) -> Option<f64> {
+    let check_count: u64 = up_count + degraded_count + down_count;
+    if check_count == 0 {
+        return None;
+    }
+
+    return Some((up_count + degraded_count) as f64 * 100.0 / check_count as f64);
+}
+
+/// # Description
+///
+/// Calculate the latency statistics of the checks that determined the monitored service to be
+/// operational, using the nearest rank method for percentiles.
+///
+/// The latencies of checks that determined the service to be down (including during maintenance)
+/// are excluded, since they usually measure how long it took for the check to time out or fail
+/// rather than the service's latency.
+///
+/// # Arguments
+///
+/// `samples` - The samples of the checks the statistics will be calculated from.
+///
+/// # Returns
+///
+/// The latency statistics that were calculated.
+fn calculate_latency_statistics(samples: &[CheckSample]) -> LatencyStatistics {
+    // Get the latencies of the operational checks, in ascending order.
+    let mut latencies: Vec<u32> = samples
+        .iter()
+        .filter(|sample| matches!(sample.status, CheckStatus::Up | CheckStatus::Degraded))
+        .map(|sample| sample.latency)
+        .collect();
+    latencies.sort_unstable();
+
+    // If no latencies were measured, there are no statistics.
+    if latencies.is_empty() {
+        return LatencyStatistics::default();
+    }
+
+    // Get the latency at a percentile.
+    let get_percentile = |percentile: usize| -> Option<u32> {
+        let rank: usize = (percentile * latencies.len()).div_ceil(100);
+        return Some(latencies[rank.max(1) - 1]);
+    };
+
+    // Return the latency statistics.
+    let total: u64 = latencies.iter().map(|latency| u64::from(*latency)).sum();
+    return LatencyStatistics {
+        minimum: latencies.first().copied(),
+        average: Some((total / latencies.len() as u64) as u32),
+        maximum: latencies.last().copied(),
+        p50: get_percentile(50),
+        p95: get_percentile(95),
+        p99: get_percentile(99),
+    };
+}
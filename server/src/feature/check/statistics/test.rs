@@ -0,0 +1,287 @@
+use crate::feature::check::{
+    model::{
+        CheckRollup, CheckSample, CheckSeriesBucket, CheckStatistics, CheckStatus, RollupPeriod,
+        SeriesBucketWidth,
+    },
+    statistics::{
+        calculate_series, calculate_statistics, combine_statistics, convert_rollup, count_buckets,
+        create_rollup,
+    },
+};
+use time::{macros::datetime, Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a check sample that can be used for testing.
+///
+/// # Arguments
+///
+/// `checked_at` - The date and time the check was performed at.
+///
+/// `status` - The status the check determined the service to be in.
+///
+/// `latency` - The amount of time (in milliseconds) the check took.
+///
+/// # Returns
+///
+/// The check sample that was created.
+fn create_test_sample(
+    checked_at: OffsetDateTime,
+    status: CheckStatus,
+    latency: u32,
+) -> CheckSample {
+    return CheckSample {
+        checked_at,
+        status,
+        latency,
+    };
+}
+
+/// # Description
+///
+/// Test calculating the statistics of a time range without any checks, and make sure nothing is
+/// reported as measured.
+#[test]
+fn statistics_without_checks_are_empty() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-02 00:00 UTC);
+
+    let statistics: CheckStatistics = calculate_statistics(&[], from, to);
+
+    assert_eq!(0, statistics.check_count);
+    assert_eq!(None, statistics.uptime_percentage);
+    assert_eq!(0, statistics.downtime);
+    assert_eq!(None, statistics.latency.p50);
+}
+
+/// # Description
+///
+/// Test calculating the statistics of a time range, and make sure the counts, uptime, downtime and
+/// latency percentiles are correct.
+#[test]
+fn statistics_are_calculated_from_checks() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 00:10 UTC);
+
+    // Check every minute, with the service down from the fourth to the sixth minute.
+    let samples: Vec<CheckSample> = (0..10)
+        .map(|minute| {
+            let status: CheckStatus = match minute {
+                3 | 4 => CheckStatus::Down,
+                9 => CheckStatus::Degraded,
+                _ => CheckStatus::Up,
+            };
+            return create_test_sample(
+                from + Duration::minutes(minute),
+                status,
+                100 * (minute as u32 + 1),
+            );
+        })
+        .collect();
+
+    let statistics: CheckStatistics = calculate_statistics(&samples, from, to);
+
+    assert_eq!(10, statistics.check_count);
+    assert_eq!(7, statistics.up_count);
+    assert_eq!(1, statistics.degraded_count);
+    assert_eq!(2, statistics.down_count);
+    assert_eq!(Some(80.0), statistics.uptime_percentage);
+    assert_eq!(120, statistics.downtime);
+
+    // The latencies of the down checks (400 and 500) are excluded.
+    assert_eq!(Some(600), statistics.latency.p50);
+    assert_eq!(Some(1000), statistics.latency.p95);
+    assert_eq!(Some(1000), statistics.latency.p99);
+}
+
+/// # Description
+///
+/// Test calculating the statistics of a time range whose last check determined the service to be
+/// down, and make sure the downtime extends until the end of the time range.
+#[test]
+fn trailing_downtime_extends_to_end_of_range() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 01:00 UTC);
+    let samples: Vec<CheckSample> = vec![
+        create_test_sample(from, CheckStatus::Up, 100),
+        create_test_sample(from + Duration::minutes(30), CheckStatus::Down, 5000),
+    ];
+
+    let statistics: CheckStatistics = calculate_statistics(&samples, from, to);
+
+    assert_eq!(1800, statistics.downtime);
+    assert_eq!(Some(50.0), statistics.uptime_percentage);
+}
+
+/// # Description
+///
+/// Test calculating the statistics of a time range that contains checks performed during
+/// maintenance, and make sure they are excluded from the uptime, downtime and latencies.
+#[test]
+fn maintenance_checks_are_excluded_from_uptime() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 00:04 UTC);
+    let samples: Vec<CheckSample> = vec![
+        create_test_sample(from, CheckStatus::Up, 100),
+        create_test_sample(from + Duration::minutes(1), CheckStatus::Maintenance, 5000),
+        create_test_sample(from + Duration::minutes(2), CheckStatus::Maintenance, 5000),
+        create_test_sample(from + Duration::minutes(3), CheckStatus::Down, 5000),
+    ];
+
+    let statistics: CheckStatistics = calculate_statistics(&samples, from, to);
+
+    assert_eq!(4, statistics.check_count);
+    assert_eq!(2, statistics.maintenance_count);
+    assert_eq!(Some(50.0), statistics.uptime_percentage);
+    assert_eq!(60, statistics.downtime);
+    assert_eq!(Some(100), statistics.latency.maximum);
+}
+
+/// # Description
+///
+/// Test calculating a time series, and make sure the buckets are aligned, cover the whole time
+/// range, and summarize the checks that fall within them.
+#[test]
+fn series_buckets_are_aligned_and_summarized() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:30 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 03:00 UTC);
+    let samples: Vec<CheckSample> = vec![
+        create_test_sample(datetime!(2026-01-01 00:45 UTC), CheckStatus::Up, 100),
+        create_test_sample(datetime!(2026-01-01 02:10 UTC), CheckStatus::Up, 200),
+        create_test_sample(datetime!(2026-01-01 02:20 UTC), CheckStatus::Down, 300),
+    ];
+
+    let series: Vec<CheckSeriesBucket> =
+        calculate_series(&samples, from, to, SeriesBucketWidth::Hour);
+
+    // Make sure the buckets start at the top of each hour.
+    assert_eq!(
+        vec![
+            datetime!(2026-01-01 00:00 UTC),
+            datetime!(2026-01-01 01:00 UTC),
+            datetime!(2026-01-01 02:00 UTC),
+        ],
+        series
+            .iter()
+            .map(|bucket| bucket.start)
+            .collect::<Vec<OffsetDateTime>>()
+    );
+
+    // Make sure each bucket summarizes its checks.
+    assert_eq!(1, series[0].check_count);
+    assert_eq!(Some(100.0), series[0].uptime_percentage);
+    assert_eq!(0, series[1].check_count);
+    assert_eq!(None, series[1].uptime_percentage);
+    assert_eq!(2, series[2].check_count);
+    assert_eq!(1, series[2].down_count);
+    assert_eq!(Some(50.0), series[2].uptime_percentage);
+    assert_eq!(Some(200), series[2].latency.p99);
+}
+
+/// # Description
+///
+/// Test counting the buckets of time series, and make sure partially covered buckets are counted.
+#[test]
+fn buckets_are_counted() {
+    let from: OffsetDateTime = datetime!(2026-01-01 12:00 UTC);
+
+    assert_eq!(0, count_buckets(from, from, SeriesBucketWidth::Minute));
+    assert_eq!(
+        1440,
+        count_buckets(from, from + Duration::DAY, SeriesBucketWidth::Minute)
+    );
+    assert_eq!(
+        2,
+        count_buckets(from, from + Duration::DAY, SeriesBucketWidth::Day)
+    );
+}
+
+/// # Description
+///
+/// Test calculating a time series whose service goes down near the end of a bucket, and make sure
+/// the downtime is attributed to the buckets it falls within.
+#[test]
+fn series_bucket_downtime_is_split_between_buckets() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 02:00 UTC);
+    let samples: Vec<CheckSample> = vec![
+        create_test_sample(datetime!(2026-01-01 00:50 UTC), CheckStatus::Down, 100),
+        create_test_sample(datetime!(2026-01-01 01:20 UTC), CheckStatus::Up, 100),
+    ];
+
+    let series: Vec<CheckSeriesBucket> =
+        calculate_series(&samples, from, to, SeriesBucketWidth::Hour);
+
+    // The first bucket only accounts for the downtime until the end of its hour.
+    assert_eq!(600, series[0].downtime);
+    assert_eq!(0, series[1].downtime);
+}
+
+/// # Description
+///
+/// Test converting a series bucket into a rollup and back, and make sure nothing is lost.
+#[test]
+fn rollups_are_converted_without_loss() {
+    let samples: Vec<CheckSample> = vec![
+        create_test_sample(datetime!(2026-01-01 00:00 UTC), CheckStatus::Up, 100),
+        create_test_sample(datetime!(2026-01-01 00:30 UTC), CheckStatus::Down, 300),
+    ];
+    let bucket: CheckSeriesBucket = calculate_series(
+        &samples,
+        datetime!(2026-01-01 00:00 UTC),
+        datetime!(2026-01-01 01:00 UTC),
+        SeriesBucketWidth::Hour,
+    )
+    .remove(0);
+
+    let rollup: CheckRollup = create_rollup(1, RollupPeriod::Hour, &bucket);
+
+    assert_eq!(1, rollup.monitor_id);
+    assert_eq!(1800, rollup.downtime);
+    assert_eq!(Some(100), rollup.maximum_latency);
+    assert_eq!(bucket, convert_rollup(&rollup));
+}
+
+/// # Description
+///
+/// Test combining the statistics of rollups with the statistics of check results, and make sure the
+/// counts and downtime are summed and the latencies are combined.
+#[test]
+fn statistics_are_combined_with_rollups() {
+    let from: OffsetDateTime = datetime!(2026-01-01 00:00 UTC);
+    let to: OffsetDateTime = datetime!(2026-01-01 03:00 UTC);
+
+    // Summarize the first two hours as rollups, and the last hour from its check results.
+    let older_samples: Vec<CheckSample> = vec![
+        create_test_sample(datetime!(2026-01-01 00:00 UTC), CheckStatus::Up, 100),
+        create_test_sample(datetime!(2026-01-01 01:00 UTC), CheckStatus::Up, 300),
+        create_test_sample(datetime!(2026-01-01 01:30 UTC), CheckStatus::Down, 900),
+    ];
+    let newer_samples: Vec<CheckSample> = vec![create_test_sample(
+        datetime!(2026-01-01 02:00 UTC),
+        CheckStatus::Degraded,
+        500,
+    )];
+    let buckets: Vec<CheckSeriesBucket> = calculate_series(
+        &older_samples,
+        from,
+        datetime!(2026-01-01 02:00 UTC),
+        SeriesBucketWidth::Hour,
+    );
+    let newer_statistics: CheckStatistics =
+        calculate_statistics(&newer_samples, datetime!(2026-01-01 02:00 UTC), to);
+
+    let statistics: CheckStatistics = combine_statistics(&buckets, &newer_statistics, from, to);
+
+    assert_eq!(from, statistics.from);
+    assert_eq!(to, statistics.to);
+    assert_eq!(4, statistics.check_count);
+    assert_eq!(2, statistics.up_count);
+    assert_eq!(1, statistics.degraded_count);
+    assert_eq!(1, statistics.down_count);
+    assert_eq!(Some(75.0), statistics.uptime_percentage);
+    assert_eq!(1800, statistics.downtime);
+    assert_eq!(Some(100), statistics.latency.minimum);
+    assert_eq!(Some(300), statistics.latency.average);
+    assert_eq!(Some(500), statistics.latency.maximum);
+}
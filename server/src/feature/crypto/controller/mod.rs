@@ -0,0 +1,39 @@
+use crate::{feature::crypto::service::CryptoService, injector::DependencyInjector};
+use actix_web::{
+    get,
+    web::{self, ServiceConfig},
+    HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the crypto controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the crypto controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(web::scope("/.well-known").service(get_public_key_set));
+}
+
+/// # Description
+///
+/// An api endpoint to get the public keys authentication tokens are verified with, so that other
+/// services can verify the tokens the server creates.
+///
+/// This endpoint does not require authentication, since the keys are public.
+///
+/// # Arguments
+///
+/// `crypto_service` - The crypto service that will be used to get the public keys.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/jwks.json")]
+async fn get_public_key_set(
+    crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+) -> HttpResponse {
+    return HttpResponse::Ok().json(crypto_service.get_public_key_set());
+}
@@ -1,2 +1,3 @@
+pub(crate) mod controller;
 pub(crate) mod model;
 pub(crate) mod service;
@@ -1,3 +1,4 @@
+use jsonwebtoken::{jwk::Jwk, Algorithm, DecodingKey, EncodingKey};
 use serde::{Deserialize, Serialize};
 
 /// A struct containing claims for users tokens.
@@ -7,6 +8,10 @@ pub(crate) struct UserClaims {
     /// The id of the user the token belongs to.
     pub(crate) id: u64,
 
+    /// The id of the token, which is the id of the session family it was issued for. If the
+    /// session family has been revoked, the token is no longer valid.
+    pub(crate) jti: String,
+
     /// The date and time the token expires at (UTC).
     pub(crate) exp: i64,
 
@@ -18,3 +23,24 @@ pub(crate) struct UserClaims {
     /// storage record, the token is not longer valid.
     pub(crate) password_last_reset: i64,
 }
+
+/// A jwt key struct, which holds a key that is used to create or verify json web tokens.
+#[derive(Clone)]
+pub(crate) struct JwtKey {
+    /// The key's unique identifier, which is included as the `kid` in the header of the tokens it
+    /// creates.
+    pub(crate) id: String,
+
+    /// The algorithm the key is used with.
+    pub(crate) algorithm: Algorithm,
+
+    /// The key that will be used to encode tokens, if the key can be used to create them.
+    pub(crate) encoding_key: Option<EncodingKey>,
+
+    /// The key that will be used to decode tokens.
+    pub(crate) decoding_key: DecodingKey,
+
+    /// The public key in the json web key format (RFC 7517), which is published so that others can
+    /// verify the tokens the key creates.
+    pub(crate) public_key: Jwk,
+}
@@ -1,30 +1,79 @@
 #[cfg(test)]
 mod test;
 
-use crate::feature::{crypto::model::UserClaims, user::model::User};
+use crate::{
+    common::utility::generate_random_string,
+    config::{JwtAlgorithm, JwtConfig, JwtKeyConfig},
+    feature::{
+        crypto::model::{JwtKey, UserClaims},
+        user::model::User,
+    },
+};
 use argon2::{
     password_hash::rand_core::OsRng, password_hash::SaltString, Argon2, PasswordHash,
     PasswordHasher, PasswordVerifier,
 };
 use jsonwebtoken::{
-    decode, encode, Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation,
+    decode, decode_header, encode,
+    errors::ErrorKind,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, EllipticCurveKeyParameters,
+        EllipticCurveKeyType, Jwk, JwkSet, KeyAlgorithm, OctetKeyPairParameters, OctetKeyPairType,
+        PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+    Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation,
+};
+use openssl::{
+    base64,
+    bn::{BigNum, BigNumContext},
+    hash::MessageDigest,
+    memcmp,
+    nid::Nid,
+    pkey::{Id, PKey, Public},
+    sha::sha256,
+    sign::Signer,
 };
+use rand::{rng, Rng};
 use shaku::{Component, Interface};
-use time::OffsetDateTime;
+use std::{error::Error, fs};
+use time::{Duration, OffsetDateTime};
+
+/// The amount of bytes in each coordinate of a P-256 public key.
+const P256_COORDINATE_LENGTH: i32 = 32;
+
+/// The amount of characters in the secret tokens that are generated.
+const SECRET_TOKEN_LENGTH: usize = 48;
+
+/// The amount of random bytes in the totp secrets that are generated.
+const TOTP_SECRET_LENGTH: usize = 20;
 
-/// The algorithm that will be used to encode and decode json web tokens.
-const JWT_ALGORITHM: Algorithm = Algorithm::RS256;
+/// The amount of time (in seconds) each totp code is valid for.
+pub(crate) const TOTP_PERIOD: i64 = 30;
+
+/// The amount of digits in each totp code.
+pub(crate) const TOTP_DIGITS: u32 = 6;
+
+/// The amount of periods before and after the current period whose totp codes are accepted, to
+/// allow for clock drift between the server and the user's device.
+const TOTP_ALLOWED_DRIFT: i64 = 1;
+
+/// The alphabet that is used to encode totp secrets as base32 (RFC 4648).
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
 
 /// A crypto service trait.
 pub(crate) trait CryptoService: Interface {
     /// # Description
     ///
-    /// Create an authentication token for a user.
+    /// Create an authentication token for a user. The token expires once the access token lifetime
+    /// has passed, after which the user must use their refresh token to get a new one.
     ///
     /// # Arguments
     ///
     /// `user` - The user the authentication token is being created for.
     ///
+    /// `session_family_id` - The id of the session family the token is being created for, which
+    /// is used as the id of the token.
+    ///
     /// # Returns
     ///
     /// This function returns a result:
@@ -32,11 +81,17 @@ pub(crate) trait CryptoService: Interface {
     /// was created.
     /// - If the token creation was not successful, the Err variant will be returned with the error
     /// that occurred.
-    fn create_token(&self, user: &User) -> Result<String, jsonwebtoken::errors::Error>;
+    fn create_token(
+        &self,
+        user: &User,
+        session_family_id: &String,
+    ) -> Result<String, jsonwebtoken::errors::Error>;
 
     /// # Description
     ///
-    /// Decode an authentication token.
+    /// Decode an authentication token. The token is verified with the key whose id is in its
+    /// header, so tokens created with a key that has been removed, or that have expired, can not be
+    /// decoded.
     ///
     /// # Arguments
     ///
@@ -54,6 +109,17 @@ pub(crate) trait CryptoService: Interface {
         token: &String,
     ) -> Result<TokenData<UserClaims>, jsonwebtoken::errors::Error>;
 
+    /// # Description
+    ///
+    /// Get the public keys authentication tokens are verified with, so that they can be published
+    /// for others to verify tokens with.
+    ///
+    /// # Returns
+    ///
+    /// The json web key set (RFC 7517) of the public keys, including the keys that are no longer
+    /// used to create tokens.
+    fn get_public_key_set(&self) -> JwkSet;
+
     /// # Description
     ///
     /// Hash a password.
@@ -93,51 +159,181 @@ pub(crate) trait CryptoService: Interface {
         password: &String,
         hash: &String,
     ) -> Result<(), argon2::password_hash::Error>;
+
+    /// # Description
+    ///
+    /// Generate a random secret token, such as the ones that are emailed to users so that they can
+    /// verify their email address.
+    ///
+    /// # Returns
+    ///
+    /// The secret token that was generated.
+    fn generate_secret_token(&self) -> String;
+
+    /// # Description
+    ///
+    /// Hash a secret token so that it can be stored and looked up without storing the token
+    /// itself.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The secret token that will be hashed.
+    ///
+    /// # Returns
+    ///
+    /// The sha-256 hash of the secret token, encoded as hex.
+    fn hash_secret_token(&self, token: &String) -> String;
+
+    /// # Description
+    ///
+    /// Create the code challenge for a proof key for code exchange (RFC 7636), which lets an
+    /// authorization server make sure the authorization code it issued is redeemed by the client
+    /// that requested it.
+    ///
+    /// # Arguments
+    ///
+    /// `code_verifier` - The secret code verifier.
+    ///
+    /// # Returns
+    ///
+    /// The sha-256 hash of the code verifier, encoded as unpadded base64url (the S256 method).
+    fn create_code_challenge(&self, code_verifier: &String) -> String;
+
+    /// # Description
+    ///
+    /// Generate a secret that can be used to create time-based one-time passwords (RFC 6238).
+    ///
+    /// # Returns
+    ///
+    /// The secret that was generated, encoded as base32 so that it can be entered into
+    /// authenticator apps.
+    fn generate_totp_secret(&self) -> String;
+
+    /// # Description
+    ///
+    /// Create the time-based one-time password for a secret at a specific time.
+    ///
+    /// # Arguments
+    ///
+    /// `secret` - The base32 encoded secret.
+    ///
+    /// `time` - The time the code is being created for.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the code was created, the Ok variant will be returned with the code.
+    /// - If the secret is not valid base32 or an unexpected error occurs, the Err variant will be
+    /// returned with the error that occurred.
+    fn create_totp_code(
+        &self,
+        secret: &String,
+        time: &OffsetDateTime,
+    ) -> Result<String, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Verify a time-based one-time password. Codes from the periods just before and after the
+    /// current one are accepted as well, to allow for clock drift.
+    ///
+    /// # Arguments
+    ///
+    /// `secret` - The base32 encoded secret.
+    ///
+    /// `code` - The code that is being verified.
+    ///
+    /// `time` - The time the code is being verified at.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the verification was performed, the Ok variant will be returned with an option that
+    /// will contain the time step the code was created for if it is correct. Callers should not
+    /// accept a time step that was already used, so that codes can not be replayed.
+    /// - If the secret is not valid base32 or an unexpected error occurs, the Err variant will be
+    /// returned with the error that occurred.
+    fn verify_totp_code(
+        &self,
+        secret: &String,
+        code: &String,
+        time: &OffsetDateTime,
+    ) -> Result<Option<i64>, Box<dyn Error>>;
 }
 
 /// A CryptoServiceImpl struct.
 #[derive(Component)]
 #[shaku(interface = CryptoService)]
 pub(crate) struct CryptoServiceImpl {
-    /// The key that will be used to encode tokens.
-    jwt_encoding_key: EncodingKey,
+    /// The id of the key that will be used to encode tokens.
+    jwt_active_key_id: String,
+
+    /// The keys that will be used to encode and decode tokens.
+    jwt_keys: Vec<JwtKey>,
 
-    /// The key that will be used to decode tokens.
-    jwt_decoding_key: DecodingKey,
+    /// The amount of time (in seconds) the tokens that are created are valid for.
+    access_token_lifetime: u64,
 }
 
 /// A CryptoService implementation for the CryptoServiceImpl struct.
 impl CryptoService for CryptoServiceImpl {
-    fn create_token(&self, user: &User) -> Result<String, jsonwebtoken::errors::Error> {
-        // Create the token's header.
+    fn create_token(
+        &self,
+        user: &User,
+        session_family_id: &String,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
+        // Get the active key, and create the token's header with its id.
+        let key: &JwtKey = self
+            .get_jwt_key(&self.jwt_active_key_id)
+            .ok_or(ErrorKind::InvalidKeyFormat)?;
+        let encoding_key: &EncodingKey = key
+            .encoding_key
+            .as_ref()
+            .ok_or(ErrorKind::InvalidKeyFormat)?;
         let header: Header = Header {
-            alg: JWT_ALGORITHM,
+            alg: key.algorithm,
+            kid: Some(key.id.clone()),
             ..Default::default()
         };
 
         // Create the token's claims.
+        let issued_at: i64 = OffsetDateTime::now_utc().unix_timestamp();
         let claims: UserClaims = UserClaims {
             id: user.id,
-            exp: i64::MAX,
-            iat: OffsetDateTime::now_utc().unix_timestamp(),
+            jti: session_family_id.clone(),
+            exp: issued_at.saturating_add(self.access_token_lifetime as i64),
+            iat: issued_at,
             password_last_reset: user.password_reset_at.unix_timestamp(),
         };
 
         // Encode the token and return the result.
-        return encode(&header, &claims, &self.jwt_encoding_key);
+        return encode(&header, &claims, encoding_key);
     }
 
     fn decode_token(
         &self,
         token: &String,
     ) -> Result<TokenData<UserClaims>, jsonwebtoken::errors::Error> {
-        // Create the validation rules.
-        let mut validation_rules: Validation = Validation::default();
-        validation_rules.validate_exp = false;
-        validation_rules.algorithms = vec![JWT_ALGORITHM];
+        // Get the key the token was created with.
+        let key: &JwtKey = decode_header(token)?
+            .kid
+            .and_then(|kid| self.get_jwt_key(&kid))
+            .ok_or(ErrorKind::InvalidToken)?;
+
+        // Create the validation rules, which only allow the key's algorithm.
+        let validation_rules: Validation = Validation::new(key.algorithm);
 
         // Decode the token and return the result.
-        return decode(token, &self.jwt_decoding_key, &validation_rules);
+        return decode(token, &key.decoding_key, &validation_rules);
+    }
+
+    fn get_public_key_set(&self) -> JwkSet {
+        return JwkSet {
+            keys: self
+                .jwt_keys
+                .iter()
+                .map(|key| key.public_key.clone())
+                .collect(),
+        };
     }
 
     fn hash_password(&self, password: &String) -> Result<String, argon2::password_hash::Error> {
@@ -164,6 +360,56 @@ impl CryptoService for CryptoServiceImpl {
         // Verify the password and return the result.
         return Argon2::default().verify_password(password.as_bytes(), &parsed_hash);
     }
+
+    fn generate_secret_token(&self) -> String {
+        return generate_random_string(SECRET_TOKEN_LENGTH);
+    }
+
+    fn hash_secret_token(&self, token: &String) -> String {
+        return sha256(token.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+    }
+
+    fn create_code_challenge(&self, code_verifier: &String) -> String {
+        return encode_base64_url(&sha256(code_verifier.as_bytes()));
+    }
+
+    fn generate_totp_secret(&self) -> String {
+        let mut secret: [u8; TOTP_SECRET_LENGTH] = [0; TOTP_SECRET_LENGTH];
+        rng().fill(&mut secret);
+        return encode_base32(&secret);
+    }
+
+    fn create_totp_code(
+        &self,
+        secret: &String,
+        time: &OffsetDateTime,
+    ) -> Result<String, Box<dyn Error>> {
+        let key: Vec<u8> = decode_base32(secret).ok_or("The totp secret is not valid base32")?;
+        return self.create_totp_code_for_step(&key, get_totp_step(time));
+    }
+
+    fn verify_totp_code(
+        &self,
+        secret: &String,
+        code: &String,
+        time: &OffsetDateTime,
+    ) -> Result<Option<i64>, Box<dyn Error>> {
+        // Check the code of each period within the allowed drift, comparing them in constant time.
+        for drift in -TOTP_ALLOWED_DRIFT..=TOTP_ALLOWED_DRIFT {
+            let step_time: OffsetDateTime = *time + Duration::seconds(drift * TOTP_PERIOD);
+            let expected_code: String = self.create_totp_code(secret, &step_time)?;
+            if expected_code.len() == code.len()
+                && memcmp::eq(expected_code.as_bytes(), code.as_bytes())
+            {
+                return Ok(Some(get_totp_step(&step_time)));
+            }
+        }
+
+        return Ok(None);
+    }
 }
 
 /// An implementation for the CryptoServiceImpl struct.
@@ -177,20 +423,332 @@ impl CryptoServiceImpl {
     ///
     /// # Arguments
     ///
-    /// `jwt_encoding_key` - The key that will be used to encode jwt tokens.
-    ///
-    /// `jwt_decoding_key` - The key that will be used to decode jwt tokens.
+    /// `jwt_config` - The jwt configuration that determines which keys tokens are created and
+    /// verified with, and how long the tokens that are created are valid for.
     ///
     /// # Returns
     ///
-    /// The crypto service parameters that were created.
+    /// This function returns a result:
+    /// - If the keys were loaded, the Ok variant will be returned with the crypto service parameters
+    /// that were created.
+    /// - If a key could not be loaded, two keys have the same id, or the active key does not exist or
+    /// has no private key, the Err variant will be returned with the error that occurred.
     pub(crate) fn create_parameters(
-        jwt_encoding_key: EncodingKey,
-        jwt_decoding_key: DecodingKey,
-    ) -> CryptoServiceImplParameters {
-        return CryptoServiceImplParameters {
-            jwt_encoding_key,
-            jwt_decoding_key,
+        jwt_config: &JwtConfig,
+    ) -> Result<CryptoServiceImplParameters, Box<dyn Error>> {
+        // Load the keys, and make sure their ids are unique.
+        let mut jwt_keys: Vec<JwtKey> = Vec::new();
+        for key_config in &jwt_config.keys {
+            if jwt_keys.iter().any(|key| key.id == key_config.id) {
+                return Err(
+                    format!("The jwt key id {} is used more than once", key_config.id).into(),
+                );
+            }
+            jwt_keys.push(load_jwt_key(key_config)?);
+        }
+
+        // Make sure the active key can be used to create tokens.
+        match jwt_keys
+            .iter()
+            .find(|key| key.id == jwt_config.active_key_id)
+        {
+            Some(key) if key.encoding_key.is_some() => {}
+            Some(_) => return Err("The active jwt key does not have a private key".into()),
+            None => return Err("The active jwt key does not exist".into()),
         };
+
+        return Ok(CryptoServiceImplParameters {
+            jwt_active_key_id: jwt_config.active_key_id.clone(),
+            jwt_keys,
+            access_token_lifetime: jwt_config.access_token_lifetime,
+        });
     }
+
+    /// # Description
+    ///
+    /// Get one of the keys tokens are created and verified with.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the key.
+    ///
+    /// # Returns
+    ///
+    /// The key, if it exists.
+    fn get_jwt_key(&self, id: &String) -> Option<&JwtKey> {
+        return self.jwt_keys.iter().find(|key| key.id == *id);
+    }
+
+    /// # Description
+    ///
+    /// Create the time-based one-time password for a key at a specific time step (RFC 4226 and RFC
+    /// 6238), using hmac-sha1 since it is the only algorithm every authenticator app supports.
+    ///
+    /// # Arguments
+    ///
+    /// `key` - The decoded secret.
+    ///
+    /// `step` - The time step the code is being created for.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the code was created, the Ok variant will be returned with the code.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    fn create_totp_code_for_step(&self, key: &[u8], step: i64) -> Result<String, Box<dyn Error>> {
+        // Sign the time step.
+        let key = PKey::hmac(key)?;
+        let mut signer: Signer = Signer::new(MessageDigest::sha1(), &key)?;
+        signer.update(&step.to_be_bytes())?;
+        let hmac: Vec<u8> = signer.sign_to_vec()?;
+
+        // Dynamically truncate the signature into a code.
+        let offset: usize = (hmac[hmac.len() - 1] & 0x0f) as usize;
+        let binary: u32 = u32::from_be_bytes([
+            hmac[offset] & 0x7f,
+            hmac[offset + 1],
+            hmac[offset + 2],
+            hmac[offset + 3],
+        ]);
+        return Ok(format!(
+            "{:0width$}",
+            binary % 10_u32.pow(TOTP_DIGITS),
+            width = TOTP_DIGITS as usize
+        ));
+    }
+}
+
+/// # Description
+///
+/// Get the totp time step a time falls within.
+///
+/// # Arguments
+///
+/// `time` - The time.
+///
+/// # Returns
+///
+/// The amount of totp periods that have passed since the unix epoch.
+fn get_totp_step(time: &OffsetDateTime) -> i64 {
+    return time.unix_timestamp().div_euclid(TOTP_PERIOD);
+}
+
+/// # Description
+///
+/// Encode bytes as base32 (RFC 4648) without padding.
+///
+/// # Arguments
+///
+/// `bytes` - The bytes to encode.
+///
+/// # Returns
+///
+/// The base32 encoded bytes.
+fn encode_base32(bytes: &[u8]) -> String {
+    let mut encoded: String = String::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    return encoded;
+}
+
+/// # Description
+///
+/// Decode base32 (RFC 4648). Padding, spaces and lowercase letters are accepted, since secrets
+/// are often copied by hand.
+///
+/// # Arguments
+///
+/// `encoded` - The base32 encoded bytes.
+///
+/// # Returns
+///
+/// The decoded bytes, or None if the value is not valid base32.
+fn decode_base32(encoded: &String) -> Option<Vec<u8>> {
+    let mut decoded: Vec<u8> = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut bits: u32 = 0;
+    for character in encoded.chars() {
+        if character == '=' || character == ' ' {
+            continue;
+        }
+        let value: u32 = BASE32_ALPHABET
+            .iter()
+            .position(|letter| *letter as char == character.to_ascii_uppercase())?
+            as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push(((buffer >> bits) & 0xff) as u8);
+        }
+    }
+    return Some(decoded);
+}
+
+/// # Description
+///
+/// Encode bytes as unpadded base64url (RFC 4648).
+///
+/// # Arguments
+///
+/// `bytes` - The bytes to encode.
+///
+/// # Returns
+///
+/// The encoded bytes.
+fn encode_base64_url(bytes: &[u8]) -> String {
+    return base64::encode_block(bytes)
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_");
+}
+
+/// # Description
+///
+/// Load a key that is used to create or verify json web tokens from the files configured.
+///
+/// # Arguments
+///
+/// `key_config` - The configuration of the key.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the key was loaded, the Ok variant will be returned with the key.
+/// - If a file could not be read, or the key is not valid for its algorithm, the Err variant will
+/// be returned with the error that occurred.
+fn load_jwt_key(key_config: &JwtKeyConfig) -> Result<JwtKey, Box<dyn Error>> {
+    let private_key: Option<Vec<u8>> = match &key_config.private_key_path {
+        Some(private_key_path) => Some(fs::read(private_key_path)?),
+        None => None,
+    };
+    let public_key: Vec<u8> = fs::read(&key_config.public_key_path)?;
+
+    return create_jwt_key(
+        &key_config.id,
+        &key_config.algorithm,
+        private_key.as_deref(),
+        &public_key,
+    );
+}
+
+/// # Description
+///
+/// Create a key that is used to create or verify json web tokens from pem encoded keys.
+///
+/// # Arguments
+///
+/// `id` - The key's unique identifier.
+///
+/// `algorithm` - The algorithm the key is used with.
+///
+/// `private_key` - The pem encoded private key, if the key will be used to create tokens.
+///
+/// `public_key` - The pem encoded public key.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the keys are valid for the algorithm, the Ok variant will be returned with the key.
+/// - If the keys could not be parsed, or are not valid for the algorithm, the Err variant will be
+/// returned with the error that occurred.
+fn create_jwt_key(
+    id: &String,
+    algorithm: &JwtAlgorithm,
+    private_key: Option<&[u8]>,
+    public_key: &[u8],
+) -> Result<JwtKey, Box<dyn Error>> {
+    // Parse the public key, and get its parameters in the json web key format.
+    let parsed_public_key: PKey<Public> = PKey::public_key_from_pem(public_key)?;
+    let (jwt_algorithm, key_algorithm, algorithm_parameters) = match algorithm {
+        JwtAlgorithm::Rs256 => {
+            let rsa_key = parsed_public_key.rsa()?;
+            (
+                Algorithm::RS256,
+                KeyAlgorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: encode_base64_url(&rsa_key.n().to_vec()),
+                    e: encode_base64_url(&rsa_key.e().to_vec()),
+                }),
+            )
+        }
+        JwtAlgorithm::Es256 => {
+            let ec_key = parsed_public_key.ec_key()?;
+            if ec_key.group().curve_name() != Some(Nid::X9_62_PRIME256V1) {
+                return Err(format!("The jwt key {} is not on the P-256 curve", id).into());
+            }
+            let mut x: BigNum = BigNum::new()?;
+            let mut y: BigNum = BigNum::new()?;
+            let mut context: BigNumContext = BigNumContext::new()?;
+            ec_key.public_key().affine_coordinates_gfp(
+                ec_key.group(),
+                &mut x,
+                &mut y,
+                &mut context,
+            )?;
+            (
+                Algorithm::ES256,
+                KeyAlgorithm::ES256,
+                AlgorithmParameters::EllipticCurve(EllipticCurveKeyParameters {
+                    key_type: EllipticCurveKeyType::EC,
+                    curve: EllipticCurve::P256,
+                    x: encode_base64_url(&x.to_vec_padded(P256_COORDINATE_LENGTH)?),
+                    y: encode_base64_url(&y.to_vec_padded(P256_COORDINATE_LENGTH)?),
+                }),
+            )
+        }
+        JwtAlgorithm::EdDsa => {
+            if parsed_public_key.id() != Id::ED25519 {
+                return Err(format!("The jwt key {} is not an Ed25519 key", id).into());
+            }
+            (
+                Algorithm::EdDSA,
+                KeyAlgorithm::EdDSA,
+                AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: encode_base64_url(&parsed_public_key.raw_public_key()?),
+                }),
+            )
+        }
+    };
+    let jwk: Jwk = Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(id.clone()),
+            ..Default::default()
+        },
+        algorithm: algorithm_parameters,
+    };
+
+    // Parse the private key, if the key will be used to create tokens.
+    let encoding_key: Option<EncodingKey> = match private_key {
+        Some(private_key) => Some(match algorithm {
+            JwtAlgorithm::Rs256 => EncodingKey::from_rsa_pem(private_key)?,
+            JwtAlgorithm::Es256 => EncodingKey::from_ec_pem(private_key)?,
+            JwtAlgorithm::EdDsa => EncodingKey::from_ed_pem(private_key)?,
+        }),
+        None => None,
+    };
+
+    return Ok(JwtKey {
+        id: id.clone(),
+        algorithm: jwt_algorithm,
+        encoding_key,
+        decoding_key: DecodingKey::from_jwk(&jwk)?,
+        public_key: jwk,
+    });
 }
@@ -1,16 +1,28 @@
 use crate::{
     common::utility::generate_random_string,
-    config::Config,
+    config::{Config, JwtAlgorithm, JwtKeyConfig},
     feature::{
-        crypto::{model::UserClaims, service::CryptoService},
+        crypto::{
+            model::{JwtKey, UserClaims},
+            service::{create_jwt_key, CryptoService, CryptoServiceImpl},
+        },
         user::model::User,
     },
     injector::DependencyInjector,
 };
-use jsonwebtoken::TokenData;
+use jsonwebtoken::{
+    decode, decode_header, encode, jwk::JwkSet, Algorithm, DecodingKey, EncodingKey, Header,
+    TokenData, Validation,
+};
+use openssl::{
+    ec::{EcGroup, EcKey},
+    nid::Nid,
+    pkey::{PKey, Private},
+    rsa::Rsa,
+};
 use regex::Regex;
 use shaku::HasComponent;
-use std::sync::Arc;
+use std::{fs, sync::Arc};
 use time::OffsetDateTime;
 
 /// # Description
@@ -61,6 +73,7 @@ fn create_test_user() -> User {
         email_is_verified: false,
         password_reset_is_required: false,
         account_is_locked: false,
+        account_unlocks_at: None,
         account_is_banned: false,
     };
 }
@@ -117,7 +130,7 @@ async fn creating_a_token_returns_a_valid_jwt() {
 
     // Generate the token.
     let token = crypto_service
-        .create_token(&user)
+        .create_token(&user, &generate_random_string(48))
         .expect("Failed to create token");
 
     assert!(is_valid_jwt(&token));
@@ -162,8 +175,9 @@ async fn decoding_a_valid_token_contains_the_correct_information() {
     let user: User = create_test_user();
 
     // Generate the token.
+    let session_family_id: String = generate_random_string(48);
     let token = crypto_service
-        .create_token(&user)
+        .create_token(&user, &session_family_id)
         .expect("Failed to create token");
 
     assert!(is_valid_jwt(&token));
@@ -176,12 +190,95 @@ async fn decoding_a_valid_token_contains_the_correct_information() {
 
     // Make sure the returned token contains correct data.
     assert_eq!(user.id, token_data.claims.id);
+    assert_eq!(session_family_id, token_data.claims.jti);
     assert_eq!(
         user.password_reset_at.unix_timestamp(),
         token_data.claims.password_last_reset
     );
 }
 
+/// # Description
+///
+/// Test creating a token, and make sure it expires once the access token lifetime has passed.
+#[actix_web::test]
+async fn created_tokens_expire_after_the_access_token_lifetime() {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Generate and decode a token.
+    let token = crypto_service
+        .create_token(&create_test_user(), &generate_random_string(48))
+        .expect("Failed to create token");
+    let token_data: TokenData<UserClaims> = crypto_service
+        .decode_token(&token)
+        .expect("Failed to decode token");
+
+    // Make sure the token expires after the access token lifetime.
+    assert_eq!(
+        token_data.claims.iat + config.jwt.access_token_lifetime as i64,
+        token_data.claims.exp
+    );
+}
+
+/// # Description
+///
+/// Make sure an error is returned when you attempt to decode an expired token.
+#[actix_web::test]
+async fn decoding_an_expired_token_returns_an_error() {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Create a token that expired an hour ago.
+    let issued_at: i64 =
+        OffsetDateTime::now_utc().unix_timestamp() - config.jwt.access_token_lifetime as i64 - 3600;
+    let claims: UserClaims = UserClaims {
+        id: 0,
+        jti: generate_random_string(48),
+        exp: issued_at + config.jwt.access_token_lifetime as i64,
+        iat: issued_at,
+        password_last_reset: issued_at,
+    };
+    let key_config: &JwtKeyConfig = config
+        .jwt
+        .keys
+        .iter()
+        .find(|key_config| key_config.id == config.jwt.active_key_id)
+        .expect("The active key is not configured");
+    let encoding_key: EncodingKey = EncodingKey::from_rsa_pem(
+        &fs::read(
+            key_config
+                .private_key_path
+                .as_ref()
+                .expect("The active key does not have a private key"),
+        )
+        .expect("Failed to read private key"),
+    )
+    .expect("Failed to parse private key");
+    let header: Header = Header {
+        alg: Algorithm::RS256,
+        kid: Some(key_config.id.clone()),
+        ..Default::default()
+    };
+    let token: String = encode(&header, &claims, &encoding_key).expect("Failed to create token");
+
+    // Attempt to decode the expired token.
+    crypto_service
+        .decode_token(&token)
+        .expect_err("Decoded an expired token when it should have failed");
+}
+
 /// # Description
 ///
 /// Test hashing a password, and make sure the hash does not match the original password.
@@ -263,3 +360,276 @@ async fn verifying_a_correct_password_succeeds() {
         .verify_password(&password, &hash)
         .expect("Password failed when it should have been correct");
 }
+
+/// # Description
+///
+/// Test hashing secret tokens, and make sure the same token always has the same hash.
+#[actix_web::test]
+async fn hashing_a_secret_token_is_deterministic() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Generate two secret tokens.
+    let token: String = crypto_service.generate_secret_token();
+    let other_token: String = crypto_service.generate_secret_token();
+    assert_ne!(token, other_token);
+
+    // Make sure the hash is a hex encoded sha-256 digest that only depends on the token.
+    let hash: String = crypto_service.hash_secret_token(&token);
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, crypto_service.hash_secret_token(&token));
+    assert_ne!(hash, crypto_service.hash_secret_token(&other_token));
+}
+
+/// # Description
+///
+/// Test creating time-based one-time passwords, and make sure they match the test vectors from
+/// RFC 6238.
+#[actix_web::test]
+async fn totp_codes_match_the_rfc_test_vectors() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // The base32 encoding of the ascii secret "12345678901234567890" used by the RFC.
+    let secret: String = String::from("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+
+    // Make sure the codes match the last six digits of the RFC's sha-1 test vectors.
+    for (timestamp, expected_code) in [
+        (59, "287082"),
+        (1111111109, "081804"),
+        (1111111111, "050471"),
+        (1234567890, "005924"),
+        (2000000000, "279037"),
+    ] {
+        let time: OffsetDateTime =
+            OffsetDateTime::from_unix_timestamp(timestamp).expect("Invalid timestamp");
+        let code: String = crypto_service
+            .create_totp_code(&secret, &time)
+            .expect("Failed to create totp code");
+        assert_eq!(expected_code, code);
+    }
+}
+
+/// # Description
+///
+/// Test verifying time-based one-time passwords, and make sure codes are only accepted within the
+/// allowed clock drift.
+#[actix_web::test]
+async fn totp_codes_are_only_accepted_within_the_allowed_drift() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Generate a secret, and create a code for the current time.
+    let secret: String = crypto_service.generate_totp_secret();
+    assert_eq!(32, secret.len());
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let code: String = crypto_service
+        .create_totp_code(&secret, &now)
+        .expect("Failed to create totp code");
+    assert_eq!(6, code.len());
+
+    // Make sure the code is accepted now and one period later, but not two periods later.
+    let step: Option<i64> = crypto_service
+        .verify_totp_code(&secret, &code, &now)
+        .expect("Failed to verify totp code");
+    assert_eq!(Some(now.unix_timestamp().div_euclid(30)), step);
+    assert_eq!(
+        step,
+        crypto_service
+            .verify_totp_code(&secret, &code, &(now + time::Duration::seconds(30)))
+            .expect("Failed to verify totp code")
+    );
+    assert!(crypto_service
+        .verify_totp_code(&secret, &code, &(now + time::Duration::seconds(90)))
+        .expect("Failed to verify totp code")
+        .is_none());
+
+    // Make sure a lowercase copy of the secret is accepted, and an invalid secret is rejected.
+    assert_eq!(
+        step,
+        crypto_service
+            .verify_totp_code(&secret.to_lowercase(), &code, &now)
+            .expect("Failed to verify totp code")
+    );
+    crypto_service
+        .verify_totp_code(&String::from("Not base32!"), &code, &now)
+        .expect_err("An invalid secret was accepted");
+}
+
+/// # Description
+///
+/// Test creating a code challenge, and make sure it matches the test vector from RFC 7636.
+#[actix_web::test]
+async fn code_challenges_match_the_rfc_test_vector() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a crypto service instance.
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Make sure the code challenge matches the test vector.
+    assert_eq!(
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM",
+        crypto_service
+            .create_code_challenge(&String::from("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"))
+    );
+}
+
+/// # Description
+///
+/// Generate a key pair for the given algorithm, and create a json web token key from it.
+///
+/// # Arguments
+///
+/// * `id` - The key's unique identifier.
+/// * `algorithm` - The algorithm the key will be used with.
+///
+/// # Returns
+///
+/// The json web token key.
+fn generate_jwt_key(id: &str, algorithm: JwtAlgorithm) -> JwtKey {
+    let private_key: PKey<Private> = match algorithm {
+        JwtAlgorithm::Rs256 => {
+            PKey::from_rsa(Rsa::generate(2048).expect("Failed to generate rsa key"))
+        }
+        JwtAlgorithm::Es256 => PKey::from_ec_key(
+            EcKey::generate(
+                &EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).expect("Failed to get curve"),
+            )
+            .expect("Failed to generate ec key"),
+        ),
+        JwtAlgorithm::EdDsa => PKey::generate_ed25519(),
+    }
+    .expect("Failed to generate private key");
+    return create_jwt_key(
+        &String::from(id),
+        &algorithm,
+        Some(
+            &private_key
+                .private_key_to_pem_pkcs8()
+                .expect("Failed to encode private key"),
+        ),
+        &private_key
+            .public_key_to_pem()
+            .expect("Failed to encode public key"),
+    )
+    .expect("Failed to create json web token key");
+}
+
+/// # Description
+///
+/// Make sure tokens created with a key that has since been rotated out can still be decoded, as
+/// long as the key is kept as a verification key, and that tokens with an unknown key id are
+/// rejected.
+#[actix_web::test]
+async fn tokens_created_with_a_retired_key_can_still_be_decoded() {
+    // Create a test user.
+    let user: User = create_test_user();
+
+    // Create a token with the old key.
+    let old_key: JwtKey = generate_jwt_key("old", JwtAlgorithm::Rs256);
+    let old_crypto_service: CryptoServiceImpl = CryptoServiceImpl {
+        jwt_active_key_id: String::from("old"),
+        jwt_keys: vec![old_key.clone()],
+        access_token_lifetime: 300,
+    };
+    let old_token: String = old_crypto_service
+        .create_token(&user, &generate_random_string(48))
+        .expect("Failed to create token");
+
+    // Rotate to a new key, and keep the old one for verification only.
+    let new_crypto_service: CryptoServiceImpl = CryptoServiceImpl {
+        jwt_active_key_id: String::from("new"),
+        jwt_keys: vec![
+            generate_jwt_key("new", JwtAlgorithm::Es256),
+            JwtKey {
+                encoding_key: None,
+                ..old_key
+            },
+        ],
+        access_token_lifetime: 300,
+    };
+    let new_token: String = new_crypto_service
+        .create_token(&user, &generate_random_string(48))
+        .expect("Failed to create token");
+
+    // Make sure the tokens are signed with the expected keys, and both can be decoded.
+    assert_eq!(
+        Some(String::from("old")),
+        decode_header(&old_token)
+            .expect("Failed to decode header")
+            .kid
+    );
+    assert_eq!(
+        Some(String::from("new")),
+        decode_header(&new_token)
+            .expect("Failed to decode header")
+            .kid
+    );
+    for token in [&old_token, &new_token] {
+        let token_data: TokenData<UserClaims> = new_crypto_service
+            .decode_token(token)
+            .expect("Failed to decode token");
+        assert_eq!(user.id, token_data.claims.id);
+    }
+
+    // Make sure a token created with the new key is rejected once the key is no longer known.
+    old_crypto_service
+        .decode_token(&new_token)
+        .expect_err("A token with an unknown key id was accepted");
+}
+
+/// # Description
+///
+/// Make sure the public key set contains every key, and that it can be used to verify tokens.
+#[actix_web::test]
+async fn the_public_key_set_verifies_tokens() {
+    // Create a test user.
+    let user: User = create_test_user();
+
+    for algorithm in [
+        JwtAlgorithm::Rs256,
+        JwtAlgorithm::Es256,
+        JwtAlgorithm::EdDsa,
+    ] {
+        // Create a crypto service with an active key, and a retired key.
+        let crypto_service: CryptoServiceImpl = CryptoServiceImpl {
+            jwt_active_key_id: String::from("active"),
+            jwt_keys: vec![
+                generate_jwt_key("active", algorithm),
+                generate_jwt_key("retired", JwtAlgorithm::Rs256),
+            ],
+            access_token_lifetime: 300,
+        };
+        let token: String = crypto_service
+            .create_token(&user, &generate_random_string(48))
+            .expect("Failed to create token");
+
+        // Make sure both keys are published.
+        let public_key_set: JwkSet = crypto_service.get_public_key_set();
+        assert_eq!(2, public_key_set.keys.len());
+        assert!(public_key_set.find("retired").is_some());
+
+        // Verify the token using only the published key.
+        let header: Header = decode_header(&token).expect("Failed to decode header");
+        let decoding_key: DecodingKey = DecodingKey::from_jwk(
+            public_key_set
+                .find(&header.kid.expect("The token does not have a key id"))
+                .expect("The key was not published"),
+        )
+        .expect("Failed to parse published key");
+        let token_data: TokenData<UserClaims> =
+            decode(&token, &decoding_key, &Validation::new(header.alg))
+                .expect("Failed to verify token");
+        assert_eq!(user.id, token_data.claims.id);
+    }
+}
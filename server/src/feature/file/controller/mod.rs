@@ -2,11 +2,15 @@ mod data;
 
 use self::data::{CreateFileRequestBody, GetFileRequestParams, UpdateFileRequestBody};
 use crate::{
-    common::enumeration::{
-        AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::create_authentication_failure_response,
     },
     feature::auth::service::AuthService,
     feature::{
+        api_token::model::ApiTokenScope,
         file::{model::File, service::FileService},
         user::model::User,
     },
@@ -61,10 +65,12 @@ async fn create_file(
     file_service: Inject<DependencyInjector, dyn FileService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    let user: User = match auth_service.authenticate_request(&request).await {
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::FilesWrite))
+        .await
+    {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Convert the request body into a file.
@@ -107,10 +113,12 @@ async fn get_file(
     file_service: Inject<DependencyInjector, dyn FileService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    match auth_service.authenticate_request(&request).await {
+    match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::FilesRead))
+        .await
+    {
         AuthenticationResult::Ok(_) => {}
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Get the file the user is requesting.
@@ -158,10 +166,12 @@ async fn update_file(
     file_service: Inject<DependencyInjector, dyn FileService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    let user: User = match auth_service.authenticate_request(&request).await {
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::FilesWrite))
+        .await
+    {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Get the file that is being updated.
@@ -213,10 +223,12 @@ async fn delete_file(
     file_service: Inject<DependencyInjector, dyn FileService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    let user: User = match auth_service.authenticate_request(&request).await {
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::FilesWrite))
+        .await
+    {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Get the file that is being deleted.
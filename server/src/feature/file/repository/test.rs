@@ -44,6 +44,7 @@ fn create_test_user() -> User {
         email_is_verified: false,
         password_reset_is_required: false,
         account_is_locked: false,
+        account_unlocks_at: None,
         account_is_banned: false,
     };
 }
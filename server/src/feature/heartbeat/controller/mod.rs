@@ -0,0 +1,166 @@
+use crate::{
+    common::enumeration::{InsertionResult, QueryResult},
+    feature::{
+        heartbeat::{
+            model::{Heartbeat, HeartbeatKind},
+            service::HeartbeatService,
+        },
+        monitor::{model::Monitor, service::MonitorService},
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    route,
+    web::{self, ServiceConfig},
+    HttpResponse,
+};
+use shaku_actix::Inject;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Add the heartbeat controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the heartbeat controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/heartbeat")
+            .service(send_heartbeat)
+            .service(send_heartbeat_signal),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint for a job to report that it has completed successfully.
+///
+/// This endpoint does not require authentication, the monitor's heartbeat token identifies it.
+///
+/// # Arguments
+///
+/// `token` - The heartbeat token of the monitor the heartbeat is being sent to.
+///
+/// `monitor_service` - The monitor service that will be used to find the monitor.
+///
+/// `heartbeat_service` - The heartbeat service that will be used to record the heartbeat.
+///
+/// # Returns
+///
+/// An http response.
+#[route("/{token}", method = "GET", method = "POST")]
+async fn send_heartbeat(
+    token: web::Path<String>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    heartbeat_service: Inject<DependencyInjector, dyn HeartbeatService>,
+) -> HttpResponse {
+    return record_heartbeat(
+        &token.into_inner(),
+        HeartbeatKind::Success,
+        None,
+        &monitor_service,
+        &heartbeat_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// An api endpoint for a job to report that it has started, failed, or exited with an exit code.
+///
+/// The signal must be `start`, `fail`, or an exit code, where an exit code of zero reports that the
+/// job completed successfully and any other exit code reports that it failed.
+///
+/// This endpoint does not require authentication, the monitor's heartbeat token identifies it.
+///
+/// # Arguments
+///
+/// `path` - The heartbeat token of the monitor the heartbeat is being sent to, and the signal the
+/// job is sending.
+///
+/// `monitor_service` - The monitor service that will be used to find the monitor.
+///
+/// `heartbeat_service` - The heartbeat service that will be used to record the heartbeat.
+///
+/// # Returns
+///
+/// An http response.
+#[route("/{token}/{signal}", method = "GET", method = "POST")]
+async fn send_heartbeat_signal(
+    path: web::Path<(String, String)>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    heartbeat_service: Inject<DependencyInjector, dyn HeartbeatService>,
+) -> HttpResponse {
+    let (token, signal) = path.into_inner();
+
+    // Determine the kind of heartbeat the signal reports.
+    let (kind, exit_code) = match signal.as_str() {
+        "start" => (HeartbeatKind::Start, None),
+        "fail" => (HeartbeatKind::Fail, None),
+        _ => match signal.parse::<i32>() {
+            Ok(0) => (HeartbeatKind::Success, Some(0)),
+            Ok(exit_code) => (HeartbeatKind::Fail, Some(exit_code)),
+            Err(_) => return HttpResponse::NotFound().finish(),
+        },
+    };
+
+    return record_heartbeat(
+        &token,
+        kind,
+        exit_code,
+        &monitor_service,
+        &heartbeat_service,
+    )
+    .await;
+}
+
+/// # Description
+///
+/// Record a heartbeat for the monitor a heartbeat token belongs to.
+///
+/// # Arguments
+///
+/// `token` - The heartbeat token of the monitor the heartbeat is being sent to.
+///
+/// `kind` - The kind of heartbeat that is being recorded.
+///
+/// `exit_code` - The exit code the job reported, if it reported one.
+///
+/// `monitor_service` - The monitor service that will be used to find the monitor.
+///
+/// `heartbeat_service` - The heartbeat service that will be used to record the heartbeat.
+///
+/// # Returns
+///
+/// An http response.
+async fn record_heartbeat(
+    token: &String,
+    kind: HeartbeatKind,
+    exit_code: Option<i32>,
+    monitor_service: &Inject<DependencyInjector, dyn MonitorService>,
+    heartbeat_service: &Inject<DependencyInjector, dyn HeartbeatService>,
+) -> HttpResponse {
+    // Get the monitor the token belongs to.
+    let monitor: Monitor = match monitor_service.get_by_heartbeat_token(token).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Create the heartbeat.
+    let heartbeat: Heartbeat = Heartbeat {
+        id: 0,
+        monitor_id: monitor.id,
+        received_at: OffsetDateTime::now_utc(),
+        kind,
+        exit_code,
+    };
+
+    // Record the heartbeat.
+    return match heartbeat_service.insert(&heartbeat).await {
+        InsertionResult::Ok(_) => HttpResponse::Ok().finish(),
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use validator::Validate;
+
+/// A heartbeat struct, which records a ping that was sent by a job to its heartbeat monitor.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Heartbeat {
+    /// The heartbeat's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the monitor that received the heartbeat.
+    pub(crate) monitor_id: u64,
+
+    /// The date and time the heartbeat was received at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) received_at: OffsetDateTime,
+
+    /// The kind of heartbeat that was received.
+    pub(crate) kind: HeartbeatKind,
+
+    /// The exit code the job reported, if it reported one.
+    pub(crate) exit_code: Option<i32>,
+}
+
+/// A Default implementation for the Heartbeat struct.
+impl Default for Heartbeat {
+    fn default() -> Self {
+        return Heartbeat {
+            id: 0,
+            monitor_id: 0,
+            received_at: OffsetDateTime::now_utc(),
+            kind: HeartbeatKind::Success,
+            exit_code: None,
+        };
+    }
+}
+
+/// A PartialEq implementation for the Heartbeat struct.
+impl PartialEq for Heartbeat {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.monitor_id == other.monitor_id
+            && self.received_at.date() == other.received_at.date()
+            && self.received_at.hour() == other.received_at.hour()
+            && self.received_at.minute() == other.received_at.minute()
+            && self.received_at.second() == other.received_at.second()
+            && self.kind == other.kind
+            && self.exit_code == other.exit_code;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the kinds of heartbeats a job can send.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum HeartbeatKind {
+    /// # Description
+    ///
+    /// The Start variant is used when a job reports that it has started running.
+    Start,
+
+    /// # Description
+    ///
+    /// The Success variant is used when a job reports that it has completed successfully.
+    Success,
+
+    /// # Description
+    ///
+    /// The Fail variant is used when a job reports that it has failed.
+    Fail,
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::heartbeat::model::{Heartbeat, HeartbeatKind},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// A heartbeat repository trait.
+#[async_trait]
+pub(crate) trait HeartbeatRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a heartbeat into the heartbeat repository.
+    ///
+    /// # Arguments
+    ///
+    /// `heartbeat` - The heartbeat to insert into the heartbeat repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// heartbeat that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, heartbeat: &Heartbeat, context: &mut QueryContext)
+        -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a heartbeat from the heartbeat repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the heartbeat to get from the heartbeat repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional heartbeat.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Heartbeat>, Error>;
+
+    /// # Description
+    ///
+    /// Get the most recent heartbeat that was received by a monitor from the heartbeat repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that received the heartbeat.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional heartbeat.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_latest_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<Heartbeat>, Error>;
+}
+
+/// A HeartbeatRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = HeartbeatRepository)]
+pub(crate) struct HeartbeatRepositoryImpl;
+
+/// A HeartbeatRepository implementation for the HeartbeatRepositoryImpl struct.
+#[async_trait]
+impl HeartbeatRepository for HeartbeatRepositoryImpl {
+    async fn insert(
+        &self,
+        heartbeat: &Heartbeat,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `heartbeats` (
+                    `id`,
+                    `monitor_id`,
+                    `received_at`,
+                    `kind`,
+                    `exit_code`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            heartbeat.id,
+            heartbeat.monitor_id,
+            heartbeat.received_at,
+            heartbeat.kind,
+            heartbeat.exit_code
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Heartbeat>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Heartbeat,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `received_at` AS `received_at: OffsetDateTime`,
+                    `kind` AS `kind: HeartbeatKind`,
+                    `exit_code` AS `exit_code: i32`
+                FROM
+                    `heartbeats`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_latest_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<Heartbeat>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Heartbeat,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `received_at` AS `received_at: OffsetDateTime`,
+                    `kind` AS `kind: HeartbeatKind`,
+                    `exit_code` AS `exit_code: i32`
+                FROM
+                    `heartbeats`
+                WHERE
+                    `monitor_id` = ?
+                ORDER BY
+                    `received_at` DESC,
+                    `id` DESC
+                LIMIT 1;
+            "#,
+            monitor_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+}
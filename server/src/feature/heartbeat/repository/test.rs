@@ -0,0 +1,471 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        heartbeat::{
+            model::{Heartbeat, HeartbeatKind},
+            repository::HeartbeatRepository,
+        },
+        monitor::{
+            model::{HeartbeatMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a heartbeat monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 3600,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Heartbeat(HeartbeatMonitorConfiguration {
+            token: generate_random_string(64),
+            grace_period: 300,
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a heartbeat that can be used for testing.
+///
+/// # Returns
+///
+/// The heartbeat that was created.
+fn create_test_heartbeat() -> Heartbeat {
+    return Heartbeat {
+        id: 0,
+        monitor_id: 0,
+        received_at: OffsetDateTime::now_utc(),
+        kind: HeartbeatKind::Fail,
+        exit_code: Some(2),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a heartbeat into the repository, and validate the id that was returned.
+#[actix_web::test]
+async fn heartbeat_id_is_returned_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a heartbeat repository instance.
+    let heartbeat_repository: Arc<dyn HeartbeatRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Create a test heartbeat.
+    let mut heartbeat: Heartbeat = create_test_heartbeat();
+    heartbeat.monitor_id = monitor.id;
+
+    // Insert the heartbeat.
+    let id = heartbeat_repository
+        .insert(&heartbeat, &mut context)
+        .await
+        .expect("Failed to insert heartbeat");
+
+    // Make sure the heartbeat id was returned.
+    assert!(id > 0);
+
+    // Delete the test user (their monitor and heartbeats will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying a heartbeat by id after it has been inserted, and make sure the correct data is
+/// returned.
+#[actix_web::test]
+async fn heartbeat_is_queryable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a heartbeat repository instance.
+    let heartbeat_repository: Arc<dyn HeartbeatRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Create a test heartbeat.
+    let mut heartbeat: Heartbeat = create_test_heartbeat();
+    heartbeat.monitor_id = monitor.id;
+
+    // Insert the heartbeat.
+    let id = heartbeat_repository
+        .insert(&heartbeat, &mut context)
+        .await
+        .expect("Failed to insert heartbeat");
+
+    heartbeat.id = id;
+
+    // Query the heartbeat.
+    let queried_heartbeat: Heartbeat = heartbeat_repository
+        .get(&id, &mut context)
+        .await
+        .expect("Failed to query heartbeat after insertion: An error occurred")
+        .expect("Failed to query heartbeat after insertion: Heartbeat not found");
+
+    // Make sure the heartbeat contains the correct data.
+    assert_eq!(heartbeat, queried_heartbeat);
+
+    // Delete the test user (their monitor and heartbeats will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Make sure the heartbeat was deleted with the monitor.
+    let deleted_query_result: Option<Heartbeat> = heartbeat_repository
+        .get(&id, &mut context)
+        .await
+        .expect("Failed to query heartbeat after deletion: An error occurred");
+    assert!(deleted_query_result.is_none());
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying the most recent heartbeat a monitor received, and make sure the heartbeats of
+/// other monitors are not returned.
+#[actix_web::test]
+async fn latest_heartbeat_is_queryable_by_monitor_id() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a heartbeat repository instance.
+    let heartbeat_repository: Arc<dyn HeartbeatRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let other_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Make sure no heartbeat is returned before any have been received.
+    let queried_heartbeat: Option<Heartbeat> = heartbeat_repository
+        .get_latest_by_monitor_id(&monitor.id, &mut context)
+        .await
+        .expect("Failed to query latest heartbeat by monitor id");
+    assert!(queried_heartbeat.is_none());
+
+    // Insert two heartbeats for the first monitor, and one for the second monitor.
+    let mut heartbeats: Vec<Heartbeat> = Vec::new();
+    for (index, monitor_id) in [monitor.id, monitor.id, other_monitor.id]
+        .into_iter()
+        .enumerate()
+    {
+        let mut heartbeat: Heartbeat = create_test_heartbeat();
+        heartbeat.monitor_id = monitor_id;
+        heartbeat.received_at = OffsetDateTime::now_utc() - Duration::minutes(10 - index as i64);
+        heartbeat.id = heartbeat_repository
+            .insert(&heartbeat, &mut context)
+            .await
+            .expect("Failed to insert heartbeat");
+        heartbeats.push(heartbeat);
+    }
+
+    // Query the most recent heartbeat of the first monitor.
+    let queried_heartbeat: Option<Heartbeat> = heartbeat_repository
+        .get_latest_by_monitor_id(&monitor.id, &mut context)
+        .await
+        .expect("Failed to query latest heartbeat by monitor id");
+
+    // Make sure only the first monitor's most recent heartbeat was returned.
+    assert_eq!(Some(heartbeats[1].clone()), queried_heartbeat);
+
+    // Delete the test user (their monitors and heartbeats will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
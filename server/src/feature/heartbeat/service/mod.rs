@@ -0,0 +1,416 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext, QueryResult},
+        utility::create_value_validation_error,
+    },
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::{
+            model::{CheckErrorKind, CheckResult, CheckStatus},
+            service::CheckResultService,
+        },
+        heartbeat::{
+            model::{Heartbeat, HeartbeatKind},
+            repository::HeartbeatRepository,
+        },
+        monitor::{
+            model::{Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+use validator::{Validate, ValidationErrors};
+
+/// A heartbeat service trait.
+#[async_trait(?Send)]
+pub(crate) trait HeartbeatService: Interface {
+    /// # Description
+    ///
+    /// Insert a heartbeat into persistent storage, and record the outcome of the job's run if the
+    /// heartbeat reports that it completed.
+    ///
+    /// # Arguments
+    ///
+    /// `heartbeat` - The heartbeat that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the heartbeat that was
+    /// inserted.
+    /// - If the heartbeat being inserted contains validation errors, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        heartbeat: &Heartbeat,
+    ) -> InsertionResult<Heartbeat, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a heartbeat into persistent storage, and record the outcome of the job's run if the
+    /// heartbeat reports that it completed.
+    ///
+    /// # Arguments
+    ///
+    /// `heartbeat` - The heartbeat that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the heartbeat that was
+    /// inserted.
+    /// - If the heartbeat being inserted contains validation errors, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        heartbeat: &Heartbeat,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Heartbeat, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Determine if a heartbeat monitor has missed a heartbeat, which is the case when no heartbeat
+    /// has been received within the monitor's expected period and grace period. While heartbeats
+    /// continue to be missed, a heartbeat is considered to be missed once per expected period.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The heartbeat monitor that is being evaluated.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the monitor has missed a heartbeat, the Ok variant will be returned with the check result
+    /// that should be recorded for it.
+    /// - If the monitor has not missed a heartbeat, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_missed_heartbeat_check_result(
+        &self,
+        monitor: &Monitor,
+    ) -> QueryResult<CheckResult, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Determine if a heartbeat monitor has missed a heartbeat, which is the case when no heartbeat
+    /// has been received within the monitor's expected period and grace period. While heartbeats
+    /// continue to be missed, a heartbeat is considered to be missed once per expected period.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The heartbeat monitor that is being evaluated.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the monitor has missed a heartbeat, the Ok variant will be returned with the check result
+    /// that should be recorded for it.
+    /// - If the monitor has not missed a heartbeat, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_missed_heartbeat_check_result_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> QueryResult<CheckResult, Box<dyn Error>>;
+}
+
+/// A HeartbeatServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = HeartbeatService)]
+pub(crate) struct HeartbeatServiceImpl {
+    /// The heartbeat repository that will be used to manage persistent heartbeat data.
+    #[shaku(inject)]
+    heartbeat_repository: Arc<dyn HeartbeatRepository>,
+
+    /// The monitor service that will be used to validate monitor data.
+    #[shaku(inject)]
+    monitor_service: Arc<dyn MonitorService>,
+
+    /// The check result service that will be used to record the outcomes of jobs.
+    #[shaku(inject)]
+    check_result_service: Arc<dyn CheckResultService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// A HeartbeatService implementation for the HeartbeatServiceImpl struct.
+#[async_trait(?Send)]
+impl HeartbeatService for HeartbeatServiceImpl {
+    async fn insert(
+        &self,
+        heartbeat: &Heartbeat,
+    ) -> InsertionResult<Heartbeat, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self.insert_with_context(heartbeat, &mut context).await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        heartbeat: &Heartbeat,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Heartbeat, ValidationErrors, Box<dyn Error>> {
+        // Validate the heartbeat.
+        let mut validation_errors = match heartbeat.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Check if the monitor id specified exists, and belongs to a heartbeat monitor.
+        match __self
+            .monitor_service
+            .get_with_context(&heartbeat.monitor_id, context)
+            .await
+        {
+            QueryResult::Ok(monitor) => {
+                if !matches!(monitor.configuration, MonitorConfiguration::Heartbeat(_)) {
+                    validation_errors.add(
+                        name_of!(monitor_id in Heartbeat),
+                        create_value_validation_error("not_heartbeat", &heartbeat.monitor_id),
+                    );
+                }
+            }
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(monitor_id in Heartbeat),
+                create_value_validation_error("not_found", &heartbeat.monitor_id),
+            ),
+            QueryResult::Err(error) => return InsertionResult::Err(error),
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Query the heartbeat the monitor received before this one.
+        let previous_heartbeat_option = match __self
+            .heartbeat_repository
+            .get_latest_by_monitor_id(&heartbeat.monitor_id, context)
+            .await
+        {
+            Ok(previous_heartbeat_option) => previous_heartbeat_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Perform the insertion.
+        let heartbeat_id = match __self.heartbeat_repository.insert(heartbeat, context).await {
+            Ok(heartbeat_id) => heartbeat_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the heartbeat that was inserted.
+        let inserted_heartbeat = match __self
+            .heartbeat_repository
+            .get(&heartbeat_id, context)
+            .await
+        {
+            Ok(Some(inserted_heartbeat)) => inserted_heartbeat,
+            Ok(None) => {
+                return InsertionResult::Err(Box::new(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "Heartbeat could not be found after insertion",
+                )))
+            }
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the heartbeat reports that the job completed, record the outcome of the job's run.
+        if let Some(check_result) =
+            create_completion_check_result(&inserted_heartbeat, &previous_heartbeat_option)
+        {
+            match __self
+                .check_result_service
+                .insert_with_context(&check_result, context)
+                .await
+            {
+                InsertionResult::Ok(_) => {}
+                InsertionResult::Invalid(details) => return InsertionResult::Invalid(details),
+                InsertionResult::Err(error) => return InsertionResult::Err(error),
+            }
+        }
+
+        // Return the heartbeat that was inserted.
+        return InsertionResult::Ok(inserted_heartbeat);
+    }
+
+    async fn get_missed_heartbeat_check_result(
+        &self,
+        monitor: &Monitor,
+    ) -> QueryResult<CheckResult, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the evaluation.
+        return self
+            .get_missed_heartbeat_check_result_with_context(monitor, &mut context)
+            .await;
+    }
+
+    async fn get_missed_heartbeat_check_result_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> QueryResult<CheckResult, Box<dyn Error>> {
+        // Only heartbeat monitors can miss heartbeats.
+        let grace_period: u32 = match &monitor.configuration {
+            MonitorConfiguration::Heartbeat(configuration) => configuration.grace_period,
+            _ => return QueryResult::NotFound,
+        };
+        let expected_period: Duration = Duration::seconds(i64::from(monitor.check_interval));
+
+        // Determine when the monitor was last heard from (or when it was created, if it never has).
+        let last_seen_at: OffsetDateTime = match __self
+            .heartbeat_repository
+            .get_latest_by_monitor_id(&monitor.id, context)
+            .await
+        {
+            Ok(Some(heartbeat)) => heartbeat.received_at,
+            Ok(None) => monitor.monitor_created_at,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Determine when the monitor's next heartbeat is considered to be missed.
+        let mut missed_at: OffsetDateTime =
+            last_seen_at + expected_period + Duration::seconds(i64::from(grace_period));
+
+        // If a missed heartbeat has already been recorded since the monitor was last heard from, the
+        // next one is not missed until another expected period has elapsed.
+        let latest_check_results: Vec<CheckResult> = match __self
+            .check_result_service
+            .get_by_monitor_id_with_context(&monitor.id, &1, context)
+            .await
+        {
+            QueryResult::Ok(check_results) => check_results,
+            QueryResult::NotFound => Vec::new(),
+            QueryResult::Err(error) => return QueryResult::Err(error),
+        };
+        if let Some(latest_check_result) = latest_check_results.first() {
+            if latest_check_result.error_kind == Some(CheckErrorKind::HeartbeatMissed)
+                && latest_check_result.checked_at >= last_seen_at
+            {
+                missed_at = missed_at.max(latest_check_result.checked_at + expected_period);
+            }
+        }
+
+        // If the heartbeat is not missed yet, there is nothing to record.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        if now < missed_at {
+            return QueryResult::NotFound;
+        }
+
+        // Return the check result that records the missed heartbeat.
+        return QueryResult::Ok(CheckResult {
+            monitor_id: monitor.id,
+            checked_at: now,
+            status: CheckStatus::Down,
+            error_kind: Some(CheckErrorKind::HeartbeatMissed),
+            error_message: Some(format!(
+                "No heartbeat has been received since {}",
+                last_seen_at.format(&Rfc3339).unwrap_or_default()
+            )),
+            ..Default::default()
+        });
+    }
+}
+
+/// # Description
+///
+/// Create the check result that records the outcome of a job's run, if a heartbeat reports that the
+/// job completed.
+///
+/// # Arguments
+///
+/// `heartbeat` - The heartbeat that was received.
+///
+/// `previous_heartbeat_option` - The heartbeat the monitor received before this one, if any. If it
+/// reported that the job started, the job's run time is recorded as the check result's latency.
+///
+/// # Returns
+///
+/// An option that will contain the check result if the heartbeat reports that the job completed.
+fn create_completion_check_result(
+    heartbeat: &Heartbeat,
+    previous_heartbeat_option: &Option<Heartbeat>,
+) -> Option<CheckResult> {
+    // Create the check result.
+    let mut check_result: CheckResult = CheckResult {
+        monitor_id: heartbeat.monitor_id,
+        checked_at: heartbeat.received_at,
+        ..Default::default()
+    };
+
+    // Determine the outcome of the job's run.
+    match heartbeat.kind {
+        HeartbeatKind::Start => return None,
+        HeartbeatKind::Success => check_result.status = CheckStatus::Up,
+        HeartbeatKind::Fail => {
+            check_result.status = CheckStatus::Down;
+            check_result.error_kind = Some(CheckErrorKind::HeartbeatFailed);
+            check_result.error_message = Some(match heartbeat.exit_code {
+                Some(exit_code) => format!("Job exited with code {}", exit_code),
+                None => String::from("Job reported a failure"),
+            });
+        }
+    }
+
+    // If the job reported when it started, record how long it ran for.
+    if let Some(previous_heartbeat) = previous_heartbeat_option {
+        if previous_heartbeat.kind == HeartbeatKind::Start {
+            let run_time: Duration = heartbeat.received_at - previous_heartbeat.received_at;
+            check_result.latency = u32::try_from(run_time.whole_milliseconds()).unwrap_or(0);
+        }
+    }
+
+    // Return the check result.
+    return Some(check_result);
+}
@@ -0,0 +1,397 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        check::{
+            model::{CheckErrorKind, CheckResult, CheckStatus},
+            service::CheckResultService,
+        },
+        heartbeat::{
+            model::{Heartbeat, HeartbeatKind},
+            service::HeartbeatService,
+        },
+        monitor::{
+            model::{HeartbeatMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a heartbeat monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 3600,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Heartbeat(HeartbeatMonitorConfiguration {
+            token: generate_random_string(64),
+            grace_period: 300,
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a heartbeat that can be used for testing.
+///
+/// # Arguments
+///
+/// `monitor_id` - The id of the monitor the heartbeat will be sent to.
+///
+/// `kind` - The kind of heartbeat.
+///
+/// `exit_code` - The exit code the heartbeat will report.
+///
+/// # Returns
+///
+/// The heartbeat that was created.
+fn create_test_heartbeat(
+    monitor_id: u64,
+    kind: HeartbeatKind,
+    exit_code: Option<i32>,
+) -> Heartbeat {
+    return Heartbeat {
+        id: 0,
+        monitor_id,
+        received_at: OffsetDateTime::now_utc(),
+        kind,
+        exit_code,
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting the heartbeats of a job that starts and then fails, and make sure the failure is
+/// recorded as a check result.
+#[actix_web::test]
+async fn failed_heartbeat_is_recorded_as_check_result() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get heartbeat and check result service instances.
+    let heartbeat_service: Arc<dyn HeartbeatService> = injector.resolve();
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Insert the heartbeats.
+    for heartbeat in [
+        create_test_heartbeat(monitor.id, HeartbeatKind::Start, None),
+        create_test_heartbeat(monitor.id, HeartbeatKind::Fail, Some(2)),
+    ] {
+        let inserted_heartbeat = match heartbeat_service.insert(&heartbeat).await {
+            InsertionResult::Ok(inserted_heartbeat) => inserted_heartbeat,
+            InsertionResult::Invalid(details) => panic!(
+                "Failed to insert heartbeat, the heartbeat was invalid: {}",
+                details
+            ),
+            InsertionResult::Err(error) => panic!(
+                "Failed to insert heartbeat, an unexpected error has occurred: {}",
+                error
+            ),
+        };
+
+        // Make sure the correct data was returned.
+        assert!(inserted_heartbeat.id > 0);
+        assert_eq!(heartbeat.kind, inserted_heartbeat.kind);
+        assert_eq!(heartbeat.exit_code, inserted_heartbeat.exit_code);
+    }
+
+    // Query the check results the monitor produced.
+    let queried_check_results = match check_result_service
+        .get_by_monitor_id(&monitor.id, &10)
+        .await
+    {
+        QueryResult::Ok(queried_check_results) => queried_check_results,
+        QueryResult::NotFound => panic!("Failed to query check results: Not found"),
+        QueryResult::Err(error) => panic!(
+            "Failed to query check results, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure only the failure was recorded.
+    assert_eq!(1, queried_check_results.len());
+    assert_eq!(CheckStatus::Down, queried_check_results[0].status);
+    assert_eq!(
+        Some(CheckErrorKind::HeartbeatFailed),
+        queried_check_results[0].error_kind
+    );
+    assert_eq!(
+        Some(String::from("Job exited with code 2")),
+        queried_check_results[0].error_message
+    );
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting a heartbeat for a monitor that does not exist and make sure it does not succeed.
+#[actix_web::test]
+async fn inserting_a_heartbeat_for_a_missing_monitor_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a heartbeat service instance.
+    let heartbeat_service: Arc<dyn HeartbeatService> = injector.resolve();
+
+    // Create a test heartbeat for a monitor that does not exist.
+    let heartbeat: Heartbeat = create_test_heartbeat(u64::MAX, HeartbeatKind::Success, None);
+
+    // Insert the heartbeat, and make sure the validation errors are detected.
+    match heartbeat_service.insert(&heartbeat).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert heartbeat, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+}
+
+/// # Description
+///
+/// Test evaluating a heartbeat monitor before and after its expected period and grace period have
+/// elapsed, and make sure a missed heartbeat is only detected afterwards.
+#[actix_web::test]
+async fn missed_heartbeat_is_detected_after_grace_period() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a heartbeat service instance.
+    let heartbeat_service: Arc<dyn HeartbeatService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let mut monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Make sure a heartbeat is not missed while the monitor is new.
+    match heartbeat_service
+        .get_missed_heartbeat_check_result(&monitor)
+        .await
+    {
+        QueryResult::Ok(_) => panic!("A heartbeat was missed when it should not have been"),
+        QueryResult::NotFound => {}
+        QueryResult::Err(error) => panic!(
+            "Failed to evaluate monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure a heartbeat is missed once the expected period and grace period have elapsed.
+    monitor.monitor_created_at = OffsetDateTime::now_utc() - Duration::hours(2);
+    let check_result: CheckResult = match heartbeat_service
+        .get_missed_heartbeat_check_result(&monitor)
+        .await
+    {
+        QueryResult::Ok(check_result) => check_result,
+        QueryResult::NotFound => panic!("A heartbeat was not missed when it should have been"),
+        QueryResult::Err(error) => panic!(
+            "Failed to evaluate monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+    assert_eq!(monitor.id, check_result.monitor_id);
+    assert_eq!(CheckStatus::Down, check_result.status);
+    assert_eq!(
+        Some(CheckErrorKind::HeartbeatMissed),
+        check_result.error_kind
+    );
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
@@ -0,0 +1,24 @@
+use crate::feature::incident::model::{IncidentStatus, IncidentUpdate};
+use serde::{Deserialize, Serialize};
+
+/// A create incident update request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateIncidentUpdateRequestBody {
+    /// The status the incident is moving into.
+    pub(super) status: IncidentStatus,
+
+    /// The update's message.
+    pub(super) message: String,
+}
+
+/// An Into<IncidentUpdate> implementation for the CreateIncidentUpdateRequestBody struct.
+impl Into<IncidentUpdate> for CreateIncidentUpdateRequestBody {
+    fn into(self) -> IncidentUpdate {
+        return IncidentUpdate {
+            status: self.status,
+            message: self.message,
+            ..Default::default()
+        };
+    }
+}
@@ -0,0 +1,236 @@
+mod data;
+
+use self::data::CreateIncidentUpdateRequestBody;
+use crate::{
+    common::{
+        enumeration::{AuthenticationResult, InsertionResult, QueryResult},
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        api_token::model::ApiTokenScope,
+        auth::service::AuthService,
+        incident::{
+            model::{Incident, IncidentUpdate},
+            service::IncidentService,
+        },
+        monitor::{model::Monitor, service::MonitorService},
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    get, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the incident controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the incident controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/incidents")
+            .service(get_incident)
+            .service(get_incident_updates)
+            .service(create_incident_update),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to get an incident.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the incident that is being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the affected monitor.
+///
+/// `incident_service` - The incident service that will be used to retrieve the incident.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}")]
+async fn get_incident(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    incident_service: Inject<DependencyInjector, dyn IncidentService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::IncidentsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the incident the user is requesting.
+    let incident: Incident = match incident_service.get(&id).await {
+        QueryResult::Ok(incident) => incident,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the monitor that is affected by the incident.
+    let monitor: Monitor = match monitor_service.get(&incident.monitor_id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its incidents.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Return the incident.
+    return HttpResponse::Ok().json(incident);
+}
+
+/// # Description
+///
+/// An api endpoint to get the timeline of updates that were posted to an incident.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the incident whose updates are being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the affected monitor.
+///
+/// `incident_service` - The incident service that will be used to retrieve the incident updates.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/updates")]
+async fn get_incident_updates(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    incident_service: Inject<DependencyInjector, dyn IncidentService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::IncidentsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the incident whose updates are being requested.
+    let incident: Incident = match incident_service.get(&id).await {
+        QueryResult::Ok(incident) => incident,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the monitor that is affected by the incident.
+    let monitor: Monitor = match monitor_service.get(&incident.monitor_id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its incidents.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Get the incident's updates.
+    return match incident_service.get_updates(&incident.id).await {
+        QueryResult::Ok(incident_updates) => HttpResponse::Ok().json(incident_updates),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to post an update to an incident's timeline.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the incident the update is being posted to.
+///
+/// `body` - The request body which contains the update that is being posted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the affected monitor.
+///
+/// `incident_service` - The incident service that will be used to post the update.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/{id}/updates")]
+async fn create_incident_update(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    body: web::Json<CreateIncidentUpdateRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    incident_service: Inject<DependencyInjector, dyn IncidentService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::IncidentsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the incident the update is being posted to.
+    let incident: Incident = match incident_service.get(&id).await {
+        QueryResult::Ok(incident) => incident,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the monitor that is affected by the incident.
+    let monitor: Monitor = match monitor_service.get(&incident.monitor_id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to update its incidents.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Convert the request body into an incident update.
+    let mut incident_update: IncidentUpdate = body.into_inner().into();
+    incident_update.incident_id = incident.id;
+
+    // Post the update.
+    return match incident_service.insert_update(&incident_update).await {
+        InsertionResult::Ok(created_incident_update) => {
+            HttpResponse::Ok().json(created_incident_update)
+        }
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
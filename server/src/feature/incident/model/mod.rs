@@ -0,0 +1,157 @@
+use crate::feature::check::model::CheckErrorKind;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use validator::Validate;
+
+/// An incident struct, which records a period of time a monitored service was down for.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Incident {
+    /// The incident's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the monitor that is affected by the incident.
+    pub(crate) monitor_id: u64,
+
+    /// The incident's current status.
+    pub(crate) status: IncidentStatus,
+
+    /// The date and time the incident started at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) started_at: OffsetDateTime,
+
+    /// The date and time the incident was resolved at, if it has been resolved.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(crate) resolved_at: Option<OffsetDateTime>,
+
+    /// The amount of time (in seconds) the incident lasted for, if it has been resolved.
+    pub(crate) duration: Option<u64>,
+
+    /// The kind of error that caused the incident, if one was reported.
+    pub(crate) error_kind: Option<CheckErrorKind>,
+
+    /// A description of the error that caused the incident, if one was reported.
+    #[validate(length(max = 2048))]
+    pub(crate) error_message: Option<String>,
+}
+
+/// A Default implementation for the Incident struct.
+impl Default for Incident {
+    fn default() -> Self {
+        return Incident {
+            id: 0,
+            monitor_id: 0,
+            status: IncidentStatus::Investigating,
+            started_at: OffsetDateTime::now_utc(),
+            resolved_at: None,
+            duration: None,
+            error_kind: None,
+            error_message: None,
+        };
+    }
+}
+
+/// A PartialEq implementation for the Incident struct.
+impl PartialEq for Incident {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.monitor_id == other.monitor_id
+            && self.status == other.status
+            && self.started_at.date() == other.started_at.date()
+            && self.started_at.hour() == other.started_at.hour()
+            && self.started_at.minute() == other.started_at.minute()
+            && self.started_at.second() == other.started_at.second()
+            && self
+                .resolved_at
+                .map(|resolved_at| resolved_at.unix_timestamp())
+                == other
+                    .resolved_at
+                    .map(|resolved_at| resolved_at.unix_timestamp())
+            && self.duration == other.duration
+            && self.error_kind == other.error_kind
+            && self.error_message == other.error_message;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An incident update struct, which records a message that was posted to an incident's timeline.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct IncidentUpdate {
+    /// The incident update's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the incident the update was posted to.
+    pub(crate) incident_id: u64,
+
+    /// The date and time the update was posted at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) created_at: OffsetDateTime,
+
+    /// The status the incident was in when the update was posted.
+    pub(crate) status: IncidentStatus,
+
+    /// The update's message.
+    #[validate(length(min = 1, max = 4096))]
+    pub(crate) message: String,
+}
+
+/// A Default implementation for the IncidentUpdate struct.
+impl Default for IncidentUpdate {
+    fn default() -> Self {
+        return IncidentUpdate {
+            id: 0,
+            incident_id: 0,
+            created_at: OffsetDateTime::now_utc(),
+            status: IncidentStatus::Investigating,
+            message: String::from(""),
+        };
+    }
+}
+
+/// A PartialEq implementation for the IncidentUpdate struct.
+impl PartialEq for IncidentUpdate {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.incident_id == other.incident_id
+            && self.created_at.date() == other.created_at.date()
+            && self.created_at.hour() == other.created_at.hour()
+            && self.created_at.minute() == other.created_at.minute()
+            && self.created_at.second() == other.created_at.second()
+            && self.status == other.status
+            && self.message == other.message;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the statuses an incident can be in.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum IncidentStatus {
+    /// # Description
+    ///
+    /// The Investigating variant is used while the cause of the incident is being investigated.
+    Investigating,
+
+    /// # Description
+    ///
+    /// The Identified variant is used once the cause of the incident has been identified.
+    Identified,
+
+    /// # Description
+    ///
+    /// The Monitoring variant is used once a fix has been applied and the service is being watched.
+    Monitoring,
+
+    /// # Description
+    ///
+    /// The Resolved variant is used once the incident is over.
+    Resolved,
+}
@@ -0,0 +1,486 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::{
+        check::model::CheckErrorKind,
+        incident::model::{Incident, IncidentStatus, IncidentUpdate},
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// An incident repository trait.
+#[async_trait]
+pub(crate) trait IncidentRepository: Interface {
+    /// # Description
+    ///
+    /// Insert an incident into the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `incident` - The incident to insert into the incident repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// incident that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, incident: &Incident, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get an incident from the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the incident to get from the incident repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional incident.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Incident>, Error>;
+
+    /// # Description
+    ///
+    /// Get the most recent incidents that affected a monitor from the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that was affected by the incidents.
+    ///
+    /// `limit` - The maximum amount of incidents to get.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the incidents that were
+    /// found, ordered from newest to oldest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Incident>, Error>;
+
+    /// # Description
+    ///
+    /// Get the incident that is currently affecting a monitor from the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor that is affected by the incident.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional incident.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_unresolved_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<Incident>, Error>;
+
+    /// # Description
+    ///
+    /// Update an incident in the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `incident` - The incident to update in the incident repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the number of rows
+    /// affected.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(&self, incident: &Incident, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert an incident update into the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_update` - The incident update to insert into the incident repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// incident update that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_update(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get an incident update from the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the incident update to get from the incident repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional incident
+    /// update.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_update(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<IncidentUpdate>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the updates that were posted to an incident from the incident repository.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_id` - The id of the incident the updates were posted to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the incident updates that
+    /// were found, ordered from oldest to newest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_updates_by_incident_id(
+        &self,
+        incident_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<IncidentUpdate>, Error>;
+}
+
+/// An IncidentRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = IncidentRepository)]
+pub(crate) struct IncidentRepositoryImpl;
+
+/// An IncidentRepository implementation for the IncidentRepositoryImpl struct.
+#[async_trait]
+impl IncidentRepository for IncidentRepositoryImpl {
+    async fn insert(&self, incident: &Incident, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `incidents` (
+                    `id`,
+                    `monitor_id`,
+                    `status`,
+                    `started_at`,
+                    `resolved_at`,
+                    `duration`,
+                    `error_kind`,
+                    `error_message`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            incident.id,
+            incident.monitor_id,
+            incident.status,
+            incident.started_at,
+            incident.resolved_at,
+            incident.duration,
+            incident.error_kind,
+            incident.error_message
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Incident>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Incident,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `status` AS `status: IncidentStatus`,
+                    `started_at` AS `started_at: OffsetDateTime`,
+                    `resolved_at` AS `resolved_at: OffsetDateTime`,
+                    `duration` AS `duration: u64`,
+                    `error_kind` AS `error_kind: CheckErrorKind`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `incidents`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Incident>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Incident,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `status` AS `status: IncidentStatus`,
+                    `started_at` AS `started_at: OffsetDateTime`,
+                    `resolved_at` AS `resolved_at: OffsetDateTime`,
+                    `duration` AS `duration: u64`,
+                    `error_kind` AS `error_kind: CheckErrorKind`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `incidents`
+                WHERE
+                    `monitor_id` = ?
+                ORDER BY
+                    `started_at` DESC,
+                    `id` DESC
+                LIMIT ?;
+            "#,
+            monitor_id,
+            limit
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_unresolved_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<Incident>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Incident,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `status` AS `status: IncidentStatus`,
+                    `started_at` AS `started_at: OffsetDateTime`,
+                    `resolved_at` AS `resolved_at: OffsetDateTime`,
+                    `duration` AS `duration: u64`,
+                    `error_kind` AS `error_kind: CheckErrorKind`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `incidents`
+                WHERE
+                    `monitor_id` = ?
+                    AND `resolved_at` IS NULL
+                ORDER BY
+                    `started_at` DESC,
+                    `id` DESC
+                LIMIT 1;
+            "#,
+            monitor_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(&self, incident: &Incident, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `incidents`
+                SET
+                    `monitor_id` = ?,
+                    `status` = ?,
+                    `started_at` = ?,
+                    `resolved_at` = ?,
+                    `duration` = ?,
+                    `error_kind` = ?,
+                    `error_message` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            incident.monitor_id,
+            incident.status,
+            incident.started_at,
+            incident.resolved_at,
+            incident.duration,
+            incident.error_kind,
+            incident.error_message,
+            incident.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_update(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `incident_updates` (
+                    `id`,
+                    `incident_id`,
+                    `created_at`,
+                    `status`,
+                    `message`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            incident_update.id,
+            incident_update.incident_id,
+            incident_update.created_at,
+            incident_update.status,
+            incident_update.message
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_update(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<IncidentUpdate>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            IncidentUpdate,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `incident_id` AS `incident_id: u64`,
+                    `created_at` AS `created_at: OffsetDateTime`,
+                    `status` AS `status: IncidentStatus`,
+                    `message` AS `message: String`
+                FROM
+                    `incident_updates`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_updates_by_incident_id(
+        &self,
+        incident_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<IncidentUpdate>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            IncidentUpdate,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `incident_id` AS `incident_id: u64`,
+                    `created_at` AS `created_at: OffsetDateTime`,
+                    `status` AS `status: IncidentStatus`,
+                    `message` AS `message: String`
+                FROM
+                    `incident_updates`
+                WHERE
+                    `incident_id` = ?
+                ORDER BY
+                    `created_at` ASC,
+                    `id` ASC;
+            "#,
+            incident_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+}
@@ -0,0 +1,452 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::model::CheckErrorKind,
+        incident::{
+            model::{Incident, IncidentStatus, IncidentUpdate},
+            repository::IncidentRepository,
+        },
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create an incident that can be used for testing.
+///
+/// # Returns
+///
+/// The incident that was created.
+fn create_test_incident() -> Incident {
+    return Incident {
+        id: 0,
+        monitor_id: 0,
+        status: IncidentStatus::Investigating,
+        started_at: OffsetDateTime::now_utc(),
+        resolved_at: None,
+        duration: None,
+        error_kind: Some(CheckErrorKind::UnexpectedStatusCode),
+        error_message: Some(generate_random_string(32)),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting an incident, and make sure it is queryable by its id and as its monitor's
+/// unresolved incident until it is resolved.
+#[actix_web::test]
+async fn incident_is_queryable_until_resolved() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an incident repository instance.
+    let incident_repository: Arc<dyn IncidentRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert the incident.
+    let mut incident: Incident = create_test_incident();
+    incident.monitor_id = monitor.id;
+    incident.id = incident_repository
+        .insert(&incident, &mut context)
+        .await
+        .expect("Failed to insert incident");
+
+    // Make sure the incident is queryable by its id, and as the monitor's unresolved incident.
+    let queried_incident: Option<Incident> = incident_repository
+        .get(&incident.id, &mut context)
+        .await
+        .expect("Failed to query incident");
+    assert_eq!(Some(incident.clone()), queried_incident);
+    let unresolved_incident: Option<Incident> = incident_repository
+        .get_unresolved_by_monitor_id(&monitor.id, &mut context)
+        .await
+        .expect("Failed to query unresolved incident");
+    assert_eq!(Some(incident.clone()), unresolved_incident);
+
+    // Resolve the incident.
+    incident.status = IncidentStatus::Resolved;
+    incident.resolved_at = Some(incident.started_at + Duration::minutes(5));
+    incident.duration = Some(300);
+    let rows_affected: u64 = incident_repository
+        .update(&incident, &mut context)
+        .await
+        .expect("Failed to update incident");
+    assert_eq!(1, rows_affected);
+
+    // Make sure the incident is no longer the monitor's unresolved incident.
+    let unresolved_incident: Option<Incident> = incident_repository
+        .get_unresolved_by_monitor_id(&monitor.id, &mut context)
+        .await
+        .expect("Failed to query unresolved incident");
+    assert_eq!(None, unresolved_incident);
+    let incidents: Vec<Incident> = incident_repository
+        .get_by_monitor_id(&monitor.id, &10, &mut context)
+        .await
+        .expect("Failed to query incidents by monitor id");
+    assert_eq!(vec![incident], incidents);
+
+    // Delete the test user (their monitor and incidents will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting incident updates, and make sure they are queryable in the order they were posted.
+#[actix_web::test]
+async fn incident_updates_are_queryable_by_incident_id() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an incident repository instance.
+    let incident_repository: Arc<dyn IncidentRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, monitor and incident.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let mut incident: Incident = create_test_incident();
+    incident.monitor_id = monitor.id;
+    let incident_id: u64 = incident_repository
+        .insert(&incident, &mut context)
+        .await
+        .expect("Failed to insert incident");
+
+    // Insert an update for each status.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut incident_updates: Vec<IncidentUpdate> = Vec::new();
+    for (minutes, status) in [
+        IncidentStatus::Investigating,
+        IncidentStatus::Identified,
+        IncidentStatus::Monitoring,
+        IncidentStatus::Resolved,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut incident_update: IncidentUpdate = IncidentUpdate {
+            id: 0,
+            incident_id,
+            created_at: now + Duration::minutes(minutes as i64),
+            status,
+            message: generate_random_string(32),
+        };
+        incident_update.id = incident_repository
+            .insert_update(&incident_update, &mut context)
+            .await
+            .expect("Failed to insert incident update");
+        incident_updates.push(incident_update);
+    }
+
+    // Make sure an update is queryable by its id.
+    let queried_incident_update: Option<IncidentUpdate> = incident_repository
+        .get_update(&incident_updates[0].id, &mut context)
+        .await
+        .expect("Failed to query incident update");
+    assert_eq!(Some(incident_updates[0].clone()), queried_incident_update);
+
+    // Make sure the updates are queryable in order.
+    let queried_incident_updates: Vec<IncidentUpdate> = incident_repository
+        .get_updates_by_incident_id(&incident_id, &mut context)
+        .await
+        .expect("Failed to query incident updates");
+    assert_eq!(incident_updates, queried_incident_updates);
+
+    // Delete the test user (their monitor, incident and updates will be deleted with them).
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
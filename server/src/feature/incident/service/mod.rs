@@ -0,0 +1,607 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext, QueryResult},
+        utility::create_value_validation_error,
+    },
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::model::{CheckResult, CheckStatus},
+        incident::{
+            model::{Incident, IncidentStatus, IncidentUpdate},
+            repository::IncidentRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use time::OffsetDateTime;
+use validator::{Validate, ValidationErrors};
+
+/// An incident service trait.
+#[async_trait(?Send)]
+pub(crate) trait IncidentService: Interface {
+    /// # Description
+    ///
+    /// Get an incident from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the incident to get.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the incident was found, the Ok variant will be returned with the incident.
+    /// - If the incident was not found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get(&self, id: &u64) -> QueryResult<Incident, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get an incident from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the incident to get.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the incident was found, the Ok variant will be returned with the incident.
+    /// - If the incident was not found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Incident, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the most recent incidents that affected a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that was affected by the incidents.
+    ///
+    /// `limit` - The maximum amount of incidents to get.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the incidents, ordered from
+    /// newest to oldest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<Incident>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the most recent incidents that affected a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor that was affected by the incidents.
+    ///
+    /// `limit` - The maximum amount of incidents to get.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the incidents, ordered from
+    /// newest to oldest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Incident>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the timeline of updates that were posted to an incident from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_id` - The unique identifier of the incident the updates were posted to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the incident updates,
+    /// ordered from oldest to newest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_updates(
+        &self,
+        incident_id: &u64,
+    ) -> QueryResult<Vec<IncidentUpdate>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the timeline of updates that were posted to an incident from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_id` - The unique identifier of the incident the updates were posted to.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the incident updates,
+    /// ordered from oldest to newest.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_updates_with_context(
+        &self,
+        incident_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<IncidentUpdate>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Post an update to an incident's timeline, moving the incident into the update's status. If
+    /// the update's status is resolved, the incident will be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_update` - The incident update that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the incident update
+    /// that was inserted.
+    /// - If the incident update being inserted contains validation errors, the Invalid variant will
+    /// be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_update(
+        &self,
+        incident_update: &IncidentUpdate,
+    ) -> InsertionResult<IncidentUpdate, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Post an update to an incident's timeline, moving the incident into the update's status. If
+    /// the update's status is resolved, the incident will be resolved.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_update` - The incident update that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the incident update
+    /// that was inserted.
+    /// - If the incident update being inserted contains validation errors, the Invalid variant will
+    /// be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_update_with_context(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext,
+    ) -> InsertionResult<IncidentUpdate, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Open or resolve a monitor's incident based on a check result it produced. An incident is
+    /// opened when the monitor goes from being operational to being down, and is resolved once the
+    /// monitor is operational again.
+    ///
+    /// # Arguments
+    ///
+    /// `check_result` - The check result the monitor produced.
+    ///
+    /// `previous_check_result` - The check result the monitor produced before it, if there was one.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If an incident was opened or resolved, the Ok variant will be returned with the incident.
+    /// - If no incident was opened or resolved, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn process_check_result_with_context(
+        &self,
+        check_result: &CheckResult,
+        previous_check_result: Option<&CheckResult>,
+        context: &mut QueryContext,
+    ) -> QueryResult<Incident, Box<dyn Error>>;
+}
+
+/// An IncidentServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = IncidentService)]
+pub(crate) struct IncidentServiceImpl {
+    /// The incident repository that will be used to manage persistent incident data.
+    #[shaku(inject)]
+    incident_repository: Arc<dyn IncidentRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// An IncidentService implementation for the IncidentServiceImpl struct.
+#[async_trait(?Send)]
+impl IncidentService for IncidentServiceImpl {
+    async fn get(&self, id: &u64) -> QueryResult<Incident, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_with_context(id, &mut context).await;
+    }
+
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Incident, Box<dyn Error>> {
+        // Perform the query and return the incident if it was found.
+        return match __self.incident_repository.get(id, context).await {
+            Ok(Some(incident)) => QueryResult::Ok(incident),
+            Ok(None) => QueryResult::NotFound,
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<Incident>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_monitor_id_with_context(monitor_id, limit, &mut context)
+            .await;
+    }
+
+    async fn get_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Incident>, Box<dyn Error>> {
+        // Perform the query and return the incidents that were found.
+        return match __self
+            .incident_repository
+            .get_by_monitor_id(monitor_id, limit, context)
+            .await
+        {
+            Ok(incidents) => QueryResult::Ok(incidents),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_updates(
+        &self,
+        incident_id: &u64,
+    ) -> QueryResult<Vec<IncidentUpdate>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_updates_with_context(incident_id, &mut context)
+            .await;
+    }
+
+    async fn get_updates_with_context(
+        &self,
+        incident_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<IncidentUpdate>, Box<dyn Error>> {
+        // Perform the query and return the incident updates that were found.
+        return match __self
+            .incident_repository
+            .get_updates_by_incident_id(incident_id, context)
+            .await
+        {
+            Ok(incident_updates) => QueryResult::Ok(incident_updates),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_update(
+        &self,
+        incident_update: &IncidentUpdate,
+    ) -> InsertionResult<IncidentUpdate, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self
+            .insert_update_with_context(incident_update, &mut context)
+            .await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_update_with_context(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext,
+    ) -> InsertionResult<IncidentUpdate, ValidationErrors, Box<dyn Error>> {
+        // Validate the incident update.
+        let mut validation_errors = match incident_update.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Check if the incident id specified exists, and belongs to an incident that has not been
+        // resolved.
+        let incident_option: Option<Incident> = match __self
+            .incident_repository
+            .get(&incident_update.incident_id, context)
+            .await
+        {
+            Ok(incident_option) => incident_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+        match &incident_option {
+            Some(incident) if incident.resolved_at.is_some() => validation_errors.add(
+                name_of!(incident_id in IncidentUpdate),
+                create_value_validation_error("resolved", &incident_update.incident_id),
+            ),
+            Some(_) => {}
+            None => validation_errors.add(
+                name_of!(incident_id in IncidentUpdate),
+                create_value_validation_error("not_found", &incident_update.incident_id),
+            ),
+        }
+
+        // If any validation errors exist, return them.
+        let mut incident: Incident = match incident_option {
+            Some(incident) if validation_errors.is_empty() => incident,
+            _ => return InsertionResult::Invalid(validation_errors),
+        };
+
+        // Move the incident into the update's status, resolving it if necessary.
+        incident.status = incident_update.status;
+        if incident_update.status == IncidentStatus::Resolved {
+            resolve_incident(&mut incident, incident_update.created_at);
+        }
+        if let Err(error) = __self.incident_repository.update(&incident, context).await {
+            return InsertionResult::Err(Box::new(error));
+        }
+
+        // Perform the insertion and return the incident update that was inserted.
+        return self.insert_incident_update(incident_update, context).await;
+    }
+
+    async fn process_check_result_with_context(
+        &self,
+        check_result: &CheckResult,
+        previous_check_result: Option<&CheckResult>,
+        context: &mut QueryContext,
+    ) -> QueryResult<Incident, Box<dyn Error>> {
+        // Query the incident that is currently affecting the monitor.
+        let unresolved_incident_option: Option<Incident> = match __self
+            .incident_repository
+            .get_unresolved_by_monitor_id(&check_result.monitor_id, context)
+            .await
+        {
+            Ok(unresolved_incident_option) => unresolved_incident_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Determine if the monitor was down before this check result.
+        let was_down: bool = previous_check_result
+            .map(|previous_check_result| previous_check_result.status == CheckStatus::Down)
+            .unwrap_or(false);
+
+        return match (check_result.status, unresolved_incident_option) {
+            // If the monitor recovered, resolve its incident.
+            (CheckStatus::Up | CheckStatus::Degraded, Some(mut incident)) => {
+                resolve_incident(&mut incident, check_result.checked_at);
+                if let Err(error) = __self.incident_repository.update(&incident, context).await {
+                    return QueryResult::Err(Box::new(error));
+                }
+                let incident_update: IncidentUpdate = IncidentUpdate {
+                    incident_id: incident.id,
+                    created_at: check_result.checked_at,
+                    status: IncidentStatus::Resolved,
+                    message: String::from("The monitor recovered"),
+                    ..Default::default()
+                };
+                self.post_automatic_update(&incident_update, context).await
+            }
+            // If the monitor just went down, open an incident.
+            (CheckStatus::Down, None) if !was_down => {
+                let incident: Incident = Incident {
+                    monitor_id: check_result.monitor_id,
+                    status: IncidentStatus::Investigating,
+                    started_at: check_result.checked_at,
+                    error_kind: check_result.error_kind,
+                    error_message: check_result.error_message.clone(),
+                    ..Default::default()
+                };
+                let incident_id = match __self.incident_repository.insert(&incident, context).await
+                {
+                    Ok(incident_id) => incident_id,
+                    Err(error) => return QueryResult::Err(Box::new(error)),
+                };
+                let incident_update: IncidentUpdate = IncidentUpdate {
+                    incident_id,
+                    created_at: check_result.checked_at,
+                    status: IncidentStatus::Investigating,
+                    message: match &check_result.error_message {
+                        Some(error_message) => format!("The monitor went down: {}", error_message),
+                        None => String::from("The monitor went down"),
+                    },
+                    ..Default::default()
+                };
+                self.post_automatic_update(&incident_update, context).await
+            }
+            // Failures during maintenance neither open nor resolve incidents.
+            (CheckStatus::Maintenance, _) => QueryResult::NotFound,
+            // Otherwise, the monitor's incidents are unaffected.
+            _ => QueryResult::NotFound,
+        };
+    }
+}
+
+/// An implementation for the IncidentServiceImpl struct.
+impl IncidentServiceImpl {
+    /// # Description
+    ///
+    /// Insert an incident update into persistent storage, and query the incident update that was
+    /// inserted.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_update` - The incident update that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the incident update
+    /// that was inserted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_incident_update(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext<'_>,
+    ) -> InsertionResult<IncidentUpdate, ValidationErrors, Box<dyn Error>> {
+        // Perform the insertion.
+        let incident_update_id = match self
+            .incident_repository
+            .insert_update(incident_update, context)
+            .await
+        {
+            Ok(incident_update_id) => incident_update_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the incident update that was inserted.
+        let inserted_incident_update_option = match self
+            .incident_repository
+            .get_update(&incident_update_id, context)
+            .await
+        {
+            Ok(inserted_incident_update_option) => inserted_incident_update_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the incident update was found, return it, otherwise return an error.
+        return match inserted_incident_update_option {
+            Some(inserted_incident_update) => InsertionResult::Ok(inserted_incident_update),
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Incident update could not be found after insertion",
+            ))),
+        };
+    }
+
+    /// # Description
+    ///
+    /// Post an update to the timeline of an incident that was opened or resolved automatically.
+    ///
+    /// # Arguments
+    ///
+    /// `incident_update` - The update that describes why the incident was opened or resolved.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the update was posted successfully, the Ok variant will be returned with the incident.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn post_automatic_update(
+        &self,
+        incident_update: &IncidentUpdate,
+        context: &mut QueryContext<'_>,
+    ) -> QueryResult<Incident, Box<dyn Error>> {
+        // Post the update to the incident's timeline.
+        return match self.insert_incident_update(incident_update, context).await {
+            InsertionResult::Ok(_) => {
+                self.get_with_context(&incident_update.incident_id, context)
+                    .await
+            }
+            InsertionResult::Invalid(details) => QueryResult::Err(Box::new(details)),
+            InsertionResult::Err(error) => QueryResult::Err(error),
+        };
+    }
+}
+
+/// # Description
+///
+/// Resolve an incident, recording when it was resolved and how long it lasted for.
+///
+/// # Arguments
+///
+/// `incident` - The incident that will be resolved.
+///
+/// `resolved_at` - The date and time the incident was resolved at.
+fn resolve_incident(incident: &mut Incident, resolved_at: OffsetDateTime) {
+    incident.status = IncidentStatus::Resolved;
+    incident.resolved_at = Some(resolved_at);
+    incident.duration = Some((resolved_at - incident.started_at).whole_seconds().max(0) as u64);
+}
@@ -0,0 +1,421 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        check::{
+            model::{CheckErrorKind, CheckResult, CheckStatus},
+            service::CheckResultService,
+        },
+        incident::{
+            model::{Incident, IncidentStatus, IncidentUpdate},
+            service::IncidentService,
+        },
+        monitor::{
+            model::{HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration::default()),
+    };
+}
+
+/// # Description
+///
+/// Create a check result that can be used for testing.
+///
+/// # Arguments
+///
+/// `monitor_id` - The id of the monitor that produced the check result.
+///
+/// `checked_at` - The date and time the check was performed at.
+///
+/// `status` - The status the check determined the service to be in.
+///
+/// # Returns
+///
+/// The check result that was created.
+fn create_test_check_result(
+    monitor_id: u64,
+    checked_at: OffsetDateTime,
+    status: CheckStatus,
+) -> CheckResult {
+    let is_down: bool = status == CheckStatus::Down;
+    return CheckResult {
+        monitor_id,
+        checked_at,
+        status,
+        latency: 100,
+        error_kind: is_down.then_some(CheckErrorKind::Connection),
+        error_message: is_down.then(|| String::from("Connection refused")),
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Insert a check result with the check result service.
+///
+/// # Arguments
+///
+/// `check_result` - The check result to insert.
+///
+/// `injector` - The dependency injector that will be used to acquire a check result service
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the check result.
+async fn insert_test_check_result(check_result: &CheckResult, injector: &DependencyInjector) {
+    // Get a check result service instance.
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Perform the insertion.
+    match check_result_service.insert(check_result).await {
+        InsertionResult::Ok(_) => {}
+        InsertionResult::Invalid(details) => panic!("Failed to insert check result: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert check result: {}", error),
+    }
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test a monitor going down and recovering, and make sure an incident is opened when it goes down
+/// and resolved once it recovers.
+#[actix_web::test]
+async fn incident_is_opened_and_resolved_automatically() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an incident service instance.
+    let incident_service: Arc<dyn IncidentService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Record the monitor being up, going down for two checks, and recovering.
+    let start: OffsetDateTime = OffsetDateTime::now_utc() - Duration::minutes(10);
+    let statuses: [CheckStatus; 4] = [
+        CheckStatus::Up,
+        CheckStatus::Down,
+        CheckStatus::Down,
+        CheckStatus::Up,
+    ];
+    for (minutes, status) in statuses.into_iter().enumerate() {
+        let checked_at: OffsetDateTime = start + Duration::minutes(minutes as i64);
+        insert_test_check_result(
+            &create_test_check_result(monitor.id, checked_at, status),
+            &injector,
+        )
+        .await;
+    }
+
+    // Make sure a single incident was opened, and resolved.
+    let incidents: Vec<Incident> = match incident_service.get_by_monitor_id(&monitor.id, &10).await
+    {
+        QueryResult::Ok(incidents) => incidents,
+        QueryResult::NotFound => panic!("Failed to get incidents: Incidents not found"),
+        QueryResult::Err(error) => panic!("Failed to get incidents: {}", error),
+    };
+    assert_eq!(1, incidents.len());
+    assert_eq!(IncidentStatus::Resolved, incidents[0].status);
+    assert_eq!(Some(CheckErrorKind::Connection), incidents[0].error_kind);
+    assert_eq!(
+        (start + Duration::minutes(1)).unix_timestamp(),
+        incidents[0].started_at.unix_timestamp()
+    );
+    assert_eq!(Some(120), incidents[0].duration);
+
+    // Make sure the opening and resolution were posted to the incident's timeline.
+    let incident_updates: Vec<IncidentUpdate> =
+        match incident_service.get_updates(&incidents[0].id).await {
+            QueryResult::Ok(incident_updates) => incident_updates,
+            QueryResult::NotFound => panic!("Failed to get incident updates: Not found"),
+            QueryResult::Err(error) => panic!("Failed to get incident updates: {}", error),
+        };
+    assert_eq!(
+        vec![IncidentStatus::Investigating, IncidentStatus::Resolved],
+        incident_updates
+            .iter()
+            .map(|incident_update| incident_update.status)
+            .collect::<Vec<IncidentStatus>>()
+    );
+
+    // Delete the test user (their monitor and incidents will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test posting updates to an incident, and make sure the incident follows their statuses and can
+/// no longer be updated once it has been resolved.
+#[actix_web::test]
+async fn incident_updates_change_incident_status() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an incident service instance.
+    let incident_service: Arc<dyn IncidentService> = injector.resolve();
+
+    // Insert a test user and monitor, and take the monitor down.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+    insert_test_check_result(
+        &create_test_check_result(monitor.id, OffsetDateTime::now_utc(), CheckStatus::Down),
+        &injector,
+    )
+    .await;
+    let incident: Incident = match incident_service.get_by_monitor_id(&monitor.id, &1).await {
+        QueryResult::Ok(mut incidents) if !incidents.is_empty() => incidents.remove(0),
+        QueryResult::Ok(_) | QueryResult::NotFound => panic!("Incident was not opened"),
+        QueryResult::Err(error) => panic!("Failed to get incidents: {}", error),
+    };
+
+    // Post an update that identifies the cause.
+    let mut incident_update: IncidentUpdate = IncidentUpdate {
+        incident_id: incident.id,
+        status: IncidentStatus::Identified,
+        message: String::from("The database is unreachable"),
+        ..Default::default()
+    };
+    match incident_service.insert_update(&incident_update).await {
+        InsertionResult::Ok(_) => {}
+        InsertionResult::Invalid(details) => panic!("Failed to post update: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to post update: {}", error),
+    }
+    match incident_service.get(&incident.id).await {
+        QueryResult::Ok(updated_incident) => {
+            assert_eq!(IncidentStatus::Identified, updated_incident.status);
+            assert_eq!(None, updated_incident.resolved_at);
+        }
+        QueryResult::NotFound => panic!("Failed to get incident: Incident not found"),
+        QueryResult::Err(error) => panic!("Failed to get incident: {}", error),
+    }
+
+    // Resolve the incident manually.
+    incident_update.status = IncidentStatus::Resolved;
+    incident_update.message = String::from("The database was restarted");
+    match incident_service.insert_update(&incident_update).await {
+        InsertionResult::Ok(_) => {}
+        InsertionResult::Invalid(details) => panic!("Failed to post update: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to post update: {}", error),
+    }
+    match incident_service.get(&incident.id).await {
+        QueryResult::Ok(updated_incident) => {
+            assert_eq!(IncidentStatus::Resolved, updated_incident.status);
+            assert!(updated_incident.resolved_at.is_some());
+            assert!(updated_incident.duration.is_some());
+        }
+        QueryResult::NotFound => panic!("Failed to get incident: Incident not found"),
+        QueryResult::Err(error) => panic!("Failed to get incident: {}", error),
+    }
+
+    // Make sure the resolved incident cannot be updated again.
+    incident_update.status = IncidentStatus::Monitoring;
+    match incident_service.insert_update(&incident_update).await {
+        InsertionResult::Ok(_) => panic!("Update was posted to a resolved incident"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!("Failed to post update: {}", error),
+    }
+
+    // Delete the test user (their monitor and incidents will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
@@ -0,0 +1,66 @@
+use crate::{
+    config::MailConfig, feature::mail::service::MailService, injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use std::{sync::Arc, time::Duration};
+use tokio::time::{interval, MissedTickBehavior};
+
+/// A mail delivery worker struct, which periodically sends the emails that are queued, so that an
+/// smtp server outage never blocks the code that queued them.
+pub(crate) struct MailDeliveryWorker {
+    /// The dependency injector that will be used to acquire service instances.
+    dependency_injector: Arc<DependencyInjector>,
+
+    /// The mail configuration.
+    config: MailConfig,
+}
+
+/// An implementation for the MailDeliveryWorker struct.
+impl MailDeliveryWorker {
+    /// # Description
+    ///
+    /// Create a new mail delivery worker.
+    ///
+    /// # Arguments
+    ///
+    /// `dependency_injector` - The dependency injector that will be used to acquire service
+    /// instances.
+    ///
+    /// `config` - The mail configuration.
+    ///
+    /// # Returns
+    ///
+    /// The mail delivery worker that was created.
+    pub(crate) fn new(dependency_injector: Arc<DependencyInjector>, config: MailConfig) -> Self {
+        return MailDeliveryWorker {
+            dependency_injector,
+            config,
+        };
+    }
+
+    /// # Description
+    ///
+    /// Run the mail delivery worker. Each interval, the worker will attempt to send each of the
+    /// queued emails that are due.
+    ///
+    /// ### Note: This function never returns, it should be spawned as a background task.
+    pub(crate) async fn run(self) {
+        // Create the interval.
+        let mut ticks = interval(Duration::from_secs(self.config.interval));
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        log::info!("Starting the mail delivery worker");
+
+        loop {
+            ticks.tick().await;
+
+            // Get a mail service instance.
+            let mail_service: &dyn MailService = self.dependency_injector.resolve_ref();
+
+            // Send the queued emails.
+            if let Err(error) = mail_service.send_pending().await {
+                log::error!("Failed to send the queued emails: {}", error);
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    config::{MailConfig, MailSecurity},
+    feature::mail::model::Email,
+};
+use async_trait::async_trait;
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use shaku::{Component, Interface};
+use std::{error::Error, time::Duration};
+
+/// A mailer trait.
+#[async_trait(?Send)]
+pub(crate) trait Mailer: Interface {
+    /// # Description
+    ///
+    /// Send an email to its recipient.
+    ///
+    /// ### Note: This sends the email immediately, emails should usually be queued with the mail
+    /// service instead so that they are retried if they can not be sent.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email that will be sent.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was accepted by the smtp server, the Ok variant will be returned.
+    /// - If the email could not be built or was not accepted by the smtp server, the Err variant
+    /// will be returned with the error that occurred.
+    async fn send(&self, email: &Email) -> Result<(), Box<dyn Error>>;
+}
+
+/// A MailerImpl struct, which sends emails through an smtp server.
+#[derive(Component)]
+#[shaku(interface = Mailer)]
+pub(crate) struct MailerImpl {
+    /// The transport that will be used to send emails to the smtp server.
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+
+    /// The mailbox emails will be sent from.
+    sender: Mailbox,
+}
+
+/// A Mailer implementation for the MailerImpl struct.
+#[async_trait(?Send)]
+impl Mailer for MailerImpl {
+    async fn send(&self, email: &Email) -> Result<(), Box<dyn Error>> {
+        // Build the message.
+        let message: Message = Message::builder()
+            .from(__self.sender.clone())
+            .to(email.recipient.parse()?)
+            .subject(&email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body.clone())?;
+
+        // Send the message.
+        __self.transport.send(message).await?;
+
+        return Ok(());
+    }
+}
+
+/// An implementation for the MailerImpl struct.
+impl MailerImpl {
+    /// # Description
+    ///
+    /// Create mailer parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `config` - The mail configuration that determines which smtp server emails are sent through.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the configuration is valid, the Ok variant will be returned with the mailer parameters
+    /// that were created.
+    /// - If the configuration's sender is not a valid mailbox, or its host is not a valid domain
+    /// for tls, the Err variant will be returned with the error that occurred.
+    pub(crate) fn create_parameters(
+        config: &MailConfig,
+    ) -> Result<MailerImplParameters, Box<dyn Error>> {
+        return Ok(MailerImplParameters {
+            transport: create_transport(config)?,
+            sender: config.sender.parse()?,
+        });
+    }
+}
+
+/// # Description
+///
+/// Create the transport that will be used to send emails to an smtp server. Connections are only
+/// opened when emails are sent, so this succeeds even if the smtp server is unavailable.
+///
+/// # Arguments
+///
+/// `config` - The mail configuration that determines which smtp server emails are sent through.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the transport was created, the Ok variant will be returned with the transport.
+/// - If the configuration's host is not a valid domain for tls, the Err variant will be returned
+/// with the error that occurred.
+fn create_transport(
+    config: &MailConfig,
+) -> Result<AsyncSmtpTransport<Tokio1Executor>, Box<dyn Error>> {
+    let mut builder = match config.security {
+        MailSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        MailSecurity::StartTls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)?
+        }
+        MailSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)?,
+    };
+    builder = builder
+        .port(config.port)
+        .timeout(Some(Duration::from_secs(config.timeout)));
+
+    // Authenticate with the smtp server if credentials were configured.
+    if let (Some(username), Some(password)) = (&config.username, &config.password) {
+        builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+    }
+
+    return Ok(builder.build());
+}
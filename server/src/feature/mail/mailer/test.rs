@@ -0,0 +1,185 @@
+use crate::{
+    config::{MailConfig, MailSecurity},
+    feature::mail::{
+        mailer::{create_transport, Mailer, MailerImpl},
+        model::Email,
+    },
+};
+use std::{
+    error::Error,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+
+/// # Description
+///
+/// Start a local stand-in smtp sink that emails can be sent to. The sink accepts every message it
+/// receives, unless it was started to reject recipients.
+///
+/// # Arguments
+///
+/// `rejects_recipients` - If the sink will reject every recipient.
+///
+/// # Panics
+///
+/// This function will panic if the sink could not be bound to a local port.
+///
+/// # Returns
+///
+/// A tuple containing the port the sink is listening on, and the raw messages the sink has
+/// accepted.
+async fn start_stand_in_sink(rejects_recipients: bool) -> (u16, Arc<Mutex<Vec<String>>>) {
+    // Bind a listener to a random local port.
+    let listener: TcpListener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind stand-in sink");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in sink address")
+        .port();
+
+    // Accept connections, and record the messages that are sent.
+    let messages: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let accepted_messages: Arc<Mutex<Vec<String>>> = messages.clone();
+    actix_web::rt::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            let mut message: Option<String> = None;
+
+            let _ = writer.write_all(b"220 localhost ESMTP\r\n").await;
+            while let Ok(Some(line)) = lines.next_line().await {
+                // Collect the message's content until it is terminated.
+                if let Some(content) = &mut message {
+                    if line == "." {
+                        accepted_messages.lock().unwrap().push(content.clone());
+                        message = None;
+                        let _ = writer.write_all(b"250 Accepted\r\n").await;
+                    } else {
+                        content.push_str(&line);
+                        content.push('\n');
+                    }
+                    continue;
+                }
+
+                let command: String = line.to_uppercase();
+                let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                    b"250 localhost\r\n"
+                } else if command.starts_with("RCPT") && rejects_recipients {
+                    b"550 No such user\r\n"
+                } else if command.starts_with("DATA") {
+                    message = Some(String::new());
+                    b"354 End data with <CR><LF>.<CR><LF>\r\n"
+                } else if command.starts_with("QUIT") {
+                    let _ = writer.write_all(b"221 Bye\r\n").await;
+                    break;
+                } else {
+                    b"250 OK\r\n"
+                };
+                let _ = writer.write_all(reply).await;
+            }
+        }
+    });
+
+    // Return the sink's port and the messages it accepts.
+    return (port, messages);
+}
+
+/// # Description
+///
+/// Create a mailer that sends emails to a local port.
+///
+/// # Arguments
+///
+/// `port` - The local port emails will be sent to.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the mailer was created, the Ok variant will be returned with the mailer.
+/// - If the mailer's configuration was not valid, the Err variant will be returned with the error
+/// that occurred.
+fn create_test_mailer(port: u16) -> Result<MailerImpl, Box<dyn Error>> {
+    let config: MailConfig = MailConfig {
+        host: String::from("127.0.0.1"),
+        port,
+        security: MailSecurity::None,
+        username: None,
+        password: None,
+        sender: String::from("EasyTracker <noreply@localhost>"),
+        timeout: 5,
+        interval: 5,
+        maximum_attempts: 8,
+    };
+
+    return Ok(MailerImpl {
+        transport: create_transport(&config)?,
+        sender: config.sender.parse()?,
+    });
+}
+
+/// # Description
+///
+/// Create an email that can be used for testing.
+///
+/// # Returns
+///
+/// The email that was created.
+fn create_test_email() -> Email {
+    return Email {
+        recipient: String::from("user@example.com"),
+        subject: String::from("Website is down"),
+        body: String::from("Website stopped responding."),
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Test sending an email to a stand-in smtp sink, and make sure the sink receives the message.
+#[actix_web::test]
+async fn emails_are_sent_to_the_smtp_server() {
+    // Start the stand-in sink.
+    let (port, messages) = start_stand_in_sink(false).await;
+
+    // Send the email.
+    let mailer: MailerImpl = create_test_mailer(port).expect("Failed to create mailer");
+    mailer
+        .send(&create_test_email())
+        .await
+        .expect("Failed to send email");
+
+    // Make sure the sink received the message.
+    let messages: Vec<String> = messages.lock().unwrap().clone();
+    assert_eq!(1, messages.len());
+    assert!(messages[0].contains("From: EasyTracker <noreply@localhost>"));
+    assert!(messages[0].contains("To: user@example.com"));
+    assert!(messages[0].contains("Subject: Website is down"));
+    assert!(messages[0].contains("Website stopped responding."));
+}
+
+/// # Description
+///
+/// Test sending emails that are rejected or can not be sent, and make sure errors are returned.
+#[actix_web::test]
+async fn rejected_emails_are_errors() {
+    // Make sure an email whose recipient is rejected is an error.
+    let (port, messages) = start_stand_in_sink(true).await;
+    let mailer: MailerImpl = create_test_mailer(port).expect("Failed to create mailer");
+    assert!(mailer.send(&create_test_email()).await.is_err());
+    assert!(messages.lock().unwrap().is_empty());
+
+    // Make sure an email with an invalid recipient is an error.
+    let email: Email = Email {
+        recipient: String::from("not an email address"),
+        ..create_test_email()
+    };
+    assert!(mailer.send(&email).await.is_err());
+
+    // Make sure an smtp server that can not be reached is an error.
+    let mailer: MailerImpl = create_test_mailer(1).expect("Failed to create mailer");
+    assert!(mailer.send(&create_test_email()).await.is_err());
+}
@@ -0,0 +1,6 @@
+pub(crate) mod delivery;
+pub(crate) mod mailer;
+pub(crate) mod model;
+pub(crate) mod repository;
+pub(crate) mod service;
+pub(crate) mod template;
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use validator::Validate;
+
+/// An email struct, which is a message that is queued to be sent to a recipient.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Email {
+    /// The email's unique identifier.
+    pub(crate) id: u64,
+
+    /// The date and time the email was queued at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) email_created_at: OffsetDateTime,
+
+    /// The email address the email will be sent to.
+    #[validate(non_control_character, email, length(min = 5, max = 256))]
+    pub(crate) recipient: String,
+
+    /// The email's subject.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) subject: String,
+
+    /// The email's plain text body.
+    #[validate(length(min = 1, max = 65535))]
+    pub(crate) body: String,
+
+    /// The email's current status.
+    pub(crate) status: EmailStatus,
+
+    /// The amount of times sending the email has been attempted.
+    pub(crate) attempt_count: u32,
+
+    /// The date and time the next attempt to send the email is due at, if it is still pending.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(crate) next_attempt_at: Option<OffsetDateTime>,
+
+    /// A human readable description of the error that caused the last attempt to fail, if it
+    /// failed.
+    pub(crate) error_message: Option<String>,
+}
+
+/// A Default implementation for the Email struct.
+impl Default for Email {
+    fn default() -> Self {
+        return Email {
+            id: 0,
+            email_created_at: OffsetDateTime::now_utc(),
+            recipient: String::from(""),
+            subject: String::from(""),
+            body: String::from(""),
+            status: EmailStatus::Pending,
+            attempt_count: 0,
+            next_attempt_at: None,
+            error_message: None,
+        };
+    }
+}
+
+/// An enumeration of all of the statuses an email can be in.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum EmailStatus {
+    /// # Description
+    ///
+    /// The Pending variant is used for emails that are waiting to be sent.
+    Pending,
+
+    /// # Description
+    ///
+    /// The Sent variant is used for emails that were accepted by the smtp server.
+    Sent,
+
+    /// # Description
+    ///
+    /// The Failed variant is used for emails that were not accepted after every attempt was used.
+    Failed,
+}
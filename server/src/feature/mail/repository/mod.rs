@@ -0,0 +1,261 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::mail::model::{Email, EmailStatus},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// An email repository trait.
+#[async_trait]
+pub(crate) trait EmailRepository: Interface {
+    /// # Description
+    ///
+    /// Insert an email into the email repository.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email to insert into the email repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the email
+    /// that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, email: &Email, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get an email from the email repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the email to get from the email repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional email.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Email>, Error>;
+
+    /// # Description
+    ///
+    /// Get the pending emails that are due to be sent from the email repository.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `limit` - The maximum amount of emails to get.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the emails that were
+    /// found, ordered from the longest overdue to the most recently due.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_due(
+        &self,
+        now: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Email>, Error>;
+
+    /// # Description
+    ///
+    /// Update an email in the email repository.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email to update in the email repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(&self, email: &Email, context: &mut QueryContext) -> Result<u64, Error>;
+}
+
+/// An EmailRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = EmailRepository)]
+pub(crate) struct EmailRepositoryImpl;
+
+/// An EmailRepository implementation for the EmailRepositoryImpl struct.
+#[async_trait]
+impl EmailRepository for EmailRepositoryImpl {
+    async fn insert(&self, email: &Email, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `emails` (
+                    `id`,
+                    `email_created_at`,
+                    `recipient`,
+                    `subject`,
+                    `body`,
+                    `status`,
+                    `attempt_count`,
+                    `next_attempt_at`,
+                    `error_message`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            email.id,
+            email.email_created_at,
+            email.recipient,
+            email.subject,
+            email.body,
+            email.status,
+            email.attempt_count,
+            email.next_attempt_at,
+            email.error_message
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Email>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Email,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `email_created_at` AS `email_created_at: OffsetDateTime`,
+                    `recipient` AS `recipient: String`,
+                    `subject` AS `subject: String`,
+                    `body` AS `body: String`,
+                    `status` AS `status: EmailStatus`,
+                    `attempt_count` AS `attempt_count: u32`,
+                    `next_attempt_at` AS `next_attempt_at: OffsetDateTime`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `emails`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_due(
+        &self,
+        now: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Email>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Email,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `email_created_at` AS `email_created_at: OffsetDateTime`,
+                    `recipient` AS `recipient: String`,
+                    `subject` AS `subject: String`,
+                    `body` AS `body: String`,
+                    `status` AS `status: EmailStatus`,
+                    `attempt_count` AS `attempt_count: u32`,
+                    `next_attempt_at` AS `next_attempt_at: OffsetDateTime`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `emails`
+                WHERE
+                    `status` = 'pending'
+                    AND `next_attempt_at` <= ?
+                ORDER BY
+                    `next_attempt_at` ASC,
+                    `id` ASC
+                LIMIT ?;
+            "#,
+            now,
+            limit
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(&self, email: &Email, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `emails`
+                SET
+                    `email_created_at` = ?,
+                    `recipient` = ?,
+                    `subject` = ?,
+                    `body` = ?,
+                    `status` = ?,
+                    `attempt_count` = ?,
+                    `next_attempt_at` = ?,
+                    `error_message` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            email.email_created_at,
+            email.recipient,
+            email.subject,
+            email.body,
+            email.status,
+            email.attempt_count,
+            email.next_attempt_at,
+            email.error_message,
+            email.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{
+    common::enumeration::QueryContext,
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::mail::{
+        model::{Email, EmailStatus},
+        repository::EmailRepository,
+    },
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create an email that can be used for testing.
+///
+/// # Returns
+///
+/// The email that was created, which is overdue to be sent.
+fn create_test_email() -> Email {
+    return Email {
+        id: 0,
+        email_created_at: OffsetDateTime::now_utc(),
+        recipient: String::from("user@example.com"),
+        subject: String::from("Website is down"),
+        body: String::from("Website stopped responding."),
+        status: EmailStatus::Pending,
+        attempt_count: 0,
+        next_attempt_at: Some(OffsetDateTime::now_utc() - Duration::MINUTE),
+        error_message: None,
+    };
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a maintenance window into the repository, and make sure it can be queried by its
+/// id, owner and monitors until it is deleted.
+/// # Description
+///
+/// Test inserting an email into the repository, and make sure it is due until it is sent.
+#[actix_web::test]
+async fn email_is_due_until_sent() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an email repository instance.
+    let email_repository: Arc<dyn EmailRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert the email.
+    let mut email: Email = create_test_email();
+    email.id = email_repository
+        .insert(&email, &mut context)
+        .await
+        .expect("Failed to insert email");
+    assert!(email.id > 0);
+
+    // Make sure the email is due.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    assert!(email_repository
+        .get_due(&now, &1000, &mut context)
+        .await
+        .expect("Failed to query due emails")
+        .iter()
+        .any(|due_email| due_email.id == email.id));
+
+    // Mark the email as sent, and make sure it is no longer due.
+    email.status = EmailStatus::Sent;
+    email.attempt_count = 1;
+    email.next_attempt_at = None;
+    let rows_updated = email_repository
+        .update(&email, &mut context)
+        .await
+        .expect("Failed to update email");
+    assert_eq!(1, rows_updated);
+    assert!(!email_repository
+        .get_due(&now, &1000, &mut context)
+        .await
+        .expect("Failed to query due emails")
+        .iter()
+        .any(|due_email| due_email.id == email.id));
+
+    // Make sure the changes were persisted.
+    let queried_email: Email = email_repository
+        .get(&email.id, &mut context)
+        .await
+        .expect("Failed to query email: An error occurred")
+        .expect("Failed to query email: Email not found");
+    assert_eq!(EmailStatus::Sent, queried_email.status);
+    assert_eq!(1, queried_email.attempt_count);
+    assert_eq!(None, queried_email.next_attempt_at);
+    assert_eq!(email.recipient, queried_email.recipient);
+    assert_eq!(email.subject, queried_email.subject);
+    assert_eq!(email.body, queried_email.body);
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
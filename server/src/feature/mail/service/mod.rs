@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext},
+        utility::get_retry_delay,
+    },
+    config::MailConfig,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::checker::create_error_message,
+        mail::{
+            mailer::Mailer,
+            model::{Email, EmailStatus},
+            repository::EmailRepository,
+        },
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use std::{error::Error, io, sync::Arc};
+use time::OffsetDateTime;
+use validator::{Validate, ValidationErrors};
+
+/// The maximum amount of emails that are sent each time pending emails are sent.
+const SEND_BATCH_SIZE: u32 = 100;
+
+/// A mail service trait.
+#[async_trait(?Send)]
+pub(crate) trait MailService: Interface {
+    /// # Description
+    ///
+    /// Queue an email to be sent by the mail worker, in the context specified, so that the email is
+    /// only sent if the context's transaction is committed.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email that will be queued.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the email was queued, the Ok variant will be returned with the email that was queued.
+    /// - If the email contains validation errors, the Invalid variant will be returned with the
+    /// validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn queue_with_context(
+        &self,
+        email: &Email,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Email, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Send the pending emails that are due, and schedule a retry of each email that could not be
+    /// sent.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the due emails were processed, the Ok variant will be returned. Emails that could not be
+    /// sent are not errors, since they are retried.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_pending(&self) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Send the pending emails that are due, and schedule a retry of each email that could not be
+    /// sent, in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `context` - The context the queries will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the due emails were processed, the Ok variant will be returned. Emails that could not be
+    /// sent are not errors, since they are retried.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_pending_with_context(
+        &self,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>>;
+}
+
+/// A MailServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = MailService)]
+pub(crate) struct MailServiceImpl {
+    /// The email repository that will be used to manage persistent email data.
+    #[shaku(inject)]
+    email_repository: Arc<dyn EmailRepository>,
+
+    /// The mailer that will be used to send emails.
+    #[shaku(inject)]
+    mailer: Arc<dyn Mailer>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The mail configuration that determines how many times emails are attempted.
+    mail_config: MailConfig,
+}
+
+/// A MailService implementation for the MailServiceImpl struct.
+#[async_trait(?Send)]
+impl MailService for MailServiceImpl {
+    async fn queue_with_context(
+        &self,
+        email: &Email,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Email, ValidationErrors, Box<dyn Error>> {
+        // Validate the email.
+        if let Err(validation_errors) = email.validate() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Queue the email to be sent as soon as possible.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let queued_email: Email = Email {
+            id: 0,
+            email_created_at: now,
+            status: EmailStatus::Pending,
+            attempt_count: 0,
+            next_attempt_at: Some(now),
+            error_message: None,
+            ..email.clone()
+        };
+
+        // Perform the insertion.
+        let email_id = match __self.email_repository.insert(&queued_email, context).await {
+            Ok(email_id) => email_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the email that was inserted.
+        let inserted_email_option = match __self.email_repository.get(&email_id, context).await {
+            Ok(inserted_email_option) => inserted_email_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the email was found, return the email, otherwise return an error.
+        return match inserted_email_option {
+            Some(inserted_email) => InsertionResult::Ok(inserted_email),
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Email could not be found after insertion",
+            ))),
+        };
+    }
+
+    async fn send_pending(&self) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Send the emails.
+        return self
+            .send_pending_with_context(&OffsetDateTime::now_utc(), &mut context)
+            .await;
+    }
+
+    async fn send_pending_with_context(
+        &self,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>> {
+        // Query the emails that are due to be sent.
+        let emails: Vec<Email> = __self
+            .email_repository
+            .get_due(now, &SEND_BATCH_SIZE, context)
+            .await?;
+
+        for mut email in emails {
+            // Send the email, and complete it or schedule its next attempt.
+            let send_result: Result<(), Box<dyn Error>> = __self.mailer.send(&email).await;
+            self.record_attempt(&mut email, send_result, OffsetDateTime::now_utc());
+            __self.email_repository.update(&email, context).await?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// An implementation for the MailServiceImpl struct.
+impl MailServiceImpl {
+    /// # Description
+    ///
+    /// Create mail service parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `mail_config` - The mail configuration that determines how many times emails are attempted.
+    ///
+    /// # Returns
+    ///
+    /// The mail service parameters that were created.
+    pub(crate) fn create_parameters(mail_config: MailConfig) -> MailServiceImplParameters {
+        return MailServiceImplParameters { mail_config };
+    }
+
+    /// # Description
+    ///
+    /// Update an email with the outcome of an attempt to send it. A successful attempt completes the
+    /// email, and a failed attempt schedules a retry unless every attempt has been used.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email that was attempted.
+    ///
+    /// `send_result` - The outcome of the attempt.
+    ///
+    /// `attempted_at` - The date and time the attempt was made at.
+    fn record_attempt(
+        &self,
+        email: &mut Email,
+        send_result: Result<(), Box<dyn Error>>,
+        attempted_at: OffsetDateTime,
+    ) {
+        email.attempt_count += 1;
+
+        match send_result {
+            Ok(()) => {
+                email.status = EmailStatus::Sent;
+                email.next_attempt_at = None;
+                email.error_message = None;
+            }
+            Err(error) => {
+                log::warn!("Failed to send email {}: {}", email.id, error);
+                email.error_message = Some(create_error_message(error.as_ref()));
+                if email.attempt_count >= self.mail_config.maximum_attempts {
+                    email.status = EmailStatus::Failed;
+                    email.next_attempt_at = None;
+                } else {
+                    email.next_attempt_at =
+                        Some(attempted_at + get_retry_delay(email.attempt_count));
+                }
+            }
+        }
+    }
+}
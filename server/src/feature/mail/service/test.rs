@@ -0,0 +1,135 @@
+use crate::{
+    common::enumeration::{InsertionResult, QueryContext},
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::mail::{
+        model::{Email, EmailStatus},
+        repository::EmailRepository,
+        service::MailService,
+    },
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use sqlx::Connection;
+use std::sync::Arc;
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test queueing emails, and make sure invalid emails are rejected while valid emails are queued to
+/// be sent immediately.
+#[actix_web::test]
+async fn emails_are_validated_and_queued() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a mail service and email repository instance.
+    let mail_service: Arc<dyn MailService> = injector.resolve();
+    let email_repository: Arc<dyn EmailRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+    let mut connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Make sure an email with an invalid recipient is rejected.
+    let mut email: Email = Email {
+        recipient: String::from("not an email address"),
+        subject: String::from("Website is down"),
+        body: String::from("Website stopped responding."),
+        ..Default::default()
+    };
+    match mail_service.queue_with_context(&email, &mut context).await {
+        InsertionResult::Ok(_) => panic!("Queueing succeeded when it should have failed"),
+        InsertionResult::Invalid(details) => {
+            assert!(details.field_errors().contains_key("recipient"))
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to queue email, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure an email with a valid recipient is queued to be sent immediately.
+    email.recipient = String::from("user@example.com");
+    let queued_email: Email = match mail_service.queue_with_context(&email, &mut context).await {
+        InsertionResult::Ok(queued_email) => queued_email,
+        InsertionResult::Invalid(details) => panic!("Failed to queue email: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to queue email: {}", error),
+    };
+    assert_eq!(EmailStatus::Pending, queued_email.status);
+    assert_eq!(0, queued_email.attempt_count);
+    assert!(email_repository
+        .get_due(
+            &queued_email
+                .next_attempt_at
+                .expect("Queued email is not due"),
+            &1000,
+            &mut context
+        )
+        .await
+        .expect("Failed to query due emails")
+        .iter()
+        .any(|due_email| due_email.id == queued_email.id));
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
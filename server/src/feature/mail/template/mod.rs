@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::model::CheckErrorKind,
+    mail::model::Email,
+    notification::model::{MonitorEventKind, MonitorEventPayload},
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+/// The prefix that is added to the subject of every alert email.
+const SUBJECT_PREFIX: &str = "[EasyTracker]";
+
+/// # Description
+///
+/// Create the email that alerts a recipient of an event a monitor produced. Monitors going down,
+/// recovering and having their certificate start expiring each have their own template.
+///
+/// # Arguments
+///
+/// `recipient` - The email address the alert will be sent to.
+///
+/// `payload` - The payload that describes the event.
+///
+/// # Returns
+///
+/// The alert email that was created.
+pub(crate) fn create_alert_email(recipient: &str, payload: &MonitorEventPayload) -> Email {
+    // Describe the event.
+    let headline: String = match (payload.event, payload.error_kind) {
+        (MonitorEventKind::Down, _) => format!("{} is down", payload.monitor_name),
+        (MonitorEventKind::Recovered, _) => format!("{} has recovered", payload.monitor_name),
+        (MonitorEventKind::Degraded, Some(CheckErrorKind::CertificateExpiring)) => {
+            format!("The certificate of {} is expiring", payload.monitor_name)
+        }
+        (MonitorEventKind::Degraded, _) => format!("{} is degraded", payload.monitor_name),
+    };
+
+    // Describe the details of the check that produced the event.
+    let mut body: String = format!("{}.\n\n", headline);
+    body.push_str(&format!("Monitor: {}\n", payload.monitor_name));
+    body.push_str(&format!(
+        "Occurred at: {}\n",
+        payload.occurred_at.format(&Rfc3339).unwrap_or_default()
+    ));
+    if let Some(error_message) = &payload.error_message {
+        body.push_str(&format!("Error: {}\n", error_message));
+    }
+    if let Some(incident_id) = payload.incident_id {
+        body.push_str(&format!("Incident: #{}\n", incident_id));
+    }
+    body.push_str(
+        "\nYou are receiving this email because the monitor sends its alerts to one of your \
+        notification channels.\n",
+    );
+
+    // Return the email.
+    return Email {
+        recipient: String::from(recipient),
+        subject: format!("{} {}", SUBJECT_PREFIX, headline),
+        body,
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Create the email that asks a user to verify their email address.
+///
+/// # Arguments
+///
+/// `recipient` - The email address that is being verified.
+///
+/// `username` - The username of the user the email address belongs to.
+///
+/// `link` - The link the user must open to verify their email address.
+///
+/// `expires_at` - The date and time the link expires at.
+///
+/// # Returns
+///
+/// The verification email that was created.
+pub(crate) fn create_email_verification_email(
+    recipient: &str,
+    username: &str,
+    link: &str,
+    expires_at: &OffsetDateTime,
+) -> Email {
+    // Describe how to verify the email address.
+    let mut body: String = format!("Hi {},\n\n", username);
+    body.push_str("Please verify your email address by opening the link below:\n\n");
+    body.push_str(&format!("{}\n\n", link));
+    body.push_str(&format!(
+        "The link can only be used once and expires at {}.\n\n",
+        expires_at.format(&Rfc3339).unwrap_or_default()
+    ));
+    body.push_str(
+        "If you did not create an EasyTracker account or change your email address, you can \
+        ignore this email.\n",
+    );
+
+    // Return the email.
+    return Email {
+        recipient: String::from(recipient),
+        subject: format!("{} Verify your email address", SUBJECT_PREFIX),
+        body,
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Create the email that lets a user reset their password.
+///
+/// # Arguments
+///
+/// `recipient` - The email address of the user.
+///
+/// `username` - The username of the user.
+///
+/// `link` - The link the user must open to reset their password.
+///
+/// `expires_at` - The date and time the link expires at.
+///
+/// # Returns
+///
+/// The password reset email that was created.
+pub(crate) fn create_password_reset_email(
+    recipient: &str,
+    username: &str,
+    link: &str,
+    expires_at: &OffsetDateTime,
+) -> Email {
+    // Describe how to reset the password.
+    let mut body: String = format!("Hi {},\n\n", username);
+    body.push_str(
+        "A password reset was requested for your account. Open the link below to choose a new \
+        password:\n\n",
+    );
+    body.push_str(&format!("{}\n\n", link));
+    body.push_str(&format!(
+        "The link can only be used once and expires at {}. Resetting your password signs you out \
+        everywhere.\n\n",
+        expires_at.format(&Rfc3339).unwrap_or_default()
+    ));
+    body.push_str(
+        "If you did not request a password reset, you can ignore this email and your password will \
+        not change.\n",
+    );
+
+    // Return the email.
+    return Email {
+        recipient: String::from(recipient),
+        subject: format!("{} Reset your password", SUBJECT_PREFIX),
+        body,
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Create the email that tells a user their account was locked because of failed logins, and lets
+/// them unlock it.
+///
+/// # Arguments
+///
+/// `recipient` - The email address of the user.
+///
+/// `username` - The username of the user.
+///
+/// `link` - The link the user can open to unlock their account.
+///
+/// `unlocks_at` - The date and time the account is automatically unlocked at.
+///
+/// # Returns
+///
+/// The account unlock email that was created.
+pub(crate) fn create_account_unlock_email(
+    recipient: &str,
+    username: &str,
+    link: &str,
+    unlocks_at: &OffsetDateTime,
+) -> Email {
+    // Describe why the account was locked, and how to unlock it.
+    let mut body: String = format!("Hi {},\n\n", username);
+    body.push_str(&format!(
+        "Your account was locked because of too many failed login attempts. It will be unlocked \
+        automatically at {}, or you can unlock it now by opening the link below:\n\n",
+        unlocks_at.format(&Rfc3339).unwrap_or_default()
+    ));
+    body.push_str(&format!("{}\n\n", link));
+    body.push_str(
+        "If the failed login attempts were not made by you, someone may be trying to guess your \
+        password. Consider changing it once your account is unlocked.\n",
+    );
+
+    // Return the email.
+    return Email {
+        recipient: String::from(recipient),
+        subject: format!("{} Your account was locked", SUBJECT_PREFIX),
+        body,
+        ..Default::default()
+    };
+}
@@ -0,0 +1,172 @@
+use crate::feature::{
+    check::model::{CheckErrorKind, CheckStatus},
+    mail::{
+        model::Email,
+        template::{
+            create_account_unlock_email, create_alert_email, create_email_verification_email,
+            create_password_reset_email,
+        },
+    },
+    notification::model::{MonitorEventKind, MonitorEventPayload, PAYLOAD_VERSION},
+};
+use time::macros::datetime;
+
+/// # Description
+///
+/// Create an event payload that can be used for testing.
+///
+/// # Arguments
+///
+/// `event` - The event the payload describes.
+///
+/// `status` - The status of the check that produced the event.
+///
+/// # Returns
+///
+/// The event payload that was created.
+fn create_test_payload(event: MonitorEventKind, status: CheckStatus) -> MonitorEventPayload {
+    return MonitorEventPayload {
+        version: PAYLOAD_VERSION,
+        event,
+        occurred_at: datetime!(2026-01-01 12:00 UTC),
+        monitor_id: 7,
+        monitor_name: String::from("Website"),
+        status,
+        previous_status: None,
+        error_kind: None,
+        error_message: None,
+        incident_id: None,
+    };
+}
+
+/// # Description
+///
+/// Test creating the alert email of a monitor that went down, and make sure it describes the error
+/// and incident.
+#[test]
+fn down_alerts_describe_the_error() {
+    let payload: MonitorEventPayload = MonitorEventPayload {
+        error_kind: Some(CheckErrorKind::Timeout),
+        error_message: Some(String::from("The request timed out")),
+        incident_id: Some(3),
+        ..create_test_payload(MonitorEventKind::Down, CheckStatus::Down)
+    };
+
+    let email: Email = create_alert_email("user@example.com", &payload);
+
+    assert_eq!("user@example.com", email.recipient);
+    assert_eq!("[EasyTracker] Website is down", email.subject);
+    assert!(email.body.starts_with("Website is down.\n"));
+    assert!(email.body.contains("Occurred at: 2026-01-01T12:00:00Z\n"));
+    assert!(email.body.contains("Error: The request timed out\n"));
+    assert!(email.body.contains("Incident: #3\n"));
+}
+
+/// # Description
+///
+/// Test creating the alert email of a monitor that recovered, and make sure it does not describe an
+/// error.
+#[test]
+fn recovered_alerts_have_no_error() {
+    let email: Email = create_alert_email(
+        "user@example.com",
+        &create_test_payload(MonitorEventKind::Recovered, CheckStatus::Up),
+    );
+
+    assert_eq!("[EasyTracker] Website has recovered", email.subject);
+    assert!(!email.body.contains("Error:"));
+}
+
+/// # Description
+///
+/// Test creating the alert emails of degraded monitors, and make sure expiring certificates have
+/// their own template.
+#[test]
+fn expiring_certificates_have_their_own_alert() {
+    let payload: MonitorEventPayload = MonitorEventPayload {
+        error_kind: Some(CheckErrorKind::CertificateExpiring),
+        error_message: Some(String::from("Certificate expires in 3 days")),
+        ..create_test_payload(MonitorEventKind::Degraded, CheckStatus::Degraded)
+    };
+    let email: Email = create_alert_email("user@example.com", &payload);
+    assert_eq!(
+        "[EasyTracker] The certificate of Website is expiring",
+        email.subject
+    );
+    assert!(email
+        .body
+        .contains("Error: Certificate expires in 3 days\n"));
+
+    let email: Email = create_alert_email(
+        "user@example.com",
+        &create_test_payload(MonitorEventKind::Degraded, CheckStatus::Degraded),
+    );
+    assert_eq!("[EasyTracker] Website is degraded", email.subject);
+}
+
+/// # Description
+///
+/// Test creating an email verification email, and make sure it contains the link and when it
+/// expires.
+#[test]
+fn email_verification_emails_contain_the_link() {
+    let email: Email = create_email_verification_email(
+        "user@example.com",
+        "user",
+        "https://localhost:5000/verify-email?token=abc",
+        &datetime!(2026-01-02 12:00 UTC),
+    );
+
+    assert_eq!("user@example.com", email.recipient);
+    assert_eq!("[EasyTracker] Verify your email address", email.subject);
+    assert!(email.body.starts_with("Hi user,\n"));
+    assert!(email
+        .body
+        .contains("\nhttps://localhost:5000/verify-email?token=abc\n"));
+    assert!(email.body.contains("expires at 2026-01-02T12:00:00Z."));
+}
+
+/// # Description
+///
+/// Test creating a password reset email, and make sure it contains the link and when it expires.
+#[test]
+fn password_reset_emails_contain_the_link() {
+    let email: Email = create_password_reset_email(
+        "user@example.com",
+        "user",
+        "https://localhost:5000/reset-password?token=abc",
+        &datetime!(2026-01-02 12:00 UTC),
+    );
+
+    assert_eq!("user@example.com", email.recipient);
+    assert_eq!("[EasyTracker] Reset your password", email.subject);
+    assert!(email.body.starts_with("Hi user,\n"));
+    assert!(email
+        .body
+        .contains("\nhttps://localhost:5000/reset-password?token=abc\n"));
+    assert!(email.body.contains("expires at 2026-01-02T12:00:00Z."));
+}
+
+/// # Description
+///
+/// Test creating an account unlock email, and make sure it contains the link and when the account
+/// is unlocked automatically.
+#[test]
+fn account_unlock_emails_contain_the_link() {
+    let email: Email = create_account_unlock_email(
+        "user@example.com",
+        "user",
+        "https://localhost:5000/unlock-account?token=abc",
+        &datetime!(2026-01-02 12:00 UTC),
+    );
+
+    assert_eq!("user@example.com", email.recipient);
+    assert_eq!("[EasyTracker] Your account was locked", email.subject);
+    assert!(email.body.starts_with("Hi user,\n"));
+    assert!(email
+        .body
+        .contains("\nhttps://localhost:5000/unlock-account?token=abc\n"));
+    assert!(email
+        .body
+        .contains("automatically at 2026-01-02T12:00:00Z,"));
+}
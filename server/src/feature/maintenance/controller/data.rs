@@ -0,0 +1,128 @@
+use crate::feature::maintenance::model::{MaintenanceFrequency, MaintenanceWindow};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+
+/// A create maintenance window request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateMaintenanceWindowRequestBody {
+    /// The maintenance window's name.
+    pub(super) name: String,
+
+    /// The date and time the first occurrence of the maintenance window starts at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(super) starts_at: OffsetDateTime,
+
+    /// The amount of time (in seconds) each occurrence of the maintenance window lasts for.
+    pub(super) duration: u32,
+
+    /// How often the maintenance window recurs.
+    pub(super) frequency: Option<MaintenanceFrequency>,
+
+    /// The amount of weeks or months between each occurrence of a recurring maintenance window.
+    pub(super) recurrence_interval: Option<u32>,
+
+    /// The date and time after which a recurring maintenance window no longer occurs.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) recurrence_ends_at: Option<OffsetDateTime>,
+
+    /// The unique identifiers of the monitors the maintenance window applies to.
+    pub(super) monitor_ids: Vec<u64>,
+}
+
+/// An Into<MaintenanceWindow> implementation for the CreateMaintenanceWindowRequestBody struct.
+impl Into<MaintenanceWindow> for CreateMaintenanceWindowRequestBody {
+    fn into(self) -> MaintenanceWindow {
+        return MaintenanceWindow {
+            name: self.name,
+            starts_at: self.starts_at,
+            duration: self.duration,
+            frequency: self.frequency.unwrap_or(MaintenanceFrequency::Once),
+            recurrence_interval: self.recurrence_interval.unwrap_or(1),
+            recurrence_ends_at: self.recurrence_ends_at,
+            monitor_ids: Json(self.monitor_ids),
+            ..Default::default()
+        };
+    }
+}
+
+/// An update maintenance window request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UpdateMaintenanceWindowRequestBody {
+    /// The maintenance window's name.
+    pub(super) name: Option<String>,
+
+    /// The date and time the first occurrence of the maintenance window starts at.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) starts_at: Option<OffsetDateTime>,
+
+    /// The amount of time (in seconds) each occurrence of the maintenance window lasts for.
+    pub(super) duration: Option<u32>,
+
+    /// How often the maintenance window recurs.
+    pub(super) frequency: Option<MaintenanceFrequency>,
+
+    /// The amount of weeks or months between each occurrence of a recurring maintenance window.
+    pub(super) recurrence_interval: Option<u32>,
+
+    /// The date and time after which a recurring maintenance window no longer occurs.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) recurrence_ends_at: Option<OffsetDateTime>,
+
+    /// If the maintenance window is being changed to recur indefinitely.
+    pub(super) remove_recurrence_end: Option<bool>,
+
+    /// The unique identifiers of the monitors the maintenance window applies to.
+    pub(super) monitor_ids: Option<Vec<u64>>,
+}
+
+/// An implementation for the UpdateMaintenanceWindowRequestBody struct.
+impl UpdateMaintenanceWindowRequestBody {
+    /// # Description
+    ///
+    /// Apply the changes in the update maintenance window request body to a maintenance window.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window the changes will be applied to.
+    pub(super) fn apply(&self, maintenance_window: &mut MaintenanceWindow) {
+        // If the maintenance window's name is being updated.
+        if let Some(name) = &self.name {
+            maintenance_window.name = name.clone();
+        }
+
+        // If the maintenance window's start is being updated.
+        if let Some(starts_at) = self.starts_at {
+            maintenance_window.starts_at = starts_at;
+        }
+
+        // If the maintenance window's duration is being updated.
+        if let Some(duration) = self.duration {
+            maintenance_window.duration = duration;
+        }
+
+        // If the maintenance window's frequency is being updated.
+        if let Some(frequency) = self.frequency {
+            maintenance_window.frequency = frequency;
+        }
+
+        // If the maintenance window's recurrence interval is being updated.
+        if let Some(recurrence_interval) = self.recurrence_interval {
+            maintenance_window.recurrence_interval = recurrence_interval;
+        }
+
+        // If the maintenance window's recurrence end is being removed or replaced.
+        if self.remove_recurrence_end == Some(true) {
+            maintenance_window.recurrence_ends_at = None;
+        } else if let Some(recurrence_ends_at) = self.recurrence_ends_at {
+            maintenance_window.recurrence_ends_at = Some(recurrence_ends_at);
+        }
+
+        // If the monitors the maintenance window applies to are being updated.
+        if let Some(monitor_ids) = &self.monitor_ids {
+            maintenance_window.monitor_ids = Json(monitor_ids.clone());
+        }
+    }
+}
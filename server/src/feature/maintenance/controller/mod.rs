@@ -0,0 +1,298 @@
+mod data;
+
+use self::data::{CreateMaintenanceWindowRequestBody, UpdateMaintenanceWindowRequestBody};
+use crate::{
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        api_token::model::ApiTokenScope,
+        auth::service::AuthService,
+        maintenance::{model::MaintenanceWindow, service::MaintenanceWindowService},
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, patch, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the maintenance window controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the maintenance window controller configuration will be
+/// added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/maintenance-windows")
+            .service(create_maintenance_window)
+            .service(get_maintenance_windows)
+            .service(get_maintenance_window)
+            .service(update_maintenance_window)
+            .service(delete_maintenance_window),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to create a maintenance window.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains information about the maintenance window that is being
+/// created.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to create the
+/// maintenance window.
+///
+/// # Returns
+///
+/// An http response.
+#[post("")]
+async fn create_maintenance_window(
+    request: HttpRequest,
+    body: web::Json<CreateMaintenanceWindowRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Convert the request body into a maintenance window.
+    let mut maintenance_window: MaintenanceWindow = body.into_inner().into();
+    maintenance_window.user_id = user.id;
+
+    // Create the maintenance window.
+    return match maintenance_window_service.insert(&maintenance_window).await {
+        InsertionResult::Ok(created_maintenance_window) => {
+            HttpResponse::Ok().json(created_maintenance_window)
+        }
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get all of the maintenance windows that belong to the sending user.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to retrieve the
+/// maintenance windows.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_maintenance_windows(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the maintenance windows that belong to the user.
+    return match maintenance_window_service.get_by_user_id(&user.id).await {
+        QueryResult::Ok(maintenance_windows) => HttpResponse::Ok().json(maintenance_windows),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get a maintenance window.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the maintenance window that is being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to retrieve the
+/// maintenance window.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}")]
+async fn get_maintenance_window(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the maintenance window that is being requested.
+    let maintenance_window: MaintenanceWindow = match maintenance_window_service.get(&id).await {
+        QueryResult::Ok(maintenance_window) => maintenance_window,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the maintenance window, they are not allowed to view it.
+    if user.id != maintenance_window.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Return the maintenance window.
+    return HttpResponse::Ok().json(maintenance_window);
+}
+
+/// # Description
+///
+/// An api endpoint to update a maintenance window.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the maintenance window that is being updated.
+///
+/// `body` - The request body which contains information about the maintenance window that is being
+/// updated.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to update the
+/// maintenance window.
+///
+/// # Returns
+///
+/// An http response.
+#[patch("/{id}")]
+async fn update_maintenance_window(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    body: web::Json<UpdateMaintenanceWindowRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the maintenance window that is being updated.
+    let mut maintenance_window: MaintenanceWindow = match maintenance_window_service.get(&id).await
+    {
+        QueryResult::Ok(maintenance_window) => maintenance_window,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the maintenance window, they are not allowed to update it.
+    if user.id != maintenance_window.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Apply the update to the maintenance window.
+    body.apply(&mut maintenance_window);
+
+    // Update the maintenance window.
+    return match maintenance_window_service.update(&maintenance_window).await {
+        UpdateResult::Ok(maintenance_window) => HttpResponse::Ok().json(maintenance_window),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to delete a maintenance window.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the maintenance window that is being deleted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to delete the
+/// maintenance window.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn delete_maintenance_window(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the maintenance window that is being deleted.
+    let maintenance_window: MaintenanceWindow = match maintenance_window_service.get(&id).await {
+        QueryResult::Ok(maintenance_window) => maintenance_window,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the maintenance window, they are not allowed to delete it.
+    if user.id != maintenance_window.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Delete the maintenance window.
+    return match maintenance_window_service.delete(&id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
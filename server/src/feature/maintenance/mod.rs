@@ -0,0 +1,5 @@
+pub(crate) mod controller;
+pub(crate) mod model;
+pub(crate) mod repository;
+pub(crate) mod schedule;
+pub(crate) mod service;
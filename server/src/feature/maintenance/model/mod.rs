@@ -0,0 +1,196 @@
+use crate::common::utility::create_value_validation_error;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+use validator::{Validate, ValidationError};
+
+/// The maximum amount of monitors a maintenance window can be attached to.
+const MAXIMUM_WINDOW_MONITORS: usize = 100;
+
+/// A maintenance window struct, which is a one-off or recurring period of time during which the
+/// failures of its monitors are expected and are not treated as downtime.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_recurrence"))]
+pub(crate) struct MaintenanceWindow {
+    /// The maintenance window's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the user that the maintenance window belongs to.
+    pub(crate) user_id: u64,
+
+    /// The date and time the maintenance window was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) maintenance_window_created_at: OffsetDateTime,
+
+    /// The maintenance window's name.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) name: String,
+
+    /// The date and time the first occurrence of the maintenance window starts at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) starts_at: OffsetDateTime,
+
+    /// The amount of time (in seconds) each occurrence of the maintenance window lasts for.
+    #[validate(range(min = 60, max = 604800))]
+    pub(crate) duration: u32,
+
+    /// How often the maintenance window recurs.
+    pub(crate) frequency: MaintenanceFrequency,
+
+    /// The amount of weeks or months between each occurrence of a recurring maintenance window.
+    #[validate(range(min = 1, max = 52))]
+    pub(crate) recurrence_interval: u32,
+
+    /// The date and time after which a recurring maintenance window no longer occurs, if it ever
+    /// stops recurring.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(crate) recurrence_ends_at: Option<OffsetDateTime>,
+
+    /// The unique identifiers of the monitors the maintenance window applies to.
+    #[validate(custom(function = "validate_monitor_ids"))]
+    pub(crate) monitor_ids: Json<Vec<u64>>,
+}
+
+/// A Default implementation for the MaintenanceWindow struct.
+impl Default for MaintenanceWindow {
+    fn default() -> Self {
+        return MaintenanceWindow {
+            id: 0,
+            user_id: 0,
+            maintenance_window_created_at: OffsetDateTime::now_utc(),
+            name: String::from(""),
+            starts_at: OffsetDateTime::now_utc(),
+            duration: 3600,
+            frequency: MaintenanceFrequency::Once,
+            recurrence_interval: 1,
+            recurrence_ends_at: None,
+            monitor_ids: Json(Vec::new()),
+        };
+    }
+}
+
+/// A PartialEq implementation for the MaintenanceWindow struct.
+impl PartialEq for MaintenanceWindow {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.user_id == other.user_id
+            && self.maintenance_window_created_at.date()
+                == other.maintenance_window_created_at.date()
+            && self.maintenance_window_created_at.hour()
+                == other.maintenance_window_created_at.hour()
+            && self.maintenance_window_created_at.minute()
+                == other.maintenance_window_created_at.minute()
+            && self.maintenance_window_created_at.second()
+                == other.maintenance_window_created_at.second()
+            && self.name == other.name
+            && self.starts_at.unix_timestamp() == other.starts_at.unix_timestamp()
+            && self.duration == other.duration
+            && self.frequency == other.frequency
+            && self.recurrence_interval == other.recurrence_interval
+            && self.recurrence_ends_at.map(OffsetDateTime::unix_timestamp)
+                == other.recurrence_ends_at.map(OffsetDateTime::unix_timestamp)
+            && self.monitor_ids == other.monitor_ids;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the frequencies a maintenance window can recur at.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum MaintenanceFrequency {
+    /// # Description
+    ///
+    /// The Once variant is used for maintenance windows that only occur once.
+    Once,
+
+    /// # Description
+    ///
+    /// The Weekly variant is used for maintenance windows that recur on the same day of the week,
+    /// every recurrence interval weeks.
+    Weekly,
+
+    /// # Description
+    ///
+    /// The Monthly variant is used for maintenance windows that recur on the same day of the month,
+    /// every recurrence interval months. Months that do not contain the day are skipped.
+    Monthly,
+}
+
+/// A maintenance occurrence struct, which is a single occurrence of a maintenance window.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MaintenanceOccurrence {
+    /// The unique identifier of the maintenance window that is occurring.
+    pub(crate) maintenance_window_id: u64,
+
+    /// The maintenance window's name.
+    pub(crate) name: String,
+
+    /// The date and time the occurrence starts at (inclusive).
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) starts_at: OffsetDateTime,
+
+    /// The date and time the occurrence ends at (exclusive).
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) ends_at: OffsetDateTime,
+}
+
+/// # Description
+///
+/// Validate that a maintenance window stops recurring after it starts.
+///
+/// # Arguments
+///
+/// `maintenance_window` - The maintenance window that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the maintenance window is valid, the Ok variant will be returned.
+/// - If the maintenance window stops recurring before it starts, the Err variant will be returned
+/// with a validation error.
+fn validate_recurrence(maintenance_window: &MaintenanceWindow) -> Result<(), ValidationError> {
+    if let Some(recurrence_ends_at) = &maintenance_window.recurrence_ends_at {
+        if *recurrence_ends_at < maintenance_window.starts_at {
+            return Err(create_value_validation_error(
+                "recurrence_ends_at",
+                &recurrence_ends_at.unix_timestamp(),
+            ));
+        }
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Validate the monitors a maintenance window applies to.
+///
+/// # Arguments
+///
+/// `monitor_ids` - The unique identifiers of the monitors that are being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the monitors are valid, the Ok variant will be returned.
+/// - If there are no monitors or too many monitors, or a monitor appears more than once, the Err
+/// variant will be returned with a validation error.
+fn validate_monitor_ids(monitor_ids: &Json<Vec<u64>>) -> Result<(), ValidationError> {
+    if monitor_ids.is_empty() || monitor_ids.len() > MAXIMUM_WINDOW_MONITORS {
+        return Err(create_value_validation_error("length", &monitor_ids.len()));
+    }
+
+    for (index, monitor_id) in monitor_ids.iter().enumerate() {
+        if monitor_ids[..index].contains(monitor_id) {
+            return Err(create_value_validation_error("duplicate", monitor_id));
+        }
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,385 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::maintenance::model::{MaintenanceFrequency, MaintenanceWindow},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// A maintenance window repository trait.
+#[async_trait]
+pub(crate) trait MaintenanceWindowRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a maintenance window into the maintenance window repository.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window to insert into the maintenance window repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// maintenance window that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a maintenance window from the maintenance window repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the maintenance window to get from the maintenance window repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional maintenance window.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<MaintenanceWindow>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the maintenance windows that apply to a monitor from the maintenance window
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The id of the monitor the maintenance windows apply to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the maintenance windows
+    /// that were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<MaintenanceWindow>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the maintenance windows that belong to a user from the maintenance window repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the maintenance windows belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the maintenance windows that
+    /// were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<MaintenanceWindow>, Error>;
+
+    /// # Description
+    ///
+    /// Update a maintenance window in the maintenance window repository.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window to update in the maintenance window repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete a maintenance window from the maintenance window repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the maintenance window to delete from the maintenance window repository.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+}
+
+/// A MaintenanceWindowRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = MaintenanceWindowRepository)]
+pub(crate) struct MaintenanceWindowRepositoryImpl;
+
+/// A MaintenanceWindowRepository implementation for the MaintenanceWindowRepositoryImpl struct.
+#[async_trait]
+impl MaintenanceWindowRepository for MaintenanceWindowRepositoryImpl {
+    async fn insert(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `maintenance_windows` (
+                    `id`,
+                    `user_id`,
+                    `maintenance_window_created_at`,
+                    `name`,
+                    `starts_at`,
+                    `duration`,
+                    `frequency`,
+                    `recurrence_interval`,
+                    `recurrence_ends_at`,
+                    `monitor_ids`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            maintenance_window.id,
+            maintenance_window.user_id,
+            maintenance_window.maintenance_window_created_at,
+            maintenance_window.name,
+            maintenance_window.starts_at,
+            maintenance_window.duration,
+            maintenance_window.frequency,
+            maintenance_window.recurrence_interval,
+            maintenance_window.recurrence_ends_at,
+            &maintenance_window.monitor_ids
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<MaintenanceWindow>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            MaintenanceWindow,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `maintenance_window_created_at` AS `maintenance_window_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `starts_at` AS `starts_at: OffsetDateTime`,
+                    `duration` AS `duration: u32`,
+                    `frequency` AS `frequency: MaintenanceFrequency`,
+                    `recurrence_interval` AS `recurrence_interval: u32`,
+                    `recurrence_ends_at` AS `recurrence_ends_at: OffsetDateTime`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `maintenance_windows`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<MaintenanceWindow>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            MaintenanceWindow,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `maintenance_window_created_at` AS `maintenance_window_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `starts_at` AS `starts_at: OffsetDateTime`,
+                    `duration` AS `duration: u32`,
+                    `frequency` AS `frequency: MaintenanceFrequency`,
+                    `recurrence_interval` AS `recurrence_interval: u32`,
+                    `recurrence_ends_at` AS `recurrence_ends_at: OffsetDateTime`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `maintenance_windows`
+                WHERE
+                    JSON_CONTAINS(`monitor_ids`, JSON_ARRAY(?))
+                ORDER BY
+                    `id` ASC;
+            "#,
+            monitor_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<MaintenanceWindow>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            MaintenanceWindow,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `maintenance_window_created_at` AS `maintenance_window_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `starts_at` AS `starts_at: OffsetDateTime`,
+                    `duration` AS `duration: u32`,
+                    `frequency` AS `frequency: MaintenanceFrequency`,
+                    `recurrence_interval` AS `recurrence_interval: u32`,
+                    `recurrence_ends_at` AS `recurrence_ends_at: OffsetDateTime`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `maintenance_windows`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `id` ASC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `maintenance_windows`
+                SET
+                    `user_id` = ?,
+                    `maintenance_window_created_at` = ?,
+                    `name` = ?,
+                    `starts_at` = ?,
+                    `duration` = ?,
+                    `frequency` = ?,
+                    `recurrence_interval` = ?,
+                    `recurrence_ends_at` = ?,
+                    `monitor_ids` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            maintenance_window.user_id,
+            maintenance_window.maintenance_window_created_at,
+            maintenance_window.name,
+            maintenance_window.starts_at,
+            maintenance_window.duration,
+            maintenance_window.frequency,
+            maintenance_window.recurrence_interval,
+            maintenance_window.recurrence_ends_at,
+            &maintenance_window.monitor_ids,
+            maintenance_window.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `maintenance_windows`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
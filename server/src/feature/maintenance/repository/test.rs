@@ -0,0 +1,456 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        maintenance::{
+            model::{MaintenanceFrequency, MaintenanceWindow},
+            repository::MaintenanceWindowRepository,
+        },
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, types::Json, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a maintenance window that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the maintenance window will belong to.
+///
+/// `monitor` - The monitor the maintenance window will apply to.
+///
+/// # Returns
+///
+/// The maintenance window that was created.
+fn create_test_maintenance_window(user: &User, monitor: &Monitor) -> MaintenanceWindow {
+    return MaintenanceWindow {
+        id: 0,
+        user_id: user.id,
+        maintenance_window_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(16),
+        starts_at: OffsetDateTime::now_utc(),
+        duration: 3600,
+        frequency: MaintenanceFrequency::Weekly,
+        recurrence_interval: 1,
+        recurrence_ends_at: None,
+        monitor_ids: Json(vec![monitor.id]),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a maintenance window into the repository, and make sure it can be queried by its
+/// id, owner and monitors until it is deleted.
+#[actix_web::test]
+async fn maintenance_window_is_queryable_until_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a maintenance window repository instance.
+    let maintenance_window_repository: Arc<dyn MaintenanceWindowRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let first_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let second_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert the maintenance window.
+    let mut maintenance_window: MaintenanceWindow =
+        create_test_maintenance_window(&user, &first_monitor);
+    maintenance_window.id = maintenance_window_repository
+        .insert(&maintenance_window, &mut context)
+        .await
+        .expect("Failed to insert maintenance window");
+    assert!(maintenance_window.id > 0);
+
+    // Make sure the maintenance window can be queried by its id.
+    let queried_maintenance_window: MaintenanceWindow = maintenance_window_repository
+        .get(&maintenance_window.id, &mut context)
+        .await
+        .expect("Failed to query maintenance window: An error occurred")
+        .expect("Failed to query maintenance window: Maintenance window not found");
+    assert_eq!(maintenance_window, queried_maintenance_window);
+
+    // Make sure the maintenance window can be queried by its owner.
+    let queried_maintenance_windows: Vec<MaintenanceWindow> = maintenance_window_repository
+        .get_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query maintenance windows by user id");
+    assert_eq!(
+        vec![maintenance_window.clone()],
+        queried_maintenance_windows
+    );
+
+    // Make sure the maintenance window is only found for the monitor it applies to.
+    let queried_maintenance_windows: Vec<MaintenanceWindow> = maintenance_window_repository
+        .get_by_monitor_id(&first_monitor.id, &mut context)
+        .await
+        .expect("Failed to query maintenance windows by monitor id");
+    assert_eq!(
+        vec![maintenance_window.clone()],
+        queried_maintenance_windows
+    );
+    assert!(maintenance_window_repository
+        .get_by_monitor_id(&second_monitor.id, &mut context)
+        .await
+        .expect("Failed to query maintenance windows by monitor id")
+        .is_empty());
+
+    // Delete the maintenance window, and make sure it can no longer be queried.
+    let rows_deleted = maintenance_window_repository
+        .delete(&maintenance_window.id, &mut context)
+        .await
+        .expect("Failed to delete maintenance window");
+    assert_eq!(1, rows_deleted);
+    assert!(maintenance_window_repository
+        .get(&maintenance_window.id, &mut context)
+        .await
+        .expect("Failed to query maintenance window after deletion")
+        .is_none());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test updating a maintenance window in the repository, and make sure the changes are persisted.
+#[actix_web::test]
+async fn maintenance_window_is_updatable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a maintenance window repository instance.
+    let maintenance_window_repository: Arc<dyn MaintenanceWindowRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let first_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let second_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert the maintenance window.
+    let mut maintenance_window: MaintenanceWindow =
+        create_test_maintenance_window(&user, &first_monitor);
+    maintenance_window.id = maintenance_window_repository
+        .insert(&maintenance_window, &mut context)
+        .await
+        .expect("Failed to insert maintenance window");
+
+    // Update the maintenance window.
+    maintenance_window.name = generate_random_string(16);
+    maintenance_window.duration = 7200;
+    maintenance_window.frequency = MaintenanceFrequency::Monthly;
+    maintenance_window.recurrence_interval = 3;
+    maintenance_window.recurrence_ends_at = Some(OffsetDateTime::now_utc() + Duration::days(365));
+    maintenance_window.monitor_ids.push(second_monitor.id);
+    let rows_updated = maintenance_window_repository
+        .update(&maintenance_window, &mut context)
+        .await
+        .expect("Failed to update maintenance window");
+    assert_eq!(1, rows_updated);
+
+    // Make sure the changes were persisted.
+    let queried_maintenance_window: MaintenanceWindow = maintenance_window_repository
+        .get(&maintenance_window.id, &mut context)
+        .await
+        .expect("Failed to query maintenance window: An error occurred")
+        .expect("Failed to query maintenance window: Maintenance window not found");
+    assert_eq!(maintenance_window, queried_maintenance_window);
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::maintenance::model::{
+    MaintenanceFrequency, MaintenanceOccurrence, MaintenanceWindow,
+};
+use time::{Date, Duration, Month, OffsetDateTime};
+
+/// The maximum amount of occurrences of a single maintenance window that will be calculated, so
+/// that very long time ranges can not be used to perform unbounded work.
+const MAXIMUM_OCCURRENCES: usize = 1000;
+
+/// # Description
+///
+/// Calculate the occurrences of a maintenance window that overlap a time range.
+///
+/// # Arguments
+///
+/// `maintenance_window` - The maintenance window whose occurrences will be calculated.
+///
+/// `from` - The date and time the time range starts at (inclusive).
+///
+/// `to` - The date and time the time range ends at (exclusive).
+///
+/// # Returns
+///
+/// The occurrences that overlap the time range, ordered from oldest to newest.
+pub(crate) fn get_occurrences(
+    maintenance_window: &MaintenanceWindow,
+    from: OffsetDateTime,
+    to: OffsetDateTime,
+) -> Vec<MaintenanceOccurrence> {
+    let duration: Duration = Duration::seconds(i64::from(maintenance_window.duration));
+    let mut occurrences: Vec<MaintenanceOccurrence> = Vec::new();
+
+    // Skip the occurrences that ended before the time range started, then walk forward until an
+    // occurrence starts after the time range ends.
+    let mut index: u32 = get_first_candidate_index(maintenance_window, from - duration);
+    while occurrences.len() < MAXIMUM_OCCURRENCES {
+        // A recurrence that does not exist (such as the 31st of a shorter month) is skipped.
+        let starts_at: OffsetDateTime = match get_occurrence_start(maintenance_window, index) {
+            Some(starts_at) => starts_at,
+            None if maintenance_window.frequency == MaintenanceFrequency::Once => break,
+            None => {
+                index += 1;
+                continue;
+            }
+        };
+
+        // Stop once the occurrences start after the time range, or after the recurrence ends.
+        if starts_at >= to
+            || maintenance_window
+                .recurrence_ends_at
+                .is_some_and(|recurrence_ends_at| starts_at > recurrence_ends_at)
+        {
+            break;
+        }
+
+        // Include the occurrence if it overlaps the time range.
+        let ends_at: OffsetDateTime = starts_at + duration;
+        if ends_at > from {
+            occurrences.push(MaintenanceOccurrence {
+                maintenance_window_id: maintenance_window.id,
+                name: maintenance_window.name.clone(),
+                starts_at,
+                ends_at,
+            });
+        }
+
+        // A maintenance window that does not recur only has one occurrence.
+        if maintenance_window.frequency == MaintenanceFrequency::Once {
+            break;
+        }
+        index += 1;
+    }
+
+    return occurrences;
+}
+
+/// # Description
+///
+/// Get the date and time an occurrence of a maintenance window starts at.
+///
+/// # Arguments
+///
+/// `maintenance_window` - The maintenance window.
+///
+/// `index` - The index of the occurrence, where the first occurrence has an index of 0.
+///
+/// # Returns
+///
+/// An option that will contain the date and time the occurrence starts at, if the occurrence
+/// exists.
+fn get_occurrence_start(
+    maintenance_window: &MaintenanceWindow,
+    index: u32,
+) -> Option<OffsetDateTime> {
+    let starts_at: OffsetDateTime = maintenance_window.starts_at;
+    return match maintenance_window.frequency {
+        MaintenanceFrequency::Once if index == 0 => Some(starts_at),
+        MaintenanceFrequency::Once => None,
+        MaintenanceFrequency::Weekly => Some(
+            starts_at + Duration::weeks(i64::from(maintenance_window.recurrence_interval * index)),
+        ),
+        MaintenanceFrequency::Monthly => {
+            let month_index: i64 = i64::from(starts_at.year()) * 12
+                + i64::from(u8::from(starts_at.month()) - 1)
+                + i64::from(maintenance_window.recurrence_interval * index);
+            let month: Month = Month::try_from((month_index.rem_euclid(12) + 1) as u8).ok()?;
+            let year: i32 = i32::try_from(month_index.div_euclid(12)).ok()?;
+            let date: Date = Date::from_calendar_date(year, month, starts_at.day()).ok()?;
+            Some(starts_at.replace_date(date))
+        }
+    };
+}
+
+/// # Description
+///
+/// Get the index of the earliest occurrence of a maintenance window that could start at or after a
+/// date and time, so that the occurrences before it do not need to be calculated.
+///
+/// # Arguments
+///
+/// `maintenance_window` - The maintenance window.
+///
+/// `at` - The date and time.
+///
+/// # Returns
+///
+/// The index of the occurrence.
+fn get_first_candidate_index(maintenance_window: &MaintenanceWindow, at: OffsetDateTime) -> u32 {
+    // If the date and time is before the first occurrence, start from the first occurrence.
+    let starts_at: OffsetDateTime = maintenance_window.starts_at;
+    if at <= starts_at {
+        return 0;
+    }
+
+    let interval: i64 = i64::from(maintenance_window.recurrence_interval.max(1));
+    let elapsed_periods: i64 = match maintenance_window.frequency {
+        MaintenanceFrequency::Once => 0,
+        MaintenanceFrequency::Weekly => (at - starts_at).whole_weeks() / interval,
+        // The day of the month is ignored, so one period is subtracted to never skip past an
+        // occurrence.
+        MaintenanceFrequency::Monthly => {
+            let elapsed_months: i64 = (i64::from(at.year()) - i64::from(starts_at.year())) * 12
+                + i64::from(u8::from(at.month()))
+                - i64::from(u8::from(starts_at.month()));
+            elapsed_months / interval - 1
+        }
+    };
+
+    return u32::try_from(elapsed_periods.max(0)).unwrap_or(u32::MAX);
+}
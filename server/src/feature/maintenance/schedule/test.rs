@@ -0,0 +1,174 @@
+use crate::feature::maintenance::{
+    model::{MaintenanceFrequency, MaintenanceOccurrence, MaintenanceWindow},
+    schedule::get_occurrences,
+};
+use time::{macros::datetime, Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a maintenance window that can be used for testing.
+///
+/// # Arguments
+///
+/// `starts_at` - The date and time the first occurrence starts at.
+///
+/// `frequency` - How often the maintenance window recurs.
+///
+/// `recurrence_interval` - The amount of weeks or months between each occurrence.
+///
+/// # Returns
+///
+/// The maintenance window that was created, which lasts for one hour.
+fn create_test_maintenance_window(
+    starts_at: OffsetDateTime,
+    frequency: MaintenanceFrequency,
+    recurrence_interval: u32,
+) -> MaintenanceWindow {
+    return MaintenanceWindow {
+        id: 1,
+        name: String::from("Deploy"),
+        starts_at,
+        duration: 3600,
+        frequency,
+        recurrence_interval,
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Get the start of each occurrence.
+///
+/// # Arguments
+///
+/// `occurrences` - The occurrences.
+///
+/// # Returns
+///
+/// The date and time each occurrence starts at.
+fn get_starts(occurrences: &[MaintenanceOccurrence]) -> Vec<OffsetDateTime> {
+    return occurrences
+        .iter()
+        .map(|occurrence| occurrence.starts_at)
+        .collect();
+}
+
+/// # Description
+///
+/// Determine if a maintenance window is occurring at a date and time.
+///
+/// # Arguments
+///
+/// `maintenance_window` - The maintenance window.
+///
+/// `at` - The date and time.
+///
+/// # Returns
+///
+/// True if one of the maintenance window's occurrences contains the date and time, otherwise
+/// false.
+fn is_occurring(maintenance_window: &MaintenanceWindow, at: OffsetDateTime) -> bool {
+    return !get_occurrences(maintenance_window, at, at + Duration::SECOND).is_empty();
+}
+
+/// # Description
+///
+/// Test calculating the occurrences of a maintenance window that does not recur, and make sure it
+/// only occurs once.
+#[test]
+fn one_off_windows_occur_once() {
+    let maintenance_window: MaintenanceWindow = create_test_maintenance_window(
+        datetime!(2026-01-01 02:00 UTC),
+        MaintenanceFrequency::Once,
+        1,
+    );
+
+    let occurrences: Vec<MaintenanceOccurrence> = get_occurrences(
+        &maintenance_window,
+        datetime!(2026-01-01 00:00 UTC),
+        datetime!(2027-01-01 00:00 UTC),
+    );
+
+    assert_eq!(1, occurrences.len());
+    assert_eq!(datetime!(2026-01-01 02:00 UTC), occurrences[0].starts_at);
+    assert_eq!(datetime!(2026-01-01 03:00 UTC), occurrences[0].ends_at);
+    assert!(is_occurring(
+        &maintenance_window,
+        datetime!(2026-01-01 02:30 UTC)
+    ));
+    assert!(!is_occurring(
+        &maintenance_window,
+        datetime!(2026-01-01 03:00 UTC)
+    ));
+}
+
+/// # Description
+///
+/// Test calculating the occurrences of a weekly maintenance window, and make sure the interval,
+/// the end of the recurrence and occurrences that started before the time range are respected.
+#[test]
+fn weekly_windows_recur_every_interval() {
+    let mut maintenance_window: MaintenanceWindow = create_test_maintenance_window(
+        datetime!(2026-01-01 02:00 UTC),
+        MaintenanceFrequency::Weekly,
+        2,
+    );
+    maintenance_window.recurrence_ends_at = Some(datetime!(2026-02-12 02:00 UTC));
+
+    // The time range starts in the middle of the occurrence on the 15th of January.
+    let occurrences: Vec<MaintenanceOccurrence> = get_occurrences(
+        &maintenance_window,
+        datetime!(2026-01-15 02:30 UTC),
+        datetime!(2026-06-01 00:00 UTC),
+    );
+
+    assert_eq!(
+        vec![
+            datetime!(2026-01-15 02:00 UTC),
+            datetime!(2026-01-29 02:00 UTC),
+            datetime!(2026-02-12 02:00 UTC),
+        ],
+        get_starts(&occurrences)
+    );
+    assert!(!is_occurring(
+        &maintenance_window,
+        datetime!(2026-01-08 02:30 UTC)
+    ));
+    assert!(!is_occurring(
+        &maintenance_window,
+        datetime!(2026-02-26 02:30 UTC)
+    ));
+}
+
+/// # Description
+///
+/// Test calculating the occurrences of a monthly maintenance window that starts on a day some
+/// months do not have, and make sure those months are skipped.
+#[test]
+fn monthly_windows_skip_months_without_their_day() {
+    let maintenance_window: MaintenanceWindow = create_test_maintenance_window(
+        datetime!(2026-01-31 22:00 UTC),
+        MaintenanceFrequency::Monthly,
+        1,
+    );
+
+    let occurrences: Vec<MaintenanceOccurrence> = get_occurrences(
+        &maintenance_window,
+        datetime!(2026-03-01 00:00 UTC),
+        datetime!(2026-09-01 00:00 UTC),
+    );
+
+    assert_eq!(
+        vec![
+            datetime!(2026-03-31 22:00 UTC),
+            datetime!(2026-05-31 22:00 UTC),
+            datetime!(2026-07-31 22:00 UTC),
+            datetime!(2026-08-31 22:00 UTC),
+        ],
+        get_starts(&occurrences)
+    );
+    assert_eq!(
+        Duration::HOUR,
+        occurrences[0].ends_at - occurrences[0].starts_at
+    );
+}
@@ -0,0 +1,734 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
+        utility::create_value_validation_error,
+    },
+    database::DatabaseConnectionFactory,
+    feature::{
+        maintenance::{
+            model::{MaintenanceOccurrence, MaintenanceWindow},
+            repository::MaintenanceWindowRepository,
+            schedule,
+        },
+        monitor::service::MonitorService,
+        user::service::UserService,
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use time::OffsetDateTime;
+use validator::{Validate, ValidationErrors};
+
+/// A maintenance window service trait.
+#[async_trait(?Send)]
+pub(crate) trait MaintenanceWindowService: Interface {
+    /// # Description
+    ///
+    /// Insert a maintenance window into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the maintenance window that was inserted.
+    /// - If the maintenance window being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+    ) -> InsertionResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a maintenance window into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the maintenance window that was inserted.
+    /// - If the maintenance window being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> InsertionResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a maintenance window from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the maintenance window that is being queried from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the maintenance window, the Ok variant will be returned with the maintenance window.
+    /// - If the maintenance window could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get(&self, id: &u64) -> QueryResult<MaintenanceWindow, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a maintenance window from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the maintenance window that is being queried from persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the maintenance window, the Ok variant will be returned with the maintenance window.
+    /// - If the maintenance window could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<MaintenanceWindow, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the maintenance windows that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the maintenance windows belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the maintenance windows that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+    ) -> QueryResult<Vec<MaintenanceWindow>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the maintenance windows that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the maintenance windows belong to.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the maintenance windows that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<MaintenanceWindow>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the occurrences of the maintenance windows that apply to a monitor within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor the maintenance windows apply to.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the occurrences that
+    /// overlap the time range, ordered by when they start (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_occurrences_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+    ) -> QueryResult<Vec<MaintenanceOccurrence>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the occurrences of the maintenance windows that apply to a monitor within a time range.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor_id` - The unique identifier of the monitor the maintenance windows apply to.
+    ///
+    /// `from` - The date and time the time range starts at (inclusive).
+    ///
+    /// `to` - The date and time the time range ends at (exclusive).
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the occurrences that
+    /// overlap the time range, ordered by when they start (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_occurrences_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<MaintenanceOccurrence>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a maintenance window in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window that is being updated in persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the maintenance window updated.
+    /// - If the maintenance window that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the maintenance window being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+    ) -> UpdateResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a maintenance window in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window that is being updated in persistent storage.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the maintenance window updated.
+    /// - If the maintenance window that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the maintenance window being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update_with_context(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> UpdateResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a maintenance window from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the maintenance window that is being removed from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a maintenance window was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the maintenance window being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a maintenance window from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the maintenance window that is being removed from persistent storage.
+    ///
+    /// `context` - The context the deletion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a maintenance window was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the maintenance window being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>>;
+}
+
+/// A MaintenanceWindowServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = MaintenanceWindowService)]
+pub(crate) struct MaintenanceWindowServiceImpl {
+    /// The maintenance window repository that will be used to manage persistent maintenance window data.
+    #[shaku(inject)]
+    maintenance_window_repository: Arc<dyn MaintenanceWindowRepository>,
+
+    /// The user service that will be used to validate user data.
+    #[shaku(inject)]
+    user_service: Arc<dyn UserService>,
+
+    /// The monitor service that will be used to validate the monitors maintenance windows apply to.
+    #[shaku(inject)]
+    monitor_service: Arc<dyn MonitorService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// A MaintenanceWindowService implementation for the MaintenanceWindowServiceImpl struct.
+#[async_trait(?Send)]
+impl MaintenanceWindowService for MaintenanceWindowServiceImpl {
+    async fn insert(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+    ) -> InsertionResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self
+            .insert_with_context(maintenance_window, &mut context)
+            .await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> InsertionResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>> {
+        // Validate the maintenance window.
+        let mut validation_errors = match maintenance_window.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the maintenance window refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(maintenance_window, &mut validation_errors, context)
+            .await
+        {
+            return InsertionResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Perform the insertion.
+        let maintenance_window_id = match __self
+            .maintenance_window_repository
+            .insert(maintenance_window, context)
+            .await
+        {
+            Ok(maintenance_window_id) => maintenance_window_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the maintenance window that was inserted.
+        let inserted_maintenance_window_option = match __self
+            .maintenance_window_repository
+            .get(&maintenance_window_id, context)
+            .await
+        {
+            Ok(inserted_maintenance_window_option) => inserted_maintenance_window_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the maintenance window was found, return the maintenance window, otherwise return an error.
+        return match inserted_maintenance_window_option {
+            Some(inserted_maintenance_window) => InsertionResult::Ok(inserted_maintenance_window),
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Maintenance window could not be found after insertion",
+            ))),
+        };
+    }
+
+    async fn get(&self, id: &u64) -> QueryResult<MaintenanceWindow, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_with_context(id, &mut context).await;
+    }
+
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<MaintenanceWindow, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self.maintenance_window_repository.get(id, context).await {
+            Ok(maintenance_window_option) => maintenance_window_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the maintenance window was found, return the maintenance window, otherwise return not found.
+        return match result {
+            Some(maintenance_window) => QueryResult::Ok(maintenance_window),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+    ) -> QueryResult<Vec<MaintenanceWindow>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_user_id_with_context(user_id, &mut context)
+            .await;
+    }
+
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<MaintenanceWindow>, Box<dyn Error>> {
+        // Perform the query and return the maintenance windows that were found.
+        return match __self
+            .maintenance_window_repository
+            .get_by_user_id(user_id, context)
+            .await
+        {
+            Ok(maintenance_windows) => QueryResult::Ok(maintenance_windows),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_occurrences_by_monitor_id(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+    ) -> QueryResult<Vec<MaintenanceOccurrence>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_occurrences_by_monitor_id_with_context(monitor_id, from, to, &mut context)
+            .await;
+    }
+
+    async fn get_occurrences_by_monitor_id_with_context(
+        &self,
+        monitor_id: &u64,
+        from: &OffsetDateTime,
+        to: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<MaintenanceOccurrence>, Box<dyn Error>> {
+        // Query the maintenance windows that apply to the monitor.
+        let maintenance_windows: Vec<MaintenanceWindow> = match __self
+            .maintenance_window_repository
+            .get_by_monitor_id(monitor_id, context)
+            .await
+        {
+            Ok(maintenance_windows) => maintenance_windows,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Calculate the occurrences of each maintenance window, and order them by when they start.
+        let mut occurrences: Vec<MaintenanceOccurrence> = maintenance_windows
+            .iter()
+            .flat_map(|maintenance_window| {
+                schedule::get_occurrences(maintenance_window, *from, *to)
+            })
+            .collect();
+        occurrences.sort_by_key(|occurrence| occurrence.starts_at);
+
+        // Return the occurrences.
+        return QueryResult::Ok(occurrences);
+    }
+
+    async fn update(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+    ) -> UpdateResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the update.
+        let update_result = self
+            .update_with_context(maintenance_window, &mut context)
+            .await;
+
+        // If the update was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update_with_context(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        context: &mut QueryContext,
+    ) -> UpdateResult<MaintenanceWindow, ValidationErrors, Box<dyn Error>> {
+        // Make sure the maintenance window exists.
+        match __self
+            .maintenance_window_repository
+            .get(&maintenance_window.id, context)
+            .await
+        {
+            Ok(Some(_)) => {}
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        }
+
+        // Validate the maintenance window.
+        let mut validation_errors = match maintenance_window.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the maintenance window refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(maintenance_window, &mut validation_errors, context)
+            .await
+        {
+            return UpdateResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Perform the update.
+        let records_updated = match __self
+            .maintenance_window_repository
+            .update(&maintenance_window, context)
+            .await
+        {
+            Ok(records_updated) => records_updated,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // If no records were updated.
+        if records_updated == 0 {
+            return UpdateResult::NotFound;
+        }
+
+        // Query the updated maintenance window.
+        let updated_maintenance_window_option = match __self
+            .maintenance_window_repository
+            .get(&maintenance_window.id, context)
+            .await
+        {
+            Ok(updated_maintenance_window_option) => updated_maintenance_window_option,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the maintenance window was found, and return the updated maintenance window.
+        return match updated_maintenance_window_option {
+            Some(updated_maintenance_window) => UpdateResult::Ok(updated_maintenance_window),
+            None => UpdateResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Maintenance window could not be found after update",
+            ))),
+        };
+    }
+
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the deletion.
+        return self.delete_with_context(id, &mut context).await;
+    }
+
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>> {
+        // Perform the deletion.
+        let records_deleted = match __self
+            .maintenance_window_repository
+            .delete(id, context)
+            .await
+        {
+            Ok(records_deleted) => records_deleted,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Return the result.
+        if records_deleted > 0 {
+            return DeletionResult::Ok;
+        } else {
+            return DeletionResult::NotFound;
+        }
+    }
+}
+
+/// An implementation for the MaintenanceWindowServiceImpl struct.
+impl MaintenanceWindowServiceImpl {
+    /// # Description
+    ///
+    /// Validate the records a maintenance window refers to. The maintenance window's owner must
+    /// exist, and the monitors it applies to must belong to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// `maintenance_window` - The maintenance window that is being validated.
+    ///
+    /// `validation_errors` - The validation errors that any errors that are detected will be added
+    /// to.
+    ///
+    /// `context` - The context the validation will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the validation could be performed, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn validate_references(
+        &self,
+        maintenance_window: &MaintenanceWindow,
+        validation_errors: &mut ValidationErrors,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Check if the user id specified exists.
+        match self
+            .user_service
+            .get_by_id_with_context(&maintenance_window.user_id, context)
+            .await
+        {
+            QueryResult::Ok(_) => {}
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(user_id in MaintenanceWindow),
+                create_value_validation_error("not_found", &maintenance_window.user_id),
+            ),
+            QueryResult::Err(error) => return Err(error),
+        }
+
+        // Make sure each monitor the maintenance window applies to belongs to the owner.
+        for monitor_id in maintenance_window.monitor_ids.iter() {
+            match self
+                .monitor_service
+                .get_with_context(monitor_id, context)
+                .await
+            {
+                QueryResult::Ok(monitor) if monitor.user_id == maintenance_window.user_id => {}
+                QueryResult::Ok(_) | QueryResult::NotFound => validation_errors.add(
+                    name_of!(monitor_ids in MaintenanceWindow),
+                    create_value_validation_error("not_found", monitor_id),
+                ),
+                QueryResult::Err(error) => return Err(error),
+            }
+        }
+
+        return Ok(());
+    }
+}
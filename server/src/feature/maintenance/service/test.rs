@@ -0,0 +1,362 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        maintenance::{
+            model::{MaintenanceFrequency, MaintenanceOccurrence, MaintenanceWindow},
+            service::MaintenanceWindowService,
+        },
+        monitor::{
+            model::{HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::types::Json;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration::default()),
+    };
+}
+
+/// # Description
+///
+/// Create a maintenance window that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the maintenance window will belong to.
+///
+/// `monitor` - The monitor the maintenance window will apply to.
+///
+/// # Returns
+///
+/// The maintenance window that was created, which is occurring when it is created.
+fn create_test_maintenance_window(user: &User, monitor: &Monitor) -> MaintenanceWindow {
+    return MaintenanceWindow {
+        id: 0,
+        user_id: user.id,
+        maintenance_window_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(16),
+        starts_at: OffsetDateTime::now_utc() - Duration::MINUTE,
+        duration: 3600,
+        frequency: MaintenanceFrequency::Weekly,
+        recurrence_interval: 1,
+        recurrence_ends_at: None,
+        monitor_ids: Json(vec![monitor.id]),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting maintenance windows that apply to another user's monitor, and make sure they are
+/// rejected while maintenance windows that only apply to the owner's monitors are accepted.
+#[actix_web::test]
+async fn maintenance_windows_can_only_apply_to_their_owners_monitors() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a maintenance window service instance.
+    let maintenance_window_service: Arc<dyn MaintenanceWindowService> = injector.resolve();
+
+    // Insert two test users, each with a monitor.
+    let user: User = insert_test_user(&injector).await;
+    let other_user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+    let other_monitor: Monitor = insert_test_monitor(&other_user, &injector).await;
+
+    // Make sure a maintenance window that applies to another user's monitor is rejected.
+    let mut maintenance_window: MaintenanceWindow = create_test_maintenance_window(&user, &monitor);
+    maintenance_window.monitor_ids.push(other_monitor.id);
+    match maintenance_window_service.insert(&maintenance_window).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(details) => {
+            assert!(details.field_errors().contains_key("monitor_ids"))
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert maintenance window, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure a maintenance window that only applies to the owner's monitors is accepted.
+    match maintenance_window_service
+        .insert(&create_test_maintenance_window(&user, &monitor))
+        .await
+    {
+        InsertionResult::Ok(inserted_maintenance_window) => {
+            assert_eq!(vec![monitor.id], *inserted_maintenance_window.monitor_ids)
+        }
+        InsertionResult::Invalid(details) => {
+            panic!("Failed to insert maintenance window: {}", details)
+        }
+        InsertionResult::Err(error) => panic!("Failed to insert maintenance window: {}", error),
+    };
+
+    // Delete the test users.
+    delete_test_user(&user, &injector).await;
+    delete_test_user(&other_user, &injector).await;
+}
+
+/// # Description
+///
+/// Test querying the upcoming occurrences of the maintenance windows that apply to a monitor, and
+/// make sure the occurrences of every window are listed in order.
+#[actix_web::test]
+async fn upcoming_occurrences_are_listed_per_monitor() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a maintenance window service instance.
+    let maintenance_window_service: Arc<dyn MaintenanceWindowService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Insert a weekly maintenance window, and a one-off maintenance window that starts in a day.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut inserted_maintenance_windows: Vec<MaintenanceWindow> = Vec::new();
+    for maintenance_window in [
+        create_test_maintenance_window(&user, &monitor),
+        MaintenanceWindow {
+            starts_at: now + Duration::DAY,
+            frequency: MaintenanceFrequency::Once,
+            ..create_test_maintenance_window(&user, &monitor)
+        },
+    ] {
+        match maintenance_window_service.insert(&maintenance_window).await {
+            InsertionResult::Ok(inserted_maintenance_window) => {
+                inserted_maintenance_windows.push(inserted_maintenance_window)
+            }
+            InsertionResult::Invalid(details) => {
+                panic!("Failed to insert maintenance window: {}", details)
+            }
+            InsertionResult::Err(error) => {
+                panic!("Failed to insert maintenance window: {}", error)
+            }
+        };
+    }
+
+    // Query the occurrences within the next two weeks.
+    let occurrences: Vec<MaintenanceOccurrence> = match maintenance_window_service
+        .get_occurrences_by_monitor_id(&monitor.id, &now, &(now + Duration::weeks(2)))
+        .await
+    {
+        QueryResult::Ok(occurrences) => occurrences,
+        QueryResult::NotFound => panic!("Failed to query occurrences: Not found"),
+        QueryResult::Err(error) => panic!("Failed to query occurrences: {}", error),
+    };
+
+    // The weekly window is occurring now and recurs twice, and the one-off window occurs once.
+    assert_eq!(
+        vec![
+            inserted_maintenance_windows[0].id,
+            inserted_maintenance_windows[1].id,
+            inserted_maintenance_windows[0].id,
+            inserted_maintenance_windows[0].id,
+        ],
+        occurrences
+            .iter()
+            .map(|occurrence| occurrence.maintenance_window_id)
+            .collect::<Vec<u64>>()
+    );
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
@@ -1,4 +1,17 @@
+pub(crate) mod admin;
+pub(crate) mod api_token;
 pub(crate) mod auth;
+pub(crate) mod check;
 pub(crate) mod crypto;
 pub(crate) mod file;
+pub(crate) mod heartbeat;
+pub(crate) mod incident;
+pub(crate) mod mail;
+pub(crate) mod maintenance;
+pub(crate) mod monitor;
+pub(crate) mod notification;
+pub(crate) mod oidc;
+pub(crate) mod session;
+pub(crate) mod status_page;
+pub(crate) mod two_factor;
 pub(crate) mod user;
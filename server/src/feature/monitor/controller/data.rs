@@ -0,0 +1,346 @@
+use crate::{
+    common::utility::{create_value_validation_error, generate_random_string},
+    feature::{
+        check::{model::SeriesBucketWidth, statistics::count_buckets},
+        monitor::model::{Monitor, MonitorConfiguration},
+    },
+};
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+use validator::ValidationErrors;
+
+/// The amount of characters in a heartbeat monitor's token.
+const HEARTBEAT_TOKEN_LENGTH: usize = 64;
+
+/// The longest time range statistics and series can be calculated for.
+const MAXIMUM_TIME_RANGE: Duration = Duration::days(90);
+
+/// The maximum amount of buckets a series can contain.
+const MAXIMUM_SERIES_BUCKETS: usize = 1440;
+
+/// A create monitor request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateMonitorRequestBody {
+    /// The monitor's name.
+    pub(super) name: String,
+
+    /// The amount of time (in seconds) between each check the monitor performs.
+    pub(super) check_interval: u32,
+
+    /// The amount of time (in seconds) a check is allowed to take before it is considered to have
+    /// failed.
+    pub(super) check_timeout: u32,
+
+    /// If the monitor is enabled.
+    pub(super) is_enabled: Option<bool>,
+
+    /// The monitor's type specific configuration.
+    pub(super) configuration: MonitorConfiguration,
+}
+
+/// An Into<Monitor> implementation for the CreateMonitorRequestBody struct.
+impl Into<Monitor> for CreateMonitorRequestBody {
+    fn into(self) -> Monitor {
+        // If the monitor is a heartbeat monitor, generate its token.
+        let mut configuration: MonitorConfiguration = self.configuration;
+        if let MonitorConfiguration::Heartbeat(heartbeat_configuration) = &mut configuration {
+            heartbeat_configuration.token = generate_random_string(HEARTBEAT_TOKEN_LENGTH);
+        }
+
+        return Monitor {
+            name: self.name,
+            check_interval: self.check_interval,
+            check_timeout: self.check_timeout,
+            is_enabled: self.is_enabled.unwrap_or(true),
+            configuration,
+            ..Default::default()
+        };
+    }
+}
+
+/// A get monitor check results request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetMonitorCheckResultsRequestParams {
+    /// The maximum amount of check results to return.
+    pub(super) limit: Option<u32>,
+}
+
+/// A get monitor incidents request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetMonitorIncidentsRequestParams {
+    /// The maximum amount of incidents to return.
+    pub(super) limit: Option<u32>,
+}
+
+/// A get monitor maintenance request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetMonitorMaintenanceRequestParams {
+    /// The amount of days ahead to return the maintenance window occurrences of.
+    pub(super) days: Option<u32>,
+}
+
+/// A get monitor statistics request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetMonitorStatisticsRequestParams {
+    /// The window of time ending now that the statistics will be calculated for. This is ignored
+    /// if a custom time range is specified.
+    pub(super) window: Option<StatisticsWindow>,
+
+    /// The date and time the custom time range starts at.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) from: Option<OffsetDateTime>,
+
+    /// The date and time the custom time range ends at.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) to: Option<OffsetDateTime>,
+}
+
+/// An implementation for the GetMonitorStatisticsRequestParams struct.
+impl GetMonitorStatisticsRequestParams {
+    /// # Description
+    ///
+    /// Get the time range the statistics will be calculated for.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the time range is valid, the Ok variant will be returned with the date and time the time
+    /// range starts and ends at.
+    /// - If the time range is not valid, the Err variant will be returned with the validation errors
+    /// that were detected.
+    pub(super) fn get_time_range(
+        &self,
+    ) -> Result<(OffsetDateTime, OffsetDateTime), ValidationErrors> {
+        return resolve_time_range(&self.window, &self.from, &self.to);
+    }
+}
+
+/// A get monitor series request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetMonitorSeriesRequestParams {
+    /// The window of time ending now that the series will be calculated for. This is ignored if a
+    /// custom time range is specified.
+    pub(super) window: Option<StatisticsWindow>,
+
+    /// The date and time the custom time range starts at.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) from: Option<OffsetDateTime>,
+
+    /// The date and time the custom time range ends at.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub(super) to: Option<OffsetDateTime>,
+
+    /// The width of each bucket of the series.
+    pub(super) bucket: Option<SeriesBucketWidth>,
+}
+
+/// An implementation for the GetMonitorSeriesRequestParams struct.
+impl GetMonitorSeriesRequestParams {
+    /// # Description
+    ///
+    /// Get the time range the series will be calculated for, and the width of its buckets.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the time range and bucket width are valid, the Ok variant will be returned with the date
+    /// and time the time range starts and ends at, and the width of the buckets.
+    /// - If the time range or bucket width are not valid, the Err variant will be returned with the
+    /// validation errors that were detected.
+    pub(super) fn get_time_range_and_bucket_width(
+        &self,
+    ) -> Result<(OffsetDateTime, OffsetDateTime, SeriesBucketWidth), ValidationErrors> {
+        // Get the time range.
+        let (from, to) = resolve_time_range(&self.window, &self.from, &self.to)?;
+
+        // Make sure the series would not contain too many buckets.
+        let width: SeriesBucketWidth = self.bucket.unwrap_or(SeriesBucketWidth::Hour);
+        if count_buckets(from, to, width) > MAXIMUM_SERIES_BUCKETS {
+            let mut validation_errors: ValidationErrors = ValidationErrors::new();
+            validation_errors.add(
+                "bucket",
+                create_value_validation_error("too_many_buckets", &width),
+            );
+            return Err(validation_errors);
+        }
+
+        return Ok((from, to, width));
+    }
+}
+
+/// An enumeration of all of the windows of time statistics can be calculated for.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(super) enum StatisticsWindow {
+    /// # Description
+    ///
+    /// The Day variant is used for the last 24 hours.
+    #[serde(rename = "24h")]
+    Day,
+
+    /// # Description
+    ///
+    /// The Week variant is used for the last 7 days.
+    #[serde(rename = "7d")]
+    Week,
+
+    /// # Description
+    ///
+    /// The Month variant is used for the last 30 days.
+    #[serde(rename = "30d")]
+    Month,
+}
+
+/// An implementation for the StatisticsWindow enum.
+impl StatisticsWindow {
+    /// # Description
+    ///
+    /// Get the amount of time the window covers.
+    ///
+    /// # Returns
+    ///
+    /// The amount of time the window covers.
+    fn get_duration(&self) -> Duration {
+        return match self {
+            StatisticsWindow::Day => Duration::DAY,
+            StatisticsWindow::Week => Duration::days(7),
+            StatisticsWindow::Month => Duration::days(30),
+        };
+    }
+}
+
+/// # Description
+///
+/// Resolve the time range a request is for, from either a window of time ending now or a custom
+/// time range.
+///
+/// # Arguments
+///
+/// `window` - The window of time, if one was specified (defaults to the last 24 hours).
+///
+/// `from` - The date and time the custom time range starts at, if one was specified.
+///
+/// `to` - The date and time the custom time range ends at, if one was specified.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the time range is valid, the Ok variant will be returned with the date and time the time
+/// range starts and ends at.
+/// - If the time range is not valid, the Err variant will be returned with the validation errors
+/// that were detected.
+fn resolve_time_range(
+    window: &Option<StatisticsWindow>,
+    from: &Option<OffsetDateTime>,
+    to: &Option<OffsetDateTime>,
+) -> Result<(OffsetDateTime, OffsetDateTime), ValidationErrors> {
+    let mut validation_errors: ValidationErrors = ValidationErrors::new();
+
+    // Determine the time range.
+    let (from, to) = match (from, to) {
+        (Some(from), Some(to)) => (*from, *to),
+        (None, None) => {
+            let to: OffsetDateTime = OffsetDateTime::now_utc();
+            let window: StatisticsWindow = window.unwrap_or(StatisticsWindow::Day);
+            (to - window.get_duration(), to)
+        }
+        (Some(from), None) => {
+            validation_errors.add("to", create_value_validation_error("required", from));
+            return Err(validation_errors);
+        }
+        (None, Some(to)) => {
+            validation_errors.add("from", create_value_validation_error("required", to));
+            return Err(validation_errors);
+        }
+    };
+
+    // Make sure the time range is not empty, and is not too long.
+    if to <= from {
+        validation_errors.add("to", create_value_validation_error("before_from", &to));
+    } else if to - from > MAXIMUM_TIME_RANGE {
+        validation_errors.add("to", create_value_validation_error("range_too_long", &to));
+    }
+
+    // If any validation errors exist, return them.
+    if !validation_errors.is_empty() {
+        return Err(validation_errors);
+    }
+
+    return Ok((from, to));
+}
+
+/// An update monitor request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UpdateMonitorRequestBody {
+    /// The monitor's name.
+    pub(super) name: Option<String>,
+
+    /// The amount of time (in seconds) between each check the monitor performs.
+    pub(super) check_interval: Option<u32>,
+
+    /// The amount of time (in seconds) a check is allowed to take before it is considered to have
+    /// failed.
+    pub(super) check_timeout: Option<u32>,
+
+    /// If the monitor is enabled.
+    pub(super) is_enabled: Option<bool>,
+
+    /// The monitor's type specific configuration.
+    pub(super) configuration: Option<MonitorConfiguration>,
+}
+
+/// An implementation for the UpdateMonitorRequestBody struct.
+impl UpdateMonitorRequestBody {
+    /// # Description
+    ///
+    /// Apply the changes in the update monitor request body to a monitor.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor the changes will be applied to.
+    pub(super) fn apply(&self, monitor: &mut Monitor) {
+        // If the monitor's name is being updated.
+        if let Some(name) = &self.name {
+            monitor.name = name.clone();
+        }
+
+        // If the monitor's check interval is being updated.
+        if let Some(check_interval) = self.check_interval {
+            monitor.check_interval = check_interval;
+        }
+
+        // If the monitor's check timeout is being updated.
+        if let Some(check_timeout) = self.check_timeout {
+            monitor.check_timeout = check_timeout;
+        }
+
+        // If the monitor is being enabled or disabled.
+        if let Some(is_enabled) = self.is_enabled {
+            monitor.is_enabled = is_enabled;
+        }
+
+        // If the monitor's configuration is being updated.
+        if let Some(configuration) = &self.configuration {
+            let mut configuration: MonitorConfiguration = configuration.clone();
+
+            // If the monitor is a heartbeat monitor, keep its existing token, or generate one if it
+            // is becoming a heartbeat monitor.
+            if let MonitorConfiguration::Heartbeat(heartbeat_configuration) = &mut configuration {
+                heartbeat_configuration.token = match &monitor.configuration {
+                    MonitorConfiguration::Heartbeat(existing_configuration) => {
+                        existing_configuration.token.clone()
+                    }
+                    _ => generate_random_string(HEARTBEAT_TOKEN_LENGTH),
+                };
+            }
+
+            monitor.configuration = configuration;
+        }
+    }
+}
@@ -0,0 +1,675 @@
+mod data;
+
+use self::data::{
+    CreateMonitorRequestBody, GetMonitorCheckResultsRequestParams,
+    GetMonitorIncidentsRequestParams, GetMonitorMaintenanceRequestParams,
+    GetMonitorSeriesRequestParams, GetMonitorStatisticsRequestParams, UpdateMonitorRequestBody,
+};
+use crate::{
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::{create_authentication_failure_response, create_email_not_verified_response},
+    },
+    config::Config,
+    feature::{
+        api_token::model::ApiTokenScope,
+        auth::service::AuthService,
+        check::service::CheckResultService,
+        incident::service::IncidentService,
+        maintenance::service::MaintenanceWindowService,
+        monitor::{model::Monitor, service::MonitorService},
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, patch, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+use time::{Duration, OffsetDateTime};
+
+/// The amount of check results that will be returned if a limit is not specified.
+const DEFAULT_CHECK_RESULT_LIMIT: u32 = 100;
+
+/// The maximum amount of check results that can be returned in a single request.
+const MAXIMUM_CHECK_RESULT_LIMIT: u32 = 1000;
+
+/// The amount of incidents that will be returned if a limit is not specified.
+const DEFAULT_INCIDENT_LIMIT: u32 = 100;
+
+/// The maximum amount of incidents that can be returned in a single request.
+const MAXIMUM_INCIDENT_LIMIT: u32 = 1000;
+
+/// The amount of days of upcoming maintenance that will be returned if an amount is not specified.
+const DEFAULT_MAINTENANCE_DAYS: u32 = 30;
+
+/// The maximum amount of days of upcoming maintenance that can be returned in a single request.
+const MAXIMUM_MAINTENANCE_DAYS: u32 = 365;
+
+/// # Description
+///
+/// Add the monitor controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the monitor controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/monitors")
+            .service(create_monitor)
+            .service(get_monitors)
+            .service(get_monitor)
+            .service(update_monitor)
+            .service(delete_monitor)
+            .service(get_monitor_check_results)
+            .service(get_monitor_statistics)
+            .service(get_monitor_series)
+            .service(get_monitor_incidents)
+            .service(get_monitor_maintenance),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to create a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains information about the monitor that is being created.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to create the monitor.
+///
+/// `config` - The server's configuration data.
+///
+/// # Returns
+///
+/// An http response.
+#[post("")]
+async fn create_monitor(
+    request: HttpRequest,
+    body: web::Json<CreateMonitorRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    config: web::Data<Config>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Users must verify their email address before they can create monitors, if it is required.
+    if config.account.require_verified_email && !user.email_is_verified {
+        return create_email_not_verified_response();
+    }
+
+    // Convert the request body into a monitor.
+    let mut monitor: Monitor = body.into_inner().into();
+    monitor.user_id = user.id;
+
+    // Create the monitor.
+    return match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(created_monitor) => HttpResponse::Ok().json(created_monitor),
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get all of the monitors that belong to the sending user.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitors.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_monitors(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitors that belong to the user.
+    return match monitor_service.get_by_user_id(&user.id).await {
+        QueryResult::Ok(monitors) => HttpResponse::Ok().json(monitors),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor that is being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}")]
+async fn get_monitor(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor the user is requesting.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view it.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Return the monitor.
+    return HttpResponse::Ok().json(monitor);
+}
+
+/// # Description
+///
+/// An api endpoint to update a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor that is being updated.
+///
+/// `body` - The request body which contains information about the monitor that is being updated.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to update the monitor.
+///
+/// # Returns
+///
+/// An http response.
+#[patch("/{id}")]
+async fn update_monitor(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    body: web::Json<UpdateMonitorRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor that is being updated.
+    let mut monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to update it.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Apply the update to the monitor.
+    body.apply(&mut monitor);
+
+    // Update the monitor.
+    return match monitor_service.update(&monitor).await {
+        UpdateResult::Ok(monitor) => HttpResponse::Ok().json(monitor),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to delete a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor that is being deleted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to delete the monitor.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn delete_monitor(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsWrite))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor that is being deleted.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to delete it.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Delete the monitor.
+    return match monitor_service.delete(&id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the most recent check results of a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor whose check results are being retrieved.
+///
+/// `params` - Query parameters sent with the request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// `check_result_service` - The check result service that will be used to retrieve the check
+/// results.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/checks")]
+async fn get_monitor_check_results(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetMonitorCheckResultsRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    check_result_service: Inject<DependencyInjector, dyn CheckResultService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor whose check results are being requested.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its check results.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Determine how many check results to return.
+    let limit: u32 = params
+        .limit
+        .unwrap_or(DEFAULT_CHECK_RESULT_LIMIT)
+        .min(MAXIMUM_CHECK_RESULT_LIMIT);
+
+    // Get the monitor's check results.
+    return match check_result_service
+        .get_by_monitor_id(&monitor.id, &limit)
+        .await
+    {
+        QueryResult::Ok(check_results) => HttpResponse::Ok().json(check_results),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the uptime, downtime, check counts and latency percentiles of a monitor
+/// over a window of time or a custom time range.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor whose statistics are being retrieved.
+///
+/// `params` - Query parameters sent with the request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// `check_result_service` - The check result service that will be used to calculate the
+/// statistics.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/statistics")]
+async fn get_monitor_statistics(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetMonitorStatisticsRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    check_result_service: Inject<DependencyInjector, dyn CheckResultService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Determine the time range the statistics are being requested for.
+    let (from, to) = match params.get_time_range() {
+        Ok(time_range) => time_range,
+        Err(details) => return HttpResponse::BadRequest().json(details),
+    };
+
+    // Get the monitor whose statistics are being requested.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its statistics.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Get the monitor's statistics.
+    return match check_result_service
+        .get_statistics(&monitor.id, &from, &to)
+        .await
+    {
+        QueryResult::Ok(statistics) => HttpResponse::Ok().json(statistics),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get a time series of a monitor's uptime, check counts and latency
+/// percentiles, bucketed by minute, hour or day, for charting.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor whose series is being retrieved.
+///
+/// `params` - Query parameters sent with the request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// `check_result_service` - The check result service that will be used to calculate the series.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/series")]
+async fn get_monitor_series(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetMonitorSeriesRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    check_result_service: Inject<DependencyInjector, dyn CheckResultService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Determine the time range and bucket width the series is being requested for.
+    let (from, to, width) = match params.get_time_range_and_bucket_width() {
+        Ok(time_range_and_bucket_width) => time_range_and_bucket_width,
+        Err(details) => return HttpResponse::BadRequest().json(details),
+    };
+
+    // Get the monitor whose series is being requested.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its series.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Get the monitor's series.
+    return match check_result_service
+        .get_series(&monitor.id, &from, &to, &width)
+        .await
+    {
+        QueryResult::Ok(series) => HttpResponse::Ok().json(series),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the most recent incidents that affected a monitor.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor whose incidents are being retrieved.
+///
+/// `params` - Query parameters sent with the request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// `incident_service` - The incident service that will be used to retrieve the incidents.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/incidents")]
+async fn get_monitor_incidents(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetMonitorIncidentsRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    incident_service: Inject<DependencyInjector, dyn IncidentService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor whose incidents are being requested.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its incidents.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Determine how many incidents to return.
+    let limit: u32 = params
+        .limit
+        .unwrap_or(DEFAULT_INCIDENT_LIMIT)
+        .min(MAXIMUM_INCIDENT_LIMIT);
+
+    // Get the monitor's incidents.
+    return match incident_service
+        .get_by_monitor_id(&monitor.id, &limit)
+        .await
+    {
+        QueryResult::Ok(incidents) => HttpResponse::Ok().json(incidents),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the upcoming occurrences of the maintenance windows that apply to a
+/// monitor, including any occurrence that is currently in progress.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the monitor whose upcoming maintenance is being retrieved.
+///
+/// `params` - Query parameters sent with the request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitor.
+///
+/// `maintenance_window_service` - The maintenance window service that will be used to retrieve the
+/// upcoming maintenance.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/maintenance-windows")]
+async fn get_monitor_maintenance(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetMonitorMaintenanceRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    maintenance_window_service: Inject<DependencyInjector, dyn MaintenanceWindowService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service
+        .authenticate_request(&request, Some(ApiTokenScope::MonitorsRead))
+        .await
+    {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the monitor whose upcoming maintenance is being requested.
+    let monitor: Monitor = match monitor_service.get(&id).await {
+        QueryResult::Ok(monitor) => monitor,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the monitor, they are not allowed to view its maintenance.
+    if user.id != monitor.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Determine how far ahead to look for maintenance.
+    let days: u32 = params
+        .days
+        .unwrap_or(DEFAULT_MAINTENANCE_DAYS)
+        .min(MAXIMUM_MAINTENANCE_DAYS);
+    let from: OffsetDateTime = OffsetDateTime::now_utc();
+    let to: OffsetDateTime = from + Duration::days(i64::from(days));
+
+    // Get the monitor's upcoming maintenance.
+    return match maintenance_window_service
+        .get_occurrences_by_monitor_id(&monitor.id, &from, &to)
+        .await
+    {
+        QueryResult::Ok(occurrences) => HttpResponse::Ok().json(occurrences),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
@@ -0,0 +1,599 @@
+use crate::common::utility::create_value_validation_error;
+use regex::Regex;
+use reqwest::header::HeaderName;
+use serde::{Deserialize, Serialize};
+use serde_json_path::JsonPath;
+use sqlx::types::Json;
+use std::net::SocketAddr;
+use time::OffsetDateTime;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// A monitor struct.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Monitor {
+    /// The monitor's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the user that the monitor belongs to.
+    pub(crate) user_id: u64,
+
+    /// The date and time the monitor was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) monitor_created_at: OffsetDateTime,
+
+    /// The monitor's name.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) name: String,
+
+    /// The amount of time (in seconds) between each check the monitor performs.
+    #[validate(range(min = 10, max = 86400))]
+    pub(crate) check_interval: u32,
+
+    /// The amount of time (in seconds) a check is allowed to take before it is considered to have
+    /// failed.
+    #[validate(range(min = 1, max = 60))]
+    pub(crate) check_timeout: u32,
+
+    /// If the monitor is enabled.
+    pub(crate) is_enabled: bool,
+
+    /// The monitor's type specific configuration.
+    #[validate(nested)]
+    pub(crate) configuration: MonitorConfiguration,
+}
+
+/// A Default implementation for the Monitor struct.
+impl Default for Monitor {
+    fn default() -> Self {
+        return Monitor {
+            id: 0,
+            user_id: 0,
+            monitor_created_at: OffsetDateTime::now_utc(),
+            name: String::from(""),
+            check_interval: 60,
+            check_timeout: 10,
+            is_enabled: true,
+            configuration: MonitorConfiguration::Http(HttpMonitorConfiguration::default()),
+        };
+    }
+}
+
+/// A PartialEq implementation for the Monitor struct.
+impl PartialEq for Monitor {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.user_id == other.user_id
+            && self.monitor_created_at.date() == other.monitor_created_at.date()
+            && self.monitor_created_at.hour() == other.monitor_created_at.hour()
+            && self.monitor_created_at.minute() == other.monitor_created_at.minute()
+            && self.monitor_created_at.second() == other.monitor_created_at.second()
+            && self.name == other.name
+            && self.check_interval == other.check_interval
+            && self.check_timeout == other.check_timeout
+            && self.is_enabled == other.is_enabled
+            && self.configuration == other.configuration;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the types of monitors, and their type specific configuration.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum MonitorConfiguration {
+    /// # Description
+    ///
+    /// The Http variant is used for monitors that send http(s) requests to a url.
+    Http(HttpMonitorConfiguration),
+
+    /// # Description
+    ///
+    /// The Tcp variant is used for monitors that open tcp connections to a host and port.
+    Tcp(TcpMonitorConfiguration),
+
+    /// # Description
+    ///
+    /// The Tls variant is used for monitors that perform tls handshakes with a host and port to
+    /// inspect its certificate.
+    Tls(TlsMonitorConfiguration),
+
+    /// # Description
+    ///
+    /// The Dns variant is used for monitors that query a dns resolver for a host name's records.
+    Dns(DnsMonitorConfiguration),
+
+    /// # Description
+    ///
+    /// The Heartbeat variant is used for monitors that wait for jobs to send them heartbeats,
+    /// rather than performing checks themselves.
+    Heartbeat(HeartbeatMonitorConfiguration),
+}
+
+/// A Validate implementation for the MonitorConfiguration enum.
+impl Validate for MonitorConfiguration {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        return match self {
+            MonitorConfiguration::Http(configuration) => configuration.validate(),
+            MonitorConfiguration::Tcp(configuration) => configuration.validate(),
+            MonitorConfiguration::Tls(configuration) => configuration.validate(),
+            MonitorConfiguration::Dns(configuration) => configuration.validate(),
+            MonitorConfiguration::Heartbeat(configuration) => configuration.validate(),
+        };
+    }
+}
+
+/// A From<Json<MonitorConfiguration>> implementation for the MonitorConfiguration enum.
+///
+/// This allows monitor configurations to be queried directly from their json column.
+impl From<Json<MonitorConfiguration>> for MonitorConfiguration {
+    fn from(json: Json<MonitorConfiguration>) -> Self {
+        return json.0;
+    }
+}
+
+/// An http monitor configuration struct.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HttpMonitorConfiguration {
+    /// The url the request will be sent to.
+    #[validate(non_control_character, url, length(min = 5, max = 2048))]
+    pub(crate) url: String,
+
+    /// The request method that will be used.
+    pub(crate) method: HttpMethod,
+
+    /// The response status codes that indicate the service is operational.
+    #[validate(length(min = 1, max = 64), custom(function = "validate_status_codes"))]
+    pub(crate) expected_status_codes: Vec<u16>,
+
+    /// The assertions that will be made on the response, in addition to its status code.
+    #[serde(default)]
+    #[validate(length(max = 32), nested)]
+    pub(crate) assertions: Vec<HttpAssertion>,
+}
+
+/// A Default implementation for the HttpMonitorConfiguration struct.
+impl Default for HttpMonitorConfiguration {
+    fn default() -> Self {
+        return HttpMonitorConfiguration {
+            url: String::from(""),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        };
+    }
+}
+
+/// An enumeration of all of the http request methods a monitor can use.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub(crate) enum HttpMethod {
+    /// # Description
+    ///
+    /// The GET request method.
+    Get,
+
+    /// # Description
+    ///
+    /// The HEAD request method.
+    Head,
+
+    /// # Description
+    ///
+    /// The POST request method.
+    Post,
+
+    /// # Description
+    ///
+    /// The PUT request method.
+    Put,
+
+    /// # Description
+    ///
+    /// The PATCH request method.
+    Patch,
+
+    /// # Description
+    ///
+    /// The DELETE request method.
+    Delete,
+
+    /// # Description
+    ///
+    /// The OPTIONS request method.
+    Options,
+}
+
+/// An enumeration of all of the assertions an http monitor can make on the responses it receives.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum HttpAssertion {
+    /// # Description
+    ///
+    /// The BodyContains variant asserts that the response body contains a keyword.
+    BodyContains {
+        /// The keyword the response body must contain.
+        keyword: String,
+    },
+
+    /// # Description
+    ///
+    /// The BodyNotContains variant asserts that the response body does not contain a keyword.
+    BodyNotContains {
+        /// The keyword the response body must not contain.
+        keyword: String,
+    },
+
+    /// # Description
+    ///
+    /// The BodyMatches variant asserts that the response body matches a regular expression.
+    BodyMatches {
+        /// The regular expression the response body must match.
+        pattern: String,
+    },
+
+    /// # Description
+    ///
+    /// The JsonPath variant asserts that the value a json path selects from the response body
+    /// compares to an expected value.
+    JsonPath {
+        /// The json path that selects the value from the response body.
+        path: String,
+
+        /// The comparison that will be made between the selected value and the expected value.
+        comparison: JsonPathComparison,
+
+        /// The expected value.
+        value: serde_json::Value,
+    },
+
+    /// # Description
+    ///
+    /// The HeaderMatches variant asserts that a response header is present and that its value
+    /// matches a regular expression.
+    HeaderMatches {
+        /// The name of the response header.
+        name: String,
+
+        /// The regular expression the response header's value must match.
+        pattern: String,
+    },
+
+    /// # Description
+    ///
+    /// The LatencyBelow variant asserts that the response was received in less than a threshold.
+    LatencyBelow {
+        /// The amount of time (in milliseconds) the response must be received within.
+        threshold: u32,
+    },
+}
+
+/// A Validate implementation for the HttpAssertion enum.
+impl Validate for HttpAssertion {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut validation_errors: ValidationErrors = ValidationErrors::new();
+
+        // Validate the assertion's fields.
+        match self {
+            HttpAssertion::BodyContains { keyword }
+            | HttpAssertion::BodyNotContains { keyword } => {
+                if keyword.is_empty() || keyword.len() > 1024 {
+                    validation_errors
+                        .add("keyword", create_value_validation_error("length", keyword));
+                }
+            }
+            HttpAssertion::BodyMatches { pattern } => {
+                if let Err(error) = validate_pattern(pattern) {
+                    validation_errors.add("pattern", error);
+                }
+            }
+            HttpAssertion::JsonPath { path, .. } => {
+                if path.len() > 1024 || JsonPath::parse(path).is_err() {
+                    validation_errors.add("path", create_value_validation_error("json_path", path));
+                }
+            }
+            HttpAssertion::HeaderMatches { name, pattern } => {
+                if HeaderName::from_bytes(name.as_bytes()).is_err() {
+                    validation_errors
+                        .add("name", create_value_validation_error("header_name", name));
+                }
+                if let Err(error) = validate_pattern(pattern) {
+                    validation_errors.add("pattern", error);
+                }
+            }
+            HttpAssertion::LatencyBelow { threshold } => {
+                if *threshold < 1 || *threshold > 60000 {
+                    validation_errors.add(
+                        "threshold",
+                        create_value_validation_error("range", threshold),
+                    );
+                }
+            }
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return Err(validation_errors);
+        }
+
+        return Ok(());
+    }
+}
+
+/// An enumeration of all of the comparisons a json path assertion can make.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum JsonPathComparison {
+    /// # Description
+    ///
+    /// The Equals variant asserts that the selected value is equal to the expected value.
+    Equals,
+
+    /// # Description
+    ///
+    /// The NotEquals variant asserts that the selected value is not equal to the expected value.
+    NotEquals,
+
+    /// # Description
+    ///
+    /// The GreaterThan variant asserts that the selected value is a number greater than the
+    /// expected value.
+    GreaterThan,
+
+    /// # Description
+    ///
+    /// The LessThan variant asserts that the selected value is a number less than the expected
+    /// value.
+    LessThan,
+}
+
+/// A tcp monitor configuration struct.
+///
+/// The monitor's check timeout is used as the connect timeout, and also bounds the amount of time
+/// spent waiting for the banner.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TcpMonitorConfiguration {
+    /// The host name or ip address the connection will be opened to.
+    #[validate(non_control_character, length(min = 1, max = 253))]
+    pub(crate) host: String,
+
+    /// The port the connection will be opened to.
+    #[validate(range(min = 1))]
+    pub(crate) port: u16,
+
+    /// A regular expression the first bytes sent by the service must match, if any.
+    #[validate(length(min = 1, max = 1024), custom(function = "validate_regex"))]
+    pub(crate) banner_pattern: Option<String>,
+}
+
+/// A tls monitor configuration struct.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TlsMonitorConfiguration {
+    /// The host name or ip address the connection will be opened to.
+    #[validate(non_control_character, length(min = 1, max = 253))]
+    pub(crate) host: String,
+
+    /// The port the connection will be opened to.
+    #[validate(range(min = 1))]
+    pub(crate) port: u16,
+
+    /// The server name that will be sent during the handshake and that the certificate will be
+    /// verified against, if it differs from the host.
+    #[validate(non_control_character, length(min = 1, max = 253))]
+    pub(crate) server_name: Option<String>,
+
+    /// The amount of days before the certificate expires that the service will be considered to be
+    /// degraded.
+    #[validate(range(max = 365))]
+    pub(crate) expiry_threshold: u16,
+}
+
+/// A dns monitor configuration struct.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+#[validate(schema(function = "validate_dns_assertion"))]
+pub(crate) struct DnsMonitorConfiguration {
+    /// The host name whose records will be queried.
+    #[validate(non_control_character, length(min = 1, max = 253))]
+    pub(crate) host: String,
+
+    /// The type of records that will be queried.
+    pub(crate) record_type: DnsRecordType,
+
+    /// The address (ip:port) of the dns resolver the query will be sent to.
+    #[validate(custom(function = "validate_socket_address"))]
+    pub(crate) resolver: String,
+
+    /// The assertion that will be made on the records that are returned.
+    pub(crate) assertion: DnsAssertion,
+
+    /// The records the assertion will be made with (this is unused by the resolves assertion).
+    #[validate(length(max = 64))]
+    pub(crate) expected_records: Vec<String>,
+}
+
+/// A heartbeat monitor configuration struct.
+///
+/// The monitor's check interval is the period heartbeats are expected to be received within.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HeartbeatMonitorConfiguration {
+    /// The unguessable token that identifies the monitor in its heartbeat urls (this is generated
+    /// by the server).
+    #[serde(default)]
+    #[validate(length(max = 64))]
+    pub(crate) token: String,
+
+    /// The amount of time (in seconds) a heartbeat may be late before the monitor is considered to
+    /// be down.
+    #[validate(range(max = 86400))]
+    pub(crate) grace_period: u32,
+}
+
+/// An enumeration of all of the dns record types a monitor can query.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub(crate) enum DnsRecordType {
+    /// # Description
+    ///
+    /// The A record type, which contains ipv4 addresses.
+    A,
+
+    /// # Description
+    ///
+    /// The AAAA record type, which contains ipv6 addresses.
+    Aaaa,
+
+    /// # Description
+    ///
+    /// The CNAME record type, which contains canonical names.
+    Cname,
+
+    /// # Description
+    ///
+    /// The MX record type, which contains mail exchanges and their preferences.
+    Mx,
+
+    /// # Description
+    ///
+    /// The TXT record type, which contains text.
+    Txt,
+}
+
+/// An enumeration of all of the assertions a dns monitor can make on the records it queries.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum DnsAssertion {
+    /// # Description
+    ///
+    /// The Resolves variant asserts that at least one record is returned.
+    Resolves,
+
+    /// # Description
+    ///
+    /// The Exact variant asserts that the records returned are exactly the expected records.
+    Exact,
+
+    /// # Description
+    ///
+    /// The Contains variant asserts that the records returned include all of the expected records.
+    Contains,
+}
+
+/// # Description
+///
+/// Validate a list of http status codes.
+///
+/// # Arguments
+///
+/// `status_codes` - The status codes that are being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If all of the status codes are valid, the Ok variant will be returned.
+/// - If a status code is not valid, the Err variant will be returned with a validation error.
+fn validate_status_codes(status_codes: &Vec<u16>) -> Result<(), ValidationError> {
+    for status_code in status_codes {
+        if *status_code < 100 || *status_code > 599 {
+            return Err(create_value_validation_error("range", status_code));
+        }
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Validate a regular expression.
+///
+/// # Arguments
+///
+/// `pattern` - The regular expression that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the regular expression is valid, the Ok variant will be returned.
+/// - If the regular expression is not valid, the Err variant will be returned with a validation
+/// error.
+fn validate_regex(pattern: &String) -> Result<(), ValidationError> {
+    return match Regex::new(pattern) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(create_value_validation_error("regex", pattern)),
+    };
+}
+
+/// # Description
+///
+/// Validate a socket address.
+///
+/// # Arguments
+///
+/// `address` - The socket address that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the socket address is valid, the Ok variant will be returned.
+/// - If the socket address is not valid, the Err variant will be returned with a validation error.
+fn validate_socket_address(address: &String) -> Result<(), ValidationError> {
+    return match address.parse::<SocketAddr>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(create_value_validation_error("socket_address", address)),
+    };
+}
+
+/// # Description
+///
+/// Validate that a dns monitor configuration has the expected records its assertion requires.
+///
+/// # Arguments
+///
+/// `configuration` - The dns monitor configuration that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the configuration is valid, the Ok variant will be returned.
+/// - If the assertion requires expected records and none were specified, the Err variant will be
+/// returned with a validation error.
+fn validate_dns_assertion(configuration: &DnsMonitorConfiguration) -> Result<(), ValidationError> {
+    if configuration.assertion != DnsAssertion::Resolves
+        && configuration.expected_records.is_empty()
+    {
+        return Err(create_value_validation_error(
+            "expected_records",
+            &configuration.assertion,
+        ));
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Validate the length and syntax of a regular expression.
+///
+/// # Arguments
+///
+/// `pattern` - The regular expression that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the regular expression is valid, the Ok variant will be returned.
+/// - If the regular expression is not valid, the Err variant will be returned with a validation
+/// error.
+fn validate_pattern(pattern: &String) -> Result<(), ValidationError> {
+    if pattern.is_empty() || pattern.len() > 1024 {
+        return Err(create_value_validation_error("length", pattern));
+    }
+
+    return validate_regex(pattern);
+}
@@ -0,0 +1,388 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::monitor::model::{Monitor, MonitorConfiguration},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// A monitor repository trait.
+#[async_trait]
+pub(crate) trait MonitorRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a monitor into the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor to insert into the monitor repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// monitor that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, monitor: &Monitor, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a monitor from the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the monitor to get from the monitor repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional monitor.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Monitor>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the monitors that belong to a user from the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the monitors belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the monitors that were
+    /// found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Monitor>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the monitors that are enabled from the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the monitors that were
+    /// found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_enabled(&self, context: &mut QueryContext) -> Result<Vec<Monitor>, Error>;
+
+    /// # Description
+    ///
+    /// Get a heartbeat monitor from the monitor repository by it's heartbeat token.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The heartbeat token of the monitor to get from the monitor repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional monitor.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_heartbeat_token(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<Monitor>, Error>;
+
+    /// # Description
+    ///
+    /// Update a monitor in the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor to update in the monitor repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(&self, monitor: &Monitor, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete a monitor from the monitor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the monitor to delete from the monitor repository.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+}
+
+/// A MonitorRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = MonitorRepository)]
+pub(crate) struct MonitorRepositoryImpl;
+
+/// A MonitorRepository implementation for the MonitorRepositoryImpl struct.
+#[async_trait]
+impl MonitorRepository for MonitorRepositoryImpl {
+    async fn insert(&self, monitor: &Monitor, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `monitors` (
+                    `id`,
+                    `user_id`,
+                    `monitor_created_at`,
+                    `name`,
+                    `check_interval`,
+                    `check_timeout`,
+                    `is_enabled`,
+                    `configuration`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            monitor.id,
+            monitor.user_id,
+            monitor.monitor_created_at,
+            monitor.name,
+            monitor.check_interval,
+            monitor.check_timeout,
+            monitor.is_enabled,
+            Json(&monitor.configuration)
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<Monitor>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Monitor,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `monitor_created_at` AS `monitor_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `check_interval` AS `check_interval: u32`,
+                    `check_timeout` AS `check_timeout: u32`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<MonitorConfiguration>`
+                FROM
+                    `monitors`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<Monitor>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Monitor,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `monitor_created_at` AS `monitor_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `check_interval` AS `check_interval: u32`,
+                    `check_timeout` AS `check_timeout: u32`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<MonitorConfiguration>`
+                FROM
+                    `monitors`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `id` ASC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_enabled(&self, context: &mut QueryContext) -> Result<Vec<Monitor>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Monitor,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `monitor_created_at` AS `monitor_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `check_interval` AS `check_interval: u32`,
+                    `check_timeout` AS `check_timeout: u32`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<MonitorConfiguration>`
+                FROM
+                    `monitors`
+                WHERE
+                    `is_enabled` = 1
+                ORDER BY
+                    `id` ASC;
+            "#
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_heartbeat_token(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<Monitor>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Monitor,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `monitor_created_at` AS `monitor_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `check_interval` AS `check_interval: u32`,
+                    `check_timeout` AS `check_timeout: u32`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<MonitorConfiguration>`
+                FROM
+                    `monitors`
+                WHERE
+                    `heartbeat_token` = ?;
+            "#,
+            token
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(&self, monitor: &Monitor, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `monitors`
+                SET
+                    `user_id` = ?,
+                    `monitor_created_at` = ?,
+                    `name` = ?,
+                    `check_interval` = ?,
+                    `check_timeout` = ?,
+                    `is_enabled` = ?,
+                    `configuration` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            monitor.user_id,
+            monitor.monitor_created_at,
+            monitor.name,
+            monitor.check_interval,
+            monitor.check_timeout,
+            monitor.is_enabled,
+            Json(&monitor.configuration),
+            monitor.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `monitors`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
@@ -0,0 +1,656 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
+        utility::create_value_validation_error,
+    },
+    database::DatabaseConnectionFactory,
+    feature::{
+        monitor::{model::Monitor, repository::MonitorRepository},
+        user::service::UserService,
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use validator::{Validate, ValidationErrors};
+
+/// A monitor service trait.
+#[async_trait(?Send)]
+pub(crate) trait MonitorService: Interface {
+    /// # Description
+    ///
+    /// Insert a monitor into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the monitor that was inserted.
+    /// - If the monitor being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        monitor: &Monitor,
+    ) -> InsertionResult<Monitor, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a monitor into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the monitor that was inserted.
+    /// - If the monitor being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Monitor, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a monitor from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the monitor that is being queried from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the monitor, the Ok variant will be returned with the monitor.
+    /// - If the monitor could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get(&self, id: &u64) -> QueryResult<Monitor, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a monitor from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the monitor that is being queried from persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the monitor, the Ok variant will be returned with the monitor.
+    /// - If the monitor could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Monitor, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a heartbeat monitor from persistent storage by it's heartbeat token.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The heartbeat token of the monitor that is being queried from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the monitor, the Ok variant will be returned with the monitor.
+    /// - If the monitor could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_heartbeat_token(&self, token: &String) -> QueryResult<Monitor, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a heartbeat monitor from persistent storage by it's heartbeat token.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The heartbeat token of the monitor that is being queried from persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the monitor, the Ok variant will be returned with the monitor.
+    /// - If the monitor could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_heartbeat_token_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<Monitor, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the monitors that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the monitors belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the monitors that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id(&self, user_id: &u64) -> QueryResult<Vec<Monitor>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the monitors that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the monitors belong to.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the monitors that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Monitor>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the monitors that are enabled from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the monitors that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_enabled(&self) -> QueryResult<Vec<Monitor>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the monitors that are enabled from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the monitors that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_enabled_with_context(
+        &self,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Monitor>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a monitor in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor that is being updated in persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the monitor updated.
+    /// - If the monitor that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the monitor being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update(
+        &self,
+        monitor: &Monitor,
+    ) -> UpdateResult<Monitor, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a monitor in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor that is being updated in persistent storage.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the monitor updated.
+    /// - If the monitor that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the monitor being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> UpdateResult<Monitor, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the monitor that is being removed from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a monitor was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the monitor being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a monitor from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the monitor that is being removed from persistent storage.
+    ///
+    /// `context` - The context the deletion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a monitor was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the monitor being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>>;
+}
+
+/// A MonitorServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = MonitorService)]
+pub(crate) struct MonitorServiceImpl {
+    /// The monitor repository that will be used to manage persistent monitor data.
+    #[shaku(inject)]
+    monitor_repository: Arc<dyn MonitorRepository>,
+
+    /// The user service that will be used to validate user data.
+    #[shaku(inject)]
+    user_service: Arc<dyn UserService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// A MonitorService implementation for the MonitorServiceImpl struct.
+#[async_trait(?Send)]
+impl MonitorService for MonitorServiceImpl {
+    async fn insert(
+        &self,
+        monitor: &Monitor,
+    ) -> InsertionResult<Monitor, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self.insert_with_context(monitor, &mut context).await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> InsertionResult<Monitor, ValidationErrors, Box<dyn Error>> {
+        // Validate the monitor.
+        let mut validation_errors = match monitor.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Check if the user id specified exists.
+        match __self.user_service.get_by_id(&monitor.user_id).await {
+            QueryResult::Ok(_) => {}
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(user_id in Monitor),
+                create_value_validation_error("not_found", &monitor.user_id),
+            ),
+            QueryResult::Err(error) => return InsertionResult::Err(error),
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Perform the insertion.
+        let monitor_id = match __self.monitor_repository.insert(monitor, context).await {
+            Ok(monitor_id) => monitor_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the monitor that was inserted.
+        let inserted_monitor_option =
+            match __self.monitor_repository.get(&monitor_id, context).await {
+                Ok(inserted_monitor_option) => inserted_monitor_option,
+                Err(error) => return InsertionResult::Err(Box::new(error)),
+            };
+
+        // If the monitor was found, return the monitor, otherwise return an error.
+        return match inserted_monitor_option {
+            Some(inserted_monitor) => InsertionResult::Ok(inserted_monitor),
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Monitor could not be found after insertion",
+            ))),
+        };
+    }
+
+    async fn get(&self, id: &u64) -> QueryResult<Monitor, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_with_context(id, &mut context).await;
+    }
+
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Monitor, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self.monitor_repository.get(id, context).await {
+            Ok(monitor_option) => monitor_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the monitor was found, return the monitor, otherwise return not found.
+        return match result {
+            Some(monitor) => QueryResult::Ok(monitor),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_heartbeat_token(&self, token: &String) -> QueryResult<Monitor, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_heartbeat_token_with_context(token, &mut context)
+            .await;
+    }
+
+    async fn get_by_heartbeat_token_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<Monitor, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self
+            .monitor_repository
+            .get_by_heartbeat_token(token, context)
+            .await
+        {
+            Ok(monitor_option) => monitor_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the monitor was found, return the monitor, otherwise return not found.
+        return match result {
+            Some(monitor) => QueryResult::Ok(monitor),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_user_id(&self, user_id: &u64) -> QueryResult<Vec<Monitor>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_user_id_with_context(user_id, &mut context)
+            .await;
+    }
+
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Monitor>, Box<dyn Error>> {
+        // Perform the query and return the monitors that were found.
+        return match __self
+            .monitor_repository
+            .get_by_user_id(user_id, context)
+            .await
+        {
+            Ok(monitors) => QueryResult::Ok(monitors),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_enabled(&self) -> QueryResult<Vec<Monitor>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_enabled_with_context(&mut context).await;
+    }
+
+    async fn get_enabled_with_context(
+        &self,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<Monitor>, Box<dyn Error>> {
+        // Perform the query and return the monitors that were found.
+        return match __self.monitor_repository.get_enabled(context).await {
+            Ok(monitors) => QueryResult::Ok(monitors),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update(
+        &self,
+        monitor: &Monitor,
+    ) -> UpdateResult<Monitor, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the update.
+        let update_result = self.update_with_context(monitor, &mut context).await;
+
+        // If the update was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update_with_context(
+        &self,
+        monitor: &Monitor,
+        context: &mut QueryContext,
+    ) -> UpdateResult<Monitor, ValidationErrors, Box<dyn Error>> {
+        // Validate the monitor.
+        let mut validation_errors = match monitor.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Check if the user id specified exists.
+        match __self.user_service.get_by_id(&monitor.user_id).await {
+            QueryResult::Ok(_) => {}
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(user_id in Monitor),
+                create_value_validation_error("not_found", &monitor.user_id),
+            ),
+            QueryResult::Err(error) => return UpdateResult::Err(error),
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Perform the update.
+        let records_updated = match __self.monitor_repository.update(&monitor, context).await {
+            Ok(records_updated) => records_updated,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // If no records were updated.
+        if records_updated == 0 {
+            return UpdateResult::NotFound;
+        }
+
+        // Query the updated monitor.
+        let updated_monitor_option = match __self.monitor_repository.get(&monitor.id, context).await
+        {
+            Ok(updated_monitor_option) => updated_monitor_option,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the monitor was found, and return the updated monitor.
+        return match updated_monitor_option {
+            Some(updated_monitor) => UpdateResult::Ok(updated_monitor),
+            None => UpdateResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Monitor could not be found after update",
+            ))),
+        };
+    }
+
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the deletion.
+        return self.delete_with_context(id, &mut context).await;
+    }
+
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>> {
+        // Perform the deletion.
+        let records_deleted = match __self.monitor_repository.delete(id, context).await {
+            Ok(records_deleted) => records_deleted,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Return the result.
+        if records_deleted > 0 {
+            return DeletionResult::Ok;
+        } else {
+            return DeletionResult::NotFound;
+        }
+    }
+}
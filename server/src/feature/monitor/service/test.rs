@@ -0,0 +1,760 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult, UpdateResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting a monitor and make sure the correct data is returned.
+#[actix_web::test]
+async fn monitor_is_returned_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: Monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting a monitor with validation errors and make sure it does not succeed.
+#[actix_web::test]
+async fn inserting_a_monitor_with_validation_errors_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+    monitor.name = generate_random_string(1048576);
+
+    // Insert the monitor, and make sure the validation errors are detected.
+    match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting a monitor with an invalid user id and make sure it does not succeed.
+#[actix_web::test]
+async fn inserting_a_monitor_with_an_invalid_user_id_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = 0;
+
+    // Insert the monitor, and make sure the invalid user id is detected.
+    match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(_) => panic!("Inserted succeeded when it should have failed"),
+        InsertionResult::Invalid(_) => {}
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    }
+}
+
+/// # Description
+///
+/// Test querying a monitor after it has been inserted and make sure the correct data is returned.
+#[actix_web::test]
+async fn monitor_is_queryable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Query the monitor that was inserted.
+    let queried_monitor = match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(queried_monitor) => queried_monitor,
+        QueryResult::NotFound => {
+            panic!("Failed to query monitor after insertion: Monitor not found")
+        }
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after insertion: an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the monitor has the correct data.
+    assert_eq!(monitor, queried_monitor);
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: Monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test updating a monitor after insertion and make sure the data is updated correctly.
+#[actix_web::test]
+async fn monitor_is_updatable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Query the monitor that was inserted.
+    let queried_monitor = match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(queried_monitor) => queried_monitor,
+        QueryResult::NotFound => {
+            panic!("Failed to query monitor after insertion: Monitor not found")
+        }
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after insertion: an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the monitor has the correct data.
+    assert_eq!(monitor, queried_monitor);
+
+    // Create an updated monitor.
+    let mut updated_monitor = create_test_monitor();
+    updated_monitor.id = monitor.id;
+    updated_monitor.user_id = monitor.user_id;
+
+    // Perform the update.
+    let returned_updated_monitor = match monitor_service.update(&updated_monitor).await {
+        UpdateResult::Ok(returned_updated_monitor) => returned_updated_monitor,
+        UpdateResult::NotFound => panic!("Failed to update monitor: Monitor not found"),
+        UpdateResult::Invalid(details) => panic!(
+            "Failed to update monitor, invalid values specified: {}",
+            details
+        ),
+        UpdateResult::Err(error) => panic!(
+            "Failed to update monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the returned updated monitor contains the correct information.
+    assert_eq!(updated_monitor, returned_updated_monitor);
+
+    // Query the updated monitor.
+    let queried_updated_monitor = match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(queried_updated_monitor) => queried_updated_monitor,
+        QueryResult::NotFound => panic!("Failed to query monitor after update: Monitor not found"),
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after update: an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the queried updated monitor contains the correct information.
+    assert_eq!(updated_monitor, queried_updated_monitor);
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test updating a monitor with validation errors and make sure it does not succeed.
+#[actix_web::test]
+async fn updating_a_monitor_with_validation_errors_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Query the monitor that was inserted.
+    let queried_monitor = match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(queried_monitor) => queried_monitor,
+        QueryResult::NotFound => {
+            panic!("Failed to query monitor after insertion: Monitor not found")
+        }
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after insertion: an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the monitor has the correct data.
+    assert_eq!(monitor, queried_monitor);
+
+    // Create an updated monitor.
+    let mut updated_monitor = create_test_monitor();
+    updated_monitor.id = monitor.id;
+    updated_monitor.user_id = monitor.user_id;
+    updated_monitor.name = generate_random_string(1048576);
+
+    // Perform the update.
+    match monitor_service.update(&updated_monitor).await {
+        UpdateResult::Ok(_) => panic!("Monitor was updated when it contained validation errors"),
+        UpdateResult::NotFound => panic!("Failed to update monitor: Monitor not found"),
+        UpdateResult::Invalid(_) => {}
+        UpdateResult::Err(error) => panic!(
+            "Failed to update monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test updating a monitor with an invalid user id and make sure it does not succeed.
+#[actix_web::test]
+async fn updating_a_monitor_with_an_invalid_user_id_does_not_succeed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Query the monitor that was inserted.
+    let queried_monitor = match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(queried_monitor) => queried_monitor,
+        QueryResult::NotFound => {
+            panic!("Failed to query monitor after insertion: Monitor not found")
+        }
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after insertion: an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the monitor has the correct data.
+    assert_eq!(monitor, queried_monitor);
+
+    // Create an updated monitor.
+    let mut updated_monitor = create_test_monitor();
+    updated_monitor.id = monitor.id;
+    updated_monitor.user_id = 0;
+
+    // Perform the update.
+    match monitor_service.update(&updated_monitor).await {
+        UpdateResult::Ok(_) => panic!("Monitor was updated when it contained validation errors"),
+        UpdateResult::NotFound => panic!("Failed to update monitor: Monitor not found"),
+        UpdateResult::Invalid(_) => {}
+        UpdateResult::Err(error) => panic!(
+            "Failed to update monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test querying a monitor after it was deleted and make sure it was not returned.
+#[actix_web::test]
+async fn monitor_is_not_queryable_after_deletion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Create a test monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Insert the monitor.
+    let inserted_monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(inserted_monitor) => inserted_monitor,
+        InsertionResult::Invalid(details) => {
+            panic!(
+                "Failed to insert monitor, the monitor was invalid: {}",
+                details
+            )
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the monitor id with the one that was generated.
+    monitor.id = inserted_monitor.id;
+
+    // Make sure the correct data was returned.
+    assert_eq!(monitor, inserted_monitor);
+
+    // Delete the test monitor.
+    match monitor_service.delete(&monitor.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => {
+            panic!("Failed to delete monitor: Monitor could not be found")
+        }
+        DeletionResult::Err(error) => panic!(
+            "Failed to delete monitor, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Query the monitor and make sure it was not found.
+    match monitor_service.get(&monitor.id).await {
+        QueryResult::Ok(_) => {
+            panic!("Failed was returned when it should have been deleted")
+        }
+        QueryResult::NotFound => {}
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitor after insertion, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test querying the monitors that belong to a user and make sure the correct monitors are
+/// returned.
+#[actix_web::test]
+async fn monitors_are_queryable_by_user_id() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector).await;
+
+    // Insert a couple of monitors for the user.
+    let mut monitors: Vec<Monitor> = Vec::new();
+    for _ in 0..2 {
+        let mut monitor: Monitor = create_test_monitor();
+        monitor.user_id = user.id;
+
+        match monitor_service.insert(&monitor).await {
+            InsertionResult::Ok(inserted_monitor) => monitors.push(inserted_monitor),
+            InsertionResult::Invalid(details) => {
+                panic!(
+                    "Failed to insert monitor, the monitor was invalid: {}",
+                    details
+                )
+            }
+            InsertionResult::Err(error) => panic!(
+                "Failed to insert monitor, an unexpected error has occurred: {}",
+                error
+            ),
+        };
+    }
+
+    // Query the user's monitors.
+    let queried_monitors: Vec<Monitor> = match monitor_service.get_by_user_id(&user.id).await {
+        QueryResult::Ok(queried_monitors) => queried_monitors,
+        QueryResult::NotFound => panic!("Failed to query monitors: Monitors not found"),
+        QueryResult::Err(error) => panic!(
+            "Failed to query monitors, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure the correct monitors were returned.
+    assert_eq!(monitors, queried_monitors);
+
+    // Delete the test user (their monitors will be deleted with them).
+    delete_test_user(&user, &injector).await;
+}
@@ -0,0 +1,118 @@
+use crate::{
+    common::utility::generate_random_string,
+    feature::notification::model::{NotificationChannel, NotificationChannelConfiguration},
+};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+
+/// The length of the secrets that are generated for webhooks that are created without one.
+const GENERATED_SECRET_LENGTH: usize = 32;
+
+/// A create notification channel request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateNotificationChannelRequestBody {
+    /// The notification channel's name.
+    pub(super) name: String,
+
+    /// If the notification channel is enabled.
+    pub(super) is_enabled: Option<bool>,
+
+    /// The notification channel's type specific configuration. If a webhook's secret is empty, one
+    /// will be generated.
+    pub(super) configuration: NotificationChannelConfiguration,
+
+    /// The unique identifiers of the monitors whose events are sent to the notification channel.
+    pub(super) monitor_ids: Option<Vec<u64>>,
+}
+
+/// An Into<NotificationChannel> implementation for the CreateNotificationChannelRequestBody struct.
+impl Into<NotificationChannel> for CreateNotificationChannelRequestBody {
+    fn into(self) -> NotificationChannel {
+        // Generate a secret for webhooks that were not given one.
+        let mut configuration: NotificationChannelConfiguration = self.configuration;
+        if let NotificationChannelConfiguration::Webhook(configuration) = &mut configuration {
+            if configuration.secret.is_empty() {
+                configuration.secret = generate_random_string(GENERATED_SECRET_LENGTH);
+            }
+        }
+
+        return NotificationChannel {
+            name: self.name,
+            is_enabled: self.is_enabled.unwrap_or(true),
+            configuration,
+            monitor_ids: Json(self.monitor_ids.unwrap_or_default()),
+            ..Default::default()
+        };
+    }
+}
+
+/// An update notification channel request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UpdateNotificationChannelRequestBody {
+    /// The notification channel's name.
+    pub(super) name: Option<String>,
+
+    /// If the notification channel is enabled.
+    pub(super) is_enabled: Option<bool>,
+
+    /// The notification channel's type specific configuration. If a webhook's secret is empty, its
+    /// current secret will be kept.
+    pub(super) configuration: Option<NotificationChannelConfiguration>,
+
+    /// The unique identifiers of the monitors whose events are sent to the notification channel.
+    pub(super) monitor_ids: Option<Vec<u64>>,
+}
+
+/// An implementation for the UpdateNotificationChannelRequestBody struct.
+impl UpdateNotificationChannelRequestBody {
+    /// # Description
+    ///
+    /// Apply the changes in the update notification channel request body to a notification
+    /// channel.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel the changes will be applied to.
+    pub(super) fn apply(&self, notification_channel: &mut NotificationChannel) {
+        // If the notification channel's name is being updated.
+        if let Some(name) = &self.name {
+            notification_channel.name = name.clone();
+        }
+
+        // If the notification channel is being enabled or disabled.
+        if let Some(is_enabled) = self.is_enabled {
+            notification_channel.is_enabled = is_enabled;
+        }
+
+        // If the notification channel's configuration is being updated, keep the current secret
+        // unless a new one was specified.
+        if let Some(configuration) = &self.configuration {
+            let mut configuration: NotificationChannelConfiguration = configuration.clone();
+            if let (
+                NotificationChannelConfiguration::Webhook(configuration),
+                NotificationChannelConfiguration::Webhook(current_configuration),
+            ) = (&mut configuration, &notification_channel.configuration)
+            {
+                if configuration.secret.is_empty() {
+                    configuration.secret = current_configuration.secret.clone();
+                }
+            }
+            notification_channel.configuration = configuration;
+        }
+
+        // If the monitors whose events are sent to the notification channel are being updated.
+        if let Some(monitor_ids) = &self.monitor_ids {
+            notification_channel.monitor_ids = Json(monitor_ids.clone());
+        }
+    }
+}
+
+/// A get notification channel deliveries request query parameter struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetNotificationChannelDeliveriesRequestParams {
+    /// The maximum amount of deliveries to return.
+    pub(super) limit: Option<u32>,
+}
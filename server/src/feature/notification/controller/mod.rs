@@ -0,0 +1,529 @@
+mod data;
+
+use self::data::{
+    CreateNotificationChannelRequestBody, GetNotificationChannelDeliveriesRequestParams,
+    UpdateNotificationChannelRequestBody,
+};
+use crate::{
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::{create_authentication_failure_response, create_email_not_verified_response},
+    },
+    config::Config,
+    feature::{
+        auth::service::AuthService,
+        notification::{
+            model::{NotificationChannel, NotificationDelivery},
+            service::NotificationChannelService,
+        },
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, patch, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// The amount of deliveries that will be returned if a limit is not specified.
+const DEFAULT_DELIVERY_LIMIT: u32 = 100;
+
+/// The maximum amount of deliveries that can be returned.
+const MAXIMUM_DELIVERY_LIMIT: u32 = 1000;
+
+/// # Description
+///
+/// Add the notification channel controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the notification channel controller configuration will be
+/// added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/notification-channels")
+            .service(create_notification_channel)
+            .service(get_notification_channels)
+            .service(get_notification_channel)
+            .service(update_notification_channel)
+            .service(delete_notification_channel)
+            .service(get_notification_channel_deliveries)
+            .service(get_notification_delivery_attempts)
+            .service(replay_notification_delivery),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to create a notification channel.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains information about the notification channel that is
+/// being created.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to create
+/// the notification channel.
+///
+/// `config` - The server's configuration data.
+///
+/// # Returns
+///
+/// An http response.
+#[post("")]
+async fn create_notification_channel(
+    request: HttpRequest,
+    body: web::Json<CreateNotificationChannelRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+    config: web::Data<Config>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Users must verify their email address before they can create notification channels, if it
+    // is required.
+    if config.account.require_verified_email && !user.email_is_verified {
+        return create_email_not_verified_response();
+    }
+
+    // Convert the request body into a notification channel.
+    let mut notification_channel: NotificationChannel = body.into_inner().into();
+    notification_channel.user_id = user.id;
+
+    // Create the notification channel.
+    return match notification_channel_service
+        .insert(&notification_channel)
+        .await
+    {
+        InsertionResult::Ok(created_notification_channel) => {
+            HttpResponse::Ok().json(created_notification_channel)
+        }
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get all of the notification channels that belong to the sending user.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to retrieve
+/// the notification channels.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_notification_channels(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channels that belong to the user.
+    return match notification_channel_service.get_by_user_id(&user.id).await {
+        QueryResult::Ok(notification_channels) => HttpResponse::Ok().json(notification_channels),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get a notification channel.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the notification channel that is being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to retrieve
+/// the notification channel.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}")]
+async fn get_notification_channel(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel that is being requested.
+    let notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to view it.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Return the notification channel.
+    return HttpResponse::Ok().json(notification_channel);
+}
+
+/// # Description
+///
+/// An api endpoint to update a notification channel.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the notification channel that is being updated.
+///
+/// `body` - The request body which contains information about the notification channel that is
+/// being updated.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to update
+/// the notification channel.
+///
+/// # Returns
+///
+/// An http response.
+#[patch("/{id}")]
+async fn update_notification_channel(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    body: web::Json<UpdateNotificationChannelRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel that is being updated.
+    let mut notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to update it.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Apply the update to the notification channel.
+    body.apply(&mut notification_channel);
+
+    // Update the notification channel.
+    return match notification_channel_service
+        .update(&notification_channel)
+        .await
+    {
+        UpdateResult::Ok(notification_channel) => HttpResponse::Ok().json(notification_channel),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to delete a notification channel.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the notification channel that is being deleted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to delete
+/// the notification channel.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn delete_notification_channel(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel that is being deleted.
+    let notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to delete it.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Delete the notification channel.
+    return match notification_channel_service.delete(&id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the most recent deliveries of a notification channel.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the notification channel whose deliveries are being retrieved.
+///
+/// `params` - The request query parameters.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to retrieve
+/// the notification channel and its deliveries.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/deliveries")]
+async fn get_notification_channel_deliveries(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    params: web::Query<GetNotificationChannelDeliveriesRequestParams>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel whose deliveries are being requested.
+    let notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to view its
+    // deliveries.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Determine how many deliveries to return.
+    let limit: u32 = params
+        .limit
+        .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+        .min(MAXIMUM_DELIVERY_LIMIT);
+
+    // Get the notification channel's deliveries.
+    return match notification_channel_service
+        .get_deliveries_by_notification_channel_id(&notification_channel.id, &limit)
+        .await
+    {
+        QueryResult::Ok(notification_deliveries) => {
+            HttpResponse::Ok().json(notification_deliveries)
+        }
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the attempts of one of a notification channel's deliveries.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `path` - The id of the notification channel, and the id of the delivery whose attempts are
+/// being retrieved.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to retrieve
+/// the notification channel, the delivery and its attempts.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{id}/deliveries/{delivery_id}/attempts")]
+async fn get_notification_delivery_attempts(
+    request: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    let (id, delivery_id) = path.into_inner();
+
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel whose delivery is being requested.
+    let notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to view its
+    // deliveries.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Get the delivery, and make sure it was sent to the notification channel.
+    let notification_delivery: NotificationDelivery = match notification_channel_service
+        .get_delivery(&delivery_id)
+        .await
+    {
+        QueryResult::Ok(notification_delivery)
+            if notification_delivery.notification_channel_id == notification_channel.id =>
+        {
+            notification_delivery
+        }
+        QueryResult::Ok(_) | QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the delivery's attempts.
+    return match notification_channel_service
+        .get_attempts_by_notification_delivery_id(&notification_delivery.id)
+        .await
+    {
+        QueryResult::Ok(notification_delivery_attempts) => {
+            HttpResponse::Ok().json(notification_delivery_attempts)
+        }
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to replay one of a notification channel's deliveries, so that its payload is
+/// sent again.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `path` - The id of the notification channel, and the id of the delivery that is being
+/// replayed.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `notification_channel_service` - The notification channel service that will be used to retrieve
+/// the notification channel and replay the delivery.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/{id}/deliveries/{delivery_id}/replay")]
+async fn replay_notification_delivery(
+    request: HttpRequest,
+    path: web::Path<(u64, u64)>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    notification_channel_service: Inject<DependencyInjector, dyn NotificationChannelService>,
+) -> HttpResponse {
+    let (id, delivery_id) = path.into_inner();
+
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the notification channel whose delivery is being replayed.
+    let notification_channel: NotificationChannel =
+        match notification_channel_service.get(&id).await {
+            QueryResult::Ok(notification_channel) => notification_channel,
+            QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+            QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // If the user is not the owner of the notification channel, they are not allowed to replay its
+    // deliveries.
+    if user.id != notification_channel.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Get the delivery, and make sure it was sent to the notification channel.
+    match notification_channel_service
+        .get_delivery(&delivery_id)
+        .await
+    {
+        QueryResult::Ok(notification_delivery)
+            if notification_delivery.notification_channel_id == notification_channel.id => {}
+        QueryResult::Ok(_) | QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    }
+
+    // Replay the delivery.
+    return match notification_channel_service
+        .replay_delivery(&delivery_id)
+        .await
+    {
+        UpdateResult::Ok(notification_delivery) => HttpResponse::Ok().json(notification_delivery),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
@@ -0,0 +1,90 @@
+use crate::{
+    config::NotificationConfig, feature::notification::service::NotificationChannelService,
+    injector::DependencyInjector,
+};
+use reqwest::Client;
+use shaku::HasComponent;
+use std::{error::Error, sync::Arc, time::Duration};
+use tokio::time::{interval, MissedTickBehavior};
+
+/// A delivery worker struct, which periodically sends the payloads of pending notification
+/// deliveries to their notification channels.
+pub(crate) struct DeliveryWorker {
+    /// The dependency injector that will be used to acquire service instances.
+    dependency_injector: Arc<DependencyInjector>,
+
+    /// The notification configuration.
+    config: NotificationConfig,
+
+    /// The http client that will be used to send the payloads.
+    http_client: Client,
+}
+
+/// An implementation for the DeliveryWorker struct.
+impl DeliveryWorker {
+    /// # Description
+    ///
+    /// Create a new delivery worker.
+    ///
+    /// # Arguments
+    ///
+    /// `dependency_injector` - The dependency injector that will be used to acquire service
+    /// instances.
+    ///
+    /// `config` - The notification configuration.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the delivery worker was created successfully, the Ok variant will be returned with the
+    /// delivery worker.
+    /// - If an error occurs while creating the delivery worker's http client, the Err variant will
+    /// be returned with the error that occurred.
+    pub(crate) fn new(
+        dependency_injector: Arc<DependencyInjector>,
+        config: NotificationConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        // Create the http client.
+        let http_client: Client = Client::builder()
+            .user_agent(concat!("EasyTracker/", env!("CARGO_PKG_VERSION")))
+            .timeout(Duration::from_secs(config.timeout))
+            .build()?;
+
+        // Return the delivery worker.
+        return Ok(DeliveryWorker {
+            dependency_injector,
+            config,
+            http_client,
+        });
+    }
+
+    /// # Description
+    ///
+    /// Run the delivery worker. Each interval, the worker will attempt each of the pending
+    /// deliveries that are due.
+    ///
+    /// ### Note: This function never returns, it should be spawned as a background task.
+    pub(crate) async fn run(self) {
+        // Create the interval.
+        let mut ticks = interval(Duration::from_secs(self.config.interval));
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        log::info!("Starting the notification delivery worker");
+
+        loop {
+            ticks.tick().await;
+
+            // Get a notification channel service instance.
+            let notification_channel_service: &dyn NotificationChannelService =
+                self.dependency_injector.resolve_ref();
+
+            // Attempt the pending deliveries.
+            if let Err(error) = notification_channel_service
+                .deliver_pending(&self.http_client)
+                .await
+            {
+                log::error!("Failed to deliver the pending notifications: {}", error);
+            }
+        }
+    }
+}
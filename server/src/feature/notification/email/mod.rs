@@ -0,0 +1,72 @@
+use crate::{
+    common::enumeration::{InsertionResult, QueryContext},
+    feature::{
+        check::checker::{create_error_message, get_elapsed_milliseconds},
+        mail::{service::MailService, template},
+        notification::model::{
+            EmailChannelConfiguration, NotificationDelivery, NotificationDeliveryAttempt,
+        },
+    },
+};
+use std::time::Instant;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Queue the alert email that describes a delivery's payload, so that it is sent by the mail worker.
+/// Sending the email is retried by the mail worker, so the attempt is successful once the email is
+/// queued.
+///
+/// # Arguments
+///
+/// `mail_service` - The mail service that will be used to queue the email.
+///
+/// `configuration` - The configuration of the email channel the alert will be sent to.
+///
+/// `delivery` - The delivery whose payload will be sent.
+///
+/// `context` - The context the email will be queued in.
+///
+/// # Returns
+///
+/// The outcome of the attempt.
+pub(crate) async fn send(
+    mail_service: &dyn MailService,
+    configuration: &EmailChannelConfiguration,
+    delivery: &NotificationDelivery,
+    context: &mut QueryContext<'_>,
+) -> NotificationDeliveryAttempt {
+    // Create the attempt.
+    let mut attempt: NotificationDeliveryAttempt = NotificationDeliveryAttempt {
+        id: 0,
+        notification_delivery_id: delivery.id,
+        attempted_at: OffsetDateTime::now_utc(),
+        is_successful: false,
+        status_code: None,
+        latency: 0,
+        error_message: None,
+    };
+
+    // Queue the alert email.
+    let start: Instant = Instant::now();
+    let queue_result = mail_service
+        .queue_with_context(
+            &template::create_alert_email(&configuration.address, &delivery.payload),
+            context,
+        )
+        .await;
+    attempt.latency = get_elapsed_milliseconds(&start);
+
+    // Record if the email was queued.
+    match queue_result {
+        InsertionResult::Ok(_) => attempt.is_successful = true,
+        InsertionResult::Invalid(details) => {
+            attempt.error_message = Some(create_error_message(&details))
+        }
+        InsertionResult::Err(error) => {
+            attempt.error_message = Some(create_error_message(error.as_ref()))
+        }
+    }
+
+    return attempt;
+}
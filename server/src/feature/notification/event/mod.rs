@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::model::{CheckResult, CheckStatus},
+    incident::model::Incident,
+    monitor::model::Monitor,
+    notification::model::{MonitorEventKind, MonitorEventPayload, PAYLOAD_VERSION},
+};
+
+/// # Description
+///
+/// Determine the event a check result produced, if its monitor's state changed.
+///
+/// # Arguments
+///
+/// `check_result` - The check result the monitor produced.
+///
+/// `previous_check_result` - The check result the monitor produced before it, if there was one.
+///
+/// `incident` - The incident the check result opened or resolved, if there was one.
+///
+/// # Returns
+///
+/// An option that will contain the event that was produced, if the monitor's state changed.
+pub(crate) fn get_event_kind(
+    check_result: &CheckResult,
+    previous_check_result: Option<&CheckResult>,
+    incident: Option<&Incident>,
+) -> Option<MonitorEventKind> {
+    // Failures during maintenance are expected, so they never produce events.
+    if check_result.status == CheckStatus::Maintenance {
+        return None;
+    }
+
+    // If an incident was opened the monitor went down, and if one was resolved it recovered.
+    if let Some(incident) = incident {
+        return match incident.resolved_at {
+            Some(_) => Some(MonitorEventKind::Recovered),
+            None => Some(MonitorEventKind::Down),
+        };
+    }
+
+    // Otherwise, the monitor's state only changes when it starts or stops being degraded.
+    let previous_status: Option<CheckStatus> =
+        previous_check_result.map(|previous_check_result| previous_check_result.status);
+    return match (previous_status, check_result.status) {
+        (Some(CheckStatus::Degraded), CheckStatus::Degraded) => None,
+        (_, CheckStatus::Degraded) => Some(MonitorEventKind::Degraded),
+        (Some(CheckStatus::Degraded), CheckStatus::Up) => Some(MonitorEventKind::Recovered),
+        _ => None,
+    };
+}
+
+/// # Description
+///
+/// Create the payload that describes an event a monitor produced.
+///
+/// # Arguments
+///
+/// `event` - The event the monitor produced.
+///
+/// `monitor` - The monitor that produced the event.
+///
+/// `check_result` - The check result that produced the event.
+///
+/// `previous_check_result` - The check result the monitor produced before it, if there was one.
+///
+/// `incident` - The incident the check result opened or resolved, if there was one.
+///
+/// # Returns
+///
+/// The payload that was created.
+pub(crate) fn create_payload(
+    event: MonitorEventKind,
+    monitor: &Monitor,
+    check_result: &CheckResult,
+    previous_check_result: Option<&CheckResult>,
+    incident: Option<&Incident>,
+) -> MonitorEventPayload {
+    return MonitorEventPayload {
+        version: PAYLOAD_VERSION,
+        event,
+        occurred_at: check_result.checked_at,
+        monitor_id: monitor.id,
+        monitor_name: monitor.name.clone(),
+        status: check_result.status,
+        previous_status: previous_check_result
+            .map(|previous_check_result| previous_check_result.status),
+        error_kind: check_result.error_kind,
+        error_message: check_result.error_message.clone(),
+        incident_id: incident.map(|incident| incident.id),
+    };
+}
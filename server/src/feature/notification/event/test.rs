@@ -0,0 +1,189 @@
+use crate::feature::{
+    check::model::{CheckErrorKind, CheckResult, CheckStatus},
+    incident::model::Incident,
+    monitor::model::Monitor,
+    notification::{
+        event::{create_payload, get_event_kind},
+        model::{MonitorEventKind, MonitorEventPayload},
+    },
+};
+use serde_json::json;
+use time::{macros::datetime, OffsetDateTime};
+
+/// # Description
+///
+/// Create a check result that can be used for testing.
+///
+/// # Arguments
+///
+/// `status` - The status of the check result.
+///
+/// # Returns
+///
+/// The check result that was created.
+fn create_test_check_result(status: CheckStatus) -> CheckResult {
+    return CheckResult {
+        monitor_id: 1,
+        status,
+        ..Default::default()
+    };
+}
+
+/// # Description
+///
+/// Determine the event a check result produced, given the status of the check result before it.
+///
+/// # Arguments
+///
+/// `previous_status` - The status of the previous check result, if there was one.
+///
+/// `status` - The status of the check result.
+///
+/// # Returns
+///
+/// An option that will contain the event that was produced, if there was one.
+fn get_status_event_kind(
+    previous_status: Option<CheckStatus>,
+    status: CheckStatus,
+) -> Option<MonitorEventKind> {
+    let previous_check_result: Option<CheckResult> = previous_status.map(create_test_check_result);
+    return get_event_kind(
+        &create_test_check_result(status),
+        previous_check_result.as_ref(),
+        None,
+    );
+}
+
+/// # Description
+///
+/// Test determining the events of check results that opened or resolved incidents, and make sure
+/// they are reported as the monitor going down and recovering.
+#[test]
+fn incidents_produce_down_and_recovered_events() {
+    let opened_incident: Incident = Incident {
+        id: 1,
+        monitor_id: 1,
+        ..Default::default()
+    };
+    let resolved_incident: Incident = Incident {
+        resolved_at: Some(OffsetDateTime::now_utc()),
+        ..opened_incident.clone()
+    };
+
+    assert_eq!(
+        Some(MonitorEventKind::Down),
+        get_event_kind(
+            &create_test_check_result(CheckStatus::Down),
+            Some(&create_test_check_result(CheckStatus::Up)),
+            Some(&opened_incident),
+        )
+    );
+    assert_eq!(
+        Some(MonitorEventKind::Recovered),
+        get_event_kind(
+            &create_test_check_result(CheckStatus::Up),
+            Some(&create_test_check_result(CheckStatus::Down)),
+            Some(&resolved_incident),
+        )
+    );
+}
+
+/// # Description
+///
+/// Test determining the events of check results that did not open or resolve incidents, and make
+/// sure only changes into and out of the degraded status produce events.
+#[test]
+fn degraded_transitions_produce_events() {
+    assert_eq!(
+        Some(MonitorEventKind::Degraded),
+        get_status_event_kind(Some(CheckStatus::Up), CheckStatus::Degraded)
+    );
+    assert_eq!(
+        Some(MonitorEventKind::Degraded),
+        get_status_event_kind(None, CheckStatus::Degraded)
+    );
+    assert_eq!(
+        Some(MonitorEventKind::Recovered),
+        get_status_event_kind(Some(CheckStatus::Degraded), CheckStatus::Up)
+    );
+    assert_eq!(
+        None,
+        get_status_event_kind(Some(CheckStatus::Degraded), CheckStatus::Degraded)
+    );
+    assert_eq!(
+        None,
+        get_status_event_kind(Some(CheckStatus::Up), CheckStatus::Up)
+    );
+
+    // A monitor that keeps failing has already produced its down event.
+    assert_eq!(
+        None,
+        get_status_event_kind(Some(CheckStatus::Down), CheckStatus::Down)
+    );
+}
+
+/// # Description
+///
+/// Test determining the events of check results that were performed during maintenance, and make
+/// sure they never produce events.
+#[test]
+fn maintenance_does_not_produce_events() {
+    assert_eq!(
+        None,
+        get_status_event_kind(Some(CheckStatus::Degraded), CheckStatus::Maintenance)
+    );
+    assert_eq!(
+        None,
+        get_status_event_kind(Some(CheckStatus::Up), CheckStatus::Maintenance)
+    );
+}
+
+/// # Description
+///
+/// Test creating the payload of an event, and make sure it is serialized with its version and the
+/// details of the check that produced it.
+#[test]
+fn payloads_are_versioned() {
+    let monitor: Monitor = Monitor {
+        id: 7,
+        name: String::from("Website"),
+        ..Default::default()
+    };
+    let check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: datetime!(2026-01-01 12:00 UTC),
+        status: CheckStatus::Down,
+        error_kind: Some(CheckErrorKind::Timeout),
+        error_message: Some(String::from("The request timed out")),
+        ..Default::default()
+    };
+    let incident: Incident = Incident {
+        id: 3,
+        monitor_id: monitor.id,
+        ..Default::default()
+    };
+
+    let payload: MonitorEventPayload = create_payload(
+        MonitorEventKind::Down,
+        &monitor,
+        &check_result,
+        Some(&create_test_check_result(CheckStatus::Up)),
+        Some(&incident),
+    );
+
+    assert_eq!(
+        json!({
+            "version": 1,
+            "event": "down",
+            "occurredAt": "2026-01-01T12:00:00Z",
+            "monitorId": 7,
+            "monitorName": "Website",
+            "status": "down",
+            "previousStatus": "up",
+            "errorKind": "timeout",
+            "errorMessage": "The request timed out",
+            "incidentId": 3,
+        }),
+        serde_json::to_value(&payload).expect("Failed to serialize payload")
+    );
+}
@@ -0,0 +1,8 @@
+pub(crate) mod controller;
+pub(crate) mod delivery;
+pub(crate) mod email;
+pub(crate) mod event;
+pub(crate) mod model;
+pub(crate) mod repository;
+pub(crate) mod service;
+pub(crate) mod webhook;
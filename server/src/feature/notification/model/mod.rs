@@ -0,0 +1,330 @@
+use crate::{
+    common::utility::create_value_validation_error,
+    feature::check::model::{CheckErrorKind, CheckStatus},
+};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+/// The version of the payload that is sent to notification channels. This is incremented whenever
+/// a change is made to the payload that could break the receivers that parse it.
+pub(crate) const PAYLOAD_VERSION: u32 = 1;
+
+/// The maximum amount of monitors a notification channel can be limited to.
+const MAXIMUM_CHANNEL_MONITORS: usize = 100;
+
+/// A notification channel struct, which is a destination that a user's monitor events are sent to.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotificationChannel {
+    /// The notification channel's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the user that the notification channel belongs to.
+    pub(crate) user_id: u64,
+
+    /// The date and time the notification channel was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) notification_channel_created_at: OffsetDateTime,
+
+    /// The notification channel's name.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) name: String,
+
+    /// If the notification channel is enabled.
+    pub(crate) is_enabled: bool,
+
+    /// The notification channel's type specific configuration.
+    #[validate(nested)]
+    pub(crate) configuration: NotificationChannelConfiguration,
+
+    /// The unique identifiers of the monitors whose events are sent to the notification channel. If
+    /// this is empty, the events of all of the owner's monitors are sent to it.
+    #[validate(custom(function = "validate_monitor_ids"))]
+    pub(crate) monitor_ids: Json<Vec<u64>>,
+}
+
+/// A Default implementation for the NotificationChannel struct.
+impl Default for NotificationChannel {
+    fn default() -> Self {
+        return NotificationChannel {
+            id: 0,
+            user_id: 0,
+            notification_channel_created_at: OffsetDateTime::now_utc(),
+            name: String::from(""),
+            is_enabled: true,
+            configuration: NotificationChannelConfiguration::Webhook(
+                WebhookChannelConfiguration::default(),
+            ),
+            monitor_ids: Json(Vec::new()),
+        };
+    }
+}
+
+/// A PartialEq implementation for the NotificationChannel struct.
+impl PartialEq for NotificationChannel {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.user_id == other.user_id
+            && self.notification_channel_created_at.date()
+                == other.notification_channel_created_at.date()
+            && self.notification_channel_created_at.hour()
+                == other.notification_channel_created_at.hour()
+            && self.notification_channel_created_at.minute()
+                == other.notification_channel_created_at.minute()
+            && self.notification_channel_created_at.second()
+                == other.notification_channel_created_at.second()
+            && self.name == other.name
+            && self.is_enabled == other.is_enabled
+            && self.configuration == other.configuration
+            && self.monitor_ids == other.monitor_ids;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// An enumeration of all of the types of notification channels, and their type specific
+/// configuration.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub(crate) enum NotificationChannelConfiguration {
+    /// # Description
+    ///
+    /// The Webhook variant is used for notification channels that post signed json payloads to a
+    /// url.
+    Webhook(WebhookChannelConfiguration),
+
+    /// # Description
+    ///
+    /// The Email variant is used for notification channels that send templated alert emails to an
+    /// email address.
+    Email(EmailChannelConfiguration),
+}
+
+/// A Validate implementation for the NotificationChannelConfiguration enum.
+impl Validate for NotificationChannelConfiguration {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        return match self {
+            NotificationChannelConfiguration::Webhook(configuration) => configuration.validate(),
+            NotificationChannelConfiguration::Email(configuration) => configuration.validate(),
+        };
+    }
+}
+
+/// A From<Json<NotificationChannelConfiguration>> implementation for the
+/// NotificationChannelConfiguration enum.
+///
+/// This allows notification channel configurations to be queried directly from their json column.
+impl From<Json<NotificationChannelConfiguration>> for NotificationChannelConfiguration {
+    fn from(json: Json<NotificationChannelConfiguration>) -> Self {
+        return json.0;
+    }
+}
+
+/// A webhook channel configuration struct.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WebhookChannelConfiguration {
+    /// The url the payloads will be posted to.
+    #[validate(non_control_character, url, length(min = 5, max = 2048))]
+    pub(crate) url: String,
+
+    /// The secret that is used to sign the payloads, so that the receiver can verify they were sent
+    /// by the server.
+    #[serde(default)]
+    #[validate(non_control_character, length(min = 16, max = 256))]
+    pub(crate) secret: String,
+}
+
+/// A Default implementation for the WebhookChannelConfiguration struct.
+impl Default for WebhookChannelConfiguration {
+    fn default() -> Self {
+        return WebhookChannelConfiguration {
+            url: String::from(""),
+            secret: String::from(""),
+        };
+    }
+}
+
+/// An email channel configuration struct.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EmailChannelConfiguration {
+    /// The email address the alerts will be sent to.
+    #[validate(non_control_character, email, length(min = 5, max = 256))]
+    pub(crate) address: String,
+}
+
+/// An enumeration of all of the events a monitor can produce.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum MonitorEventKind {
+    /// # Description
+    ///
+    /// The Down variant is used when a monitor goes down.
+    Down,
+
+    /// # Description
+    ///
+    /// The Degraded variant is used when a monitor starts performing worse than expected.
+    Degraded,
+
+    /// # Description
+    ///
+    /// The Recovered variant is used when a monitor that was down or degraded is operational again.
+    Recovered,
+}
+
+/// A monitor event payload struct, which is the versioned json document that is sent to
+/// notification channels when a monitor's state changes.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MonitorEventPayload {
+    /// The version of the payload's format.
+    pub(crate) version: u32,
+
+    /// The event that occurred.
+    pub(crate) event: MonitorEventKind,
+
+    /// The date and time the event occurred at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) occurred_at: OffsetDateTime,
+
+    /// The unique identifier of the monitor that produced the event.
+    pub(crate) monitor_id: u64,
+
+    /// The name of the monitor that produced the event.
+    pub(crate) monitor_name: String,
+
+    /// The status of the check that produced the event.
+    pub(crate) status: CheckStatus,
+
+    /// The status of the check the monitor performed before it, if there was one.
+    pub(crate) previous_status: Option<CheckStatus>,
+
+    /// The kind of error that caused the check to fail, if it failed.
+    pub(crate) error_kind: Option<CheckErrorKind>,
+
+    /// A human readable description of the error that caused the check to fail, if it failed.
+    pub(crate) error_message: Option<String>,
+
+    /// The unique identifier of the incident that was opened or resolved, if there was one.
+    pub(crate) incident_id: Option<u64>,
+}
+
+/// A notification delivery struct, which is a payload that is being sent to a notification
+/// channel.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotificationDelivery {
+    /// The notification delivery's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the notification channel the payload is being sent to.
+    pub(crate) notification_channel_id: u64,
+
+    /// The unique identifier of the monitor that produced the event.
+    pub(crate) monitor_id: u64,
+
+    /// The date and time the notification delivery was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) delivery_created_at: OffsetDateTime,
+
+    /// The event that is being delivered.
+    pub(crate) event: MonitorEventKind,
+
+    /// The payload that is being delivered.
+    pub(crate) payload: Json<MonitorEventPayload>,
+
+    /// The notification delivery's current status.
+    pub(crate) status: DeliveryStatus,
+
+    /// The amount of times sending the payload has been attempted.
+    pub(crate) attempt_count: u32,
+
+    /// The date and time the next attempt to send the payload is due at, if it is still pending.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(crate) next_attempt_at: Option<OffsetDateTime>,
+}
+
+/// An enumeration of all of the statuses a notification delivery can be in.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum DeliveryStatus {
+    /// # Description
+    ///
+    /// The Pending variant is used for deliveries that are waiting to be attempted.
+    Pending,
+
+    /// # Description
+    ///
+    /// The Succeeded variant is used for deliveries that were accepted by the notification channel.
+    Succeeded,
+
+    /// # Description
+    ///
+    /// The Failed variant is used for deliveries that were not accepted after every attempt was
+    /// used.
+    Failed,
+}
+
+/// A notification delivery attempt struct, which is the outcome of a single attempt to send a
+/// payload to a notification channel.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct NotificationDeliveryAttempt {
+    /// The notification delivery attempt's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the notification delivery that was attempted.
+    pub(crate) notification_delivery_id: u64,
+
+    /// The date and time the attempt was made at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) attempted_at: OffsetDateTime,
+
+    /// If the notification channel accepted the payload.
+    pub(crate) is_successful: bool,
+
+    /// The http status code the notification channel responded with, if it responded.
+    pub(crate) status_code: Option<u16>,
+
+    /// The amount of time (in milliseconds) the attempt took to complete.
+    pub(crate) latency: u32,
+
+    /// A human readable description of the error that caused the attempt to fail, if it failed.
+    pub(crate) error_message: Option<String>,
+}
+
+/// # Description
+///
+/// Validate the monitors a notification channel is limited to.
+///
+/// # Arguments
+///
+/// `monitor_ids` - The unique identifiers of the monitors that are being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the monitors are valid, the Ok variant will be returned.
+/// - If there are too many monitors, or a monitor appears more than once, the Err variant will be
+/// returned with a validation error.
+fn validate_monitor_ids(monitor_ids: &Json<Vec<u64>>) -> Result<(), ValidationError> {
+    if monitor_ids.len() > MAXIMUM_CHANNEL_MONITORS {
+        return Err(create_value_validation_error("length", &monitor_ids.len()));
+    }
+
+    for (index, monitor_id) in monitor_ids.iter().enumerate() {
+        if monitor_ids[..index].contains(monitor_id) {
+            return Err(create_value_validation_error("duplicate", monitor_id));
+        }
+    }
+
+    return Ok(());
+}
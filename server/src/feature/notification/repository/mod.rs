@@ -0,0 +1,837 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::notification::model::{
+        DeliveryStatus, MonitorEventKind, MonitorEventPayload, NotificationChannel,
+        NotificationChannelConfiguration, NotificationDelivery, NotificationDeliveryAttempt,
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// A notification channel repository trait.
+#[async_trait]
+pub(crate) trait NotificationChannelRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a notification channel into the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel to insert into the notification channel
+    /// repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// notification channel that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a notification channel from the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the notification channel to get from the notification channel repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional notification
+    /// channel.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<NotificationChannel>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the notification channels that belong to a user from the notification channel
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the notification channels belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the notification channels
+    /// that were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationChannel>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the enabled notification channels that a monitor's events are sent to from the
+    /// notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the monitor belongs to.
+    ///
+    /// `monitor_id` - The id of the monitor.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the notification channels
+    /// that were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_enabled_by_monitor_id(
+        &self,
+        user_id: &u64,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationChannel>, Error>;
+
+    /// # Description
+    ///
+    /// Update a notification channel in the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel to update in the notification channel
+    /// repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete a notification channel from the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the notification channel to delete from the notification channel
+    /// repository.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a notification delivery into the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery` - The notification delivery to insert into the notification channel
+    /// repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// notification delivery that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_delivery(
+        &self,
+        notification_delivery: &NotificationDelivery,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a notification delivery from the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the notification delivery to get from the notification channel repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional notification
+    /// delivery.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_delivery(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<NotificationDelivery>, Error>;
+
+    /// # Description
+    ///
+    /// Get the most recent notification deliveries of a notification channel from the notification
+    /// channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel_id` - The id of the notification channel the deliveries were sent to.
+    ///
+    /// `limit` - The maximum amount of notification deliveries to get.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the notification
+    /// deliveries that were found, ordered from newest to oldest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_deliveries_by_notification_channel_id(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Error>;
+
+    /// # Description
+    ///
+    /// Get the pending notification deliveries that are due to be attempted from the notification
+    /// channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `limit` - The maximum amount of notification deliveries to get.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the notification
+    /// deliveries that were found, ordered from the longest overdue to the most recently due.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_due_deliveries(
+        &self,
+        now: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Error>;
+
+    /// # Description
+    ///
+    /// Update a notification delivery in the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery` - The notification delivery to update in the notification channel
+    /// repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update_delivery(
+        &self,
+        notification_delivery: &NotificationDelivery,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a notification delivery attempt into the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery_attempt` - The notification delivery attempt to insert into the
+    /// notification channel repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// notification delivery attempt that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_attempt(
+        &self,
+        notification_delivery_attempt: &NotificationDeliveryAttempt,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the attempts of a notification delivery from the notification channel repository.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery_id` - The id of the notification delivery that was attempted.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the notification delivery
+    /// attempts that were found, ordered from oldest to newest.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_attempts_by_notification_delivery_id(
+        &self,
+        notification_delivery_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDeliveryAttempt>, Error>;
+}
+
+/// A NotificationChannelRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = NotificationChannelRepository)]
+pub(crate) struct NotificationChannelRepositoryImpl;
+
+/// A NotificationChannelRepository implementation for the NotificationChannelRepositoryImpl struct.
+#[async_trait]
+impl NotificationChannelRepository for NotificationChannelRepositoryImpl {
+    async fn insert(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `notification_channels` (
+                    `id`,
+                    `user_id`,
+                    `notification_channel_created_at`,
+                    `name`,
+                    `is_enabled`,
+                    `configuration`,
+                    `monitor_ids`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            notification_channel.id,
+            notification_channel.user_id,
+            notification_channel.notification_channel_created_at,
+            notification_channel.name,
+            notification_channel.is_enabled,
+            Json(&notification_channel.configuration),
+            &notification_channel.monitor_ids
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<NotificationChannel>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationChannel,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `notification_channel_created_at` AS `notification_channel_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<NotificationChannelConfiguration>`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `notification_channels`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationChannel>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationChannel,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `notification_channel_created_at` AS `notification_channel_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<NotificationChannelConfiguration>`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `notification_channels`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `id` ASC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_enabled_by_monitor_id(
+        &self,
+        user_id: &u64,
+        monitor_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationChannel>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationChannel,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `notification_channel_created_at` AS `notification_channel_created_at: OffsetDateTime`,
+                    `name` AS `name: String`,
+                    `is_enabled` AS `is_enabled: bool`,
+                    `configuration` AS `configuration: Json<NotificationChannelConfiguration>`,
+                    `monitor_ids` AS `monitor_ids: Json<Vec<u64>>`
+                FROM
+                    `notification_channels`
+                WHERE
+                    `user_id` = ?
+                    AND `is_enabled` = 1
+                    AND (
+                        JSON_LENGTH(`monitor_ids`) = 0
+                        OR JSON_CONTAINS(`monitor_ids`, JSON_ARRAY(?))
+                    )
+                ORDER BY
+                    `id` ASC;
+            "#,
+            user_id,
+            monitor_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `notification_channels`
+                SET
+                    `user_id` = ?,
+                    `notification_channel_created_at` = ?,
+                    `name` = ?,
+                    `is_enabled` = ?,
+                    `configuration` = ?,
+                    `monitor_ids` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            notification_channel.user_id,
+            notification_channel.notification_channel_created_at,
+            notification_channel.name,
+            notification_channel.is_enabled,
+            Json(&notification_channel.configuration),
+            &notification_channel.monitor_ids,
+            notification_channel.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `notification_channels`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_delivery(
+        &self,
+        notification_delivery: &NotificationDelivery,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `notification_deliveries` (
+                    `id`,
+                    `notification_channel_id`,
+                    `monitor_id`,
+                    `delivery_created_at`,
+                    `event`,
+                    `payload`,
+                    `status`,
+                    `attempt_count`,
+                    `next_attempt_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            notification_delivery.id,
+            notification_delivery.notification_channel_id,
+            notification_delivery.monitor_id,
+            notification_delivery.delivery_created_at,
+            notification_delivery.event,
+            &notification_delivery.payload,
+            notification_delivery.status,
+            notification_delivery.attempt_count,
+            notification_delivery.next_attempt_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_delivery(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<NotificationDelivery>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationDelivery,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `notification_channel_id` AS `notification_channel_id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `delivery_created_at` AS `delivery_created_at: OffsetDateTime`,
+                    `event` AS `event: MonitorEventKind`,
+                    `payload` AS `payload: Json<MonitorEventPayload>`,
+                    `status` AS `status: DeliveryStatus`,
+                    `attempt_count` AS `attempt_count: u32`,
+                    `next_attempt_at` AS `next_attempt_at: OffsetDateTime`
+                FROM
+                    `notification_deliveries`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_deliveries_by_notification_channel_id(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationDelivery,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `notification_channel_id` AS `notification_channel_id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `delivery_created_at` AS `delivery_created_at: OffsetDateTime`,
+                    `event` AS `event: MonitorEventKind`,
+                    `payload` AS `payload: Json<MonitorEventPayload>`,
+                    `status` AS `status: DeliveryStatus`,
+                    `attempt_count` AS `attempt_count: u32`,
+                    `next_attempt_at` AS `next_attempt_at: OffsetDateTime`
+                FROM
+                    `notification_deliveries`
+                WHERE
+                    `notification_channel_id` = ?
+                ORDER BY
+                    `delivery_created_at` DESC,
+                    `id` DESC
+                LIMIT ?;
+            "#,
+            notification_channel_id,
+            limit
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_due_deliveries(
+        &self,
+        now: &OffsetDateTime,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationDelivery,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `notification_channel_id` AS `notification_channel_id: u64`,
+                    `monitor_id` AS `monitor_id: u64`,
+                    `delivery_created_at` AS `delivery_created_at: OffsetDateTime`,
+                    `event` AS `event: MonitorEventKind`,
+                    `payload` AS `payload: Json<MonitorEventPayload>`,
+                    `status` AS `status: DeliveryStatus`,
+                    `attempt_count` AS `attempt_count: u32`,
+                    `next_attempt_at` AS `next_attempt_at: OffsetDateTime`
+                FROM
+                    `notification_deliveries`
+                WHERE
+                    `status` = 'pending'
+                    AND `next_attempt_at` <= ?
+                ORDER BY
+                    `next_attempt_at` ASC,
+                    `id` ASC
+                LIMIT ?;
+            "#,
+            now,
+            limit
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update_delivery(
+        &self,
+        notification_delivery: &NotificationDelivery,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `notification_deliveries`
+                SET
+                    `notification_channel_id` = ?,
+                    `monitor_id` = ?,
+                    `delivery_created_at` = ?,
+                    `event` = ?,
+                    `payload` = ?,
+                    `status` = ?,
+                    `attempt_count` = ?,
+                    `next_attempt_at` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            notification_delivery.notification_channel_id,
+            notification_delivery.monitor_id,
+            notification_delivery.delivery_created_at,
+            notification_delivery.event,
+            &notification_delivery.payload,
+            notification_delivery.status,
+            notification_delivery.attempt_count,
+            notification_delivery.next_attempt_at,
+            notification_delivery.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_attempt(
+        &self,
+        notification_delivery_attempt: &NotificationDeliveryAttempt,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `notification_delivery_attempts` (
+                    `id`,
+                    `notification_delivery_id`,
+                    `attempted_at`,
+                    `is_successful`,
+                    `status_code`,
+                    `latency`,
+                    `error_message`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            notification_delivery_attempt.id,
+            notification_delivery_attempt.notification_delivery_id,
+            notification_delivery_attempt.attempted_at,
+            notification_delivery_attempt.is_successful,
+            notification_delivery_attempt.status_code,
+            notification_delivery_attempt.latency,
+            notification_delivery_attempt.error_message
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_attempts_by_notification_delivery_id(
+        &self,
+        notification_delivery_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDeliveryAttempt>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            NotificationDeliveryAttempt,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `notification_delivery_id` AS `notification_delivery_id: u64`,
+                    `attempted_at` AS `attempted_at: OffsetDateTime`,
+                    `is_successful` AS `is_successful: bool`,
+                    `status_code` AS `status_code: u16`,
+                    `latency` AS `latency: u32`,
+                    `error_message` AS `error_message: String`
+                FROM
+                    `notification_delivery_attempts`
+                WHERE
+                    `notification_delivery_id` = ?
+                ORDER BY
+                    `attempted_at` ASC,
+                    `id` ASC;
+            "#,
+            notification_delivery_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+}
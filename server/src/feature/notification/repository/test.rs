@@ -0,0 +1,569 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::model::CheckStatus,
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        notification::{
+            model::{
+                DeliveryStatus, MonitorEventKind, MonitorEventPayload, NotificationChannel,
+                NotificationChannelConfiguration, NotificationDelivery,
+                NotificationDeliveryAttempt, WebhookChannelConfiguration, PAYLOAD_VERSION,
+            },
+            repository::NotificationChannelRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, types::Json, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a notification channel that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the notification channel will belong to.
+///
+/// `monitor_ids` - The unique identifiers of the monitors the notification channel will be limited
+/// to.
+///
+/// # Returns
+///
+/// The notification channel that was created.
+fn create_test_notification_channel(user: &User, monitor_ids: Vec<u64>) -> NotificationChannel {
+    return NotificationChannel {
+        id: 0,
+        user_id: user.id,
+        notification_channel_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(16),
+        is_enabled: true,
+        configuration: NotificationChannelConfiguration::Webhook(WebhookChannelConfiguration {
+            url: format!("https://{}.com/webhook", generate_random_string(8)),
+            secret: generate_random_string(32),
+        }),
+        monitor_ids: Json(monitor_ids),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a maintenance window into the repository, and make sure it can be queried by its
+/// id, owner and monitors until it is deleted.
+/// # Description
+///
+/// Test inserting notification channels into the repository, and make sure only the enabled
+/// notification channels are found for the monitors they are limited to until they are deleted.
+#[actix_web::test]
+async fn notification_channel_is_found_for_its_monitors() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a notification channel repository instance.
+    let notification_channel_repository: Arc<dyn NotificationChannelRepository> =
+        injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let first_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let second_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert a notification channel that is limited to the first monitor, a notification channel
+    // that receives the events of every monitor, and a notification channel that is disabled.
+    let mut limited_channel: NotificationChannel =
+        create_test_notification_channel(&user, vec![first_monitor.id]);
+    let mut unlimited_channel: NotificationChannel =
+        create_test_notification_channel(&user, Vec::new());
+    let mut disabled_channel: NotificationChannel = NotificationChannel {
+        is_enabled: false,
+        ..create_test_notification_channel(&user, Vec::new())
+    };
+    for notification_channel in [
+        &mut limited_channel,
+        &mut unlimited_channel,
+        &mut disabled_channel,
+    ] {
+        notification_channel.id = notification_channel_repository
+            .insert(notification_channel, &mut context)
+            .await
+            .expect("Failed to insert notification channel");
+        assert!(notification_channel.id > 0);
+    }
+
+    // Make sure the notification channel can be queried by its id.
+    let queried_notification_channel: NotificationChannel = notification_channel_repository
+        .get(&limited_channel.id, &mut context)
+        .await
+        .expect("Failed to query notification channel: An error occurred")
+        .expect("Failed to query notification channel: Notification channel not found");
+    assert_eq!(limited_channel, queried_notification_channel);
+
+    // Make sure the notification channels can be queried by their owner.
+    let queried_notification_channels: Vec<NotificationChannel> = notification_channel_repository
+        .get_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query notification channels by user id");
+    assert_eq!(
+        vec![
+            limited_channel.clone(),
+            unlimited_channel.clone(),
+            disabled_channel.clone()
+        ],
+        queried_notification_channels
+    );
+
+    // Make sure only the enabled notification channels are found for each monitor.
+    let queried_notification_channels: Vec<NotificationChannel> = notification_channel_repository
+        .get_enabled_by_monitor_id(&user.id, &first_monitor.id, &mut context)
+        .await
+        .expect("Failed to query notification channels by monitor id");
+    assert_eq!(
+        vec![limited_channel.clone(), unlimited_channel.clone()],
+        queried_notification_channels
+    );
+    let queried_notification_channels: Vec<NotificationChannel> = notification_channel_repository
+        .get_enabled_by_monitor_id(&user.id, &second_monitor.id, &mut context)
+        .await
+        .expect("Failed to query notification channels by monitor id");
+    assert_eq!(
+        vec![unlimited_channel.clone()],
+        queried_notification_channels
+    );
+
+    // Delete the notification channel, and make sure it can no longer be queried.
+    let rows_deleted = notification_channel_repository
+        .delete(&limited_channel.id, &mut context)
+        .await
+        .expect("Failed to delete notification channel");
+    assert_eq!(1, rows_deleted);
+    assert!(notification_channel_repository
+        .get(&limited_channel.id, &mut context)
+        .await
+        .expect("Failed to query notification channel after deletion")
+        .is_none());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting a notification delivery into the repository, and make sure it is due until an
+/// attempt to send it succeeds.
+#[actix_web::test]
+async fn notification_delivery_is_due_until_attempted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a notification channel repository instance.
+    let notification_channel_repository: Arc<dyn NotificationChannelRepository> =
+        injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, monitor and notification channel.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let mut notification_channel: NotificationChannel =
+        create_test_notification_channel(&user, Vec::new());
+    notification_channel.id = notification_channel_repository
+        .insert(&notification_channel, &mut context)
+        .await
+        .expect("Failed to insert notification channel");
+
+    // Insert a notification delivery that is overdue.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut notification_delivery: NotificationDelivery = NotificationDelivery {
+        id: 0,
+        notification_channel_id: notification_channel.id,
+        monitor_id: monitor.id,
+        delivery_created_at: now,
+        event: MonitorEventKind::Down,
+        payload: Json(MonitorEventPayload {
+            version: PAYLOAD_VERSION,
+            event: MonitorEventKind::Down,
+            occurred_at: now,
+            monitor_id: monitor.id,
+            monitor_name: monitor.name.clone(),
+            status: CheckStatus::Down,
+            previous_status: Some(CheckStatus::Up),
+            error_kind: None,
+            error_message: None,
+            incident_id: None,
+        }),
+        status: DeliveryStatus::Pending,
+        attempt_count: 0,
+        next_attempt_at: Some(now - Duration::minutes(1)),
+    };
+    notification_delivery.id = notification_channel_repository
+        .insert_delivery(&notification_delivery, &mut context)
+        .await
+        .expect("Failed to insert notification delivery");
+    assert!(notification_delivery.id > 0);
+
+    // Make sure the notification delivery is due.
+    assert!(notification_channel_repository
+        .get_due_deliveries(&now, &1000, &mut context)
+        .await
+        .expect("Failed to query due notification deliveries")
+        .iter()
+        .any(|delivery| delivery.id == notification_delivery.id));
+
+    // Record a successful attempt to send the notification delivery.
+    let mut attempt: NotificationDeliveryAttempt = NotificationDeliveryAttempt {
+        id: 0,
+        notification_delivery_id: notification_delivery.id,
+        attempted_at: now,
+        is_successful: true,
+        status_code: Some(200),
+        latency: 25,
+        error_message: None,
+    };
+    attempt.id = notification_channel_repository
+        .insert_attempt(&attempt, &mut context)
+        .await
+        .expect("Failed to insert notification delivery attempt");
+    assert!(attempt.id > 0);
+
+    notification_delivery.status = DeliveryStatus::Succeeded;
+    notification_delivery.attempt_count = 1;
+    notification_delivery.next_attempt_at = None;
+    let rows_updated = notification_channel_repository
+        .update_delivery(&notification_delivery, &mut context)
+        .await
+        .expect("Failed to update notification delivery");
+    assert_eq!(1, rows_updated);
+
+    // Make sure the notification delivery is no longer due.
+    assert!(!notification_channel_repository
+        .get_due_deliveries(&now, &1000, &mut context)
+        .await
+        .expect("Failed to query due notification deliveries")
+        .iter()
+        .any(|delivery| delivery.id == notification_delivery.id));
+
+    // Make sure the notification delivery and its attempt can be queried.
+    let queried_notification_delivery: NotificationDelivery = notification_channel_repository
+        .get_delivery(&notification_delivery.id, &mut context)
+        .await
+        .expect("Failed to query notification delivery: An error occurred")
+        .expect("Failed to query notification delivery: Notification delivery not found");
+    assert_eq!(
+        DeliveryStatus::Succeeded,
+        queried_notification_delivery.status
+    );
+    assert_eq!(1, queried_notification_delivery.attempt_count);
+    assert_eq!(None, queried_notification_delivery.next_attempt_at);
+
+    let queried_notification_deliveries: Vec<NotificationDelivery> =
+        notification_channel_repository
+            .get_deliveries_by_notification_channel_id(&notification_channel.id, &100, &mut context)
+            .await
+            .expect("Failed to query notification deliveries by notification channel id");
+    assert_eq!(1, queried_notification_deliveries.len());
+    assert_eq!(
+        notification_delivery.id,
+        queried_notification_deliveries[0].id
+    );
+
+    let queried_attempts: Vec<NotificationDeliveryAttempt> = notification_channel_repository
+        .get_attempts_by_notification_delivery_id(&notification_delivery.id, &mut context)
+        .await
+        .expect("Failed to query notification delivery attempts");
+    assert_eq!(1, queried_attempts.len());
+    assert_eq!(attempt.id, queried_attempts[0].id);
+    assert!(queried_attempts[0].is_successful);
+    assert_eq!(Some(200), queried_attempts[0].status_code);
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
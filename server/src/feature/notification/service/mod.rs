@@ -0,0 +1,1304 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
+        utility::{create_value_validation_error, get_retry_delay},
+    },
+    config::NotificationConfig,
+    database::DatabaseConnectionFactory,
+    feature::{
+        check::model::CheckResult,
+        incident::model::Incident,
+        mail::service::MailService,
+        monitor::{model::Monitor, service::MonitorService},
+        notification::{
+            email, event,
+            model::{
+                DeliveryStatus, MonitorEventKind, MonitorEventPayload, NotificationChannel,
+                NotificationChannelConfiguration, NotificationDelivery,
+                NotificationDeliveryAttempt,
+            },
+            repository::NotificationChannelRepository,
+            webhook,
+        },
+        user::service::UserService,
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use reqwest::Client;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Connection};
+use std::{error::Error, io, sync::Arc};
+use time::OffsetDateTime;
+use validator::{Validate, ValidationErrors};
+
+/// The maximum amount of deliveries that are attempted each time pending deliveries are sent.
+const DELIVERY_BATCH_SIZE: u32 = 100;
+
+/// A notification channel service trait.
+#[async_trait(?Send)]
+pub(crate) trait NotificationChannelService: Interface {
+    /// # Description
+    ///
+    /// Insert a notification channel into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel that will be inserted into persistent
+    /// storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the notification channel
+    /// that was inserted.
+    /// - If the notification channel being inserted contains validation errors, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        notification_channel: &NotificationChannel,
+    ) -> InsertionResult<NotificationChannel, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a notification channel into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel that will be inserted into persistent
+    /// storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the notification channel
+    /// that was inserted.
+    /// - If the notification channel being inserted contains validation errors, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> InsertionResult<NotificationChannel, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a notification channel from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification channel that is being queried from
+    /// persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the notification channel, the Ok variant will be
+    /// returned with the notification channel.
+    /// - If the notification channel could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get(&self, id: &u64) -> QueryResult<NotificationChannel, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a notification channel from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification channel that is being queried from
+    /// persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the notification channel, the Ok variant will be
+    /// returned with the notification channel.
+    /// - If the notification channel could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<NotificationChannel, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the notification channels that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the notification channels belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the notification channels
+    /// that were found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+    ) -> QueryResult<Vec<NotificationChannel>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the notification channels that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the notification channels belong to.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the notification channels
+    /// that were found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationChannel>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a notification channel in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel that is being updated in persistent
+    /// storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the notification channel
+    /// updated.
+    /// - If the notification channel that is being updated could not be found, the NotFound variant
+    /// will be returned.
+    /// - If the notification channel being updated contains validation errors, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update(
+        &self,
+        notification_channel: &NotificationChannel,
+    ) -> UpdateResult<NotificationChannel, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a notification channel in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel that is being updated in persistent
+    /// storage.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the notification channel
+    /// updated.
+    /// - If the notification channel that is being updated could not be found, the NotFound variant
+    /// will be returned.
+    /// - If the notification channel being updated contains validation errors, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update_with_context(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> UpdateResult<NotificationChannel, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a notification channel, and all of its deliveries, from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification channel that is being removed from
+    /// persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a notification channel was successfully deleted, the Ok
+    /// variant will be returned.
+    /// - If the notification channel being deleted could not be found, the NotFound variant will be
+    /// returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a notification channel, and all of its deliveries, from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification channel that is being removed from
+    /// persistent storage.
+    ///
+    /// `context` - The context the deletion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a notification channel was successfully deleted, the Ok
+    /// variant will be returned.
+    /// - If the notification channel being deleted could not be found, the NotFound variant will be
+    /// returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Queue a delivery to each of the enabled notification channels a monitor's events are sent
+    /// to, if a check result the monitor produced changed its state. The deliveries are sent by the
+    /// delivery worker, so that slow notification channels do not delay the monitor's checks.
+    ///
+    /// # Arguments
+    ///
+    /// `monitor` - The monitor that produced the check result.
+    ///
+    /// `check_result` - The check result the monitor produced.
+    ///
+    /// `previous_check_result` - The check result the monitor produced before it, if there was one.
+    ///
+    /// `incident` - The incident the check result opened or resolved, if there was one.
+    ///
+    /// `context` - The context the deliveries will be queued in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deliveries were queued, the Ok variant will be returned with the deliveries (this
+    /// will be empty if the monitor's state did not change).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn notify_with_context(
+        &self,
+        monitor: &Monitor,
+        check_result: &CheckResult,
+        previous_check_result: Option<&CheckResult>,
+        incident: Option<&Incident>,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a notification delivery from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification delivery that is being queried from
+    /// persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the notification delivery, the Ok variant will be
+    /// returned with the notification delivery.
+    /// - If the notification delivery could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_delivery(&self, id: &u64) -> QueryResult<NotificationDelivery, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a notification delivery from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification delivery that is being queried from
+    /// persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the notification delivery, the Ok variant will be
+    /// returned with the notification delivery.
+    /// - If the notification delivery could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_delivery_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<NotificationDelivery, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the most recent deliveries of a notification channel from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel_id` - The unique identifier of the notification channel.
+    ///
+    /// `limit` - The maximum amount of deliveries to query.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the deliveries that were
+    /// found, ordered from newest to oldest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_deliveries_by_notification_channel_id(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<NotificationDelivery>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query the most recent deliveries of a notification channel from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel_id` - The unique identifier of the notification channel.
+    ///
+    /// `limit` - The maximum amount of deliveries to query.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the deliveries that were
+    /// found, ordered from newest to oldest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_deliveries_by_notification_channel_id_with_context(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationDelivery>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the attempts of a notification delivery from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery_id` - The unique identifier of the notification delivery.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the attempts that were
+    /// found, ordered from oldest to newest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_attempts_by_notification_delivery_id(
+        &self,
+        notification_delivery_id: &u64,
+    ) -> QueryResult<Vec<NotificationDeliveryAttempt>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the attempts of a notification delivery from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery_id` - The unique identifier of the notification delivery.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the attempts that were
+    /// found, ordered from oldest to newest (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_attempts_by_notification_delivery_id_with_context(
+        &self,
+        notification_delivery_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationDeliveryAttempt>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Replay a notification delivery that is no longer pending, so that its payload is sent to
+    /// its notification channel again with a fresh set of attempts.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification delivery that is being replayed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the replay is successful, the Ok variant will be returned with the notification delivery.
+    /// - If the notification delivery could not be found, the NotFound variant will be returned.
+    /// - If the notification delivery is still pending, the Invalid variant will be returned with
+    /// the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn replay_delivery(
+        &self,
+        id: &u64,
+    ) -> UpdateResult<NotificationDelivery, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Replay a notification delivery that is no longer pending, so that its payload is sent to
+    /// its notification channel again with a fresh set of attempts.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the notification delivery that is being replayed.
+    ///
+    /// `context` - The context the replay will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the replay is successful, the Ok variant will be returned with the notification delivery.
+    /// - If the notification delivery could not be found, the NotFound variant will be returned.
+    /// - If the notification delivery is still pending, the Invalid variant will be returned with
+    /// the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn replay_delivery_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> UpdateResult<NotificationDelivery, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Attempt each of the pending deliveries that are due. Each attempt is recorded, and failed
+    /// deliveries are retried with an exponential backoff until every attempt has been used.
+    ///
+    /// # Arguments
+    ///
+    /// `client` - The http client that will be used to send the payloads.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deliveries were attempted, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn deliver_pending(&self, client: &Client) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Attempt each of the pending deliveries that are due. Each attempt is recorded, and failed
+    /// deliveries are retried with an exponential backoff until every attempt has been used.
+    ///
+    /// # Arguments
+    ///
+    /// `client` - The http client that will be used to send the payloads.
+    ///
+    /// `now` - The current date and time.
+    ///
+    /// `context` - The context the deliveries will be attempted in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deliveries were attempted, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn deliver_pending_with_context(
+        &self,
+        client: &Client,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>>;
+}
+
+/// A NotificationChannelServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = NotificationChannelService)]
+pub(crate) struct NotificationChannelServiceImpl {
+    /// The notification channel repository that will be used to manage persistent notification
+    /// channel data.
+    #[shaku(inject)]
+    notification_channel_repository: Arc<dyn NotificationChannelRepository>,
+
+    /// The user service that will be used to validate user data.
+    #[shaku(inject)]
+    user_service: Arc<dyn UserService>,
+
+    /// The monitor service that will be used to validate the monitors notification channels are
+    /// limited to.
+    #[shaku(inject)]
+    monitor_service: Arc<dyn MonitorService>,
+
+    /// The mail service that will be used to queue the alerts of email channels.
+    #[shaku(inject)]
+    mail_service: Arc<dyn MailService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The notification configuration that determines how many times deliveries are attempted.
+    notification_config: NotificationConfig,
+}
+
+/// A NotificationChannelService implementation for the NotificationChannelServiceImpl struct.
+#[async_trait(?Send)]
+impl NotificationChannelService for NotificationChannelServiceImpl {
+    async fn insert(
+        &self,
+        notification_channel: &NotificationChannel,
+    ) -> InsertionResult<NotificationChannel, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self
+            .insert_with_context(notification_channel, &mut context)
+            .await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> InsertionResult<NotificationChannel, ValidationErrors, Box<dyn Error>> {
+        // Validate the notification channel.
+        let mut validation_errors = match notification_channel.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the notification channel refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(notification_channel, &mut validation_errors, context)
+            .await
+        {
+            return InsertionResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Perform the insertion.
+        let notification_channel_id = match __self
+            .notification_channel_repository
+            .insert(notification_channel, context)
+            .await
+        {
+            Ok(notification_channel_id) => notification_channel_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the notification channel that was inserted.
+        let inserted_notification_channel_option = match __self
+            .notification_channel_repository
+            .get(&notification_channel_id, context)
+            .await
+        {
+            Ok(inserted_notification_channel_option) => inserted_notification_channel_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the notification channel was found, return the notification channel, otherwise return
+        // an error.
+        return match inserted_notification_channel_option {
+            Some(inserted_notification_channel) => {
+                InsertionResult::Ok(inserted_notification_channel)
+            }
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Notification channel could not be found after insertion",
+            ))),
+        };
+    }
+
+    async fn get(&self, id: &u64) -> QueryResult<NotificationChannel, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_with_context(id, &mut context).await;
+    }
+
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<NotificationChannel, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self
+            .notification_channel_repository
+            .get(id, context)
+            .await
+        {
+            Ok(notification_channel_option) => notification_channel_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the notification channel was found, return the notification channel, otherwise return
+        // not found.
+        return match result {
+            Some(notification_channel) => QueryResult::Ok(notification_channel),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+    ) -> QueryResult<Vec<NotificationChannel>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_user_id_with_context(user_id, &mut context)
+            .await;
+    }
+
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationChannel>, Box<dyn Error>> {
+        // Perform the query and return the notification channels that were found.
+        return match __self
+            .notification_channel_repository
+            .get_by_user_id(user_id, context)
+            .await
+        {
+            Ok(notification_channels) => QueryResult::Ok(notification_channels),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update(
+        &self,
+        notification_channel: &NotificationChannel,
+    ) -> UpdateResult<NotificationChannel, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the update.
+        let update_result = self
+            .update_with_context(notification_channel, &mut context)
+            .await;
+
+        // If the update was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update_with_context(
+        &self,
+        notification_channel: &NotificationChannel,
+        context: &mut QueryContext,
+    ) -> UpdateResult<NotificationChannel, ValidationErrors, Box<dyn Error>> {
+        // Make sure the notification channel exists.
+        match __self
+            .notification_channel_repository
+            .get(&notification_channel.id, context)
+            .await
+        {
+            Ok(Some(_)) => {}
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        }
+
+        // Validate the notification channel.
+        let mut validation_errors = match notification_channel.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the notification channel refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(notification_channel, &mut validation_errors, context)
+            .await
+        {
+            return UpdateResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Perform the update.
+        let records_updated = match __self
+            .notification_channel_repository
+            .update(&notification_channel, context)
+            .await
+        {
+            Ok(records_updated) => records_updated,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // If no records were updated.
+        if records_updated == 0 {
+            return UpdateResult::NotFound;
+        }
+
+        // Query the updated notification channel.
+        let updated_notification_channel_option = match __self
+            .notification_channel_repository
+            .get(&notification_channel.id, context)
+            .await
+        {
+            Ok(updated_notification_channel_option) => updated_notification_channel_option,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the notification channel was found, and return the updated notification channel.
+        return match updated_notification_channel_option {
+            Some(updated_notification_channel) => UpdateResult::Ok(updated_notification_channel),
+            None => UpdateResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Notification channel could not be found after update",
+            ))),
+        };
+    }
+
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the deletion.
+        return self.delete_with_context(id, &mut context).await;
+    }
+
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>> {
+        // Perform the deletion.
+        let records_deleted = match __self
+            .notification_channel_repository
+            .delete(id, context)
+            .await
+        {
+            Ok(records_deleted) => records_deleted,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Return the result.
+        if records_deleted > 0 {
+            return DeletionResult::Ok;
+        } else {
+            return DeletionResult::NotFound;
+        }
+    }
+
+    async fn notify_with_context(
+        &self,
+        monitor: &Monitor,
+        check_result: &CheckResult,
+        previous_check_result: Option<&CheckResult>,
+        incident: Option<&Incident>,
+        context: &mut QueryContext,
+    ) -> Result<Vec<NotificationDelivery>, Box<dyn Error>> {
+        // If the monitor's state did not change, there is nothing to deliver.
+        let event: MonitorEventKind =
+            match event::get_event_kind(check_result, previous_check_result, incident) {
+                Some(event) => event,
+                None => return Ok(Vec::new()),
+            };
+
+        // Query the notification channels the monitor's events are sent to.
+        let notification_channels: Vec<NotificationChannel> = __self
+            .notification_channel_repository
+            .get_enabled_by_monitor_id(&monitor.user_id, &monitor.id, context)
+            .await?;
+
+        // Queue a delivery of the event's payload to each notification channel.
+        let payload: MonitorEventPayload = event::create_payload(
+            event,
+            monitor,
+            check_result,
+            previous_check_result,
+            incident,
+        );
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let mut notification_deliveries: Vec<NotificationDelivery> = Vec::new();
+        for notification_channel in &notification_channels {
+            let notification_delivery: NotificationDelivery = NotificationDelivery {
+                id: 0,
+                notification_channel_id: notification_channel.id,
+                monitor_id: monitor.id,
+                delivery_created_at: now,
+                event,
+                payload: Json(payload.clone()),
+                status: DeliveryStatus::Pending,
+                attempt_count: 0,
+                next_attempt_at: Some(now),
+            };
+            let notification_delivery_id = __self
+                .notification_channel_repository
+                .insert_delivery(&notification_delivery, context)
+                .await?;
+            notification_deliveries.push(NotificationDelivery {
+                id: notification_delivery_id,
+                ..notification_delivery
+            });
+        }
+
+        // Return the deliveries that were queued.
+        return Ok(notification_deliveries);
+    }
+
+    async fn get_delivery(&self, id: &u64) -> QueryResult<NotificationDelivery, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_delivery_with_context(id, &mut context).await;
+    }
+
+    async fn get_delivery_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<NotificationDelivery, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self
+            .notification_channel_repository
+            .get_delivery(id, context)
+            .await
+        {
+            Ok(notification_delivery_option) => notification_delivery_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the notification delivery was found, return the notification delivery, otherwise
+        // return not found.
+        return match result {
+            Some(notification_delivery) => QueryResult::Ok(notification_delivery),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_deliveries_by_notification_channel_id(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+    ) -> QueryResult<Vec<NotificationDelivery>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_deliveries_by_notification_channel_id_with_context(
+                notification_channel_id,
+                limit,
+                &mut context,
+            )
+            .await;
+    }
+
+    async fn get_deliveries_by_notification_channel_id_with_context(
+        &self,
+        notification_channel_id: &u64,
+        limit: &u32,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationDelivery>, Box<dyn Error>> {
+        // Perform the query and return the notification deliveries that were found.
+        return match __self
+            .notification_channel_repository
+            .get_deliveries_by_notification_channel_id(notification_channel_id, limit, context)
+            .await
+        {
+            Ok(notification_deliveries) => QueryResult::Ok(notification_deliveries),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn get_attempts_by_notification_delivery_id(
+        &self,
+        notification_delivery_id: &u64,
+    ) -> QueryResult<Vec<NotificationDeliveryAttempt>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_attempts_by_notification_delivery_id_with_context(
+                notification_delivery_id,
+                &mut context,
+            )
+            .await;
+    }
+
+    async fn get_attempts_by_notification_delivery_id_with_context(
+        &self,
+        notification_delivery_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<NotificationDeliveryAttempt>, Box<dyn Error>> {
+        // Perform the query and return the notification delivery attempts that were found.
+        return match __self
+            .notification_channel_repository
+            .get_attempts_by_notification_delivery_id(notification_delivery_id, context)
+            .await
+        {
+            Ok(notification_delivery_attempts) => QueryResult::Ok(notification_delivery_attempts),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn replay_delivery(
+        &self,
+        id: &u64,
+    ) -> UpdateResult<NotificationDelivery, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the replay.
+        let update_result = self.replay_delivery_with_context(id, &mut context).await;
+
+        // If the replay was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn replay_delivery_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> UpdateResult<NotificationDelivery, ValidationErrors, Box<dyn Error>> {
+        // Query the notification delivery that is being replayed.
+        let mut notification_delivery: NotificationDelivery = match __self
+            .notification_channel_repository
+            .get_delivery(id, context)
+            .await
+        {
+            Ok(Some(notification_delivery)) => notification_delivery,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // A delivery that is still pending will be attempted anyway, so it can not be replayed.
+        if notification_delivery.status == DeliveryStatus::Pending {
+            let mut validation_errors = ValidationErrors::new();
+            validation_errors.add(
+                name_of!(status in NotificationDelivery),
+                create_value_validation_error("pending", &notification_delivery.id),
+            );
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Queue the delivery to be attempted again.
+        notification_delivery.status = DeliveryStatus::Pending;
+        notification_delivery.attempt_count = 0;
+        notification_delivery.next_attempt_at = Some(OffsetDateTime::now_utc());
+        if let Err(error) = __self
+            .notification_channel_repository
+            .update_delivery(&notification_delivery, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Return the notification delivery.
+        return UpdateResult::Ok(notification_delivery);
+    }
+
+    async fn deliver_pending(&self, client: &Client) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Attempt the deliveries.
+        return self
+            .deliver_pending_with_context(client, &OffsetDateTime::now_utc(), &mut context)
+            .await;
+    }
+
+    async fn deliver_pending_with_context(
+        &self,
+        client: &Client,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>> {
+        // Query the deliveries that are due to be attempted.
+        let notification_deliveries: Vec<NotificationDelivery> = __self
+            .notification_channel_repository
+            .get_due_deliveries(now, &DELIVERY_BATCH_SIZE, context)
+            .await?;
+
+        for mut notification_delivery in notification_deliveries {
+            // Query the notification channel the delivery is being sent to. Deliveries that were
+            // queued before their channel was disabled are still attempted.
+            let notification_channel: NotificationChannel = match __self
+                .notification_channel_repository
+                .get(&notification_delivery.notification_channel_id, context)
+                .await?
+            {
+                Some(notification_channel) => notification_channel,
+                None => continue,
+            };
+
+            // Attempt the delivery, and record the attempt.
+            let attempt: NotificationDeliveryAttempt = match &notification_channel.configuration {
+                NotificationChannelConfiguration::Webhook(configuration) => {
+                    webhook::send(client, configuration, &notification_delivery).await
+                }
+                NotificationChannelConfiguration::Email(configuration) => {
+                    email::send(
+                        __self.mail_service.as_ref(),
+                        configuration,
+                        &notification_delivery,
+                        context,
+                    )
+                    .await
+                }
+            };
+            __self
+                .notification_channel_repository
+                .insert_attempt(&attempt, context)
+                .await?;
+
+            // Complete the delivery, or schedule its next attempt.
+            self.record_attempt(&mut notification_delivery, &attempt);
+            __self
+                .notification_channel_repository
+                .update_delivery(&notification_delivery, context)
+                .await?;
+        }
+
+        return Ok(());
+    }
+}
+
+/// An implementation for the NotificationChannelServiceImpl struct.
+impl NotificationChannelServiceImpl {
+    /// # Description
+    ///
+    /// Create notification channel service parameters that can be used to override the default
+    /// values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_config` - The notification configuration that determines how many times
+    /// deliveries are attempted.
+    ///
+    /// # Returns
+    ///
+    /// The notification channel service parameters that were created.
+    pub(crate) fn create_parameters(
+        notification_config: NotificationConfig,
+    ) -> NotificationChannelServiceImplParameters {
+        return NotificationChannelServiceImplParameters {
+            notification_config,
+        };
+    }
+
+    /// # Description
+    ///
+    /// Update a notification delivery with the outcome of an attempt. A successful attempt
+    /// completes the delivery, and a failed attempt schedules a retry unless every attempt has been
+    /// used.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_delivery` - The notification delivery that was attempted.
+    ///
+    /// `attempt` - The outcome of the attempt.
+    fn record_attempt(
+        &self,
+        notification_delivery: &mut NotificationDelivery,
+        attempt: &NotificationDeliveryAttempt,
+    ) {
+        notification_delivery.attempt_count += 1;
+
+        if attempt.is_successful {
+            notification_delivery.status = DeliveryStatus::Succeeded;
+            notification_delivery.next_attempt_at = None;
+        } else if notification_delivery.attempt_count >= self.notification_config.maximum_attempts {
+            notification_delivery.status = DeliveryStatus::Failed;
+            notification_delivery.next_attempt_at = None;
+        } else {
+            notification_delivery.next_attempt_at =
+                Some(attempt.attempted_at + get_retry_delay(notification_delivery.attempt_count));
+        }
+    }
+
+    /// # Description
+    ///
+    /// Validate the records a notification channel refers to. The notification channel's owner
+    /// must exist, and the monitors it is limited to must belong to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// `notification_channel` - The notification channel that is being validated.
+    ///
+    /// `validation_errors` - The validation errors that any errors that are detected will be added
+    /// to.
+    ///
+    /// `context` - The context the validation will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the validation could be performed, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn validate_references(
+        &self,
+        notification_channel: &NotificationChannel,
+        validation_errors: &mut ValidationErrors,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Check if the user id specified exists.
+        match self
+            .user_service
+            .get_by_id_with_context(&notification_channel.user_id, context)
+            .await
+        {
+            QueryResult::Ok(_) => {}
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(user_id in NotificationChannel),
+                create_value_validation_error("not_found", &notification_channel.user_id),
+            ),
+            QueryResult::Err(error) => return Err(error),
+        }
+
+        // Make sure each monitor the notification channel is limited to belongs to the owner.
+        for monitor_id in notification_channel.monitor_ids.iter() {
+            match self
+                .monitor_service
+                .get_with_context(monitor_id, context)
+                .await
+            {
+                QueryResult::Ok(monitor) if monitor.user_id == notification_channel.user_id => {}
+                QueryResult::Ok(_) | QueryResult::NotFound => validation_errors.add(
+                    name_of!(monitor_ids in NotificationChannel),
+                    create_value_validation_error("not_found", monitor_id),
+                ),
+                QueryResult::Err(error) => return Err(error),
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,489 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult, UpdateResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        check::{
+            model::{CheckResult, CheckStatus},
+            service::CheckResultService,
+        },
+        monitor::{
+            model::{HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        notification::{
+            model::{
+                DeliveryStatus, MonitorEventKind, NotificationChannel,
+                NotificationChannelConfiguration, NotificationDelivery,
+                NotificationDeliveryAttempt, WebhookChannelConfiguration,
+            },
+            service::NotificationChannelService,
+            webhook::{sign_payload, SIGNATURE_HEADER},
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use core::panic;
+use reqwest::Client;
+use shaku::HasComponent;
+use sqlx::types::Json;
+use std::{net::TcpListener, sync::Arc};
+use time::OffsetDateTime;
+
+/// The secret the test notification channels sign their payloads with.
+const TEST_SECRET: &str = "0123456789abcdef";
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration::default()),
+    };
+}
+
+/// # Description
+///
+/// Create a notification channel that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the notification channel will belong to.
+///
+/// `url` - The url the notification channel's payloads will be posted to.
+///
+/// # Returns
+///
+/// The notification channel that was created.
+fn create_test_notification_channel(user: &User, url: String) -> NotificationChannel {
+    return NotificationChannel {
+        id: 0,
+        user_id: user.id,
+        notification_channel_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(16),
+        is_enabled: true,
+        configuration: NotificationChannelConfiguration::Webhook(WebhookChannelConfiguration {
+            url,
+            secret: String::from(TEST_SECRET),
+        }),
+        monitor_ids: Json(Vec::new()),
+    };
+}
+
+/// # Description
+///
+/// Start a local stand-in webhook receiver that notification channels can post payloads to. The
+/// receiver responds with a 204 status code if a payload's signature is valid, otherwise it
+/// responds with a 401 status code.
+///
+/// # Panics
+///
+/// This function will panic if the receiver could not be bound to a local port.
+///
+/// # Returns
+///
+/// The url of the receiver that was started.
+fn start_stand_in_receiver() -> String {
+    // Bind a listener to a random local port.
+    let listener: TcpListener =
+        TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in receiver");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in receiver address")
+        .port();
+
+    // Create and start the receiver.
+    let server = HttpServer::new(|| {
+        App::new().route(
+            "/",
+            web::post().to(|request: HttpRequest, body: web::Bytes| async move {
+                let expected_signature: String =
+                    sign_payload(TEST_SECRET, &body).expect("Failed to sign payload");
+                let signature: Option<&str> = request
+                    .headers()
+                    .get(SIGNATURE_HEADER)
+                    .and_then(|value| value.to_str().ok());
+
+                if signature == Some(expected_signature.as_str()) {
+                    return HttpResponse::NoContent().finish();
+                }
+                return HttpResponse::Unauthorized().finish();
+            }),
+        )
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to start stand-in receiver")
+    .run();
+    actix_web::rt::spawn(server);
+
+    // Return the receiver's url.
+    return format!("http://127.0.0.1:{}/", port);
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting notification channels that are limited to another user's monitor, and make sure
+/// they are rejected while notification channels that are limited to the owner's monitors are
+/// accepted.
+#[actix_web::test]
+async fn notification_channels_can_only_be_limited_to_their_owners_monitors() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a notification channel service instance.
+    let notification_channel_service: Arc<dyn NotificationChannelService> = injector.resolve();
+
+    // Insert two test users, each with a monitor.
+    let user: User = insert_test_user(&injector).await;
+    let other_user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+    let other_monitor: Monitor = insert_test_monitor(&other_user, &injector).await;
+
+    // Make sure a notification channel that is limited to another user's monitor is rejected.
+    let mut notification_channel: NotificationChannel =
+        create_test_notification_channel(&user, String::from("https://example.com/webhook"));
+    notification_channel.monitor_ids = Json(vec![monitor.id, other_monitor.id]);
+    match notification_channel_service
+        .insert(&notification_channel)
+        .await
+    {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(details) => {
+            assert!(details.field_errors().contains_key("monitor_ids"))
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert notification channel, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Make sure a notification channel that is limited to the owner's monitors is accepted.
+    notification_channel.monitor_ids = Json(vec![monitor.id]);
+    match notification_channel_service
+        .insert(&notification_channel)
+        .await
+    {
+        InsertionResult::Ok(inserted_notification_channel) => {
+            assert_eq!(vec![monitor.id], *inserted_notification_channel.monitor_ids)
+        }
+        InsertionResult::Invalid(details) => {
+            panic!("Failed to insert notification channel: {}", details)
+        }
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert notification channel: {}", error)
+        }
+    };
+
+    // Delete the test users.
+    delete_test_user(&user, &injector).await;
+    delete_test_user(&other_user, &injector).await;
+}
+
+/// # Description
+///
+/// Test a monitor going down, and make sure a signed payload is delivered to its notification
+/// channel and that the delivery can be replayed once it is no longer pending.
+#[actix_web::test]
+async fn monitor_events_are_delivered_to_notification_channels() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a notification channel service and check result service instance.
+    let notification_channel_service: Arc<dyn NotificationChannelService> = injector.resolve();
+    let check_result_service: Arc<dyn CheckResultService> = injector.resolve();
+
+    // Insert a test user, monitor and notification channel that posts to the stand-in receiver.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+    let notification_channel: NotificationChannel = match notification_channel_service
+        .insert(&create_test_notification_channel(
+            &user,
+            start_stand_in_receiver(),
+        ))
+        .await
+    {
+        InsertionResult::Ok(notification_channel) => notification_channel,
+        InsertionResult::Invalid(details) => {
+            panic!("Failed to insert notification channel: {}", details)
+        }
+        InsertionResult::Err(error) => {
+            panic!("Failed to insert notification channel: {}", error)
+        }
+    };
+
+    // Record the monitor going down.
+    let check_result: CheckResult = CheckResult {
+        monitor_id: monitor.id,
+        checked_at: OffsetDateTime::now_utc(),
+        status: CheckStatus::Down,
+        error_message: Some(String::from("The request timed out")),
+        ..Default::default()
+    };
+    match check_result_service.insert(&check_result).await {
+        InsertionResult::Ok(_) => {}
+        InsertionResult::Invalid(details) => panic!("Failed to insert check result: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert check result: {}", error),
+    };
+
+    // Make sure a delivery of the down event was queued.
+    let notification_deliveries: Vec<NotificationDelivery> = match notification_channel_service
+        .get_deliveries_by_notification_channel_id(&notification_channel.id, &100)
+        .await
+    {
+        QueryResult::Ok(notification_deliveries) => notification_deliveries,
+        QueryResult::NotFound => panic!("Failed to query notification deliveries: Not found"),
+        QueryResult::Err(error) => panic!("Failed to query notification deliveries: {}", error),
+    };
+    assert_eq!(1, notification_deliveries.len());
+    assert_eq!(MonitorEventKind::Down, notification_deliveries[0].event);
+    assert_eq!(DeliveryStatus::Pending, notification_deliveries[0].status);
+
+    // Make sure a pending delivery can not be replayed.
+    match notification_channel_service
+        .replay_delivery(&notification_deliveries[0].id)
+        .await
+    {
+        UpdateResult::Invalid(details) => {
+            assert!(details.field_errors().contains_key("status"))
+        }
+        _ => panic!("Replaying a pending notification delivery should have been rejected"),
+    };
+
+    // Deliver the pending deliveries, and make sure the receiver accepted the payload.
+    notification_channel_service
+        .deliver_pending(&Client::new())
+        .await
+        .expect("Failed to deliver the pending notifications");
+    let notification_delivery: NotificationDelivery = match notification_channel_service
+        .get_delivery(&notification_deliveries[0].id)
+        .await
+    {
+        QueryResult::Ok(notification_delivery) => notification_delivery,
+        QueryResult::NotFound => panic!("Failed to query notification delivery: Not found"),
+        QueryResult::Err(error) => panic!("Failed to query notification delivery: {}", error),
+    };
+    assert_eq!(DeliveryStatus::Succeeded, notification_delivery.status);
+    assert_eq!(1, notification_delivery.attempt_count);
+    assert_eq!(None, notification_delivery.next_attempt_at);
+
+    let attempts: Vec<NotificationDeliveryAttempt> = match notification_channel_service
+        .get_attempts_by_notification_delivery_id(&notification_delivery.id)
+        .await
+    {
+        QueryResult::Ok(attempts) => attempts,
+        QueryResult::NotFound => panic!("Failed to query delivery attempts: Not found"),
+        QueryResult::Err(error) => panic!("Failed to query delivery attempts: {}", error),
+    };
+    assert_eq!(1, attempts.len());
+    assert!(attempts[0].is_successful);
+    assert_eq!(Some(204), attempts[0].status_code);
+
+    // Make sure the delivery can be replayed now that it is no longer pending.
+    match notification_channel_service
+        .replay_delivery(&notification_delivery.id)
+        .await
+    {
+        UpdateResult::Ok(replayed_notification_delivery) => {
+            assert_eq!(
+                DeliveryStatus::Pending,
+                replayed_notification_delivery.status
+            );
+            assert_eq!(0, replayed_notification_delivery.attempt_count);
+        }
+        UpdateResult::NotFound => panic!("Failed to replay notification delivery: Not found"),
+        UpdateResult::Invalid(details) => {
+            panic!("Failed to replay notification delivery: {}", details)
+        }
+        UpdateResult::Err(error) => panic!("Failed to replay notification delivery: {}", error),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
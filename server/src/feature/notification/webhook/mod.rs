@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod test;
+
+use crate::feature::{
+    check::checker::{create_error_message, get_elapsed_milliseconds},
+    notification::model::{
+        MonitorEventKind, NotificationDelivery, NotificationDeliveryAttempt,
+        WebhookChannelConfiguration,
+    },
+};
+use openssl::{error::ErrorStack, hash::MessageDigest, pkey::PKey, sign::Signer};
+use reqwest::{header::CONTENT_TYPE, Client};
+use std::time::Instant;
+use time::OffsetDateTime;
+
+/// The header that contains the signature of the payload.
+pub(crate) const SIGNATURE_HEADER: &str = "X-EasyTracker-Signature";
+
+/// The header that contains the event the payload describes.
+pub(crate) const EVENT_HEADER: &str = "X-EasyTracker-Event";
+
+/// The header that contains the unique identifier of the delivery, which stays the same when a
+/// delivery is retried or replayed so receivers can ignore duplicates.
+pub(crate) const DELIVERY_HEADER: &str = "X-EasyTracker-Delivery";
+
+/// # Description
+///
+/// Sign a payload with a webhook's secret, using HMAC-SHA256.
+///
+/// # Arguments
+///
+/// `secret` - The webhook's secret.
+///
+/// `body` - The payload that will be signed.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the payload was signed, the Ok variant will be returned with the signature, formatted as
+/// `sha256=<hex digest>`.
+/// - If an error occurs while signing the payload, the Err variant will be returned with the error
+/// that occurred.
+pub(crate) fn sign_payload(secret: &str, body: &[u8]) -> Result<String, ErrorStack> {
+    // Calculate the message authentication code.
+    let key = PKey::hmac(secret.as_bytes())?;
+    let mut signer: Signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(body)?;
+    let code: Vec<u8> = signer.sign_to_vec()?;
+
+    // Encode the message authentication code as hex.
+    let digest: String = code.iter().map(|byte| format!("{:02x}", byte)).collect();
+    return Ok(format!("sha256={}", digest));
+}
+
+/// # Description
+///
+/// Attempt to send a delivery's payload to a webhook.
+///
+/// # Arguments
+///
+/// `client` - The http client that will be used to send the payload.
+///
+/// `configuration` - The configuration of the webhook the payload will be sent to.
+///
+/// `delivery` - The delivery whose payload will be sent.
+///
+/// # Returns
+///
+/// The outcome of the attempt. The attempt is successful if the webhook responded with a 2xx
+/// status code.
+pub(crate) async fn send(
+    client: &Client,
+    configuration: &WebhookChannelConfiguration,
+    delivery: &NotificationDelivery,
+) -> NotificationDeliveryAttempt {
+    // Create the attempt.
+    let mut attempt: NotificationDeliveryAttempt = NotificationDeliveryAttempt {
+        id: 0,
+        notification_delivery_id: delivery.id,
+        attempted_at: OffsetDateTime::now_utc(),
+        is_successful: false,
+        status_code: None,
+        latency: 0,
+        error_message: None,
+    };
+
+    // Serialize the payload.
+    let body: Vec<u8> = match serde_json::to_vec(&delivery.payload.0) {
+        Ok(body) => body,
+        Err(error) => {
+            attempt.error_message = Some(create_error_message(&error));
+            return attempt;
+        }
+    };
+
+    // Sign the payload.
+    let signature: String = match sign_payload(&configuration.secret, &body) {
+        Ok(signature) => signature,
+        Err(error) => {
+            attempt.error_message = Some(create_error_message(&error));
+            return attempt;
+        }
+    };
+
+    // Send the payload.
+    let start: Instant = Instant::now();
+    let response_result = client
+        .post(&configuration.url)
+        .header(CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, signature)
+        .header(EVENT_HEADER, get_event_name(&delivery.event))
+        .header(DELIVERY_HEADER, delivery.id.to_string())
+        .body(body)
+        .send()
+        .await;
+    attempt.latency = get_elapsed_milliseconds(&start);
+
+    // Record how the webhook responded.
+    match response_result {
+        Ok(response) => {
+            let status_code: u16 = response.status().as_u16();
+            attempt.status_code = Some(status_code);
+            attempt.is_successful = response.status().is_success();
+            if !attempt.is_successful {
+                attempt.error_message = Some(format!("Received status code {}", status_code));
+            }
+        }
+        Err(error) => attempt.error_message = Some(create_error_message(&error)),
+    }
+
+    return attempt;
+}
+
+/// # Description
+///
+/// Get the name of an event, as it appears in the payload.
+///
+/// # Arguments
+///
+/// `event` - The event to get the name of.
+///
+/// # Returns
+///
+/// The name of the event.
+fn get_event_name(event: &MonitorEventKind) -> &'static str {
+    return match event {
+        MonitorEventKind::Down => "down",
+        MonitorEventKind::Degraded => "degraded",
+        MonitorEventKind::Recovered => "recovered",
+    };
+}
@@ -0,0 +1,219 @@
+use crate::feature::{
+    check::model::CheckStatus,
+    notification::{
+        model::{
+            DeliveryStatus, MonitorEventKind, MonitorEventPayload, NotificationDelivery,
+            NotificationDeliveryAttempt, WebhookChannelConfiguration, PAYLOAD_VERSION,
+        },
+        webhook::{send, sign_payload, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER},
+    },
+};
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use reqwest::Client;
+use sqlx::types::Json;
+use std::net::TcpListener;
+use time::OffsetDateTime;
+
+/// The secret the stand-in receiver uses to verify signatures.
+const TEST_SECRET: &str = "0123456789abcdef";
+
+/// # Description
+///
+/// Start a local stand-in webhook receiver that deliveries can be sent to.
+///
+/// The receiver responds to the following routes:
+/// - `/verify` - Responds with a 204 status code if the payload's signature, event and delivery
+/// headers are valid, otherwise responds with a 401 status code.
+/// - `/error` - Responds with a 500 status code.
+///
+/// # Panics
+///
+/// This function will panic if the receiver could not be bound to a local port.
+///
+/// # Returns
+///
+/// The base url of the receiver that was started.
+fn start_stand_in_receiver() -> String {
+    // Bind a listener to a random local port.
+    let listener: TcpListener =
+        TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in receiver");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in receiver address")
+        .port();
+
+    // Create and start the receiver.
+    let server = HttpServer::new(|| {
+        App::new()
+            .route(
+                "/verify",
+                web::post().to(|request: HttpRequest, body: web::Bytes| async move {
+                    let get_header = |name: &str| {
+                        request
+                            .headers()
+                            .get(name)
+                            .and_then(|value| value.to_str().ok())
+                            .map(String::from)
+                    };
+                    let expected_signature: String =
+                        sign_payload(TEST_SECRET, &body).expect("Failed to sign payload");
+                    let payload: Option<MonitorEventPayload> = serde_json::from_slice(&body).ok();
+
+                    if get_header(SIGNATURE_HEADER) == Some(expected_signature)
+                        && get_header(EVENT_HEADER) == Some(String::from("down"))
+                        && get_header(DELIVERY_HEADER) == Some(String::from("42"))
+                        && payload.is_some_and(|payload| payload.version == PAYLOAD_VERSION)
+                    {
+                        return HttpResponse::NoContent().finish();
+                    }
+                    return HttpResponse::Unauthorized().finish();
+                }),
+            )
+            .route(
+                "/error",
+                web::to(|| async { HttpResponse::InternalServerError().finish() }),
+            )
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to start stand-in receiver")
+    .run();
+    actix_web::rt::spawn(server);
+
+    // Return the receiver's base url.
+    return format!("http://127.0.0.1:{}", port);
+}
+
+/// # Description
+///
+/// Create a delivery that can be used for testing.
+///
+/// # Returns
+///
+/// The delivery that was created.
+fn create_test_delivery() -> NotificationDelivery {
+    return NotificationDelivery {
+        id: 42,
+        notification_channel_id: 1,
+        monitor_id: 1,
+        delivery_created_at: OffsetDateTime::now_utc(),
+        event: MonitorEventKind::Down,
+        payload: Json(MonitorEventPayload {
+            version: PAYLOAD_VERSION,
+            event: MonitorEventKind::Down,
+            occurred_at: OffsetDateTime::now_utc(),
+            monitor_id: 1,
+            monitor_name: String::from("Website"),
+            status: CheckStatus::Down,
+            previous_status: Some(CheckStatus::Up),
+            error_kind: None,
+            error_message: None,
+            incident_id: Some(1),
+        }),
+        status: DeliveryStatus::Pending,
+        attempt_count: 0,
+        next_attempt_at: Some(OffsetDateTime::now_utc()),
+    };
+}
+
+/// # Description
+///
+/// Create a webhook configuration that can be used for testing.
+///
+/// # Arguments
+///
+/// `url` - The url the payloads will be posted to.
+///
+/// # Returns
+///
+/// The webhook configuration that was created.
+fn create_test_configuration(url: String) -> WebhookChannelConfiguration {
+    return WebhookChannelConfiguration {
+        url,
+        secret: String::from(TEST_SECRET),
+    };
+}
+
+/// # Description
+///
+/// Test signing a payload, and make sure the signature matches the HMAC-SHA256 test vector from
+/// RFC 4231.
+#[test]
+fn payloads_are_signed_with_hmac_sha256() {
+    assert_eq!(
+        "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+        sign_payload("Jefe", b"what do ya want for nothing?").expect("Failed to sign payload")
+    );
+}
+
+/// # Description
+///
+/// Test sending a delivery to a receiver that verifies its signature, and make sure the receiver
+/// accepts it.
+#[actix_web::test]
+async fn signed_deliveries_are_accepted() {
+    // Start the stand-in receiver.
+    let base_url: String = start_stand_in_receiver();
+
+    // Send the delivery.
+    let attempt: NotificationDeliveryAttempt = send(
+        &Client::new(),
+        &create_test_configuration(format!("{}/verify", base_url)),
+        &create_test_delivery(),
+    )
+    .await;
+
+    // Make sure the receiver accepted the delivery.
+    assert_eq!(42, attempt.notification_delivery_id);
+    assert!(attempt.is_successful);
+    assert_eq!(Some(204), attempt.status_code);
+    assert_eq!(None, attempt.error_message);
+}
+
+/// # Description
+///
+/// Test sending deliveries that are rejected or can not be sent, and make sure the attempts are
+/// recorded as failures.
+#[actix_web::test]
+async fn rejected_deliveries_are_failures() {
+    // Start the stand-in receiver.
+    let base_url: String = start_stand_in_receiver();
+
+    // Make sure a delivery signed with the wrong secret is rejected.
+    let attempt: NotificationDeliveryAttempt = send(
+        &Client::new(),
+        &WebhookChannelConfiguration {
+            secret: String::from("fedcba9876543210"),
+            ..create_test_configuration(format!("{}/verify", base_url))
+        },
+        &create_test_delivery(),
+    )
+    .await;
+    assert!(!attempt.is_successful);
+    assert_eq!(Some(401), attempt.status_code);
+
+    // Make sure a server error is a failure.
+    let attempt: NotificationDeliveryAttempt = send(
+        &Client::new(),
+        &create_test_configuration(format!("{}/error", base_url)),
+        &create_test_delivery(),
+    )
+    .await;
+    assert!(!attempt.is_successful);
+    assert_eq!(Some(500), attempt.status_code);
+    assert_eq!(
+        Some(String::from("Received status code 500")),
+        attempt.error_message
+    );
+
+    // Make sure a receiver that can not be reached is a failure.
+    let attempt: NotificationDeliveryAttempt = send(
+        &Client::new(),
+        &create_test_configuration(String::from("http://127.0.0.1:1/verify")),
+        &create_test_delivery(),
+    )
+    .await;
+    assert!(!attempt.is_successful);
+    assert_eq!(None, attempt.status_code);
+    assert!(attempt.error_message.is_some());
+}
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// An OpenID Connect authorization request struct, which holds what is needed to finish a single
+/// sign-on login after the identity provider sends the user back.
+#[derive(Clone, Debug)]
+pub(crate) struct OidcAuthorizationRequest {
+    /// The authorization request's unique identifier.
+    pub(crate) id: u64,
+
+    /// The name of the identity provider the user was sent to.
+    pub(crate) provider: String,
+
+    /// The sha-256 hash of the state that was sent to the identity provider, encoded as hex.
+    pub(crate) state_hash: String,
+
+    /// The pkce code verifier (RFC 7636), which is sent with the authorization code to prove the
+    /// login was started by the server.
+    pub(crate) code_verifier: String,
+
+    /// The nonce that was sent to the identity provider, which the id token must contain.
+    pub(crate) nonce: String,
+
+    /// The date and time the authorization request was created at.
+    pub(crate) request_created_at: OffsetDateTime,
+
+    /// The date and time the authorization request expires at.
+    pub(crate) expires_at: OffsetDateTime,
+}
+
+/// A user identity struct, which links an account at an identity provider to a user.
+#[derive(Clone, Debug)]
+pub(crate) struct UserIdentity {
+    /// The user identity's unique identifier.
+    pub(crate) id: u64,
+
+    /// The id of the user the identity belongs to.
+    pub(crate) user_id: u64,
+
+    /// The name of the identity provider.
+    pub(crate) provider: String,
+
+    /// The subject identifier of the account at the identity provider, which never changes.
+    pub(crate) subject: String,
+
+    /// The date and time the identity was linked at.
+    pub(crate) identity_created_at: OffsetDateTime,
+}
+
+/// The part of an identity provider's discovery document that is needed to login with it.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct OidcProviderMetadata {
+    /// The issuer identifier of the identity provider.
+    pub(crate) issuer: String,
+
+    /// The url users are sent to to login.
+    pub(crate) authorization_endpoint: String,
+
+    /// The url authorization codes are exchanged for tokens at.
+    pub(crate) token_endpoint: String,
+
+    /// The url of the identity provider's json web key set, which id tokens are signed with.
+    pub(crate) jwks_uri: String,
+}
+
+/// The part of an identity provider's token response that is needed to login with it.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct OidcTokenResponse {
+    /// The id token, which identifies the user that logged in.
+    pub(crate) id_token: String,
+}
+
+/// The claims of an id token that are used to identify the user that logged in.
+#[derive(Clone, Debug, Deserialize)]
+pub(crate) struct IdTokenClaims {
+    /// The subject identifier of the user at the identity provider.
+    pub(crate) sub: String,
+
+    /// The nonce that was sent to the identity provider.
+    pub(crate) nonce: Option<String>,
+
+    /// The user's email address.
+    pub(crate) email: Option<String>,
+
+    /// If the identity provider has verified the user's email address.
+    pub(crate) email_verified: Option<bool>,
+
+    /// The username the user prefers.
+    pub(crate) preferred_username: Option<String>,
+}
@@ -0,0 +1,367 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::oidc::model::{OidcAuthorizationRequest, UserIdentity},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// An oidc repository trait.
+#[async_trait]
+pub(crate) trait OidcRepository: Interface {
+    /// # Description
+    ///
+    /// Insert an authorization request into the oidc repository.
+    ///
+    /// # Arguments
+    ///
+    /// `authorization_request` - The authorization request to insert into the oidc repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// authorization request that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_authorization_request(
+        &self,
+        authorization_request: &OidcAuthorizationRequest,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query an authorization request from the oidc repository by the hash of its state.
+    ///
+    /// # Arguments
+    ///
+    /// `state_hash` - The hash of the state.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the authorization request if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_authorization_request_by_state_hash(
+        &self,
+        state_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<OidcAuthorizationRequest>, Error>;
+
+    /// # Description
+    ///
+    /// Delete an authorization request from the oidc repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the authorization request to delete.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted, which will be 0 if the authorization request was already deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_authorization_request(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the authorization requests that expired before a specific time from the oidc
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `before` - The date and time authorization requests must have expired before to be deleted.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_expired_authorization_requests(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a user identity into the oidc repository.
+    ///
+    /// # Arguments
+    ///
+    /// `identity` - The user identity to insert into the oidc repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the user
+    /// identity that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_identity(
+        &self,
+        identity: &UserIdentity,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query a user identity from the oidc repository by the identity provider and the subject
+    /// identifier of the account at it.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The name of the identity provider.
+    ///
+    /// `subject` - The subject identifier of the account at the identity provider.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the user identity if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_identity(
+        &self,
+        provider: &String,
+        subject: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<UserIdentity>, Error>;
+}
+
+/// An OidcRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = OidcRepository)]
+pub(crate) struct OidcRepositoryImpl;
+
+/// An OidcRepository implementation for the OidcRepositoryImpl struct.
+#[async_trait]
+impl OidcRepository for OidcRepositoryImpl {
+    async fn insert_authorization_request(
+        &self,
+        authorization_request: &OidcAuthorizationRequest,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `oidc_authorization_requests` (
+                    `id`,
+                    `provider`,
+                    `state_hash`,
+                    `code_verifier`,
+                    `nonce`,
+                    `request_created_at`,
+                    `expires_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            authorization_request.id,
+            authorization_request.provider,
+            authorization_request.state_hash,
+            authorization_request.code_verifier,
+            authorization_request.nonce,
+            authorization_request.request_created_at,
+            authorization_request.expires_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_authorization_request_by_state_hash(
+        &self,
+        state_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<OidcAuthorizationRequest>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            OidcAuthorizationRequest,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `provider` AS `provider: String`,
+                    `state_hash` AS `state_hash: String`,
+                    `code_verifier` AS `code_verifier: String`,
+                    `nonce` AS `nonce: String`,
+                    `request_created_at` AS `request_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`
+                FROM
+                    `oidc_authorization_requests`
+                WHERE
+                    `state_hash` = ?;
+            "#,
+            state_hash
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => {
+                query.fetch_optional(transaction.as_mut()).await
+            }
+        };
+    }
+
+    async fn delete_authorization_request(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `oidc_authorization_requests`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_expired_authorization_requests(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `oidc_authorization_requests`
+                WHERE
+                    `expires_at` < ?;
+            "#,
+            before
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_identity(
+        &self,
+        identity: &UserIdentity,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `user_identities` (
+                    `id`,
+                    `user_id`,
+                    `provider`,
+                    `subject`,
+                    `identity_created_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            identity.id,
+            identity.user_id,
+            identity.provider,
+            identity.subject,
+            identity.identity_created_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_identity(
+        &self,
+        provider: &String,
+        subject: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<UserIdentity>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            UserIdentity,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `provider` AS `provider: String`,
+                    `subject` AS `subject: String`,
+                    `identity_created_at` AS `identity_created_at: OffsetDateTime`
+                FROM
+                    `user_identities`
+                WHERE
+                    `provider` = ? AND `subject` = ?;
+            "#,
+            provider,
+            subject
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => {
+                query.fetch_optional(transaction.as_mut()).await
+            }
+        };
+    }
+}
@@ -0,0 +1,355 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        oidc::{
+            model::{OidcAuthorizationRequest, UserIdentity},
+            repository::OidcRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+/// # Description
+///
+/// Test inserting authorization requests, and make sure they can be queried by the hash of their
+/// state, can only be deleted once and that only expired authorization requests are deleted.
+#[actix_web::test]
+async fn only_expired_authorization_requests_are_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an oidc repository instance.
+    let oidc_repository: Arc<dyn OidcRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert an active and an expired authorization request.
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut active_request: OidcAuthorizationRequest = OidcAuthorizationRequest {
+        id: 0,
+        provider: generate_random_string(8),
+        state_hash: generate_random_string(64),
+        code_verifier: generate_random_string(48),
+        nonce: generate_random_string(48),
+        request_created_at: now,
+        expires_at: now + Duration::MINUTE,
+    };
+    let mut expired_request: OidcAuthorizationRequest = OidcAuthorizationRequest {
+        id: 0,
+        provider: generate_random_string(8),
+        state_hash: generate_random_string(64),
+        code_verifier: generate_random_string(48),
+        nonce: generate_random_string(48),
+        request_created_at: now - Duration::HOUR,
+        expires_at: now - Duration::MINUTE,
+    };
+    for authorization_request in [&mut active_request, &mut expired_request] {
+        authorization_request.id = oidc_repository
+            .insert_authorization_request(authorization_request, &mut context)
+            .await
+            .expect("Failed to insert authorization request");
+    }
+
+    // Make sure the active authorization request can be queried by the hash of its state.
+    let queried_request: OidcAuthorizationRequest = oidc_repository
+        .get_authorization_request_by_state_hash(&active_request.state_hash, &mut context)
+        .await
+        .expect("Failed to query authorization request")
+        .expect("The authorization request could not be found");
+    assert_eq!(active_request.id, queried_request.id);
+    assert_eq!(active_request.provider, queried_request.provider);
+    assert_eq!(active_request.code_verifier, queried_request.code_verifier);
+    assert_eq!(active_request.nonce, queried_request.nonce);
+
+    // Delete the expired authorization requests, and make sure only the expired authorization
+    // request was deleted.
+    oidc_repository
+        .delete_expired_authorization_requests(&now, &mut context)
+        .await
+        .expect("Failed to delete expired authorization requests");
+    assert!(oidc_repository
+        .get_authorization_request_by_state_hash(&expired_request.state_hash, &mut context)
+        .await
+        .expect("Failed to query authorization request")
+        .is_none());
+
+    // Delete the active authorization request, and make sure it can only be deleted once.
+    for expected_rows_deleted in [1, 0] {
+        let rows_deleted = oidc_repository
+            .delete_authorization_request(&active_request.id, &mut context)
+            .await
+            .expect("Failed to delete authorization request");
+        assert_eq!(expected_rows_deleted, rows_deleted);
+    }
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting a user identity, and make sure it can only be queried with both the identity
+/// provider and the subject identifier it was linked with.
+#[actix_web::test]
+async fn identities_are_queried_by_provider_and_subject() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get an oidc repository instance.
+    let oidc_repository: Arc<dyn OidcRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and link an identity to them.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let identity: UserIdentity = UserIdentity {
+        id: 0,
+        user_id: user.id,
+        provider: generate_random_string(8),
+        subject: generate_random_string(32),
+        identity_created_at: OffsetDateTime::now_utc(),
+    };
+    let identity_id: u64 = oidc_repository
+        .insert_identity(&identity, &mut context)
+        .await
+        .expect("Failed to insert user identity");
+
+    // Make sure the identity can be queried.
+    let queried_identity: UserIdentity = oidc_repository
+        .get_identity(&identity.provider, &identity.subject, &mut context)
+        .await
+        .expect("Failed to query user identity")
+        .expect("The user identity could not be found");
+    assert_eq!(identity_id, queried_identity.id);
+    assert_eq!(user.id, queried_identity.user_id);
+
+    // Make sure the same subject identifier at another identity provider is not found.
+    assert!(oidc_repository
+        .get_identity(&generate_random_string(8), &identity.subject, &mut context)
+        .await
+        .expect("Failed to query user identity")
+        .is_none());
+
+    // Make sure the same subject identifier can not be linked twice.
+    assert!(oidc_repository
+        .insert_identity(&identity, &mut context)
+        .await
+        .is_err());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
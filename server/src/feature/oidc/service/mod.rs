@@ -0,0 +1,964 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext, QueryResult},
+        utility::{generate_random_string, percent_encode},
+    },
+    config::{DefaultValueConfig, OidcConfig, OidcProviderConfig},
+    database::DatabaseConnectionFactory,
+    feature::{
+        crypto::service::CryptoService,
+        oidc::{
+            model::{
+                IdTokenClaims, OidcAuthorizationRequest, OidcProviderMetadata, OidcTokenResponse,
+                UserIdentity,
+            },
+            repository::OidcRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+};
+use async_trait::async_trait;
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{Jwk, JwkSet},
+    Algorithm, DecodingKey, Header, Validation,
+};
+use reqwest::{
+    header::{ACCEPT, CONTENT_TYPE},
+    Client, Response,
+};
+use shaku::{Component, Interface};
+use sqlx::Acquire;
+use std::{error::Error, io, sync::Arc, time::Duration as StdDuration};
+use time::{Duration, OffsetDateTime};
+
+/// The path of the discovery document, relative to an identity provider's issuer identifier.
+const DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+/// The scope that must be requested for identity providers to return an id token.
+const OPENID_SCOPE: &str = "openid";
+
+/// The maximum amount of characters in a username.
+const MAXIMUM_USERNAME_LENGTH: usize = 32;
+
+/// The minimum amount of characters in a username.
+const MINIMUM_USERNAME_LENGTH: usize = 3;
+
+/// The amount of random characters that are appended to a username that is already in use.
+const USERNAME_SUFFIX_LENGTH: usize = 6;
+
+/// The amount of usernames that are tried when an account is created for a user.
+const USERNAME_ATTEMPTS: usize = 5;
+
+/// An oidc service trait.
+#[async_trait(?Send)]
+pub(crate) trait OidcService: Interface {
+    /// # Description
+    ///
+    /// Start a single sign-on login with an identity provider, using the authorization code flow
+    /// with pkce (RFC 7636).
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The name of the identity provider.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the login was started, the Ok variant will be returned with the secret state of the
+    /// login and the url the user must be sent to. The state must be kept by the client that
+    /// started the login, since it is needed to finish it.
+    /// - If the identity provider is not configured, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn begin_login(&self, provider: &String)
+        -> QueryResult<(String, String), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Finish a single sign-on login after the identity provider sent the user back, by exchanging
+    /// the authorization code for an id token and validating it. The account at the identity
+    /// provider is linked to the user it was linked to before, to the user with the same verified
+    /// email address, or to a new user if the identity provider allows it.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The name of the identity provider.
+    ///
+    /// `code` - The authorization code the identity provider sent the user back with.
+    ///
+    /// `state` - The secret state of the login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the login was successful, the Ok variant will be returned with the user that logged in.
+    /// - If the login is unknown, expired or was already finished, the code or id token was not
+    /// accepted, or the account at the identity provider can not be linked to a user, the NotFound
+    /// variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn complete_login(
+        &self,
+        provider: &String,
+        code: &String,
+        state: &String,
+    ) -> QueryResult<User, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Delete the authorization requests of logins that have expired.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of
+    /// authorization requests deleted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_expired_authorization_requests(&self) -> Result<u64, Box<dyn Error>>;
+}
+
+/// An OidcServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = OidcService)]
+pub(crate) struct OidcServiceImpl {
+    /// The crypto service that will be used to create and hash secrets.
+    #[shaku(inject)]
+    crypto_service: Arc<dyn CryptoService>,
+
+    /// The user service that will be used to query and create users.
+    #[shaku(inject)]
+    user_service: Arc<dyn UserService>,
+
+    /// The oidc repository that will be used to manage persistent single sign-on data.
+    #[shaku(inject)]
+    oidc_repository: Arc<dyn OidcRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The single sign-on configuration.
+    oidc_config: OidcConfig,
+
+    /// The default profile picture that will be assigned to users that are created.
+    default_profile_picture: String,
+
+    /// The http client that will be used to send requests to identity providers.
+    http_client: Client,
+}
+
+/// An OidcService implementation for the OidcServiceImpl struct.
+#[async_trait(?Send)]
+impl OidcService for OidcServiceImpl {
+    async fn begin_login(
+        &self,
+        provider: &String,
+    ) -> QueryResult<(String, String), Box<dyn Error>> {
+        // Make sure the identity provider is configured.
+        let provider: &OidcProviderConfig = match self.get_provider(provider) {
+            Some(provider) => provider,
+            None => return QueryResult::NotFound,
+        };
+
+        // Discover the identity provider's endpoints.
+        let metadata: OidcProviderMetadata = match self.discover(provider).await {
+            Ok(metadata) => metadata,
+            Err(error) => return QueryResult::Err(error),
+        };
+
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Create the state, code verifier and nonce of the login, and store the hash of the state.
+        let state: String = __self.crypto_service.generate_secret_token();
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let authorization_request: OidcAuthorizationRequest = OidcAuthorizationRequest {
+            id: 0,
+            provider: provider.name.clone(),
+            state_hash: __self.crypto_service.hash_secret_token(&state),
+            code_verifier: __self.crypto_service.generate_secret_token(),
+            nonce: __self.crypto_service.generate_secret_token(),
+            request_created_at: now,
+            expires_at: now + Duration::seconds(__self.oidc_config.authorization_lifetime as i64),
+        };
+        if let Err(error) = __self
+            .oidc_repository
+            .insert_authorization_request(&authorization_request, &mut context)
+            .await
+        {
+            return QueryResult::Err(Box::new(error));
+        }
+
+        // Create the url the user must be sent to.
+        let parameters: String = encode_parameters(&[
+            ("response_type", &String::from("code")),
+            ("client_id", &provider.client_id),
+            ("redirect_uri", &provider.redirect_uri),
+            ("scope", &create_scope(provider)),
+            ("state", &state),
+            ("nonce", &authorization_request.nonce),
+            (
+                "code_challenge",
+                &__self
+                    .crypto_service
+                    .create_code_challenge(&authorization_request.code_verifier),
+            ),
+            ("code_challenge_method", &String::from("S256")),
+        ]);
+        let separator: char = if metadata.authorization_endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        let authorization_url: String = format!(
+            "{}{}{}",
+            metadata.authorization_endpoint, separator, parameters
+        );
+
+        return QueryResult::Ok((state, authorization_url));
+    }
+
+    async fn complete_login(
+        &self,
+        provider: &String,
+        code: &String,
+        state: &String,
+    ) -> QueryResult<User, Box<dyn Error>> {
+        // Make sure the identity provider is configured.
+        let provider: &OidcProviderConfig = match self.get_provider(provider) {
+            Some(provider) => provider,
+            None => return QueryResult::NotFound,
+        };
+
+        // Use up the authorization request of the login, so that it can only be finished once.
+        let authorization_request: OidcAuthorizationRequest =
+            match self.consume_authorization_request(state).await {
+                QueryResult::Ok(authorization_request) => authorization_request,
+                QueryResult::NotFound => return QueryResult::NotFound,
+                QueryResult::Err(error) => return QueryResult::Err(error),
+            };
+
+        // Make sure the login was started with the same identity provider.
+        if authorization_request.provider != provider.name {
+            log::warn!(
+                "A login started with identity provider {} was finished with identity provider {}",
+                authorization_request.provider,
+                provider.name
+            );
+            return QueryResult::NotFound;
+        }
+
+        // Discover the identity provider's endpoints.
+        let metadata: OidcProviderMetadata = match self.discover(provider).await {
+            Ok(metadata) => metadata,
+            Err(error) => return QueryResult::Err(error),
+        };
+
+        // Exchange the authorization code for an id token.
+        let id_token: String = match self
+            .exchange_code(provider, &metadata, code, &authorization_request)
+            .await
+        {
+            QueryResult::Ok(id_token) => id_token,
+            QueryResult::NotFound => return QueryResult::NotFound,
+            QueryResult::Err(error) => return QueryResult::Err(error),
+        };
+
+        // Validate the id token.
+        let claims: IdTokenClaims = match self
+            .validate_id_token(provider, &metadata, &id_token, &authorization_request)
+            .await
+        {
+            QueryResult::Ok(claims) => claims,
+            QueryResult::NotFound => return QueryResult::NotFound,
+            QueryResult::Err(error) => return QueryResult::Err(error),
+        };
+
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Find the user the account at the identity provider belongs to.
+        let query_result = self
+            .link_user_with_context(provider, &claims, &mut context)
+            .await;
+
+        // If the user was found, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match query_result {
+            QueryResult::Ok(_) => context.commit_if_transaction().await,
+            QueryResult::NotFound => context.rollback_if_transaction().await,
+            QueryResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the query result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => query_result,
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn delete_expired_authorization_requests(&self) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the expired authorization requests.
+        return Ok(__self
+            .oidc_repository
+            .delete_expired_authorization_requests(&OffsetDateTime::now_utc(), &mut context)
+            .await?);
+    }
+}
+
+/// An implementation for the OidcServiceImpl struct.
+impl OidcServiceImpl {
+    /// # Description
+    ///
+    /// Create oidc service parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `oidc_config` - The single sign-on configuration.
+    ///
+    /// `default_value_config` - The default value configuration, which contains the profile picture
+    /// users that are created are assigned.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the http client was created, the Ok variant will be returned with the oidc service
+    /// parameters that were created.
+    /// - If an error occurs while creating the http client, the Err variant will be returned with
+    /// the error that occurred.
+    pub(crate) fn create_parameters(
+        oidc_config: OidcConfig,
+        default_value_config: &DefaultValueConfig,
+    ) -> Result<OidcServiceImplParameters, Box<dyn Error>> {
+        // Create the http client.
+        let http_client: Client = Client::builder()
+            .user_agent(concat!("EasyTracker/", env!("CARGO_PKG_VERSION")))
+            .timeout(StdDuration::from_secs(oidc_config.timeout))
+            .build()?;
+
+        return Ok(OidcServiceImplParameters {
+            oidc_config,
+            default_profile_picture: default_value_config.user_profile_picture.clone(),
+            http_client,
+        });
+    }
+
+    /// # Description
+    ///
+    /// Get the configuration of an identity provider by its name.
+    ///
+    /// # Arguments
+    ///
+    /// `name` - The name of the identity provider.
+    ///
+    /// # Returns
+    ///
+    /// An option that will contain the configuration of the identity provider if it was found.
+    fn get_provider(&self, name: &String) -> Option<&OidcProviderConfig> {
+        return self
+            .oidc_config
+            .providers
+            .iter()
+            .find(|provider| &provider.name == name);
+    }
+
+    /// # Description
+    ///
+    /// Query the authorization request of a login by its state, and delete it so that the login
+    /// can only be finished once.
+    ///
+    /// # Arguments
+    ///
+    /// `state` - The secret state of the login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the authorization request was found, had not expired and was deleted by this call, the
+    /// Ok variant will be returned with it.
+    /// - Otherwise, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn consume_authorization_request(
+        &self,
+        state: &String,
+    ) -> QueryResult<OidcAuthorizationRequest, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the authorization request by the hash of its state.
+        let state_hash: String = self.crypto_service.hash_secret_token(state);
+        let authorization_request: OidcAuthorizationRequest = match self
+            .oidc_repository
+            .get_authorization_request_by_state_hash(&state_hash, &mut context)
+            .await
+        {
+            Ok(Some(authorization_request)) => authorization_request,
+            Ok(None) => return QueryResult::NotFound,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Delete the authorization request. If it was not deleted, another request already used it.
+        match self
+            .oidc_repository
+            .delete_authorization_request(&authorization_request.id, &mut context)
+            .await
+        {
+            Ok(0) => return QueryResult::NotFound,
+            Ok(_) => {}
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Make sure the authorization request has not expired.
+        if authorization_request.expires_at <= OffsetDateTime::now_utc() {
+            return QueryResult::NotFound;
+        }
+
+        return QueryResult::Ok(authorization_request);
+    }
+
+    /// # Description
+    ///
+    /// Fetch an identity provider's discovery document, and make sure it belongs to the identity
+    /// provider's issuer identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The configuration of the identity provider.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the discovery document was fetched and belongs to the identity provider, the Ok variant
+    /// will be returned with it.
+    /// - Otherwise, the Err variant will be returned with the error that occurred.
+    async fn discover(
+        &self,
+        provider: &OidcProviderConfig,
+    ) -> Result<OidcProviderMetadata, Box<dyn Error>> {
+        // Fetch the discovery document.
+        let url: String = format!(
+            "{}{}",
+            provider.issuer.trim_end_matches('/'),
+            DISCOVERY_PATH
+        );
+        let response: Response = self
+            .http_client
+            .get(&url)
+            .header(ACCEPT, "application/json")
+            .send()
+            .await?
+            .error_for_status()?;
+        let metadata: OidcProviderMetadata = serde_json::from_str(&response.text().await?)?;
+
+        // Make sure the discovery document belongs to the issuer identifier.
+        if metadata.issuer != provider.issuer {
+            return Err(Box::new(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "The discovery document of identity provider {} belongs to issuer {}",
+                    provider.name, metadata.issuer
+                ),
+            )));
+        }
+
+        return Ok(metadata);
+    }
+
+    /// # Description
+    ///
+    /// Exchange an authorization code for an id token at an identity provider's token endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The configuration of the identity provider.
+    ///
+    /// `metadata` - The identity provider's discovery document.
+    ///
+    /// `code` - The authorization code.
+    ///
+    /// `authorization_request` - The authorization request of the login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the authorization code was exchanged, the Ok variant will be returned with the id
+    /// token.
+    /// - If the identity provider did not accept the authorization code, the NotFound variant will
+    /// be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn exchange_code(
+        &self,
+        provider: &OidcProviderConfig,
+        metadata: &OidcProviderMetadata,
+        code: &String,
+        authorization_request: &OidcAuthorizationRequest,
+    ) -> QueryResult<String, Box<dyn Error>> {
+        // Create the token request. Confidential clients authenticate with their client secret.
+        let grant_type: String = String::from("authorization_code");
+        let mut parameters: Vec<(&str, &String)> = vec![
+            ("grant_type", &grant_type),
+            ("code", code),
+            ("redirect_uri", &provider.redirect_uri),
+            ("client_id", &provider.client_id),
+            ("code_verifier", &authorization_request.code_verifier),
+        ];
+        if let Some(client_secret) = &provider.client_secret {
+            parameters.push(("client_secret", client_secret));
+        }
+
+        // Send the token request.
+        let response: Response = match self
+            .http_client
+            .post(&metadata.token_endpoint)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .header(ACCEPT, "application/json")
+            .body(encode_parameters(&parameters))
+            .send()
+            .await
+        {
+            Ok(response) => response,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Make sure the identity provider accepted the authorization code.
+        if !response.status().is_success() {
+            log::warn!(
+                "Identity provider {} rejected an authorization code with status code {}",
+                provider.name,
+                response.status().as_u16()
+            );
+            return QueryResult::NotFound;
+        }
+
+        // Read the id token from the response.
+        let body: String = match response.text().await {
+            Ok(body) => body,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+        return match serde_json::from_str::<OidcTokenResponse>(&body) {
+            Ok(token_response) => QueryResult::Ok(token_response.id_token),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    /// # Description
+    ///
+    /// Validate an id token with the keys in an identity provider's json web key set, and make sure
+    /// it was issued to the server for the login.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The configuration of the identity provider.
+    ///
+    /// `metadata` - The identity provider's discovery document.
+    ///
+    /// `id_token` - The id token.
+    ///
+    /// `authorization_request` - The authorization request of the login.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the id token is valid, the Ok variant will be returned with its claims.
+    /// - If the id token is not valid, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn validate_id_token(
+        &self,
+        provider: &OidcProviderConfig,
+        metadata: &OidcProviderMetadata,
+        id_token: &String,
+        authorization_request: &OidcAuthorizationRequest,
+    ) -> QueryResult<IdTokenClaims, Box<dyn Error>> {
+        // Decode the header of the id token. Only asymmetric algorithms are accepted, since the
+        // keys are public.
+        let header: Header = match decode_header(id_token) {
+            Ok(header) => header,
+            Err(error) => {
+                log::warn!(
+                    "Identity provider {} returned an invalid id token: {}",
+                    provider.name,
+                    error
+                );
+                return QueryResult::NotFound;
+            }
+        };
+        if matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            log::warn!(
+                "Identity provider {} returned an id token signed with a symmetric algorithm",
+                provider.name
+            );
+            return QueryResult::NotFound;
+        }
+
+        // Fetch the json web key set.
+        let key_set: JwkSet = match self.fetch_key_set(metadata).await {
+            Ok(key_set) => key_set,
+            Err(error) => return QueryResult::Err(error),
+        };
+
+        // Find the key the id token was signed with. If the id token does not name its key, the key
+        // set must only contain one key.
+        let jwk: &Jwk = match (&header.kid, key_set.keys.as_slice()) {
+            (Some(kid), _) => match key_set.find(kid) {
+                Some(jwk) => jwk,
+                None => {
+                    log::warn!(
+                        "Identity provider {} returned an id token signed with unknown key {}",
+                        provider.name,
+                        kid
+                    );
+                    return QueryResult::NotFound;
+                }
+            },
+            (None, [jwk]) => jwk,
+            (None, _) => {
+                log::warn!(
+                    "Identity provider {} returned an id token that does not name its key",
+                    provider.name
+                );
+                return QueryResult::NotFound;
+            }
+        };
+        let key: DecodingKey = match DecodingKey::from_jwk(jwk) {
+            Ok(key) => key,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Validate the signature, expiration, issuer and audience of the id token.
+        let mut validation: Validation = Validation::new(header.alg);
+        validation.set_issuer(&[&provider.issuer]);
+        validation.set_audience(&[&provider.client_id]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        let claims: IdTokenClaims = match decode::<IdTokenClaims>(id_token, &key, &validation) {
+            Ok(token_data) => token_data.claims,
+            Err(error) => {
+                log::warn!(
+                    "Identity provider {} returned an invalid id token: {}",
+                    provider.name,
+                    error
+                );
+                return QueryResult::NotFound;
+            }
+        };
+
+        // Make sure the id token was issued for the login, so that it can not be replayed.
+        if claims.nonce.as_ref() != Some(&authorization_request.nonce) {
+            log::warn!(
+                "Identity provider {} returned an id token with the wrong nonce",
+                provider.name
+            );
+            return QueryResult::NotFound;
+        }
+
+        return QueryResult::Ok(claims);
+    }
+
+    /// # Description
+    ///
+    /// Fetch an identity provider's json web key set.
+    ///
+    /// # Arguments
+    ///
+    /// `metadata` - The identity provider's discovery document.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the json web key set was fetched, the Ok variant will be returned with it.
+    /// - Otherwise, the Err variant will be returned with the error that occurred.
+    async fn fetch_key_set(
+        &self,
+        metadata: &OidcProviderMetadata,
+    ) -> Result<JwkSet, Box<dyn Error>> {
+        let response: Response = self
+            .http_client
+            .get(&metadata.jwks_uri)
+            .header(ACCEPT, "application/json")
+            .send()
+            .await?
+            .error_for_status()?;
+        return Ok(serde_json::from_str(&response.text().await?)?);
+    }
+
+    /// # Description
+    ///
+    /// Find the user an account at an identity provider belongs to in the context specified. If
+    /// the account has not been linked to a user yet, it is linked to the user with the same
+    /// email address if both the identity provider and the user have verified it, or to a new user
+    /// if the identity provider allows it.
+    ///
+    /// # Arguments
+    ///
+    /// `provider` - The configuration of the identity provider.
+    ///
+    /// `claims` - The claims of the id token.
+    ///
+    /// `context` - The context the user will be found in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the user was found or created, the Ok variant will be returned with them.
+    /// - If the account can not be linked to a user, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn link_user_with_context(
+        &self,
+        provider: &OidcProviderConfig,
+        claims: &IdTokenClaims,
+        context: &mut QueryContext<'_>,
+    ) -> QueryResult<User, Box<dyn Error>> {
+        // If the account was linked before, return the user it was linked to.
+        match self
+            .oidc_repository
+            .get_identity(&provider.name, &claims.sub, context)
+            .await
+        {
+            Ok(Some(identity)) => {
+                return self
+                    .user_service
+                    .get_by_id_with_context(&identity.user_id, context)
+                    .await
+            }
+            Ok(None) => {}
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the identity provider has verified the account's email address, link the account to
+        // the user with the same email address. The user must have verified it as well, otherwise
+        // anyone could claim their account by registering the address at the identity provider.
+        let email: Option<&String> = claims.email.as_ref();
+        let email_is_verified: bool = claims.email_verified.unwrap_or(false);
+        if let (Some(email), true) = (email, email_is_verified) {
+            match self
+                .user_service
+                .get_by_email_with_context(email, context)
+                .await
+            {
+                QueryResult::Ok(user) if user.email_is_verified => {
+                    return match self.insert_identity(&user, provider, claims, context).await {
+                        Ok(()) => QueryResult::Ok(user),
+                        Err(error) => QueryResult::Err(error),
+                    };
+                }
+                QueryResult::Ok(user) => {
+                    log::warn!(
+                        "Identity provider {} returned the unverified email address of user {}",
+                        provider.name,
+                        user.id
+                    );
+                    return QueryResult::NotFound;
+                }
+                QueryResult::NotFound => {}
+                QueryResult::Err(error) => return QueryResult::Err(error),
+            };
+        }
+
+        // Otherwise, create a new user if the identity provider allows it.
+        let email: &String = match (provider.allow_user_creation, email) {
+            (true, Some(email)) => email,
+            _ => return QueryResult::NotFound,
+        };
+        let base_username: String = create_base_username(claims, email);
+        for attempt in 0..USERNAME_ATTEMPTS {
+            // Use the username the user prefers, unless it is too short or in use.
+            let username: String = if attempt == 0 && base_username.len() >= MINIMUM_USERNAME_LENGTH
+            {
+                base_username.clone()
+            } else {
+                format!(
+                    "{}-{}",
+                    base_username,
+                    generate_random_string(USERNAME_SUFFIX_LENGTH).to_lowercase()
+                )
+            };
+
+            // Create the user. They can only login with the identity provider until they reset
+            // their password.
+            let user: User = User {
+                profile_picture_url: self.default_profile_picture.clone(),
+                username,
+                password: self.crypto_service.generate_secret_token(),
+                email: email.clone(),
+                email_is_verified,
+                ..Default::default()
+            };
+            match self.user_service.insert_with_context(&user, context).await {
+                InsertionResult::Ok(user) => {
+                    return match self.insert_identity(&user, provider, claims, context).await {
+                        Ok(()) => QueryResult::Ok(user),
+                        Err(error) => QueryResult::Err(error),
+                    };
+                }
+                InsertionResult::Invalid(errors)
+                    if errors
+                        .field_errors()
+                        .keys()
+                        .all(|field| *field == "username") =>
+                {
+                    continue
+                }
+                InsertionResult::Invalid(errors) => {
+                    log::warn!(
+                        "A user could not be created for identity provider {}: {}",
+                        provider.name,
+                        errors
+                    );
+                    return QueryResult::NotFound;
+                }
+                InsertionResult::Err(error) => return QueryResult::Err(error),
+            };
+        }
+
+        log::warn!(
+            "A username could not be found for a user of identity provider {}",
+            provider.name
+        );
+        return QueryResult::NotFound;
+    }
+
+    /// # Description
+    ///
+    /// Link an account at an identity provider to a user in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user.
+    ///
+    /// `provider` - The configuration of the identity provider.
+    ///
+    /// `claims` - The claims of the id token.
+    ///
+    /// `context` - The context the account will be linked in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the account was linked, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_identity(
+        &self,
+        user: &User,
+        provider: &OidcProviderConfig,
+        claims: &IdTokenClaims,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        let identity: UserIdentity = UserIdentity {
+            id: 0,
+            user_id: user.id,
+            provider: provider.name.clone(),
+            subject: claims.sub.clone(),
+            identity_created_at: OffsetDateTime::now_utc(),
+        };
+        self.oidc_repository
+            .insert_identity(&identity, context)
+            .await?;
+        return Ok(());
+    }
+}
+
+/// # Description
+///
+/// Create the scope that is requested from an identity provider, which always includes the openid
+/// scope.
+///
+/// # Arguments
+///
+/// `provider` - The configuration of the identity provider.
+///
+/// # Returns
+///
+/// The scope that was created.
+fn create_scope(provider: &OidcProviderConfig) -> String {
+    let mut scopes: Vec<&str> = provider.scopes.iter().map(String::as_str).collect();
+    if !scopes.contains(&OPENID_SCOPE) {
+        scopes.insert(0, OPENID_SCOPE);
+    }
+    return scopes.join(" ");
+}
+
+/// # Description
+///
+/// Encode parameters as a query string or form body.
+///
+/// # Arguments
+///
+/// `parameters` - The names and values of the parameters.
+///
+/// # Returns
+///
+/// The encoded parameters.
+fn encode_parameters(parameters: &[(&str, &String)]) -> String {
+    return parameters
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, percent_encode(value)))
+        .collect::<Vec<String>>()
+        .join("&");
+}
+
+/// # Description
+///
+/// Create the username a user that logged in with an identity provider prefers, from their
+/// preferred username or the local part of their email address. Characters that are not
+/// alphanumeric, '.', '-' or '_' are removed, and the username is shortened so that a suffix can
+/// be appended to it.
+///
+/// # Arguments
+///
+/// `claims` - The claims of the id token.
+///
+/// `email` - The user's email address.
+///
+/// # Returns
+///
+/// The username that was created.
+fn create_base_username(claims: &IdTokenClaims, email: &String) -> String {
+    let preferred_username: &str = match &claims.preferred_username {
+        Some(preferred_username) => preferred_username,
+        None => email.split('@').next().unwrap_or_default(),
+    };
+    return preferred_username
+        .chars()
+        .filter(|character| {
+            character.is_ascii_alphanumeric() || matches!(character, '.' | '-' | '_')
+        })
+        .take(MAXIMUM_USERNAME_LENGTH - USERNAME_SUFFIX_LENGTH - 1)
+        .collect();
+}
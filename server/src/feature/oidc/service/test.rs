@@ -0,0 +1,754 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult},
+        utility::generate_random_string,
+    },
+    config::{Config, JwtKeyConfig, OidcProviderConfig},
+    feature::{
+        oidc::service::OidcService,
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{web, App, HttpResponse, HttpServer};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use openssl::{base64, rsa::Rsa, sha::sha256};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fs,
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+use time::{Duration, OffsetDateTime};
+
+/// The name the stand-in identity provider is configured with.
+const TEST_PROVIDER: &str = "stand-in";
+
+/// The client id the server is registered with at the stand-in identity provider.
+const TEST_CLIENT_ID: &str = "easytracker";
+
+/// The id of the key the stand-in identity provider signs id tokens with.
+const TEST_KEY_ID: &str = "test";
+
+/// The authorizations of the stand-in identity provider, which map each authorization code to the
+/// code challenge of the login it was issued for and the id token it is exchanged for.
+type StandInAuthorizations = web::Data<Mutex<HashMap<String, (String, String)>>>;
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Arguments
+///
+/// `email_is_verified` - If the user has verified their email address.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user(email_is_verified: bool) -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `email_is_verified` - If the user has verified their email address.
+///
+/// `user_service` - The user service the user will be inserted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(email_is_verified: bool, user_service: &Arc<dyn UserService>) -> User {
+    return match user_service
+        .insert(&create_test_user(email_is_verified))
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `user_service` - The user service the user will be deleted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user.
+async fn delete_test_user(user: &User, user_service: &Arc<dyn UserService>) {
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    return Config::load_config(String::from("config.json")).expect("Failed to load config");
+}
+
+/// # Description
+///
+/// A utility function to load the configuration of the server's active jwt key, which the stand-in
+/// identity provider reuses to sign its id tokens.
+///
+/// # Panics
+///
+/// This function will panic if the configuration file could not be loaded, or the active key is not
+/// configured.
+///
+/// # Returns
+///
+/// The configuration of the server's active jwt key.
+fn load_jwt_key_config() -> JwtKeyConfig {
+    let config: Config = load_config();
+    return config
+        .jwt
+        .keys
+        .into_iter()
+        .find(|key_config| key_config.id == config.jwt.active_key_id)
+        .expect("The active jwt key is not configured");
+}
+
+/// # Description
+///
+/// Create a dependency injector, with the stand-in identity provider configured.
+///
+/// # Arguments
+///
+/// `issuer` - The issuer identifier of the stand-in identity provider.
+///
+/// `allow_user_creation` - If accounts are created for users that login with the stand-in identity
+/// provider and do not have one yet.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector(
+    issuer: &String,
+    allow_user_creation: bool,
+) -> DependencyInjector {
+    // Load the config, and configure the stand-in identity provider.
+    let mut config: Config = load_config();
+    config.oidc.providers = vec![OidcProviderConfig {
+        name: String::from(TEST_PROVIDER),
+        display_name: String::from("Stand-In"),
+        issuer: issuer.clone(),
+        client_id: String::from(TEST_CLIENT_ID),
+        client_secret: None,
+        redirect_uri: String::from("https://example.com/login/oidc"),
+        scopes: vec![String::from("email"), String::from("profile")],
+        allow_user_creation,
+    }];
+
+    return DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to load dependency injector");
+}
+
+/// # Description
+///
+/// Encode bytes as base64url without padding.
+///
+/// # Arguments
+///
+/// `bytes` - The bytes to encode.
+///
+/// # Returns
+///
+/// The encoded bytes.
+fn encode_base64_url(bytes: &[u8]) -> String {
+    return base64::encode_block(bytes)
+        .trim_end_matches('=')
+        .replace('+', "-")
+        .replace('/', "_");
+}
+
+/// # Description
+///
+/// Start a local stand-in identity provider that the server can login with. It signs id tokens
+/// with the server's jwt key.
+///
+/// The identity provider responds to the following routes:
+/// - `/.well-known/openid-configuration` - Responds with the identity provider's discovery
+/// document.
+/// - `/jwks` - Responds with the identity provider's json web key set.
+/// - `/token` - Responds with the id token of an authorization code, if the code verifier matches
+/// the code challenge it was issued for, otherwise responds with a 400 status code. Authorization
+/// codes can only be exchanged once.
+///
+/// # Panics
+///
+/// This function will panic if the identity provider could not be bound to a local port, or the
+/// server's jwt public key could not be loaded.
+///
+/// # Returns
+///
+/// The issuer identifier of the identity provider that was started, and its authorizations.
+fn start_stand_in_provider() -> (String, StandInAuthorizations) {
+    // Bind a listener to a random local port.
+    let listener: TcpListener =
+        TcpListener::bind("127.0.0.1:0").expect("Failed to bind stand-in identity provider");
+    let port: u16 = listener
+        .local_addr()
+        .expect("Failed to get stand-in identity provider address")
+        .port();
+    let issuer: String = format!("http://127.0.0.1:{}", port);
+
+    // Create the json web key set from the server's jwt public key.
+    let public_key = Rsa::public_key_from_pem(
+        &fs::read(load_jwt_key_config().public_key_path).expect("Failed to read jwt public key"),
+    )
+    .expect("Failed to parse jwt public key");
+    let key_set: Value = json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": TEST_KEY_ID,
+            "n": encode_base64_url(&public_key.n().to_vec()),
+            "e": encode_base64_url(&public_key.e().to_vec()),
+        }]
+    });
+    let discovery_document: Value = json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{}/authorize", issuer),
+        "token_endpoint": format!("{}/token", issuer),
+        "jwks_uri": format!("{}/jwks", issuer),
+    });
+
+    // Create and start the identity provider.
+    let authorizations: StandInAuthorizations = web::Data::new(Mutex::new(HashMap::new()));
+    let server_authorizations: StandInAuthorizations = authorizations.clone();
+    let server = HttpServer::new(move || {
+        let discovery_document: Value = discovery_document.clone();
+        let key_set: Value = key_set.clone();
+        App::new()
+            .app_data(server_authorizations.clone())
+            .route(
+                "/.well-known/openid-configuration",
+                web::get().to(move || {
+                    let discovery_document: Value = discovery_document.clone();
+                    async move { HttpResponse::Ok().json(discovery_document) }
+                }),
+            )
+            .route(
+                "/jwks",
+                web::get().to(move || {
+                    let key_set: Value = key_set.clone();
+                    async move { HttpResponse::Ok().json(key_set) }
+                }),
+            )
+            .route(
+                "/token",
+                web::post().to(
+                    |authorizations: StandInAuthorizations,
+                     form: web::Form<HashMap<String, String>>| async move {
+                        let get_parameter =
+                            |name: &str| form.get(name).cloned().unwrap_or_default();
+                        let authorization: Option<(String, String)> = authorizations
+                            .lock()
+                            .expect("Failed to lock authorizations")
+                            .remove(&get_parameter("code"));
+
+                        if let Some((code_challenge, id_token)) = authorization {
+                            let code_verifier: String = get_parameter("code_verifier");
+                            if get_parameter("grant_type") == "authorization_code"
+                                && get_parameter("client_id") == TEST_CLIENT_ID
+                                && encode_base64_url(&sha256(code_verifier.as_bytes()))
+                                    == code_challenge
+                            {
+                                return HttpResponse::Ok().json(json!({
+                                    "access_token": generate_random_string(32),
+                                    "token_type": "Bearer",
+                                    "id_token": id_token,
+                                }));
+                            }
+                        }
+                        return HttpResponse::BadRequest()
+                            .json(json!({ "error": "invalid_grant" }));
+                    },
+                ),
+            )
+    })
+    .workers(1)
+    .listen(listener)
+    .expect("Failed to start stand-in identity provider")
+    .run();
+    actix_web::rt::spawn(server);
+
+    // Return the identity provider's issuer identifier and authorizations.
+    return (issuer, authorizations);
+}
+
+/// # Description
+///
+/// Get the value of a query parameter from a url.
+///
+/// # Arguments
+///
+/// `url` - The url.
+///
+/// `name` - The name of the query parameter.
+///
+/// # Panics
+///
+/// This function will panic if the url does not contain the query parameter.
+///
+/// # Returns
+///
+/// The value of the query parameter, which is not decoded.
+fn get_query_parameter(url: &String, name: &str) -> String {
+    return url
+        .split_once('?')
+        .expect("The url does not have a query")
+        .1
+        .split('&')
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(parameter_name, _)| *parameter_name == name)
+        .map(|(_, value)| String::from(value))
+        .expect("The url does not contain the query parameter");
+}
+
+/// # Description
+///
+/// Create the claims of an id token the stand-in identity provider issues.
+///
+/// # Arguments
+///
+/// `issuer` - The issuer identifier of the stand-in identity provider.
+///
+/// `nonce` - The nonce of the login.
+///
+/// `subject` - The subject identifier of the user at the stand-in identity provider.
+///
+/// `email` - The user's email address.
+///
+/// `email_verified` - If the stand-in identity provider has verified the user's email address.
+///
+/// # Returns
+///
+/// The claims that were created.
+fn create_claims(
+    issuer: &String,
+    nonce: &String,
+    subject: &String,
+    email: &String,
+    email_verified: bool,
+) -> Value {
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    return json!({
+        "iss": issuer,
+        "aud": TEST_CLIENT_ID,
+        "sub": subject,
+        "iat": now.unix_timestamp(),
+        "exp": (now + Duration::MINUTE).unix_timestamp(),
+        "nonce": nonce,
+        "email": email,
+        "email_verified": email_verified,
+        "preferred_username": generate_random_string(8),
+    });
+}
+
+/// # Description
+///
+/// Sign an id token with the server's jwt private key, as the stand-in identity provider.
+///
+/// # Arguments
+///
+/// `claims` - The claims of the id token.
+///
+/// # Panics
+///
+/// This function will panic if the server's jwt private key could not be loaded, or the id token
+/// could not be signed.
+///
+/// # Returns
+///
+/// The id token that was signed.
+fn sign_id_token(claims: &Value) -> String {
+    let encoding_key: EncodingKey = EncodingKey::from_rsa_pem(
+        &fs::read(
+            load_jwt_key_config()
+                .private_key_path
+                .expect("The active jwt key does not have a private key"),
+        )
+        .expect("Failed to read jwt private key"),
+    )
+    .expect("Failed to parse jwt private key");
+    let mut header: Header = Header::new(Algorithm::RS256);
+    header.kid = Some(String::from(TEST_KEY_ID));
+    return encode(&header, claims, &encoding_key).expect("Failed to sign id token");
+}
+
+/// # Description
+///
+/// Login with the stand-in identity provider, which issues an authorization code for the id token
+/// that is created from the login's nonce.
+///
+/// # Arguments
+///
+/// `oidc_service` - The oidc service the login will be started and finished with.
+///
+/// `authorizations` - The authorizations of the stand-in identity provider.
+///
+/// `create_id_token` - A function that creates the id token from the login's nonce.
+///
+/// # Panics
+///
+/// This function will panic if the login could not be started.
+///
+/// # Returns
+///
+/// The result of finishing the login, and the state it was finished with.
+async fn login(
+    oidc_service: &Arc<dyn OidcService>,
+    authorizations: &StandInAuthorizations,
+    create_id_token: impl FnOnce(&String) -> String,
+) -> (QueryResult<User, Box<dyn Error>>, String) {
+    // Start the login, and read what the user is sent to the identity provider with.
+    let (state, authorization_url) =
+        match oidc_service.begin_login(&String::from(TEST_PROVIDER)).await {
+            QueryResult::Ok(result) => result,
+            QueryResult::NotFound => panic!("Failed to begin login: Provider not found"),
+            QueryResult::Err(error) => panic!("Failed to begin login: {}", error),
+        };
+    assert_eq!(state, get_query_parameter(&authorization_url, "state"));
+    assert_eq!(
+        "S256",
+        get_query_parameter(&authorization_url, "code_challenge_method")
+    );
+    let nonce: String = get_query_parameter(&authorization_url, "nonce");
+    let code_challenge: String = get_query_parameter(&authorization_url, "code_challenge");
+
+    // Issue an authorization code for the id token at the identity provider.
+    let id_token: String = create_id_token(&nonce);
+    let code: String = generate_random_string(32);
+    authorizations
+        .lock()
+        .expect("Failed to lock authorizations")
+        .insert(code.clone(), (code_challenge, id_token));
+
+    // Finish the login.
+    let query_result = oidc_service
+        .complete_login(&String::from(TEST_PROVIDER), &code, &state)
+        .await;
+    return (query_result, state);
+}
+
+/// # Description
+///
+/// Test logging in with an identity provider for the first time, and make sure a user is created
+/// and that they are found again when they login with a different email address.
+#[actix_web::test]
+async fn users_are_created_on_their_first_login() {
+    // Start a stand-in identity provider, and create a dependency injector.
+    let (issuer, authorizations) = start_stand_in_provider();
+    let injector: DependencyInjector = create_dependency_injector(&issuer, true).await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let oidc_service: Arc<dyn OidcService> = injector.resolve();
+
+    // Login for the first time, and make sure a user is created with the verified email address.
+    let subject: String = generate_random_string(32);
+    let email: String = format!(
+        "{}@{}.com",
+        generate_random_string(8),
+        generate_random_string(8)
+    );
+    let user: User = match login(&oidc_service, &authorizations, |nonce| {
+        sign_id_token(&create_claims(&issuer, nonce, &subject, &email, true))
+    })
+    .await
+    {
+        (QueryResult::Ok(user), _) => user,
+        (QueryResult::NotFound, _) => panic!("The login was rejected"),
+        (QueryResult::Err(error), _) => panic!("Failed to login: {}", error),
+    };
+    assert_eq!(email, user.email);
+    assert!(user.email_is_verified);
+
+    // Login again with a different email address, and make sure the same user is found.
+    let changed_email: String = format!(
+        "{}@{}.com",
+        generate_random_string(8),
+        generate_random_string(8)
+    );
+    match login(&oidc_service, &authorizations, |nonce| {
+        sign_id_token(&create_claims(
+            &issuer,
+            nonce,
+            &subject,
+            &changed_email,
+            true,
+        ))
+    })
+    .await
+    {
+        (QueryResult::Ok(linked_user), _) => assert_eq!(user.id, linked_user.id),
+        (QueryResult::NotFound, _) => panic!("The second login was rejected"),
+        (QueryResult::Err(error), _) => panic!("Failed to login: {}", error),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
+
+/// # Description
+///
+/// Test logging in with an identity provider that does not create users, and make sure accounts
+/// are only linked to users whose email address both the identity provider and the user have
+/// verified.
+#[actix_web::test]
+async fn accounts_are_linked_by_verified_email_address() {
+    // Start a stand-in identity provider, and create a dependency injector.
+    let (issuer, authorizations) = start_stand_in_provider();
+    let injector: DependencyInjector = create_dependency_injector(&issuer, false).await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let oidc_service: Arc<dyn OidcService> = injector.resolve();
+
+    // Insert a test user that has verified their email address, and one that has not.
+    let verified_user: User = insert_test_user(true, &user_service).await;
+    let unverified_user: User = insert_test_user(false, &user_service).await;
+
+    // Make sure accounts are not linked by an email address the identity provider has not
+    // verified.
+    match login(&oidc_service, &authorizations, |nonce| {
+        let subject: String = generate_random_string(32);
+        sign_id_token(&create_claims(
+            &issuer,
+            nonce,
+            &subject,
+            &verified_user.email,
+            false,
+        ))
+    })
+    .await
+    {
+        (QueryResult::NotFound, _) => {}
+        _ => panic!("An account was linked by an unverified email address"),
+    };
+
+    // Make sure accounts are not linked to users that have not verified their email address.
+    match login(&oidc_service, &authorizations, |nonce| {
+        let subject: String = generate_random_string(32);
+        sign_id_token(&create_claims(
+            &issuer,
+            nonce,
+            &subject,
+            &unverified_user.email,
+            true,
+        ))
+    })
+    .await
+    {
+        (QueryResult::NotFound, _) => {}
+        _ => panic!("An account was linked to a user with an unverified email address"),
+    };
+
+    // Make sure accounts are linked by a verified email address.
+    match login(&oidc_service, &authorizations, |nonce| {
+        let subject: String = generate_random_string(32);
+        sign_id_token(&create_claims(
+            &issuer,
+            nonce,
+            &subject,
+            &verified_user.email,
+            true,
+        ))
+    })
+    .await
+    {
+        (QueryResult::Ok(user), _) => assert_eq!(verified_user.id, user.id),
+        (QueryResult::NotFound, _) => panic!("The login was rejected"),
+        (QueryResult::Err(error), _) => panic!("Failed to login: {}", error),
+    };
+
+    // Delete the test users.
+    delete_test_user(&verified_user, &user_service).await;
+    delete_test_user(&unverified_user, &user_service).await;
+}
+
+/// # Description
+///
+/// Test finishing logins with tampered id tokens, wrong code verifiers and used states, and make
+/// sure they are rejected.
+#[actix_web::test]
+async fn tampered_and_replayed_logins_are_rejected() {
+    // Start a stand-in identity provider, and create a dependency injector.
+    let (issuer, authorizations) = start_stand_in_provider();
+    let injector: DependencyInjector = create_dependency_injector(&issuer, true).await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let oidc_service: Arc<dyn OidcService> = injector.resolve();
+
+    // Make sure an unknown state is rejected.
+    match oidc_service
+        .complete_login(
+            &String::from(TEST_PROVIDER),
+            &generate_random_string(32),
+            &generate_random_string(48),
+        )
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("A login with an unknown state was finished"),
+    };
+
+    // Make sure id tokens issued for another login, another client or another issuer, and id tokens
+    // that have expired or were tampered with are rejected.
+    let subject: String = generate_random_string(32);
+    let email: String = format!(
+        "{}@{}.com",
+        generate_random_string(8),
+        generate_random_string(8)
+    );
+    let tamper_functions: Vec<Box<dyn Fn(&String) -> String + '_>> = vec![
+        Box::new(|_| {
+            let nonce: String = generate_random_string(48);
+            sign_id_token(&create_claims(&issuer, &nonce, &subject, &email, true))
+        }),
+        Box::new(|nonce| {
+            let mut claims: Value = create_claims(&issuer, nonce, &subject, &email, true);
+            claims["aud"] = json!(generate_random_string(8));
+            sign_id_token(&claims)
+        }),
+        Box::new(|nonce| {
+            let mut claims: Value = create_claims(&issuer, nonce, &subject, &email, true);
+            claims["iss"] = json!("https://example.com");
+            sign_id_token(&claims)
+        }),
+        Box::new(|nonce| {
+            let mut claims: Value = create_claims(&issuer, nonce, &subject, &email, true);
+            claims["exp"] = json!((OffsetDateTime::now_utc() - Duration::HOUR).unix_timestamp());
+            sign_id_token(&claims)
+        }),
+        Box::new(|nonce| {
+            let id_token: String =
+                sign_id_token(&create_claims(&issuer, nonce, &subject, &email, true));
+            let mut parts: Vec<String> = id_token.split('.').map(String::from).collect();
+            let mut claims: Value = create_claims(&issuer, nonce, &subject, &email, true);
+            claims["sub"] = json!(generate_random_string(32));
+            parts[1] = encode_base64_url(claims.to_string().as_bytes());
+            parts.join(".")
+        }),
+    ];
+    for tamper_function in tamper_functions {
+        match login(&oidc_service, &authorizations, tamper_function).await {
+            (QueryResult::NotFound, _) => {}
+            _ => panic!("A tampered id token was accepted"),
+        };
+    }
+
+    // Make sure an authorization code is rejected if the code verifier does not match the code
+    // challenge it was issued for.
+    let (state, authorization_url) =
+        match oidc_service.begin_login(&String::from(TEST_PROVIDER)).await {
+            QueryResult::Ok(result) => result,
+            QueryResult::NotFound => panic!("Failed to begin login: Provider not found"),
+            QueryResult::Err(error) => panic!("Failed to begin login: {}", error),
+        };
+    let nonce: String = get_query_parameter(&authorization_url, "nonce");
+    let code: String = generate_random_string(32);
+    authorizations
+        .lock()
+        .expect("Failed to lock authorizations")
+        .insert(
+            code.clone(),
+            (
+                encode_base64_url(&sha256(generate_random_string(48).as_bytes())),
+                sign_id_token(&create_claims(&issuer, &nonce, &subject, &email, true)),
+            ),
+        );
+    match oidc_service
+        .complete_login(&String::from(TEST_PROVIDER), &code, &state)
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("An authorization code was exchanged with the wrong code verifier"),
+    };
+
+    // Login, and make sure the login can not be finished again with the same state.
+    let (query_result, state) = login(&oidc_service, &authorizations, |nonce| {
+        sign_id_token(&create_claims(&issuer, nonce, &subject, &email, true))
+    })
+    .await;
+    let user: User = match query_result {
+        QueryResult::Ok(user) => user,
+        QueryResult::NotFound => panic!("The login was rejected"),
+        QueryResult::Err(error) => panic!("Failed to login: {}", error),
+    };
+    match oidc_service
+        .complete_login(
+            &String::from(TEST_PROVIDER),
+            &generate_random_string(32),
+            &state,
+        )
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("A login was finished twice"),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
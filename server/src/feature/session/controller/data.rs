@@ -0,0 +1,38 @@
+use crate::feature::session::model::SessionFamily;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// A get session response body struct, which describes a place a user is logged in.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetSessionResponseBody {
+    /// The session's unique identifier.
+    pub(super) id: String,
+
+    /// The user agent of the client the user logged in with, if it sent one.
+    pub(super) user_agent: Option<String>,
+
+    /// The ip address of the client the user logged in with.
+    pub(super) ip_address: String,
+
+    /// The date and time the user logged in at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(super) created_at: OffsetDateTime,
+
+    /// The date and time the session was last used at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(super) last_seen_at: OffsetDateTime,
+}
+
+/// An Into<GetSessionResponseBody> implementation for the SessionFamily struct.
+impl Into<GetSessionResponseBody> for SessionFamily {
+    fn into(self) -> GetSessionResponseBody {
+        return GetSessionResponseBody {
+            id: self.id,
+            user_agent: self.user_agent,
+            ip_address: self.ip_address,
+            created_at: self.family_created_at,
+            last_seen_at: self.last_seen_at,
+        };
+    }
+}
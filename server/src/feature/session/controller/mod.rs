@@ -0,0 +1,155 @@
+mod data;
+
+use self::data::GetSessionResponseBody;
+use crate::{
+    common::{
+        enumeration::{AuthenticationResult, DeletionResult},
+        utility::{create_authentication_failure_response, create_cleared_authentication_cookies},
+    },
+    feature::{auth::service::AuthService, session::service::SessionService, user::model::User},
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the session controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the session controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/sessions")
+            .service(get_sessions)
+            .service(revoke_session)
+            .service(revoke_all_sessions),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to get the sessions of the user that sent the request, which are the places
+/// they are logged in.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `session_service` - The session service that will be used to get the user's sessions.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_sessions(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the user's sessions, and convert them into the response body format.
+    let sessions: Vec<GetSessionResponseBody> =
+        match session_service.get_active_families(&user.id).await {
+            Ok(families) => families.into_iter().map(|family| family.into()).collect(),
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // Return the sessions.
+    return HttpResponse::Ok().json(sessions);
+}
+
+/// # Description
+///
+/// An api endpoint to revoke one of the sessions of the user that sent the request, which signs
+/// them out of that login immediately.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the session that is being revoked.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `session_service` - The session service that will be used to revoke the session.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn revoke_session(
+    request: HttpRequest,
+    id: web::Path<String>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Revoke the session.
+    return match session_service.revoke_family(&user.id, &id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to revoke every session of the user that sent the request, which signs them out
+/// everywhere, including the client that sent the request.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `session_service` - The session service that will be used to revoke the sessions.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("")]
+async fn revoke_all_sessions(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Revoke the sessions.
+    if session_service.revoke_all(&user.id).await.is_err() {
+        return HttpResponse::InternalServerError().finish();
+    }
+
+    // Clear the user's authentication cookies.
+    let mut response = HttpResponse::Ok();
+    for cookie in create_cleared_authentication_cookies() {
+        response.cookie(cookie);
+    }
+    return response.finish();
+}
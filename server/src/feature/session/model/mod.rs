@@ -0,0 +1,64 @@
+use time::OffsetDateTime;
+
+/// A session struct, which holds the hash of a refresh token that lets a user get new access tokens
+/// without logging in again.
+///
+/// Each time a refresh token is used, it is rotated: the session is marked as rotated, and a new
+/// session with a new refresh token is created in the same family. The sessions in a family all
+/// belong to the same login, so if a refresh token that was already rotated is used again, the
+/// whole family is revoked.
+#[derive(Clone, Debug)]
+pub(crate) struct Session {
+    /// The session's unique identifier.
+    pub(crate) id: u64,
+
+    /// The identifier of the login the session belongs to, which is shared by every session that
+    /// was created by rotating its refresh token.
+    pub(crate) family_id: String,
+
+    /// The id of the user the session belongs to.
+    pub(crate) user_id: u64,
+
+    /// The sha-256 hash of the refresh token, encoded as hex.
+    pub(crate) refresh_token_hash: String,
+
+    /// The date and time the user logged in at.
+    pub(crate) authenticated_at: OffsetDateTime,
+
+    /// The date and time the session was created at.
+    pub(crate) session_created_at: OffsetDateTime,
+
+    /// The date and time the session expires at.
+    pub(crate) expires_at: OffsetDateTime,
+
+    /// The date and time the session's refresh token was rotated at, if it has been used.
+    pub(crate) rotated_at: Option<OffsetDateTime>,
+}
+
+/// A session family struct, which represents a single login of a user. Access tokens carry the
+/// identifier of the family they were issued for, so revoking the family signs the user out of
+/// that login.
+#[derive(Clone, Debug)]
+pub(crate) struct SessionFamily {
+    /// The session family's unique identifier, which is used as the id of the access tokens issued
+    /// for it.
+    pub(crate) id: String,
+
+    /// The id of the user the session family belongs to.
+    pub(crate) user_id: u64,
+
+    /// The user agent of the client the user logged in with, if it sent one.
+    pub(crate) user_agent: Option<String>,
+
+    /// The ip address of the client the user logged in with.
+    pub(crate) ip_address: String,
+
+    /// The date and time the session family was created at, which is when the user logged in.
+    pub(crate) family_created_at: OffsetDateTime,
+
+    /// The date and time the session family was last used to authenticate a request at.
+    pub(crate) last_seen_at: OffsetDateTime,
+
+    /// The date and time the session family was revoked at, if it has been revoked.
+    pub(crate) revoked_at: Option<OffsetDateTime>,
+}
@@ -0,0 +1,651 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::session::model::{Session, SessionFamily},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// A session repository trait.
+#[async_trait]
+pub(crate) trait SessionRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a session into the session repository.
+    ///
+    /// # Arguments
+    ///
+    /// `session` - The session to insert into the session repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// session that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(&self, session: &Session, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query a session from the session repository by the hash of its refresh token.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token_hash` - The hash of the session's refresh token.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the session if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_refresh_token_hash(
+        &self,
+        refresh_token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<Session>, Error>;
+
+    /// # Description
+    ///
+    /// Mark a session's refresh token as rotated, if it has not already been rotated. Since the
+    /// session is only updated if it has not been rotated, a refresh token can only be rotated
+    /// once, even if it is used by several requests at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the session that will be marked as rotated.
+    ///
+    /// `rotated_at` - The date and time the session's refresh token was rotated at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated, which will be 0 if the session was already rotated.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn rotate(
+        &self,
+        id: &u64,
+        rotated_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the sessions that expired before a specific time from the session repository.
+    ///
+    /// # Arguments
+    ///
+    /// `before` - The date and time sessions must have expired before to be deleted.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_expired(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a session family into the session repository.
+    ///
+    /// # Arguments
+    ///
+    /// `family` - The session family to insert into the session repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_family(
+        &self,
+        family: &SessionFamily,
+        context: &mut QueryContext,
+    ) -> Result<(), Error>;
+
+    /// # Description
+    ///
+    /// Query a session family from the session repository by its id.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the session family.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the session family if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_family_by_id(
+        &self,
+        id: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<SessionFamily>, Error>;
+
+    /// # Description
+    ///
+    /// Query the session families of a user that have not been revoked, and still have a session
+    /// whose refresh token can be used, ordered from the most to the least recently seen.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the session families belong to.
+    ///
+    /// `now` - The current date and time, which sessions must expire after to be usable.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the session families
+    /// that were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_active_families_by_user_id(
+        &self,
+        user_id: &u64,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<SessionFamily>, Error>;
+
+    /// # Description
+    ///
+    /// Update the date and time a session family was last seen at.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the session family.
+    ///
+    /// `last_seen_at` - The date and time the session family was last seen at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update_family_last_seen_at(
+        &self,
+        id: &String,
+        last_seen_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Revoke a session family, if it has not already been revoked.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the session family that will be revoked.
+    ///
+    /// `revoked_at` - The date and time the session family is revoked at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated, which will be 0 if the session family was already revoked.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn revoke_family(
+        &self,
+        id: &String,
+        revoked_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Revoke every session family of a user that has not already been revoked.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user whose session families will be revoked.
+    ///
+    /// `revoked_at` - The date and time the session families are revoked at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn revoke_families_by_user_id(
+        &self,
+        user_id: &u64,
+        revoked_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the session families that no longer have any sessions from the session repository.
+    ///
+    /// # Arguments
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_empty_families(&self, context: &mut QueryContext) -> Result<u64, Error>;
+}
+
+/// A SessionRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = SessionRepository)]
+pub(crate) struct SessionRepositoryImpl;
+
+/// A SessionRepository implementation for the SessionRepositoryImpl struct.
+#[async_trait]
+impl SessionRepository for SessionRepositoryImpl {
+    async fn insert(&self, session: &Session, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `sessions` (
+                    `id`,
+                    `family_id`,
+                    `user_id`,
+                    `refresh_token_hash`,
+                    `authenticated_at`,
+                    `session_created_at`,
+                    `expires_at`,
+                    `rotated_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            session.id,
+            session.family_id,
+            session.user_id,
+            session.refresh_token_hash,
+            session.authenticated_at,
+            session.session_created_at,
+            session.expires_at,
+            session.rotated_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_by_refresh_token_hash(
+        &self,
+        refresh_token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<Session>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            Session,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `family_id` AS `family_id: String`,
+                    `user_id` AS `user_id: u64`,
+                    `refresh_token_hash` AS `refresh_token_hash: String`,
+                    `authenticated_at` AS `authenticated_at: OffsetDateTime`,
+                    `session_created_at` AS `session_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`,
+                    `rotated_at` AS `rotated_at: OffsetDateTime`
+                FROM
+                    `sessions`
+                WHERE
+                    `refresh_token_hash` = ?;
+            "#,
+            refresh_token_hash
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn rotate(
+        &self,
+        id: &u64,
+        rotated_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `sessions`
+                SET
+                    `rotated_at` = ?
+                WHERE
+                    `id` = ? AND `rotated_at` IS NULL;
+            "#,
+            rotated_at,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_expired(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `sessions`
+                WHERE
+                    `expires_at` < ?;
+            "#,
+            before
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_family(
+        &self,
+        family: &SessionFamily,
+        context: &mut QueryContext,
+    ) -> Result<(), Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `session_families` (
+                    `id`,
+                    `user_id`,
+                    `user_agent`,
+                    `ip_address`,
+                    `family_created_at`,
+                    `last_seen_at`,
+                    `revoked_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                );
+            "#,
+            family.id,
+            family.user_id,
+            family.user_agent,
+            family.ip_address,
+            family.family_created_at,
+            family.last_seen_at,
+            family.revoked_at
+        );
+
+        // Execute the query.
+        match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(());
+    }
+
+    async fn get_family_by_id(
+        &self,
+        id: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<SessionFamily>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            SessionFamily,
+            r#"
+                SELECT
+                    `id` AS `id: String`,
+                    `user_id` AS `user_id: u64`,
+                    `user_agent` AS `user_agent: String`,
+                    `ip_address` AS `ip_address: String`,
+                    `family_created_at` AS `family_created_at: OffsetDateTime`,
+                    `last_seen_at` AS `last_seen_at: OffsetDateTime`,
+                    `revoked_at` AS `revoked_at: OffsetDateTime`
+                FROM
+                    `session_families`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_active_families_by_user_id(
+        &self,
+        user_id: &u64,
+        now: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<Vec<SessionFamily>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            SessionFamily,
+            r#"
+                SELECT
+                    `id` AS `id: String`,
+                    `user_id` AS `user_id: u64`,
+                    `user_agent` AS `user_agent: String`,
+                    `ip_address` AS `ip_address: String`,
+                    `family_created_at` AS `family_created_at: OffsetDateTime`,
+                    `last_seen_at` AS `last_seen_at: OffsetDateTime`,
+                    `revoked_at` AS `revoked_at: OffsetDateTime`
+                FROM
+                    `session_families`
+                WHERE
+                    `user_id` = ?
+                    AND `revoked_at` IS NULL
+                    AND EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            `sessions`
+                        WHERE
+                            `sessions`.`family_id` = `session_families`.`id`
+                            AND `sessions`.`rotated_at` IS NULL
+                            AND `sessions`.`expires_at` > ?
+                    )
+                ORDER BY
+                    `last_seen_at` DESC;
+            "#,
+            user_id,
+            now
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update_family_last_seen_at(
+        &self,
+        id: &String,
+        last_seen_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `session_families`
+                SET
+                    `last_seen_at` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            last_seen_at,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn revoke_family(
+        &self,
+        id: &String,
+        revoked_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `session_families`
+                SET
+                    `revoked_at` = ?
+                WHERE
+                    `id` = ? AND `revoked_at` IS NULL;
+            "#,
+            revoked_at,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn revoke_families_by_user_id(
+        &self,
+        user_id: &u64,
+        revoked_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `session_families`
+                SET
+                    `revoked_at` = ?
+                WHERE
+                    `user_id` = ? AND `revoked_at` IS NULL;
+            "#,
+            revoked_at,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_empty_families(&self, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `session_families`
+                WHERE
+                    NOT EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            `sessions`
+                        WHERE
+                            `sessions`.`family_id` = `session_families`.`id`
+                    );
+            "#
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
@@ -0,0 +1,527 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        session::{
+            model::{Session, SessionFamily},
+            repository::SessionRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a session that can be used for testing.
+///
+/// # Arguments
+///
+/// `user_id` - The id of the user the session belongs to.
+///
+/// `family_id` - The identifier of the family the session belongs to.
+///
+/// # Returns
+///
+/// The session that was created.
+fn create_test_session(user_id: u64, family_id: &String) -> Session {
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    return Session {
+        id: 0,
+        family_id: family_id.clone(),
+        user_id,
+        refresh_token_hash: generate_random_string(64),
+        authenticated_at: now,
+        session_created_at: now,
+        expires_at: now + Duration::DAY,
+        rotated_at: None,
+    };
+}
+
+/// # Description
+///
+/// Insert a session family that can be used for testing.
+///
+/// # Arguments
+///
+/// `user_id` - The id of the user the session family belongs to.
+///
+/// `session_repository` - The session repository the session family will be inserted with.
+///
+/// `context` - The query context the session family will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the session family.
+///
+/// # Returns
+///
+/// The session family that was inserted.
+async fn insert_test_family(
+    user_id: u64,
+    session_repository: &Arc<dyn SessionRepository>,
+    context: &mut QueryContext<'_>,
+) -> SessionFamily {
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let family: SessionFamily = SessionFamily {
+        id: generate_random_string(48),
+        user_id,
+        user_agent: Some(generate_random_string(16)),
+        ip_address: String::from("127.0.0.1"),
+        family_created_at: now,
+        last_seen_at: now,
+        revoked_at: None,
+    };
+    session_repository
+        .insert_family(&family, context)
+        .await
+        .expect("Failed to insert session family");
+    return family;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting sessions, and make sure they can be queried by their refresh token hash and
+/// rotated.
+#[actix_web::test]
+async fn sessions_are_queryable_and_rotatable() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a session repository instance.
+    let session_repository: Arc<dyn SessionRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user.
+    let user: User = insert_test_user(&injector, &mut context).await;
+
+    // Insert a session family, and a session in it.
+    let family_id: String = insert_test_family(user.id, &session_repository, &mut context)
+        .await
+        .id;
+    let mut session: Session = create_test_session(user.id, &family_id);
+    session.id = session_repository
+        .insert(&session, &mut context)
+        .await
+        .expect("Failed to insert session");
+    assert!(session.id > 0);
+
+    // Make sure the session can be queried by its refresh token hash.
+    let queried_session: Session = session_repository
+        .get_by_refresh_token_hash(&session.refresh_token_hash, &mut context)
+        .await
+        .expect("Failed to query session")
+        .expect("The session could not be found");
+    assert_eq!(session.id, queried_session.id);
+    assert_eq!(family_id, queried_session.family_id);
+    assert_eq!(user.id, queried_session.user_id);
+    assert!(queried_session.rotated_at.is_none());
+
+    // Rotate the session, and make sure it can only be rotated once.
+    for expected_rows_updated in [1, 0] {
+        let rows_updated = session_repository
+            .rotate(&session.id, &OffsetDateTime::now_utc(), &mut context)
+            .await
+            .expect("Failed to rotate session");
+        assert_eq!(expected_rows_updated, rows_updated);
+    }
+
+    // Insert the replacement session into the same family.
+    let replacement: Session = create_test_session(user.id, &family_id);
+    session_repository
+        .insert(&replacement, &mut context)
+        .await
+        .expect("Failed to insert session");
+
+    // Make sure the rotation was persisted.
+    let rotated_session: Session = session_repository
+        .get_by_refresh_token_hash(&session.refresh_token_hash, &mut context)
+        .await
+        .expect("Failed to query session")
+        .expect("The session could not be found");
+    assert!(rotated_session.rotated_at.is_some());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test querying the active session families of a user, and make sure revoked session families
+/// and session families without usable sessions are excluded.
+#[actix_web::test]
+async fn only_active_session_families_are_listed() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a session repository instance.
+    let session_repository: Arc<dyn SessionRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user with three session families, one of which has an expired session.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let active_family: SessionFamily =
+        insert_test_family(user.id, &session_repository, &mut context).await;
+    let revoked_family: SessionFamily =
+        insert_test_family(user.id, &session_repository, &mut context).await;
+    let expired_family: SessionFamily =
+        insert_test_family(user.id, &session_repository, &mut context).await;
+    let mut expired_session: Session = create_test_session(user.id, &expired_family.id);
+    expired_session.expires_at = OffsetDateTime::now_utc() - Duration::DAY;
+    for session in [
+        &create_test_session(user.id, &active_family.id),
+        &create_test_session(user.id, &revoked_family.id),
+        &expired_session,
+    ] {
+        session_repository
+            .insert(session, &mut context)
+            .await
+            .expect("Failed to insert session");
+    }
+
+    // Revoke one of the session families, and make sure it can only be revoked once.
+    for expected_rows_updated in [1, 0] {
+        let rows_updated = session_repository
+            .revoke_family(&revoked_family.id, &OffsetDateTime::now_utc(), &mut context)
+            .await
+            .expect("Failed to revoke session family");
+        assert_eq!(expected_rows_updated, rows_updated);
+    }
+    assert!(session_repository
+        .get_family_by_id(&revoked_family.id, &mut context)
+        .await
+        .expect("Failed to query session family")
+        .expect("The session family could not be found")
+        .revoked_at
+        .is_some());
+
+    // Make sure only the active session family is listed.
+    let families: Vec<SessionFamily> = session_repository
+        .get_active_families_by_user_id(&user.id, &OffsetDateTime::now_utc(), &mut context)
+        .await
+        .expect("Failed to query session families");
+    assert_eq!(1, families.len());
+    assert_eq!(active_family.id, families[0].id);
+    assert_eq!(active_family.user_agent, families[0].user_agent);
+    assert_eq!(active_family.ip_address, families[0].ip_address);
+
+    // Revoke every session family of the user, and make sure none are listed.
+    let rows_updated = session_repository
+        .revoke_families_by_user_id(&user.id, &OffsetDateTime::now_utc(), &mut context)
+        .await
+        .expect("Failed to revoke session families");
+    assert_eq!(2, rows_updated);
+    assert!(session_repository
+        .get_active_families_by_user_id(&user.id, &OffsetDateTime::now_utc(), &mut context)
+        .await
+        .expect("Failed to query session families")
+        .is_empty());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test deleting expired sessions, and make sure sessions that have not expired and the session
+/// families that still have sessions remain.
+#[actix_web::test]
+async fn only_expired_sessions_are_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a session repository instance.
+    let session_repository: Arc<dyn SessionRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and an expired and active session for them.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let expired_family: SessionFamily =
+        insert_test_family(user.id, &session_repository, &mut context).await;
+    let active_family: SessionFamily =
+        insert_test_family(user.id, &session_repository, &mut context).await;
+    let mut expired_session: Session = create_test_session(user.id, &expired_family.id);
+    expired_session.expires_at = OffsetDateTime::now_utc() - Duration::DAY;
+    let active_session: Session = create_test_session(user.id, &active_family.id);
+    for session in [&expired_session, &active_session] {
+        session_repository
+            .insert(session, &mut context)
+            .await
+            .expect("Failed to insert session");
+    }
+
+    // Delete the expired sessions.
+    let rows_deleted = session_repository
+        .delete_expired(&OffsetDateTime::now_utc(), &mut context)
+        .await
+        .expect("Failed to delete expired sessions");
+    assert!(rows_deleted >= 1);
+
+    // Make sure only the expired session was deleted.
+    assert!(session_repository
+        .get_by_refresh_token_hash(&expired_session.refresh_token_hash, &mut context)
+        .await
+        .expect("Failed to query session")
+        .is_none());
+    assert!(session_repository
+        .get_by_refresh_token_hash(&active_session.refresh_token_hash, &mut context)
+        .await
+        .expect("Failed to query session")
+        .is_some());
+
+    // Delete the session families without sessions, and make sure only the expired session's
+    // family was deleted.
+    let rows_deleted = session_repository
+        .delete_empty_families(&mut context)
+        .await
+        .expect("Failed to delete empty session families");
+    assert!(rows_deleted >= 1);
+    assert!(session_repository
+        .get_family_by_id(&expired_family.id, &mut context)
+        .await
+        .expect("Failed to query session family")
+        .is_none());
+    assert!(session_repository
+        .get_family_by_id(&active_family.id, &mut context)
+        .await
+        .expect("Failed to query session family")
+        .is_some());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
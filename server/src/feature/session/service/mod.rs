@@ -0,0 +1,688 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::{DeletionResult, QueryContext, QueryResult},
+    database::DatabaseConnectionFactory,
+    feature::{
+        crypto::service::CryptoService,
+        session::{
+            model::{Session, SessionFamily},
+            repository::SessionRepository,
+        },
+        user::model::User,
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::Acquire;
+use std::{error::Error, sync::Arc};
+use time::{Duration, OffsetDateTime};
+
+/// How often (in seconds) the date and time a session family was last seen at is updated, so that
+/// every authenticated request does not have to write to the database.
+const LAST_SEEN_AT_UPDATE_INTERVAL: i64 = 60;
+
+/// A session service trait.
+#[async_trait(?Send)]
+pub(crate) trait SessionService: Interface {
+    /// # Description
+    ///
+    /// Create a session for a user that has just logged in, which starts a new session family.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user the session is being created for.
+    ///
+    /// `user_agent` - The user agent of the client the user logged in with, if it sent one.
+    ///
+    /// `ip_address` - The ip address of the client the user logged in with.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the session was created, the Ok variant will be returned with the secret refresh token
+    /// and the session that was stored.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create(
+        &self,
+        user: &User,
+        user_agent: &Option<String>,
+        ip_address: &String,
+    ) -> Result<(String, Session), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Create a session for a user that has just logged in, which starts a new session family, in
+    /// the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user the session is being created for.
+    ///
+    /// `user_agent` - The user agent of the client the user logged in with, if it sent one.
+    ///
+    /// `ip_address` - The ip address of the client the user logged in with.
+    ///
+    /// `context` - The context the session will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the session was created, the Ok variant will be returned with the secret refresh token
+    /// and the session that was stored.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create_with_context(
+        &self,
+        user: &User,
+        user_agent: &Option<String>,
+        ip_address: &String,
+        context: &mut QueryContext,
+    ) -> Result<(String, Session), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Rotate a refresh token, replacing its session with a new session in the same family. If the
+    /// refresh token was already rotated, it has been used by someone else, so the whole family is
+    /// revoked.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token` - The secret refresh token that is being rotated.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the refresh token was rotated, the Ok variant will be returned with the new secret
+    /// refresh token and the session that was stored for it.
+    /// - If the refresh token does not exist, has expired, its session family has been revoked or it
+    /// was already rotated, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn rotate(
+        &self,
+        refresh_token: &String,
+    ) -> QueryResult<(String, Session), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Rotate a refresh token, replacing its session with a new session in the same family, in the
+    /// context specified. If the refresh token was already rotated, it has been used by someone
+    /// else, so the whole family is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token` - The secret refresh token that is being rotated.
+    ///
+    /// `context` - The context the refresh token will be rotated in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the refresh token was rotated, the Ok variant will be returned with the new secret
+    /// refresh token and the session that was stored for it.
+    /// - If the refresh token does not exist, has expired, its session family has been revoked or it
+    /// was already rotated, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn rotate_with_context(
+        &self,
+        refresh_token: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<(String, Session), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Revoke the session family a refresh token belongs to, such as when the user logs out.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token` - The secret refresh token whose session family will be revoked.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the session family was revoked or the refresh token does not exist, the Ok variant will
+    /// be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn revoke(&self, refresh_token: &String) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get a session family that has not been revoked, and record that it was just used to
+    /// authenticate a request.
+    ///
+    /// # Arguments
+    ///
+    /// `family_id` - The id of the session family, which is the id of the access token that was
+    /// used.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the session family was found and has not been revoked, the Ok variant will be returned
+    /// with the session family.
+    /// - If the session family does not exist or has been revoked, the NotFound variant will be
+    /// returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn mark_family_seen(
+        &self,
+        family_id: &String,
+    ) -> QueryResult<SessionFamily, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the session families of a user that can still be used, which are the places the user is
+    /// logged in.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the session families belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the session families, from
+    /// the most to the least recently seen.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_active_families(
+        &self,
+        user_id: &u64,
+    ) -> Result<Vec<SessionFamily>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Revoke one of a user's session families, which signs the user out of that login.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the session family belongs to.
+    ///
+    /// `family_id` - The id of the session family that will be revoked.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the session family was revoked, the Ok variant will be returned.
+    /// - If the session family does not exist, belongs to another user or was already revoked, the
+    /// NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn revoke_family(
+        &self,
+        user_id: &u64,
+        family_id: &String,
+    ) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Revoke every session family of a user, which signs the user out everywhere.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user whose session families will be revoked.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the session families were revoked, the Ok variant will be returned with the amount of
+    /// session families revoked.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn revoke_all(&self, user_id: &u64) -> Result<u64, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Delete the sessions that have expired, since their refresh tokens can no longer be used, and
+    /// the session families that no longer have any sessions.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of
+    /// sessions deleted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_expired(&self) -> Result<u64, Box<dyn Error>>;
+}
+
+/// A SessionServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = SessionService)]
+pub(crate) struct SessionServiceImpl {
+    /// The crypto service that will be used to generate and hash refresh tokens.
+    #[shaku(inject)]
+    crypto_service: Arc<dyn CryptoService>,
+
+    /// The session repository that will be used to manage persistent session data.
+    #[shaku(inject)]
+    session_repository: Arc<dyn SessionRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The amount of time (in seconds) refresh tokens are valid for.
+    refresh_token_lifetime: u64,
+}
+
+/// A SessionService implementation for the SessionServiceImpl struct.
+#[async_trait(?Send)]
+impl SessionService for SessionServiceImpl {
+    async fn create(
+        &self,
+        user: &User,
+        user_agent: &Option<String>,
+        ip_address: &String,
+    ) -> Result<(String, Session), Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = __self.connection_factory.get_connection().await?;
+
+        // Start a transaction.
+        let transaction = connection.begin().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Create the session.
+        let result = self
+            .create_with_context(user, user_agent, ip_address, &mut context)
+            .await;
+
+        // Commit the transaction if the session was created, otherwise roll it back.
+        match result {
+            Ok(_) => context.commit_if_transaction().await?,
+            Err(_) => context.rollback_if_transaction().await?,
+        };
+
+        return result;
+    }
+
+    async fn create_with_context(
+        &self,
+        user: &User,
+        user_agent: &Option<String>,
+        ip_address: &String,
+        context: &mut QueryContext,
+    ) -> Result<(String, Session), Box<dyn Error>> {
+        // Start a new family, since the user has just logged in.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let family: SessionFamily = SessionFamily {
+            id: __self.crypto_service.generate_secret_token(),
+            user_id: user.id,
+            user_agent: user_agent.clone(),
+            ip_address: ip_address.clone(),
+            family_created_at: now,
+            last_seen_at: now,
+            revoked_at: None,
+        };
+        __self
+            .session_repository
+            .insert_family(&family, context)
+            .await?;
+
+        return self.insert_session(user.id, family.id, now, context).await;
+    }
+
+    async fn rotate(
+        &self,
+        refresh_token: &String,
+    ) -> QueryResult<(String, Session), Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the rotation.
+        let query_result = self.rotate_with_context(refresh_token, &mut context).await;
+
+        // Commit the transaction unless an error occurred. The NotFound variant is committed as
+        // well, since the session family is revoked when a refresh token is reused.
+        let transaction_completion_result = match query_result {
+            QueryResult::Ok(_) => context.commit_if_transaction().await,
+            QueryResult::NotFound => context.commit_if_transaction().await,
+            QueryResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the query result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => query_result,
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn rotate_with_context(
+        &self,
+        refresh_token: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<(String, Session), Box<dyn Error>> {
+        // Query the session the refresh token belongs to.
+        let session: Session = match self.get_by_refresh_token(refresh_token, context).await {
+            Ok(Some(session)) => session,
+            Ok(None) => return QueryResult::NotFound,
+            Err(error) => return QueryResult::Err(error),
+        };
+
+        // Make sure the session can still be used.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        if session.expires_at <= now {
+            return QueryResult::NotFound;
+        }
+        match __self
+            .session_repository
+            .get_family_by_id(&session.family_id, context)
+            .await
+        {
+            Ok(Some(family)) if family.revoked_at.is_none() => {}
+            Ok(_) => return QueryResult::NotFound,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Mark the session as rotated. If it was already rotated, the refresh token has been used
+        // twice, which means it was stolen, so every session in the family is revoked.
+        let rows_updated = match __self
+            .session_repository
+            .rotate(&session.id, &now, context)
+            .await
+        {
+            Ok(rows_updated) => rows_updated,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+        if rows_updated == 0 {
+            log::warn!(
+                "A rotated refresh token was reused, revoking the family of session {} of user {}",
+                session.id,
+                session.user_id
+            );
+            return match __self
+                .session_repository
+                .revoke_family(&session.family_id, &now, context)
+                .await
+            {
+                Ok(_) => QueryResult::NotFound,
+                Err(error) => QueryResult::Err(Box::new(error)),
+            };
+        }
+
+        // Record that the session family was just used.
+        if let Err(error) = __self
+            .session_repository
+            .update_family_last_seen_at(&session.family_id, &now, context)
+            .await
+        {
+            return QueryResult::Err(Box::new(error));
+        }
+
+        // Replace the session with a new one in the same family.
+        return match self
+            .insert_session(
+                session.user_id,
+                session.family_id,
+                session.authenticated_at,
+                context,
+            )
+            .await
+        {
+            Ok(result) => QueryResult::Ok(result),
+            Err(error) => QueryResult::Err(error),
+        };
+    }
+
+    async fn revoke(&self, refresh_token: &String) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the session the refresh token belongs to.
+        let session: Session = match self
+            .get_by_refresh_token(refresh_token, &mut context)
+            .await?
+        {
+            Some(session) => session,
+            None => return Ok(()),
+        };
+
+        // Revoke the session's family.
+        __self
+            .session_repository
+            .revoke_family(&session.family_id, &OffsetDateTime::now_utc(), &mut context)
+            .await?;
+
+        return Ok(());
+    }
+
+    async fn mark_family_seen(
+        &self,
+        family_id: &String,
+    ) -> QueryResult<SessionFamily, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the session family, and make sure it has not been revoked.
+        let mut family: SessionFamily = match __self
+            .session_repository
+            .get_family_by_id(family_id, &mut context)
+            .await
+        {
+            Ok(Some(family)) if family.revoked_at.is_none() => family,
+            Ok(_) => return QueryResult::NotFound,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Record that the session family was just used, unless that was recorded recently.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        if now - family.last_seen_at >= Duration::seconds(LAST_SEEN_AT_UPDATE_INTERVAL) {
+            if let Err(error) = __self
+                .session_repository
+                .update_family_last_seen_at(&family.id, &now, &mut context)
+                .await
+            {
+                return QueryResult::Err(Box::new(error));
+            }
+            family.last_seen_at = now;
+        }
+
+        return QueryResult::Ok(family);
+    }
+
+    async fn get_active_families(
+        &self,
+        user_id: &u64,
+    ) -> Result<Vec<SessionFamily>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the session families.
+        return Ok(__self
+            .session_repository
+            .get_active_families_by_user_id(user_id, &OffsetDateTime::now_utc(), &mut context)
+            .await?);
+    }
+
+    async fn revoke_family(
+        &self,
+        user_id: &u64,
+        family_id: &String,
+    ) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Make sure the session family belongs to the user.
+        match __self
+            .session_repository
+            .get_family_by_id(family_id, &mut context)
+            .await
+        {
+            Ok(Some(family)) if family.user_id == *user_id => {}
+            Ok(_) => return DeletionResult::NotFound,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Revoke the session family.
+        return match __self
+            .session_repository
+            .revoke_family(family_id, &OffsetDateTime::now_utc(), &mut context)
+            .await
+        {
+            Ok(0) => DeletionResult::NotFound,
+            Ok(_) => DeletionResult::Ok,
+            Err(error) => DeletionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn revoke_all(&self, user_id: &u64) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Revoke the session families.
+        return Ok(__self
+            .session_repository
+            .revoke_families_by_user_id(user_id, &OffsetDateTime::now_utc(), &mut context)
+            .await?);
+    }
+
+    async fn delete_expired(&self) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the expired sessions.
+        let sessions_deleted: u64 = __self
+            .session_repository
+            .delete_expired(&OffsetDateTime::now_utc(), &mut context)
+            .await?;
+
+        // Delete the session families that no longer have any sessions.
+        __self
+            .session_repository
+            .delete_empty_families(&mut context)
+            .await?;
+
+        return Ok(sessions_deleted);
+    }
+}
+
+/// An implementation for the SessionServiceImpl struct.
+impl SessionServiceImpl {
+    /// # Description
+    ///
+    /// Create session service parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token_lifetime` - The amount of time (in seconds) refresh tokens are valid for.
+    ///
+    /// # Returns
+    ///
+    /// The session service parameters that were created.
+    pub(crate) fn create_parameters(refresh_token_lifetime: u64) -> SessionServiceImplParameters {
+        return SessionServiceImplParameters {
+            refresh_token_lifetime,
+        };
+    }
+
+    /// # Description
+    ///
+    /// Query a session by the secret refresh token it was created for.
+    ///
+    /// # Arguments
+    ///
+    /// `refresh_token` - The secret refresh token.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the session if it was found.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_refresh_token(
+        &self,
+        refresh_token: &String,
+        context: &mut QueryContext<'_>,
+    ) -> Result<Option<Session>, Box<dyn Error>> {
+        let refresh_token_hash: String = self.crypto_service.hash_secret_token(refresh_token);
+        return Ok(self
+            .session_repository
+            .get_by_refresh_token_hash(&refresh_token_hash, context)
+            .await?);
+    }
+
+    /// # Description
+    ///
+    /// Create a session with a new refresh token, and store its hash.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the session belongs to.
+    ///
+    /// `family_id` - The identifier of the family the session belongs to.
+    ///
+    /// `authenticated_at` - The date and time the user logged in at.
+    ///
+    /// `context` - The context the session will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the session was created, the Ok variant will be returned with the secret refresh token
+    /// and the session that was stored.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_session(
+        &self,
+        user_id: u64,
+        family_id: String,
+        authenticated_at: OffsetDateTime,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(String, Session), Box<dyn Error>> {
+        // Create a refresh token, and store its hash.
+        let refresh_token: String = self.crypto_service.generate_secret_token();
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let mut session: Session = Session {
+            id: 0,
+            family_id,
+            user_id,
+            refresh_token_hash: self.crypto_service.hash_secret_token(&refresh_token),
+            authenticated_at,
+            session_created_at: now,
+            expires_at: now + Duration::seconds(self.refresh_token_lifetime as i64),
+            rotated_at: None,
+        };
+        session.id = self.session_repository.insert(&session, context).await?;
+
+        // Return the refresh token.
+        return Ok((refresh_token, session));
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        session::{model::Session, service::SessionService},
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test rotating a refresh token, and make sure the new refresh token belongs to the same family
+/// and the old refresh token can not be used again.
+#[actix_web::test]
+async fn rotating_a_refresh_token_replaces_it() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a session service instance.
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and create a session for them.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let (refresh_token, session) = session_service
+        .create_with_context(&user, &None, &String::from("127.0.0.1"), &mut context)
+        .await
+        .expect("Failed to create session");
+    assert_eq!(user.id, session.user_id);
+    assert!(session.expires_at > OffsetDateTime::now_utc());
+
+    // Rotate the refresh token.
+    let (rotated_refresh_token, rotated_session): (String, Session) = match session_service
+        .rotate_with_context(&refresh_token, &mut context)
+        .await
+    {
+        QueryResult::Ok(result) => result,
+        QueryResult::NotFound => panic!("The refresh token could not be rotated"),
+        QueryResult::Err(error) => panic!("Failed to rotate the refresh token: {:?}", error),
+    };
+    assert_ne!(refresh_token, rotated_refresh_token);
+    assert_ne!(session.id, rotated_session.id);
+    assert_eq!(session.family_id, rotated_session.family_id);
+    assert_eq!(
+        session.authenticated_at.unix_timestamp(),
+        rotated_session.authenticated_at.unix_timestamp()
+    );
+
+    // Make sure an unknown refresh token can not be rotated.
+    match session_service
+        .rotate_with_context(&generate_random_string(48), &mut context)
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("An unknown refresh token was rotated"),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test reusing a refresh token that was already rotated, and make sure the whole session family is
+/// revoked, including the refresh token that replaced it.
+#[actix_web::test]
+async fn reusing_a_rotated_refresh_token_revokes_the_family() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a session service instance.
+    let session_service: Arc<dyn SessionService> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, create a session for them and rotate its refresh token.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let (refresh_token, _) = session_service
+        .create_with_context(&user, &None, &String::from("127.0.0.1"), &mut context)
+        .await
+        .expect("Failed to create session");
+    let (rotated_refresh_token, _) = match session_service
+        .rotate_with_context(&refresh_token, &mut context)
+        .await
+    {
+        QueryResult::Ok(result) => result,
+        QueryResult::NotFound => panic!("The refresh token could not be rotated"),
+        QueryResult::Err(error) => panic!("Failed to rotate the refresh token: {:?}", error),
+    };
+
+    // Reuse the original refresh token, and make sure it is rejected.
+    match session_service
+        .rotate_with_context(&refresh_token, &mut context)
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("A rotated refresh token was rotated again"),
+    };
+
+    // Make sure the refresh token that replaced it was revoked as well.
+    match session_service
+        .rotate_with_context(&rotated_refresh_token, &mut context)
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("A refresh token in a revoked family was rotated"),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
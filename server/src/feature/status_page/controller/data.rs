@@ -0,0 +1,179 @@
+use crate::feature::{
+    check::model::CheckStatus,
+    status_page::model::{StatusPage, StatusPageMonitorGroup},
+};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+
+/// A create status page request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CreateStatusPageRequestBody {
+    /// The slug the status page is served at.
+    pub(super) slug: String,
+
+    /// The status page's title.
+    pub(super) title: String,
+
+    /// The unique identifier of the file that contains the status page's logo.
+    pub(super) logo_file_id: Option<u64>,
+
+    /// If the uptime history of each monitor is shown on the status page.
+    pub(super) show_uptime_history: Option<bool>,
+
+    /// The groups of monitors that are shown on the status page, in the order they are shown in.
+    pub(super) monitor_groups: Vec<StatusPageMonitorGroup>,
+}
+
+/// An Into<StatusPage> implementation for the CreateStatusPageRequestBody struct.
+impl Into<StatusPage> for CreateStatusPageRequestBody {
+    fn into(self) -> StatusPage {
+        return StatusPage {
+            slug: self.slug,
+            title: self.title,
+            logo_file_id: self.logo_file_id,
+            show_uptime_history: self.show_uptime_history.unwrap_or(true),
+            monitor_groups: Json(self.monitor_groups),
+            ..Default::default()
+        };
+    }
+}
+
+/// An update status page request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UpdateStatusPageRequestBody {
+    /// The slug the status page is served at.
+    pub(super) slug: Option<String>,
+
+    /// The status page's title.
+    pub(super) title: Option<String>,
+
+    /// The unique identifier of the file that contains the status page's logo.
+    pub(super) logo_file_id: Option<u64>,
+
+    /// If the status page's logo is being removed.
+    pub(super) remove_logo: Option<bool>,
+
+    /// If the uptime history of each monitor is shown on the status page.
+    pub(super) show_uptime_history: Option<bool>,
+
+    /// The groups of monitors that are shown on the status page, in the order they are shown in.
+    pub(super) monitor_groups: Option<Vec<StatusPageMonitorGroup>>,
+}
+
+/// An implementation for the UpdateStatusPageRequestBody struct.
+impl UpdateStatusPageRequestBody {
+    /// # Description
+    ///
+    /// Apply the changes in the update status page request body to a status page.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page the changes will be applied to.
+    pub(super) fn apply(&self, status_page: &mut StatusPage) {
+        // If the status page's slug is being updated.
+        if let Some(slug) = &self.slug {
+            status_page.slug = slug.clone();
+        }
+
+        // If the status page's title is being updated.
+        if let Some(title) = &self.title {
+            status_page.title = title.clone();
+        }
+
+        // If the status page's logo is being removed or replaced.
+        if self.remove_logo == Some(true) {
+            status_page.logo_file_id = None;
+        } else if let Some(logo_file_id) = self.logo_file_id {
+            status_page.logo_file_id = Some(logo_file_id);
+        }
+
+        // If the uptime history is being shown or hidden.
+        if let Some(show_uptime_history) = self.show_uptime_history {
+            status_page.show_uptime_history = show_uptime_history;
+        }
+
+        // If the status page's monitor groups are being updated.
+        if let Some(monitor_groups) = &self.monitor_groups {
+            status_page.monitor_groups = Json(monitor_groups.clone());
+        }
+    }
+}
+
+/// A get status page response body struct, which only contains the fields of a status page that
+/// are shown to the public.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetStatusPageResponseBody {
+    /// The slug the status page is served at.
+    pub(super) slug: String,
+
+    /// The status page's title.
+    pub(super) title: String,
+
+    /// The url the status page's logo can be retrieved from, if it has one.
+    pub(super) logo_url: Option<String>,
+
+    /// The groups of monitors that are shown on the status page, in the order they are shown in.
+    pub(super) monitor_groups: Vec<GetStatusPageMonitorGroupResponseBody>,
+}
+
+/// An Into<GetStatusPageResponseBody> implementation for the StatusPage struct.
+///
+/// The monitor groups are not converted, as their monitors must be queried separately.
+impl Into<GetStatusPageResponseBody> for StatusPage {
+    fn into(self) -> GetStatusPageResponseBody {
+        return GetStatusPageResponseBody {
+            logo_url: self
+                .logo_file_id
+                .map(|_| format!("/status-pages/{}/logo", self.slug)),
+            slug: self.slug,
+            title: self.title,
+            monitor_groups: Vec::new(),
+        };
+    }
+}
+
+/// A get status page monitor group response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetStatusPageMonitorGroupResponseBody {
+    /// The group's name.
+    pub(super) name: String,
+
+    /// The monitors in the group, in the order they are shown in.
+    pub(super) monitors: Vec<GetStatusPageMonitorResponseBody>,
+}
+
+/// A get status page monitor response body struct, which only contains the fields of a monitor
+/// that are shown to the public.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetStatusPageMonitorResponseBody {
+    /// The monitor's name.
+    pub(super) name: String,
+
+    /// The status the monitor's most recent check determined the service to be in, if the monitor
+    /// is enabled and has performed a check.
+    pub(super) status: Option<CheckStatus>,
+
+    /// The monitor's daily uptime, ordered from oldest to newest, if the status page shows uptime
+    /// history.
+    pub(super) uptime_history: Option<Vec<GetStatusPageUptimeResponseBody>>,
+}
+
+/// A get status page uptime response body struct, which contains the uptime of a monitor for one
+/// day.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetStatusPageUptimeResponseBody {
+    /// The date and time the day starts at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(super) start: OffsetDateTime,
+
+    /// The percentage of checks that determined the service to be operational, if any checks were
+    /// performed.
+    pub(super) uptime_percentage: Option<f64>,
+}
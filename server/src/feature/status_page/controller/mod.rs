@@ -0,0 +1,432 @@
+mod data;
+
+use self::data::{
+    CreateStatusPageRequestBody, GetStatusPageMonitorGroupResponseBody,
+    GetStatusPageMonitorResponseBody, GetStatusPageResponseBody, GetStatusPageUptimeResponseBody,
+    UpdateStatusPageRequestBody,
+};
+use crate::{
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        auth::service::AuthService,
+        check::{
+            model::{CheckStatus, SeriesBucketWidth},
+            service::CheckResultService,
+            statistics::get_bucket_start,
+        },
+        file::{model::File, service::FileService},
+        monitor::{model::Monitor, service::MonitorService},
+        status_page::{model::StatusPage, service::StatusPageService},
+        user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    delete, get, patch, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+use std::error::Error;
+use time::{Duration, OffsetDateTime};
+
+/// The amount of days of uptime history that are shown on a status page.
+const UPTIME_HISTORY_DAYS: i64 = 90;
+
+/// # Description
+///
+/// Add the status page controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the status page controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/status-pages")
+            .service(create_status_page)
+            .service(get_status_pages)
+            .service(get_status_page)
+            .service(get_status_page_logo)
+            .service(update_status_page)
+            .service(delete_status_page),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to create a status page.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains information about the status page that is being
+/// created.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `status_page_service` - The status page service that will be used to create the status page.
+///
+/// # Returns
+///
+/// An http response.
+#[post("")]
+async fn create_status_page(
+    request: HttpRequest,
+    body: web::Json<CreateStatusPageRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Convert the request body into a status page.
+    let mut status_page: StatusPage = body.into_inner().into();
+    status_page.user_id = user.id;
+
+    // Create the status page.
+    return match status_page_service.insert(&status_page).await {
+        InsertionResult::Ok(created_status_page) => HttpResponse::Ok().json(created_status_page),
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get all of the status pages that belong to the sending user.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `status_page_service` - The status page service that will be used to retrieve the status pages.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_status_pages(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the status pages that belong to the user.
+    return match status_page_service.get_by_user_id(&user.id).await {
+        QueryResult::Ok(status_pages) => HttpResponse::Ok().json(status_pages),
+        QueryResult::NotFound => HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to get the public view of a status page. This endpoint does not require
+/// authentication.
+///
+/// # Arguments
+///
+/// `slug` - The slug of the status page that is being retrieved.
+///
+/// `status_page_service` - The status page service that will be used to retrieve the status page.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the status page's
+/// monitors.
+///
+/// `check_result_service` - The check result service that will be used to determine the status and
+/// uptime history of the status page's monitors.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{slug}")]
+async fn get_status_page(
+    slug: web::Path<String>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+    monitor_service: Inject<DependencyInjector, dyn MonitorService>,
+    check_result_service: Inject<DependencyInjector, dyn CheckResultService>,
+) -> HttpResponse {
+    // Get the status page that is being requested.
+    let status_page: StatusPage = match status_page_service.get_by_slug(&slug).await {
+        QueryResult::Ok(status_page) => status_page,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // Get the public view of the status page's monitor groups.
+    let monitor_groups: Vec<GetStatusPageMonitorGroupResponseBody> =
+        match get_monitor_groups(&status_page, &*monitor_service, &*check_result_service).await {
+            Ok(monitor_groups) => monitor_groups,
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // Convert the status page into its public view.
+    let mut response_body: GetStatusPageResponseBody = status_page.into();
+    response_body.monitor_groups = monitor_groups;
+
+    return HttpResponse::Ok().json(response_body);
+}
+
+/// # Description
+///
+/// An api endpoint to get the logo of a status page. This endpoint does not require
+/// authentication.
+///
+/// # Arguments
+///
+/// `slug` - The slug of the status page whose logo is being retrieved.
+///
+/// `status_page_service` - The status page service that will be used to retrieve the status page.
+///
+/// `file_service` - The file service that will be used to retrieve the logo.
+///
+/// # Returns
+///
+/// An http response.
+#[get("/{slug}/logo")]
+async fn get_status_page_logo(
+    slug: web::Path<String>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+    file_service: Inject<DependencyInjector, dyn FileService>,
+) -> HttpResponse {
+    // Get the status page whose logo is being requested.
+    let status_page: StatusPage = match status_page_service.get_by_slug(&slug).await {
+        QueryResult::Ok(status_page) => status_page,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the status page does not have a logo, there is nothing to return.
+    let logo_file_id: u64 = match status_page.logo_file_id {
+        Some(logo_file_id) => logo_file_id,
+        None => return HttpResponse::NotFound().finish(),
+    };
+
+    // Get the logo, and return its raw data.
+    let logo: File = match file_service.get(&logo_file_id).await {
+        QueryResult::Ok(logo) => logo,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    return HttpResponse::Ok()
+        .content_type(logo.mime_type)
+        .body(logo.data);
+}
+
+/// # Description
+///
+/// An api endpoint to update a status page.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the status page that is being updated.
+///
+/// `body` - The request body which contains information about the status page that is being
+/// updated.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `status_page_service` - The status page service that will be used to update the status page.
+///
+/// # Returns
+///
+/// An http response.
+#[patch("/{id}")]
+async fn update_status_page(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    body: web::Json<UpdateStatusPageRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the status page that is being updated.
+    let mut status_page: StatusPage = match status_page_service.get(&id).await {
+        QueryResult::Ok(status_page) => status_page,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the status page, they are not allowed to update it.
+    if user.id != status_page.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Apply the update to the status page.
+    body.apply(&mut status_page);
+
+    // Update the status page.
+    return match status_page_service.update(&status_page).await {
+        UpdateResult::Ok(status_page) => HttpResponse::Ok().json(status_page),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to delete a status page.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `id` - The id of the status page that is being deleted.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the sending user.
+///
+/// `status_page_service` - The status page service that will be used to delete the status page.
+///
+/// # Returns
+///
+/// An http response.
+#[delete("/{id}")]
+async fn delete_status_page(
+    request: HttpRequest,
+    id: web::Path<u64>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    status_page_service: Inject<DependencyInjector, dyn StatusPageService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the status page that is being deleted.
+    let status_page: StatusPage = match status_page_service.get(&id).await {
+        QueryResult::Ok(status_page) => status_page,
+        QueryResult::NotFound => return HttpResponse::NotFound().finish(),
+        QueryResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
+    // If the user is not the owner of the status page, they are not allowed to delete it.
+    if user.id != status_page.user_id {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    // Delete the status page.
+    return match status_page_service.delete(&id).await {
+        DeletionResult::Ok => HttpResponse::Ok().finish(),
+        DeletionResult::NotFound => HttpResponse::NotFound().finish(),
+        DeletionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// Get the public view of a status page's monitor groups, containing the current status and, if
+/// the status page shows it, the uptime history of each monitor. Monitors that no longer exist are
+/// left out.
+///
+/// # Arguments
+///
+/// `status_page` - The status page whose monitor groups are being retrieved.
+///
+/// `monitor_service` - The monitor service that will be used to retrieve the monitors.
+///
+/// `check_result_service` - The check result service that will be used to determine the status and
+/// uptime history of the monitors.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the monitor groups were retrieved successfully, the Ok variant will be returned with them.
+/// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+async fn get_monitor_groups(
+    status_page: &StatusPage,
+    monitor_service: &dyn MonitorService,
+    check_result_service: &dyn CheckResultService,
+) -> Result<Vec<GetStatusPageMonitorGroupResponseBody>, Box<dyn Error>> {
+    // Determine the time range the uptime history covers.
+    let to: OffsetDateTime = OffsetDateTime::now_utc();
+    let from: OffsetDateTime =
+        get_bucket_start(to, SeriesBucketWidth::Day) - Duration::days(UPTIME_HISTORY_DAYS - 1);
+
+    let mut monitor_groups: Vec<GetStatusPageMonitorGroupResponseBody> = Vec::new();
+    for monitor_group in status_page.monitor_groups.iter() {
+        let mut monitors: Vec<GetStatusPageMonitorResponseBody> = Vec::new();
+        for monitor_id in &monitor_group.monitor_ids {
+            // Get the monitor.
+            let monitor: Monitor = match monitor_service.get(monitor_id).await {
+                QueryResult::Ok(monitor) => monitor,
+                QueryResult::NotFound => continue,
+                QueryResult::Err(error) => return Err(error),
+            };
+
+            // Determine the monitor's current status from its most recent check.
+            let status: Option<CheckStatus> = if monitor.is_enabled {
+                match check_result_service.get_by_monitor_id(monitor_id, &1).await {
+                    QueryResult::Ok(check_results) => check_results
+                        .first()
+                        .map(|check_result| check_result.status),
+                    QueryResult::NotFound => None,
+                    QueryResult::Err(error) => return Err(error),
+                }
+            } else {
+                None
+            };
+
+            // Get the monitor's daily uptime, if the status page shows it.
+            let uptime_history: Option<Vec<GetStatusPageUptimeResponseBody>> =
+                if status_page.show_uptime_history {
+                    match check_result_service
+                        .get_series(monitor_id, &from, &to, &SeriesBucketWidth::Day)
+                        .await
+                    {
+                        QueryResult::Ok(series) => Some(
+                            series
+                                .into_iter()
+                                .map(|bucket| GetStatusPageUptimeResponseBody {
+                                    start: bucket.start,
+                                    uptime_percentage: bucket.uptime_percentage,
+                                })
+                                .collect(),
+                        ),
+                        QueryResult::NotFound => None,
+                        QueryResult::Err(error) => return Err(error),
+                    }
+                } else {
+                    None
+                };
+
+            monitors.push(GetStatusPageMonitorResponseBody {
+                name: monitor.name,
+                status,
+                uptime_history,
+            });
+        }
+
+        monitor_groups.push(GetStatusPageMonitorGroupResponseBody {
+            name: monitor_group.name.clone(),
+            monitors,
+        });
+    }
+
+    return Ok(monitor_groups);
+}
@@ -0,0 +1,179 @@
+use crate::common::utility::create_value_validation_error;
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use time::OffsetDateTime;
+use validator::{Validate, ValidationError};
+
+/// The maximum amount of monitor groups a status page can contain.
+const MAXIMUM_MONITOR_GROUPS: usize = 32;
+
+/// The maximum amount of monitors a monitor group can contain.
+const MAXIMUM_GROUP_MONITORS: usize = 100;
+
+/// A status page struct, which publicly shows the status of a user's monitors.
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StatusPage {
+    /// The status page's unique identifier.
+    pub(crate) id: u64,
+
+    /// The unique identifier of the user that the status page belongs to.
+    pub(crate) user_id: u64,
+
+    /// The date and time the status page was created at.
+    #[serde(with = "time::serde::rfc3339")]
+    pub(crate) status_page_created_at: OffsetDateTime,
+
+    /// The slug the status page is served at.
+    #[validate(custom(function = "validate_slug"))]
+    pub(crate) slug: String,
+
+    /// The status page's title.
+    #[validate(non_control_character, length(min = 1, max = 256))]
+    pub(crate) title: String,
+
+    /// The unique identifier of the file that contains the status page's logo, if it has one.
+    pub(crate) logo_file_id: Option<u64>,
+
+    /// If the uptime history of each monitor is shown on the status page.
+    pub(crate) show_uptime_history: bool,
+
+    /// The groups of monitors that are shown on the status page, in the order they are shown in.
+    #[validate(custom(function = "validate_monitor_groups"))]
+    pub(crate) monitor_groups: Json<Vec<StatusPageMonitorGroup>>,
+}
+
+/// A Default implementation for the StatusPage struct.
+impl Default for StatusPage {
+    fn default() -> Self {
+        return StatusPage {
+            id: 0,
+            user_id: 0,
+            status_page_created_at: OffsetDateTime::now_utc(),
+            slug: String::from(""),
+            title: String::from(""),
+            logo_file_id: None,
+            show_uptime_history: true,
+            monitor_groups: Json(Vec::new()),
+        };
+    }
+}
+
+/// A PartialEq implementation for the StatusPage struct.
+impl PartialEq for StatusPage {
+    fn eq(&self, other: &Self) -> bool {
+        return self.id == other.id
+            && self.user_id == other.user_id
+            && self.status_page_created_at.date() == other.status_page_created_at.date()
+            && self.status_page_created_at.hour() == other.status_page_created_at.hour()
+            && self.status_page_created_at.minute() == other.status_page_created_at.minute()
+            && self.status_page_created_at.second() == other.status_page_created_at.second()
+            && self.slug == other.slug
+            && self.title == other.title
+            && self.logo_file_id == other.logo_file_id
+            && self.show_uptime_history == other.show_uptime_history
+            && self.monitor_groups == other.monitor_groups;
+    }
+
+    fn ne(&self, other: &Self) -> bool {
+        return !self.eq(other);
+    }
+}
+
+/// A status page monitor group struct, which is a named group of monitors shown on a status page.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct StatusPageMonitorGroup {
+    /// The group's name.
+    pub(crate) name: String,
+
+    /// The unique identifiers of the monitors in the group, in the order they are shown in.
+    pub(crate) monitor_ids: Vec<u64>,
+}
+
+/// # Description
+///
+/// Validate a status page's slug, which must be between 3 and 64 characters long, only contain
+/// lowercase letters, digits and hyphens, and not start or end with a hyphen.
+///
+/// # Arguments
+///
+/// `slug` - The slug that is being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the slug is valid, the Ok variant will be returned.
+/// - If the slug is not valid, the Err variant will be returned with a validation error.
+fn validate_slug(slug: &String) -> Result<(), ValidationError> {
+    if slug.len() < 3 || slug.len() > 64 {
+        return Err(create_value_validation_error("length", slug));
+    }
+
+    if slug.starts_with('-')
+        || slug.ends_with('-')
+        || !slug
+            .chars()
+            .all(|character| matches!(character, 'a'..='z' | '0'..='9' | '-'))
+    {
+        return Err(create_value_validation_error("slug", slug));
+    }
+
+    return Ok(());
+}
+
+/// # Description
+///
+/// Validate the monitor groups of a status page.
+///
+/// # Arguments
+///
+/// `monitor_groups` - The monitor groups that are being validated.
+///
+/// # Returns
+///
+/// This function returns a result:
+/// - If the monitor groups are valid, the Ok variant will be returned.
+/// - If there are too many groups, a group's name or size is not valid, or a monitor appears more
+/// than once, the Err variant will be returned with a validation error.
+fn validate_monitor_groups(
+    monitor_groups: &Json<Vec<StatusPageMonitorGroup>>,
+) -> Result<(), ValidationError> {
+    if monitor_groups.len() > MAXIMUM_MONITOR_GROUPS {
+        return Err(create_value_validation_error(
+            "length",
+            &monitor_groups.len(),
+        ));
+    }
+
+    let mut monitor_ids: Vec<u64> = Vec::new();
+    for monitor_group in monitor_groups.iter() {
+        // Make sure the group's name is valid.
+        if monitor_group.name.is_empty()
+            || monitor_group.name.chars().count() > 256
+            || monitor_group.name.chars().any(char::is_control)
+        {
+            return Err(create_value_validation_error("name", &monitor_group.name));
+        }
+
+        // Make sure the group contains a valid amount of monitors.
+        if monitor_group.monitor_ids.is_empty()
+            || monitor_group.monitor_ids.len() > MAXIMUM_GROUP_MONITORS
+        {
+            return Err(create_value_validation_error(
+                "length",
+                &monitor_group.monitor_ids.len(),
+            ));
+        }
+
+        // Make sure each monitor is only shown once.
+        for monitor_id in &monitor_group.monitor_ids {
+            if monitor_ids.contains(monitor_id) {
+                return Err(create_value_validation_error("duplicate", monitor_id));
+            }
+            monitor_ids.push(*monitor_id);
+        }
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,357 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::status_page::model::{StatusPage, StatusPageMonitorGroup},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{types::Json, Error, Row};
+use time::OffsetDateTime;
+
+/// A status page repository trait.
+#[async_trait]
+pub(crate) trait StatusPageRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a status page into the status page repository.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page to insert into the status page repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// status page that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Get a status page from the status page repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the status page to get from the status page repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional status page.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<StatusPage>, Error>;
+
+    /// # Description
+    ///
+    /// Get a status page from the status page repository by it's slug.
+    ///
+    /// # Arguments
+    ///
+    /// `slug` - The slug of the status page to get from the status page repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional status page.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_slug(
+        &self,
+        slug: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<StatusPage>, Error>;
+
+    /// # Description
+    ///
+    /// Get all of the status pages that belong to a user from the status page repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the status pages belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the status pages that
+    /// were found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<StatusPage>, Error>;
+
+    /// # Description
+    ///
+    /// Update a status page in the status page repository.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page to update in the status page repository.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// modified.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete a status page from the status page repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the status page to delete from the status page repository.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+}
+
+/// A StatusPageRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = StatusPageRepository)]
+pub(crate) struct StatusPageRepositoryImpl;
+
+/// A StatusPageRepository implementation for the StatusPageRepositoryImpl struct.
+#[async_trait]
+impl StatusPageRepository for StatusPageRepositoryImpl {
+    async fn insert(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `status_pages` (
+                    `id`,
+                    `user_id`,
+                    `status_page_created_at`,
+                    `slug`,
+                    `title`,
+                    `logo_file_id`,
+                    `show_uptime_history`,
+                    `monitor_groups`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            status_page.id,
+            status_page.user_id,
+            status_page.status_page_created_at,
+            status_page.slug,
+            status_page.title,
+            status_page.logo_file_id,
+            status_page.show_uptime_history,
+            &status_page.monitor_groups
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get(&self, id: &u64, context: &mut QueryContext) -> Result<Option<StatusPage>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            StatusPage,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `status_page_created_at` AS `status_page_created_at: OffsetDateTime`,
+                    `slug` AS `slug: String`,
+                    `title` AS `title: String`,
+                    `logo_file_id` AS `logo_file_id: u64`,
+                    `show_uptime_history` AS `show_uptime_history: bool`,
+                    `monitor_groups` AS `monitor_groups: Json<Vec<StatusPageMonitorGroup>>`
+                FROM
+                    `status_pages`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_slug(
+        &self,
+        slug: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<StatusPage>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            StatusPage,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `status_page_created_at` AS `status_page_created_at: OffsetDateTime`,
+                    `slug` AS `slug: String`,
+                    `title` AS `title: String`,
+                    `logo_file_id` AS `logo_file_id: u64`,
+                    `show_uptime_history` AS `show_uptime_history: bool`,
+                    `monitor_groups` AS `monitor_groups: Json<Vec<StatusPageMonitorGroup>>`
+                FROM
+                    `status_pages`
+                WHERE
+                    `slug` = ?;
+            "#,
+            slug
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<StatusPage>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            StatusPage,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `status_page_created_at` AS `status_page_created_at: OffsetDateTime`,
+                    `slug` AS `slug: String`,
+                    `title` AS `title: String`,
+                    `logo_file_id` AS `logo_file_id: u64`,
+                    `show_uptime_history` AS `show_uptime_history: bool`,
+                    `monitor_groups` AS `monitor_groups: Json<Vec<StatusPageMonitorGroup>>`
+                FROM
+                    `status_pages`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `id` ASC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
+    async fn update(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `status_pages`
+                SET
+                    `user_id` = ?,
+                    `status_page_created_at` = ?,
+                    `slug` = ?,
+                    `title` = ?,
+                    `logo_file_id` = ?,
+                    `show_uptime_history` = ?,
+                    `monitor_groups` = ?
+                WHERE
+                    `id` = ?;
+            "#,
+            status_page.user_id,
+            status_page.status_page_created_at,
+            status_page.slug,
+            status_page.title,
+            status_page.logo_file_id,
+            status_page.show_uptime_history,
+            &status_page.monitor_groups,
+            status_page.id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `status_pages`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
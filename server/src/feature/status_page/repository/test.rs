@@ -0,0 +1,453 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        monitor::{
+            model::{HttpMethod, HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        status_page::{
+            model::{StatusPage, StatusPageMonitorGroup},
+            repository::StatusPageRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, types::Json, Connection, MySql};
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration {
+            url: format!("https://{}.com/", generate_random_string(8)),
+            method: HttpMethod::Get,
+            expected_status_codes: vec![200],
+            assertions: Vec::new(),
+        }),
+    };
+}
+
+/// # Description
+///
+/// Create a status page that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the status page will belong to.
+///
+/// `monitor` - The monitor that will be shown on the status page.
+///
+/// # Returns
+///
+/// The status page that was created.
+fn create_test_status_page(user: &User, monitor: &Monitor) -> StatusPage {
+    return StatusPage {
+        id: 0,
+        user_id: user.id,
+        status_page_created_at: OffsetDateTime::now_utc(),
+        slug: generate_random_string(16).to_lowercase(),
+        title: generate_random_string(16),
+        logo_file_id: None,
+        show_uptime_history: true,
+        monitor_groups: Json(vec![StatusPageMonitorGroup {
+            name: generate_random_string(8),
+            monitor_ids: vec![monitor.id],
+        }]),
+    };
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// `context` - The query context the monitor will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert_with_context(&monitor, context).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a status page into the repository, and make sure it can be queried by its id,
+/// slug and owner until it is deleted.
+#[actix_web::test]
+async fn status_page_is_queryable_until_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a status page repository instance.
+    let status_page_repository: Arc<dyn StatusPageRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert the status page.
+    let mut status_page: StatusPage = create_test_status_page(&user, &monitor);
+    status_page.id = status_page_repository
+        .insert(&status_page, &mut context)
+        .await
+        .expect("Failed to insert status page");
+    assert!(status_page.id > 0);
+
+    // Make sure the status page can be queried by its id.
+    let queried_status_page: StatusPage = status_page_repository
+        .get(&status_page.id, &mut context)
+        .await
+        .expect("Failed to query status page: An error occurred")
+        .expect("Failed to query status page: Status page not found");
+    assert_eq!(status_page, queried_status_page);
+
+    // Make sure the status page can be queried by its slug.
+    let queried_status_page: StatusPage = status_page_repository
+        .get_by_slug(&status_page.slug, &mut context)
+        .await
+        .expect("Failed to query status page by slug: An error occurred")
+        .expect("Failed to query status page by slug: Status page not found");
+    assert_eq!(status_page, queried_status_page);
+
+    // Make sure the status page can be queried by its owner.
+    let queried_status_pages: Vec<StatusPage> = status_page_repository
+        .get_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query status pages by user id");
+    assert_eq!(vec![status_page.clone()], queried_status_pages);
+
+    // Delete the status page, and make sure it can no longer be queried.
+    let rows_deleted = status_page_repository
+        .delete(&status_page.id, &mut context)
+        .await
+        .expect("Failed to delete status page");
+    assert_eq!(1, rows_deleted);
+    assert!(status_page_repository
+        .get_by_slug(&status_page.slug, &mut context)
+        .await
+        .expect("Failed to query status page after deletion")
+        .is_none());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test updating a status page in the repository, and make sure the changes are persisted.
+#[actix_web::test]
+async fn status_page_is_updatable_after_insertion() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a status page repository instance.
+    let status_page_repository: Arc<dyn StatusPageRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user and two monitors.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let first_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+    let second_monitor: Monitor = insert_test_monitor(&user, &injector, &mut context).await;
+
+    // Insert the status page.
+    let mut status_page: StatusPage = create_test_status_page(&user, &first_monitor);
+    status_page.id = status_page_repository
+        .insert(&status_page, &mut context)
+        .await
+        .expect("Failed to insert status page");
+
+    // Update the status page.
+    status_page.slug = generate_random_string(16).to_lowercase();
+    status_page.title = generate_random_string(16);
+    status_page.show_uptime_history = false;
+    status_page.monitor_groups.push(StatusPageMonitorGroup {
+        name: generate_random_string(8),
+        monitor_ids: vec![second_monitor.id],
+    });
+    let rows_updated = status_page_repository
+        .update(&status_page, &mut context)
+        .await
+        .expect("Failed to update status page");
+    assert_eq!(1, rows_updated);
+
+    // Make sure the changes were persisted, and the group order was kept.
+    let queried_status_page: StatusPage = status_page_repository
+        .get(&status_page.id, &mut context)
+        .await
+        .expect("Failed to query status page: An error occurred")
+        .expect("Failed to query status page: Status page not found");
+    assert_eq!(status_page, queried_status_page);
+    assert_eq!(
+        vec![first_monitor.id, second_monitor.id],
+        queried_status_page
+            .monitor_groups
+            .iter()
+            .flat_map(|monitor_group| monitor_group.monitor_ids.clone())
+            .collect::<Vec<u64>>()
+    );
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
@@ -0,0 +1,745 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
+        utility::create_value_validation_error,
+    },
+    database::DatabaseConnectionFactory,
+    feature::{
+        file::service::FileService,
+        monitor::service::MonitorService,
+        status_page::{model::StatusPage, repository::StatusPageRepository},
+        user::service::UserService,
+    },
+};
+use async_trait::async_trait;
+use nameof::name_of;
+use shaku::{Component, Interface};
+use sqlx::Connection;
+use std::{error::Error, io, sync::Arc};
+use validator::{Validate, ValidationErrors};
+
+/// A status page service trait.
+#[async_trait(?Send)]
+pub(crate) trait StatusPageService: Interface {
+    /// # Description
+    ///
+    /// Insert a status page into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page that will be inserted into persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the status page that was inserted.
+    /// - If the status page being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert(
+        &self,
+        status_page: &StatusPage,
+    ) -> InsertionResult<StatusPage, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Insert a status page into persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page that will be inserted into persistent storage.
+    ///
+    /// `context` - The context the insertion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the insertion is successful, the Ok variant will be returned with the status page that was inserted.
+    /// - If the status page being inserted contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn insert_with_context(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> InsertionResult<StatusPage, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a status page from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the status page that is being queried from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the status page, the Ok variant will be returned with the status page.
+    /// - If the status page could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get(&self, id: &u64) -> QueryResult<StatusPage, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a status page from persistent storage by it's unique identifier.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the status page that is being queried from persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the status page, the Ok variant will be returned with the status page.
+    /// - If the status page could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<StatusPage, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a status page from persistent storage by it's slug.
+    ///
+    /// # Arguments
+    ///
+    /// `slug` - The slug of the status page that is being queried from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the status page, the Ok variant will be returned with the status page.
+    /// - If the status page could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_slug(&self, slug: &String) -> QueryResult<StatusPage, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a status page from persistent storage by it's slug.
+    ///
+    /// # Arguments
+    ///
+    /// `slug` - The slug of the status page that is being queried from persistent storage.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful and finds the status page, the Ok variant will be returned with the status page.
+    /// - If the status page could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_slug_with_context(
+        &self,
+        slug: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<StatusPage, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the status pages that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the status pages belong to.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the status pages that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id(&self, user_id: &u64) -> QueryResult<Vec<StatusPage>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query all of the status pages that belong to a user from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The unique identifier of the user the status pages belong to.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the query is successful, the Ok variant will be returned with the status pages that were
+    /// found (this may be empty).
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<StatusPage>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a status page in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page that is being updated in persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the status page updated.
+    /// - If the status page that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the status page being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update(
+        &self,
+        status_page: &StatusPage,
+    ) -> UpdateResult<StatusPage, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Update a status page in persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page that is being updated in persistent storage.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the update is successful, the Ok variant will be returned with the status page updated.
+    /// - If the status page that is being updated could not be found, the NotFound variant will be returned.
+    /// - If the status page being updated contains validation errors, the Invalid variant will be returned
+    /// with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn update_with_context(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> UpdateResult<StatusPage, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a status page from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the status page that is being removed from persistent storage.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a status page was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the status page being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Remove a status page from persistent storage.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The unique identifier of the status page that is being removed from persistent storage.
+    ///
+    /// `context` - The context the deletion will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a deletion result:
+    /// - If the deletion is successful and a status page was successfully deleted, the Ok variant will be
+    /// returned.
+    /// - If the status page being deleted could not be found, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>>;
+}
+
+/// A StatusPageServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = StatusPageService)]
+pub(crate) struct StatusPageServiceImpl {
+    /// The status page repository that will be used to manage persistent status page data.
+    #[shaku(inject)]
+    status_page_repository: Arc<dyn StatusPageRepository>,
+
+    /// The user service that will be used to validate user data.
+    #[shaku(inject)]
+    user_service: Arc<dyn UserService>,
+
+    /// The monitor service that will be used to validate the monitors shown on status pages.
+    #[shaku(inject)]
+    monitor_service: Arc<dyn MonitorService>,
+
+    /// The file service that will be used to validate the logos of status pages.
+    #[shaku(inject)]
+    file_service: Arc<dyn FileService>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+}
+
+/// A StatusPageService implementation for the StatusPageServiceImpl struct.
+#[async_trait(?Send)]
+impl StatusPageService for StatusPageServiceImpl {
+    async fn insert(
+        &self,
+        status_page: &StatusPage,
+    ) -> InsertionResult<StatusPage, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the insertion.
+        let insertion_result = self.insert_with_context(status_page, &mut context).await;
+
+        // If the insertion was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn insert_with_context(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> InsertionResult<StatusPage, ValidationErrors, Box<dyn Error>> {
+        // Validate the status page.
+        let mut validation_errors = match status_page.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the status page refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(status_page, None, &mut validation_errors, context)
+            .await
+        {
+            return InsertionResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return InsertionResult::Invalid(validation_errors);
+        }
+
+        // Perform the insertion.
+        let status_page_id = match __self
+            .status_page_repository
+            .insert(status_page, context)
+            .await
+        {
+            Ok(status_page_id) => status_page_id,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Query the status page that was inserted.
+        let inserted_status_page_option = match __self
+            .status_page_repository
+            .get(&status_page_id, context)
+            .await
+        {
+            Ok(inserted_status_page_option) => inserted_status_page_option,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // If the status page was found, return the status page, otherwise return an error.
+        return match inserted_status_page_option {
+            Some(inserted_status_page) => InsertionResult::Ok(inserted_status_page),
+            None => InsertionResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Status page could not be found after insertion",
+            ))),
+        };
+    }
+
+    async fn get(&self, id: &u64) -> QueryResult<StatusPage, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_with_context(id, &mut context).await;
+    }
+
+    async fn get_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<StatusPage, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self.status_page_repository.get(id, context).await {
+            Ok(status_page_option) => status_page_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the status page was found, return the status page, otherwise return not found.
+        return match result {
+            Some(status_page) => QueryResult::Ok(status_page),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_slug(&self, slug: &String) -> QueryResult<StatusPage, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self.get_by_slug_with_context(slug, &mut context).await;
+    }
+
+    async fn get_by_slug_with_context(
+        &self,
+        slug: &String,
+        context: &mut QueryContext,
+    ) -> QueryResult<StatusPage, Box<dyn Error>> {
+        // Perform the query.
+        let result = match __self
+            .status_page_repository
+            .get_by_slug(slug, context)
+            .await
+        {
+            Ok(status_page_option) => status_page_option,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // If the status page was found, return the status page, otherwise return not found.
+        return match result {
+            Some(status_page) => QueryResult::Ok(status_page),
+            None => QueryResult::NotFound,
+        };
+    }
+
+    async fn get_by_user_id(&self, user_id: &u64) -> QueryResult<Vec<StatusPage>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the query.
+        return self
+            .get_by_user_id_with_context(user_id, &mut context)
+            .await;
+    }
+
+    async fn get_by_user_id_with_context(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> QueryResult<Vec<StatusPage>, Box<dyn Error>> {
+        // Perform the query and return the status pages that were found.
+        return match __self
+            .status_page_repository
+            .get_by_user_id(user_id, context)
+            .await
+        {
+            Ok(status_pages) => QueryResult::Ok(status_pages),
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update(
+        &self,
+        status_page: &StatusPage,
+    ) -> UpdateResult<StatusPage, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the update.
+        let update_result = self.update_with_context(status_page, &mut context).await;
+
+        // If the update was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn update_with_context(
+        &self,
+        status_page: &StatusPage,
+        context: &mut QueryContext,
+    ) -> UpdateResult<StatusPage, ValidationErrors, Box<dyn Error>> {
+        // Query the existing status page.
+        let existing_status_page = match __self
+            .status_page_repository
+            .get(&status_page.id, context)
+            .await
+        {
+            Ok(Some(existing_status_page)) => existing_status_page,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Validate the status page.
+        let mut validation_errors = match status_page.validate() {
+            Ok(()) => ValidationErrors::new(),
+            Err(errors) => errors,
+        };
+
+        // Make sure the records the status page refers to exist and belong to its owner.
+        if let Err(error) = self
+            .validate_references(
+                status_page,
+                Some(&existing_status_page),
+                &mut validation_errors,
+                context,
+            )
+            .await
+        {
+            return UpdateResult::Err(error);
+        }
+
+        // If any validation errors exist, return them.
+        if !validation_errors.is_empty() {
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Perform the update.
+        let records_updated = match __self
+            .status_page_repository
+            .update(&status_page, context)
+            .await
+        {
+            Ok(records_updated) => records_updated,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // If no records were updated.
+        if records_updated == 0 {
+            return UpdateResult::NotFound;
+        }
+
+        // Query the updated status page.
+        let updated_status_page_option = match __self
+            .status_page_repository
+            .get(&status_page.id, context)
+            .await
+        {
+            Ok(updated_status_page_option) => updated_status_page_option,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the status page was found, and return the updated status page.
+        return match updated_status_page_option {
+            Some(updated_status_page) => UpdateResult::Ok(updated_status_page),
+            None => UpdateResult::Err(Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Status page could not be found after update",
+            ))),
+        };
+    }
+
+    async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the deletion.
+        return self.delete_with_context(id, &mut context).await;
+    }
+
+    async fn delete_with_context(
+        &self,
+        id: &u64,
+        context: &mut QueryContext,
+    ) -> DeletionResult<Box<dyn Error>> {
+        // Perform the deletion.
+        let records_deleted = match __self.status_page_repository.delete(id, context).await {
+            Ok(records_deleted) => records_deleted,
+            Err(error) => return DeletionResult::Err(Box::new(error)),
+        };
+
+        // Return the result.
+        if records_deleted > 0 {
+            return DeletionResult::Ok;
+        } else {
+            return DeletionResult::NotFound;
+        }
+    }
+}
+
+/// An implementation for the StatusPageServiceImpl struct.
+impl StatusPageServiceImpl {
+    /// # Description
+    ///
+    /// Validate the records a status page refers to. The status page's owner must exist, its slug
+    /// must not be used by another status page, and its logo and monitors must belong to its owner.
+    ///
+    /// # Arguments
+    ///
+    /// `status_page` - The status page that is being validated.
+    ///
+    /// `existing_status_page` - The status page as it currently exists in persistent storage, if it
+    /// is being updated.
+    ///
+    /// `validation_errors` - The validation errors that any errors that are detected will be added
+    /// to.
+    ///
+    /// `context` - The context the validation will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the validation could be performed, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn validate_references(
+        &self,
+        status_page: &StatusPage,
+        existing_status_page: Option<&StatusPage>,
+        validation_errors: &mut ValidationErrors,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        // Check if the user id specified exists.
+        match self
+            .user_service
+            .get_by_id_with_context(&status_page.user_id, context)
+            .await
+        {
+            QueryResult::Ok(_) => {}
+            QueryResult::NotFound => validation_errors.add(
+                name_of!(user_id in StatusPage),
+                create_value_validation_error("not_found", &status_page.user_id),
+            ),
+            QueryResult::Err(error) => return Err(error),
+        }
+
+        // If the slug is new, make sure it is not used by another status page.
+        if existing_status_page.map(|existing_status_page| &existing_status_page.slug)
+            != Some(&status_page.slug)
+        {
+            match self
+                .status_page_repository
+                .get_by_slug(&status_page.slug, context)
+                .await
+            {
+                Ok(Some(_)) => validation_errors.add(
+                    name_of!(slug in StatusPage),
+                    create_value_validation_error("unique", &status_page.slug),
+                ),
+                Ok(None) => {}
+                Err(error) => return Err(Box::new(error)),
+            }
+        }
+
+        // If the status page has a logo, make sure it is an image that belongs to the owner.
+        if let Some(logo_file_id) = &status_page.logo_file_id {
+            match self
+                .file_service
+                .get_with_context(logo_file_id, context)
+                .await
+            {
+                QueryResult::Ok(file) if file.user_id != status_page.user_id => validation_errors
+                    .add(
+                        name_of!(logo_file_id in StatusPage),
+                        create_value_validation_error("not_found", logo_file_id),
+                    ),
+                QueryResult::Ok(file) if !file.mime_type.starts_with("image/") => validation_errors
+                    .add(
+                        name_of!(logo_file_id in StatusPage),
+                        create_value_validation_error("mime_type", &file.mime_type),
+                    ),
+                QueryResult::Ok(_) => {}
+                QueryResult::NotFound => validation_errors.add(
+                    name_of!(logo_file_id in StatusPage),
+                    create_value_validation_error("not_found", logo_file_id),
+                ),
+                QueryResult::Err(error) => return Err(error),
+            }
+        }
+
+        // Make sure each monitor shown on the status page belongs to the owner.
+        for monitor_group in status_page.monitor_groups.iter() {
+            for monitor_id in &monitor_group.monitor_ids {
+                match self
+                    .monitor_service
+                    .get_with_context(monitor_id, context)
+                    .await
+                {
+                    QueryResult::Ok(monitor) if monitor.user_id == status_page.user_id => {}
+                    QueryResult::Ok(_) | QueryResult::NotFound => validation_errors.add(
+                        name_of!(monitor_groups in StatusPage),
+                        create_value_validation_error("not_found", monitor_id),
+                    ),
+                    QueryResult::Err(error) => return Err(error),
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
@@ -0,0 +1,415 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, UpdateResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        file::{model::File, service::FileService},
+        monitor::{
+            model::{HttpMonitorConfiguration, Monitor, MonitorConfiguration},
+            service::MonitorService,
+        },
+        status_page::{
+            model::{StatusPage, StatusPageMonitorGroup},
+            service::StatusPageService,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::types::Json;
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Create a monitor that can be used for testing.
+///
+/// # Returns
+///
+/// The monitor that was created.
+fn create_test_monitor() -> Monitor {
+    return Monitor {
+        id: 0,
+        user_id: 0,
+        monitor_created_at: OffsetDateTime::now_utc(),
+        name: generate_random_string(8),
+        check_interval: 60,
+        check_timeout: 10,
+        is_enabled: true,
+        configuration: MonitorConfiguration::Http(HttpMonitorConfiguration::default()),
+    };
+}
+
+/// # Description
+///
+/// Create a status page that can be used for testing.
+///
+/// # Arguments
+///
+/// `user` - The user the status page will belong to.
+///
+/// `monitor` - The monitor that will be shown on the status page.
+///
+/// # Returns
+///
+/// The status page that was created.
+fn create_test_status_page(user: &User, monitor: &Monitor) -> StatusPage {
+    return StatusPage {
+        id: 0,
+        user_id: user.id,
+        status_page_created_at: OffsetDateTime::now_utc(),
+        slug: generate_random_string(16).to_lowercase(),
+        title: generate_random_string(16),
+        logo_file_id: None,
+        show_uptime_history: true,
+        monitor_groups: Json(vec![StatusPageMonitorGroup {
+            name: generate_random_string(8),
+            monitor_ids: vec![monitor.id],
+        }]),
+    };
+}
+
+/// # Description
+///
+/// Insert a test file with the file service.
+///
+/// # Arguments
+///
+/// `user` - The user the file will belong to.
+///
+/// `mime_type` - The file's mime type.
+///
+/// `injector` - The dependency injector that will be used to acquire a file service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the file with the file
+/// service.
+///
+/// # Returns
+///
+/// The file that was inserted.
+async fn insert_test_file(user: &User, mime_type: &str, injector: &DependencyInjector) -> File {
+    // Get a file service instance.
+    let file_service: Arc<dyn FileService> = injector.resolve();
+
+    // Create the file.
+    let file: File = File {
+        user_id: user.id,
+        mime_type: String::from(mime_type),
+        name: generate_random_string(8),
+        data: generate_random_string(64).into_bytes(),
+        ..Default::default()
+    };
+
+    // Perform the insertion.
+    let file: File = match file_service.insert(&file).await {
+        InsertionResult::Ok(file) => file,
+        InsertionResult::Invalid(details) => panic!("Failed to insert file: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert file: {}", error),
+    };
+
+    // Return the file.
+    return file;
+}
+
+/// # Description
+///
+/// Insert a test monitor with the monitor service.
+///
+/// # Arguments
+///
+/// `user` - The user the monitor will belong to.
+///
+/// `injector` - The dependency injector that will be used to acquire a monitor service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the monitor with the
+/// monitor service.
+///
+/// # Returns
+///
+/// The monitor that was inserted.
+async fn insert_test_monitor(user: &User, injector: &DependencyInjector) -> Monitor {
+    // Get a monitor service instance.
+    let monitor_service: Arc<dyn MonitorService> = injector.resolve();
+
+    // Create the monitor.
+    let mut monitor: Monitor = create_test_monitor();
+    monitor.user_id = user.id;
+
+    // Perform the insertion.
+    let monitor: Monitor = match monitor_service.insert(&monitor).await {
+        InsertionResult::Ok(monitor) => monitor,
+        InsertionResult::Invalid(details) => panic!("Failed to insert monitor: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert monitor: {}", error),
+    };
+
+    // Return the monitor.
+    return monitor;
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(user: &User, injector: &DependencyInjector) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Test inserting status pages with the same slug, and make sure only the first one succeeds while
+/// the first one can still be updated without changing its slug.
+#[actix_web::test]
+async fn status_page_slugs_must_be_unique() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a status page service instance.
+    let status_page_service: Arc<dyn StatusPageService> = injector.resolve();
+
+    // Insert a test user and monitor.
+    let user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+
+    // Insert the first status page.
+    let mut status_page: StatusPage = match status_page_service
+        .insert(&create_test_status_page(&user, &monitor))
+        .await
+    {
+        InsertionResult::Ok(status_page) => status_page,
+        InsertionResult::Invalid(details) => panic!("Failed to insert status page: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert status page: {}", error),
+    };
+
+    // Insert a second status page with the same slug, and make sure the slug is rejected.
+    let mut duplicate_status_page: StatusPage = create_test_status_page(&user, &monitor);
+    duplicate_status_page.slug = status_page.slug.clone();
+    match status_page_service.insert(&duplicate_status_page).await {
+        InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+        InsertionResult::Invalid(details) => {
+            assert!(details.field_errors().contains_key("slug"))
+        }
+        InsertionResult::Err(error) => panic!(
+            "Failed to insert status page, an unexpected error has occurred: {}",
+            error
+        ),
+    };
+
+    // Update the first status page without changing its slug, and make sure it succeeds.
+    status_page.title = generate_random_string(16);
+    match status_page_service.update(&status_page).await {
+        UpdateResult::Ok(updated_status_page) => assert_eq!(status_page, updated_status_page),
+        UpdateResult::NotFound => panic!("Failed to update status page: Status page not found"),
+        UpdateResult::Invalid(details) => panic!("Failed to update status page: {}", details),
+        UpdateResult::Err(error) => panic!("Failed to update status page: {}", error),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &injector).await;
+}
+
+/// # Description
+///
+/// Test inserting status pages that show another user's monitor or logo, or a logo that is not an
+/// image, and make sure they do not succeed.
+#[actix_web::test]
+async fn status_pages_can_only_use_their_owners_monitors_and_images() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a status page service instance.
+    let status_page_service: Arc<dyn StatusPageService> = injector.resolve();
+
+    // Insert two test users, each with a monitor and files.
+    let user: User = insert_test_user(&injector).await;
+    let other_user: User = insert_test_user(&injector).await;
+    let monitor: Monitor = insert_test_monitor(&user, &injector).await;
+    let other_monitor: Monitor = insert_test_monitor(&other_user, &injector).await;
+    let logo: File = insert_test_file(&user, "image/png", &injector).await;
+    let text_file: File = insert_test_file(&user, "text/plain", &injector).await;
+    let other_logo: File = insert_test_file(&other_user, "image/png", &injector).await;
+
+    // Make sure each status page that refers to records it may not use is rejected.
+    let invalid_status_pages: Vec<(StatusPage, &str)> = vec![
+        (
+            create_test_status_page(&user, &other_monitor),
+            "monitor_groups",
+        ),
+        (
+            StatusPage {
+                logo_file_id: Some(other_logo.id),
+                ..create_test_status_page(&user, &monitor)
+            },
+            "logo_file_id",
+        ),
+        (
+            StatusPage {
+                logo_file_id: Some(text_file.id),
+                ..create_test_status_page(&user, &monitor)
+            },
+            "logo_file_id",
+        ),
+    ];
+    for (status_page, field) in invalid_status_pages {
+        match status_page_service.insert(&status_page).await {
+            InsertionResult::Ok(_) => panic!("Insertion succeeded when it should have failed"),
+            InsertionResult::Invalid(details) => {
+                assert!(details.field_errors().contains_key(field))
+            }
+            InsertionResult::Err(error) => panic!(
+                "Failed to insert status page, an unexpected error has occurred: {}",
+                error
+            ),
+        };
+    }
+
+    // Make sure a status page that only uses the owner's records is accepted.
+    let status_page: StatusPage = StatusPage {
+        logo_file_id: Some(logo.id),
+        ..create_test_status_page(&user, &monitor)
+    };
+    match status_page_service.insert(&status_page).await {
+        InsertionResult::Ok(inserted_status_page) => {
+            assert_eq!(Some(logo.id), inserted_status_page.logo_file_id)
+        }
+        InsertionResult::Invalid(details) => panic!("Failed to insert status page: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert status page: {}", error),
+    };
+
+    // Delete the test users.
+    delete_test_user(&user, &injector).await;
+    delete_test_user(&other_user, &injector).await;
+}
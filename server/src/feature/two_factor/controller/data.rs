@@ -0,0 +1,51 @@
+use crate::feature::two_factor::model::TwoFactorEnrollment;
+use serde::{Deserialize, Serialize};
+
+/// A get two-factor status response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct GetTwoFactorStatusResponseBody {
+    /// If the user has enabled two-factor authentication.
+    pub(super) enabled: bool,
+
+    /// The amount of recovery codes the user has left.
+    pub(super) remaining_recovery_codes: u64,
+}
+
+/// A two-factor enrollment response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoFactorEnrollmentResponseBody {
+    /// The base32 encoded totp secret, for authenticator apps the uri can not be scanned into.
+    pub(super) secret: String,
+
+    /// The otpauth:// uri of the credential.
+    pub(super) uri: String,
+}
+
+/// An Into<TwoFactorEnrollmentResponseBody> implementation for the TwoFactorEnrollment struct.
+impl Into<TwoFactorEnrollmentResponseBody> for TwoFactorEnrollment {
+    fn into(self) -> TwoFactorEnrollmentResponseBody {
+        return TwoFactorEnrollmentResponseBody {
+            secret: self.secret,
+            uri: self.uri,
+        };
+    }
+}
+
+/// A two-factor code request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct TwoFactorCodeRequestBody {
+    /// A code from the user's authenticator app, or one of their recovery codes where they are
+    /// accepted.
+    pub(super) code: String,
+}
+
+/// A recovery codes response body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RecoveryCodesResponseBody {
+    /// The user's recovery codes, which can not be shown again.
+    pub(super) recovery_codes: Vec<String>,
+}
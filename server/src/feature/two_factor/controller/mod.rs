@@ -0,0 +1,215 @@
+mod data;
+
+use self::data::{
+    GetTwoFactorStatusResponseBody, RecoveryCodesResponseBody, TwoFactorCodeRequestBody,
+    TwoFactorEnrollmentResponseBody,
+};
+use crate::{
+    common::{
+        enumeration::{AuthenticationResult, InsertionResult, UpdateResult},
+        utility::create_authentication_failure_response,
+    },
+    feature::{
+        auth::service::AuthService, two_factor::service::TwoFactorService, user::model::User,
+    },
+    injector::DependencyInjector,
+};
+use actix_web::{
+    get, post,
+    web::{self, ServiceConfig},
+    HttpRequest, HttpResponse,
+};
+use shaku_actix::Inject;
+
+/// # Description
+///
+/// Add the two-factor controller configuration to a service config.
+///
+/// # Arguments
+///
+/// `config` - The service config that the two-factor controller configuration will be added to.
+pub(crate) fn configure(config: &mut ServiceConfig) {
+    config.service(
+        web::scope("/two-factor")
+            .service(get_status)
+            .service(begin_enrollment)
+            .service(confirm_enrollment)
+            .service(disable),
+    );
+}
+
+/// # Description
+///
+/// An api endpoint to check if the user that sent the request has enabled two-factor
+/// authentication, and how many recovery codes they have left.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `two_factor_service` - The two-factor service that will be used to get the user's status.
+///
+/// # Returns
+///
+/// An http response.
+#[get("")]
+async fn get_status(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Get the user's status.
+    let enabled: bool = match two_factor_service.is_enabled(&user.id).await {
+        Ok(enabled) => enabled,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+    let remaining_recovery_codes: u64 =
+        match two_factor_service.count_recovery_codes(&user.id).await {
+            Ok(count) => count,
+            Err(_) => return HttpResponse::InternalServerError().finish(),
+        };
+
+    // Return the status.
+    return HttpResponse::Ok().json(GetTwoFactorStatusResponseBody {
+        enabled,
+        remaining_recovery_codes,
+    });
+}
+
+/// # Description
+///
+/// An api endpoint to start enrolling the user that sent the request in two-factor
+/// authentication. Two-factor authentication is not required until the enrollment is confirmed.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `two_factor_service` - The two-factor service that will be used to start the enrollment.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/enrollment")]
+async fn begin_enrollment(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Start the enrollment.
+    return match two_factor_service.begin_enrollment(&user).await {
+        InsertionResult::Ok(enrollment) => {
+            let response_body: TwoFactorEnrollmentResponseBody = enrollment.into();
+            HttpResponse::Ok().json(response_body)
+        }
+        InsertionResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        InsertionResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to confirm the enrollment of the user that sent the request with the first
+/// code their authenticator app created, which enables two-factor authentication. The response
+/// contains the user's recovery codes, which can not be shown again.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains the code.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `two_factor_service` - The two-factor service that will be used to confirm the enrollment.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/enrollment/confirm")]
+async fn confirm_enrollment(
+    request: HttpRequest,
+    body: web::Json<TwoFactorCodeRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Confirm the enrollment.
+    return match two_factor_service
+        .confirm_enrollment(&user, &body.code)
+        .await
+    {
+        UpdateResult::Ok(recovery_codes) => {
+            HttpResponse::Ok().json(RecoveryCodesResponseBody { recovery_codes })
+        }
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to disable two-factor authentication for the user that sent the request, with
+/// a code from their authenticator app or one of their recovery codes.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `body` - The request body which contains the code.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `two_factor_service` - The two-factor service that will be used to disable two-factor
+/// authentication.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/disable")]
+async fn disable(
+    request: HttpRequest,
+    body: web::Json<TwoFactorCodeRequestBody>,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    two_factor_service: Inject<DependencyInjector, dyn TwoFactorService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Disable two-factor authentication.
+    return match two_factor_service.disable(&user, &body.code).await {
+        UpdateResult::Ok(()) => HttpResponse::Ok().finish(),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
@@ -0,0 +1,70 @@
+use time::OffsetDateTime;
+
+/// A two-factor credential struct, which holds the secret a user's authenticator app uses to create
+/// time-based one-time passwords (RFC 6238).
+#[derive(Clone, Debug)]
+pub(crate) struct TwoFactorCredential {
+    /// The id of the user the credential belongs to.
+    pub(crate) user_id: u64,
+
+    /// The base32 encoded totp secret.
+    pub(crate) secret: String,
+
+    /// The date and time the credential was created at, which is when the user started enrolling.
+    pub(crate) credential_created_at: OffsetDateTime,
+
+    /// The date and time the user confirmed their enrollment at. Two-factor authentication is only
+    /// required once the enrollment has been confirmed.
+    pub(crate) enabled_at: Option<OffsetDateTime>,
+
+    /// The time step of the last code that was accepted, so that codes can not be replayed.
+    pub(crate) last_used_step: Option<i64>,
+}
+
+/// A recovery code struct, which holds the hash of a single-use code a user can enter instead of a
+/// time-based one-time password if they lose access to their authenticator app.
+#[derive(Clone, Debug)]
+pub(crate) struct RecoveryCode {
+    /// The recovery code's unique identifier.
+    pub(crate) id: u64,
+
+    /// The id of the user the recovery code belongs to.
+    pub(crate) user_id: u64,
+
+    /// The sha-256 hash of the recovery code, encoded as hex.
+    pub(crate) code_hash: String,
+
+    /// The date and time the recovery code was created at.
+    pub(crate) code_created_at: OffsetDateTime,
+}
+
+/// A two-factor challenge struct, which holds the hash of the token a user receives after entering
+/// their password, and must send back with their second factor to finish logging in.
+#[derive(Clone, Debug)]
+pub(crate) struct TwoFactorChallenge {
+    /// The challenge's unique identifier.
+    pub(crate) id: u64,
+
+    /// The id of the user the challenge belongs to.
+    pub(crate) user_id: u64,
+
+    /// The sha-256 hash of the challenge token, encoded as hex.
+    pub(crate) challenge_hash: String,
+
+    /// The date and time the challenge was created at.
+    pub(crate) challenge_created_at: OffsetDateTime,
+
+    /// The date and time the challenge expires at.
+    pub(crate) expires_at: OffsetDateTime,
+}
+
+/// A two-factor enrollment struct, which holds what a user needs to add their account to an
+/// authenticator app.
+#[derive(Clone, Debug)]
+pub(crate) struct TwoFactorEnrollment {
+    /// The base32 encoded totp secret, for authenticator apps the uri can not be scanned into.
+    pub(crate) secret: String,
+
+    /// The otpauth:// uri of the credential, which is usually shown as a qr code.
+    pub(crate) uri: String,
+}
@@ -0,0 +1,711 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::enumeration::QueryContext,
+    feature::two_factor::model::{RecoveryCode, TwoFactorChallenge, TwoFactorCredential},
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::{Error, Row};
+use time::OffsetDateTime;
+
+/// A two-factor repository trait.
+#[async_trait]
+pub(crate) trait TwoFactorRepository: Interface {
+    /// # Description
+    ///
+    /// Insert a two-factor credential into the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `credential` - The two-factor credential to insert into the two-factor repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_credential(
+        &self,
+        credential: &TwoFactorCredential,
+        context: &mut QueryContext,
+    ) -> Result<(), Error>;
+
+    /// # Description
+    ///
+    /// Query the two-factor credential of a user from the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the two-factor credential belongs to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the two-factor credential if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_credential_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<TwoFactorCredential>, Error>;
+
+    /// # Description
+    ///
+    /// Enable a user's two-factor credential.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the two-factor credential belongs to.
+    ///
+    /// `enabled_at` - The date and time the two-factor credential is enabled at.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn enable_credential(
+        &self,
+        user_id: &u64,
+        enabled_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Record the time step of a code that was accepted for a user's two-factor credential, if it
+    /// is later than the last time step that was accepted. Since the credential is only updated if
+    /// the time step is later, a code can only be accepted once, even if it is used by several
+    /// requests at the same time.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the two-factor credential belongs to.
+    ///
+    /// `step` - The time step of the code that was accepted.
+    ///
+    /// `context` - The query context the update will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the update was successful, the Ok variant will be returned with the amount of records
+    /// updated, which will be 0 if a code for the same or a later time step was already accepted.
+    /// - If the update was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn update_last_used_step(
+        &self,
+        user_id: &u64,
+        step: &i64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the two-factor credential of a user from the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the two-factor credential belongs to.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_credential(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a recovery code into the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `recovery_code` - The recovery code to insert into the two-factor repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// recovery code that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_recovery_code(
+        &self,
+        recovery_code: &RecoveryCode,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Count the recovery codes a user has left.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the recovery codes belong to.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the amount of recovery
+    /// codes.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn count_recovery_codes(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete one of a user's recovery codes by its hash, which uses it up.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the recovery code belongs to.
+    ///
+    /// `code_hash` - The hash of the recovery code.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted, which will be 0 if the user does not have the recovery code.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_recovery_code(
+        &self,
+        user_id: &u64,
+        code_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete every recovery code of a user.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the recovery codes belong to.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_recovery_codes(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a two-factor challenge into the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `challenge` - The two-factor challenge to insert into the two-factor repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the
+    /// two-factor challenge that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_challenge(
+        &self,
+        challenge: &TwoFactorChallenge,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query a two-factor challenge from the two-factor repository by the hash of its token.
+    ///
+    /// # Arguments
+    ///
+    /// `challenge_hash` - The hash of the challenge token.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an option that will
+    /// contain the two-factor challenge if it was found.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_challenge_by_hash(
+        &self,
+        challenge_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<TwoFactorChallenge>, Error>;
+
+    /// # Description
+    ///
+    /// Delete a two-factor challenge from the two-factor repository.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the two-factor challenge to delete.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted, which will be 0 if the challenge was already deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_challenge(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Delete the two-factor challenges that expired before a specific time from the two-factor
+    /// repository.
+    ///
+    /// # Arguments
+    ///
+    /// `before` - The date and time two-factor challenges must have expired before to be deleted.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_expired_challenges(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+}
+
+/// A TwoFactorRepositoryImpl struct.
+#[derive(Component)]
+#[shaku(interface = TwoFactorRepository)]
+pub(crate) struct TwoFactorRepositoryImpl;
+
+/// A TwoFactorRepository implementation for the TwoFactorRepositoryImpl struct.
+#[async_trait]
+impl TwoFactorRepository for TwoFactorRepositoryImpl {
+    async fn insert_credential(
+        &self,
+        credential: &TwoFactorCredential,
+        context: &mut QueryContext,
+    ) -> Result<(), Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `two_factor_credentials` (
+                    `user_id`,
+                    `secret`,
+                    `credential_created_at`,
+                    `enabled_at`,
+                    `last_used_step`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                );
+            "#,
+            credential.user_id,
+            credential.secret,
+            credential.credential_created_at,
+            credential.enabled_at,
+            credential.last_used_step
+        );
+
+        // Execute the query.
+        match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(());
+    }
+
+    async fn get_credential_by_user_id(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Option<TwoFactorCredential>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            TwoFactorCredential,
+            r#"
+                SELECT
+                    `user_id` AS `user_id: u64`,
+                    `secret` AS `secret: String`,
+                    `credential_created_at` AS `credential_created_at: OffsetDateTime`,
+                    `enabled_at` AS `enabled_at: OffsetDateTime`,
+                    `last_used_step` AS `last_used_step: i64`
+                FROM
+                    `two_factor_credentials`
+                WHERE
+                    `user_id` = ?;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn enable_credential(
+        &self,
+        user_id: &u64,
+        enabled_at: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `two_factor_credentials`
+                SET
+                    `enabled_at` = ?
+                WHERE
+                    `user_id` = ? AND `enabled_at` IS NULL;
+            "#,
+            enabled_at,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn update_last_used_step(
+        &self,
+        user_id: &u64,
+        step: &i64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                UPDATE
+                    `two_factor_credentials`
+                SET
+                    `last_used_step` = ?
+                WHERE
+                    `user_id` = ? AND (`last_used_step` IS NULL OR `last_used_step` < ?);
+            "#,
+            step,
+            user_id,
+            step
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_credential(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `two_factor_credentials`
+                WHERE
+                    `user_id` = ?;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_recovery_code(
+        &self,
+        recovery_code: &RecoveryCode,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `recovery_codes` (
+                    `id`,
+                    `user_id`,
+                    `code_hash`,
+                    `code_created_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            recovery_code.id,
+            recovery_code.user_id,
+            recovery_code.code_hash,
+            recovery_code.code_created_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn count_recovery_codes(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT
+                    COUNT(*) AS `count: u64`
+                FROM
+                    `recovery_codes`
+                WHERE
+                    `user_id` = ?;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.count);
+    }
+
+    async fn delete_recovery_code(
+        &self,
+        user_id: &u64,
+        code_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `recovery_codes`
+                WHERE
+                    `user_id` = ? AND `code_hash` = ?;
+            "#,
+            user_id,
+            code_hash
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_recovery_codes(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `recovery_codes`
+                WHERE
+                    `user_id` = ?;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn insert_challenge(
+        &self,
+        challenge: &TwoFactorChallenge,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `two_factor_challenges` (
+                    `id`,
+                    `user_id`,
+                    `challenge_hash`,
+                    `challenge_created_at`,
+                    `expires_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            challenge.id,
+            challenge.user_id,
+            challenge.challenge_hash,
+            challenge.challenge_created_at,
+            challenge.expires_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_challenge_by_hash(
+        &self,
+        challenge_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<TwoFactorChallenge>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            TwoFactorChallenge,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `challenge_hash` AS `challenge_hash: String`,
+                    `challenge_created_at` AS `challenge_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`
+                FROM
+                    `two_factor_challenges`
+                WHERE
+                    `challenge_hash` = ?;
+            "#,
+            challenge_hash
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn delete_challenge(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `two_factor_challenges`
+                WHERE
+                    `id` = ?;
+            "#,
+            id
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_expired_challenges(
+        &self,
+        before: &OffsetDateTime,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `two_factor_challenges`
+                WHERE
+                    `expires_at` < ?;
+            "#,
+            before
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+}
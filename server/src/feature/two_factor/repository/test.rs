@@ -0,0 +1,444 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryContext},
+        utility::generate_random_string,
+    },
+    config::Config,
+    database::DatabaseConnectionFactory,
+    feature::{
+        two_factor::{
+            model::{RecoveryCode, TwoFactorChallenge, TwoFactorCredential},
+            repository::TwoFactorRepository,
+        },
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use core::panic;
+use shaku::HasComponent;
+use sqlx::{pool::PoolConnection, Connection, MySql};
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// Create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be inserted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user with the user
+/// service.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(injector: &DependencyInjector, context: &mut QueryContext<'_>) -> User {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the insertion.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+
+    // Return the user.
+    return user;
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `injector` - The dependency injector that will be used to acquire a user service instance.
+///
+/// `context` - The query context the user will be deleted in.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user with the user
+/// service.
+async fn delete_test_user(
+    user: &User,
+    injector: &DependencyInjector,
+    context: &mut QueryContext<'_>,
+) {
+    // Get a user service instance.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+
+    // Perform the deletion.
+    match user_service.delete_with_context(&user.id, context).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    // Load the config.
+    let config: Config =
+        Config::load_config(String::from("config.json")).expect("Failed to load config");
+
+    // Return the config.
+    return config;
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    // Create the dependency injector.
+    let injector: DependencyInjector = DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to create dependency injector");
+
+    // Return the dependency injector.
+    return injector;
+}
+
+/// # Description
+///
+/// Acquire a database connection.
+///
+/// # Arguments
+///
+/// `injector` - The dependency injector that will be used to get the database connection factory
+/// instance.
+///
+/// # Panics
+///
+/// This function will panic if a database connection could not be created.
+///
+/// # Returns
+///
+/// The database connection that was created.
+async fn get_database_connection(injector: &DependencyInjector) -> PoolConnection<MySql> {
+    // Get the database connection factory.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Return the connection.
+    return connection;
+}
+
+/// # Description
+///
+/// Test inserting a two-factor credential, and make sure it can be enabled once and that accepted
+/// time steps can not be reused.
+#[actix_web::test]
+async fn credentials_can_be_enabled_and_steps_can_not_be_reused() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a two-factor repository instance.
+    let two_factor_repository: Arc<dyn TwoFactorRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and a two-factor credential for them.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let credential: TwoFactorCredential = TwoFactorCredential {
+        user_id: user.id,
+        secret: generate_random_string(32),
+        credential_created_at: OffsetDateTime::now_utc(),
+        enabled_at: None,
+        last_used_step: None,
+    };
+    two_factor_repository
+        .insert_credential(&credential, &mut context)
+        .await
+        .expect("Failed to insert two-factor credential");
+
+    // Make sure the credential can be queried.
+    let queried_credential: TwoFactorCredential = two_factor_repository
+        .get_credential_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query two-factor credential")
+        .expect("The two-factor credential could not be found");
+    assert_eq!(credential.secret, queried_credential.secret);
+    assert!(queried_credential.enabled_at.is_none());
+
+    // Enable the credential, and make sure it can only be enabled once.
+    for expected_rows_updated in [1, 0] {
+        let rows_updated = two_factor_repository
+            .enable_credential(&user.id, &OffsetDateTime::now_utc(), &mut context)
+            .await
+            .expect("Failed to enable two-factor credential");
+        assert_eq!(expected_rows_updated, rows_updated);
+    }
+
+    // Make sure a time step can only be used once, and earlier time steps can not be used after it.
+    for (step, expected_rows_updated) in [(100, 1), (100, 0), (99, 0), (101, 1)] {
+        let rows_updated = two_factor_repository
+            .update_last_used_step(&user.id, &step, &mut context)
+            .await
+            .expect("Failed to update the last used step");
+        assert_eq!(expected_rows_updated, rows_updated);
+    }
+
+    // Delete the credential, and make sure it can no longer be queried.
+    let rows_deleted = two_factor_repository
+        .delete_credential(&user.id, &mut context)
+        .await
+        .expect("Failed to delete two-factor credential");
+    assert_eq!(1, rows_deleted);
+    assert!(two_factor_repository
+        .get_credential_by_user_id(&user.id, &mut context)
+        .await
+        .expect("Failed to query two-factor credential")
+        .is_none());
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting recovery codes, and make sure each one can only be used once.
+#[actix_web::test]
+async fn recovery_codes_can_only_be_used_once() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a two-factor repository instance.
+    let two_factor_repository: Arc<dyn TwoFactorRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and three recovery codes for them.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let mut code_hashes: Vec<String> = Vec::new();
+    for _ in 0..3 {
+        let recovery_code: RecoveryCode = RecoveryCode {
+            id: 0,
+            user_id: user.id,
+            code_hash: generate_random_string(64),
+            code_created_at: OffsetDateTime::now_utc(),
+        };
+        let id = two_factor_repository
+            .insert_recovery_code(&recovery_code, &mut context)
+            .await
+            .expect("Failed to insert recovery code");
+        assert!(id > 0);
+        code_hashes.push(recovery_code.code_hash);
+    }
+    assert_eq!(
+        3,
+        two_factor_repository
+            .count_recovery_codes(&user.id, &mut context)
+            .await
+            .expect("Failed to count recovery codes")
+    );
+
+    // Use one of the recovery codes, and make sure it can only be used once.
+    for expected_rows_deleted in [1, 0] {
+        let rows_deleted = two_factor_repository
+            .delete_recovery_code(&user.id, &code_hashes[0], &mut context)
+            .await
+            .expect("Failed to delete recovery code");
+        assert_eq!(expected_rows_deleted, rows_deleted);
+    }
+
+    // Delete the remaining recovery codes.
+    let rows_deleted = two_factor_repository
+        .delete_recovery_codes(&user.id, &mut context)
+        .await
+        .expect("Failed to delete recovery codes");
+    assert_eq!(2, rows_deleted);
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test inserting two-factor challenges, and make sure they can be queried by their hash and that
+/// only expired challenges are deleted.
+#[actix_web::test]
+async fn only_expired_challenges_are_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a two-factor repository instance.
+    let two_factor_repository: Arc<dyn TwoFactorRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection(&injector).await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user with an active and an expired challenge.
+    let user: User = insert_test_user(&injector, &mut context).await;
+    let now: OffsetDateTime = OffsetDateTime::now_utc();
+    let mut active_challenge: TwoFactorChallenge = TwoFactorChallenge {
+        id: 0,
+        user_id: user.id,
+        challenge_hash: generate_random_string(64),
+        challenge_created_at: now,
+        expires_at: now + Duration::MINUTE,
+    };
+    let mut expired_challenge: TwoFactorChallenge = TwoFactorChallenge {
+        id: 0,
+        user_id: user.id,
+        challenge_hash: generate_random_string(64),
+        challenge_created_at: now - Duration::HOUR,
+        expires_at: now - Duration::MINUTE,
+    };
+    for challenge in [&mut active_challenge, &mut expired_challenge] {
+        challenge.id = two_factor_repository
+            .insert_challenge(challenge, &mut context)
+            .await
+            .expect("Failed to insert two-factor challenge");
+    }
+
+    // Make sure the active challenge can be queried by its hash.
+    let queried_challenge: TwoFactorChallenge = two_factor_repository
+        .get_challenge_by_hash(&active_challenge.challenge_hash, &mut context)
+        .await
+        .expect("Failed to query two-factor challenge")
+        .expect("The two-factor challenge could not be found");
+    assert_eq!(active_challenge.id, queried_challenge.id);
+    assert_eq!(user.id, queried_challenge.user_id);
+
+    // Delete the expired challenges, and make sure only the expired challenge was deleted.
+    two_factor_repository
+        .delete_expired_challenges(&now, &mut context)
+        .await
+        .expect("Failed to delete expired two-factor challenges");
+    assert!(two_factor_repository
+        .get_challenge_by_hash(&expired_challenge.challenge_hash, &mut context)
+        .await
+        .expect("Failed to query two-factor challenge")
+        .is_none());
+
+    // Delete the active challenge, and make sure it can only be deleted once.
+    for expected_rows_deleted in [1, 0] {
+        let rows_deleted = two_factor_repository
+            .delete_challenge(&active_challenge.id, &mut context)
+            .await
+            .expect("Failed to delete two-factor challenge");
+        assert_eq!(expected_rows_deleted, rows_deleted);
+    }
+
+    // Delete the test user.
+    delete_test_user(&user, &injector, &mut context).await;
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
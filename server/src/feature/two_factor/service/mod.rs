@@ -0,0 +1,927 @@
+#[cfg(test)]
+mod test;
+
+use crate::{
+    common::{
+        enumeration::{InsertionResult, QueryContext, QueryResult, UpdateResult},
+        utility::{generate_random_string, percent_encode},
+    },
+    config::TwoFactorConfig,
+    database::DatabaseConnectionFactory,
+    feature::{
+        crypto::service::{CryptoService, TOTP_DIGITS, TOTP_PERIOD},
+        two_factor::{
+            model::{RecoveryCode, TwoFactorChallenge, TwoFactorCredential, TwoFactorEnrollment},
+            repository::TwoFactorRepository,
+        },
+        user::model::User,
+    },
+};
+use async_trait::async_trait;
+use shaku::{Component, Interface};
+use sqlx::Acquire;
+use std::{error::Error, sync::Arc};
+use time::{Duration, OffsetDateTime};
+use validator::{ValidationError, ValidationErrors};
+
+/// The amount of recovery codes a user receives when they enable two-factor authentication.
+const RECOVERY_CODE_COUNT: usize = 10;
+
+/// The amount of characters in each half of a recovery code.
+const RECOVERY_CODE_HALF_LENGTH: usize = 5;
+
+/// A two-factor service trait.
+#[async_trait(?Send)]
+pub(crate) trait TwoFactorService: Interface {
+    /// # Description
+    ///
+    /// Check if a user has enabled two-factor authentication.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the check was performed, the Ok variant will be returned with if two-factor
+    /// authentication is enabled.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn is_enabled(&self, user_id: &u64) -> Result<bool, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Count the recovery codes a user has left.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the amount of recovery
+    /// codes.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn count_recovery_codes(&self, user_id: &u64) -> Result<u64, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Start enrolling a user in two-factor authentication by generating a new secret. Two-factor
+    /// authentication is not required until the enrollment is confirmed with a code, and starting
+    /// again replaces a secret that was never confirmed.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is enrolling.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the secret was created, the Ok variant will be returned with the enrollment.
+    /// - If the user has already enabled two-factor authentication, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn begin_enrollment(
+        &self,
+        user: &User,
+    ) -> InsertionResult<TwoFactorEnrollment, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Start enrolling a user in two-factor authentication by generating a new secret, in the
+    /// context specified. Two-factor authentication is not required until the enrollment is
+    /// confirmed with a code, and starting again replaces a secret that was never confirmed.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is enrolling.
+    ///
+    /// `context` - The context the secret will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an insertion result:
+    /// - If the secret was created, the Ok variant will be returned with the enrollment.
+    /// - If the user has already enabled two-factor authentication, the Invalid variant will be
+    /// returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn begin_enrollment_with_context(
+        &self,
+        user: &User,
+        context: &mut QueryContext,
+    ) -> InsertionResult<TwoFactorEnrollment, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Confirm a user's enrollment with the first code their authenticator app created, which
+    /// enables two-factor authentication and creates their recovery codes.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is enrolling.
+    ///
+    /// `code` - The code the user's authenticator app created.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If two-factor authentication was enabled, the Ok variant will be returned with the
+    /// recovery codes. They are only stored hashed, so this is the only time they can be shown.
+    /// - If the user has not started enrolling, the NotFound variant will be returned.
+    /// - If the code is not correct or the enrollment was already confirmed, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn confirm_enrollment(
+        &self,
+        user: &User,
+        code: &String,
+    ) -> UpdateResult<Vec<String>, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Confirm a user's enrollment with the first code their authenticator app created, which
+    /// enables two-factor authentication and creates their recovery codes, in the context
+    /// specified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is enrolling.
+    ///
+    /// `code` - The code the user's authenticator app created.
+    ///
+    /// `context` - The context the enrollment will be confirmed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If two-factor authentication was enabled, the Ok variant will be returned with the
+    /// recovery codes. They are only stored hashed, so this is the only time they can be shown.
+    /// - If the user has not started enrolling, the NotFound variant will be returned.
+    /// - If the code is not correct or the enrollment was already confirmed, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn confirm_enrollment_with_context(
+        &self,
+        user: &User,
+        code: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<Vec<String>, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Disable a user's two-factor authentication, which deletes their secret and recovery codes.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose two-factor authentication is being disabled.
+    ///
+    /// `code` - A code from the user's authenticator app, or one of their recovery codes.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If two-factor authentication was disabled, the Ok variant will be returned.
+    /// - If the user has not enabled two-factor authentication, the NotFound variant will be
+    /// returned.
+    /// - If the code is not correct, the Invalid variant will be returned with the validation
+    /// errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn disable(
+        &self,
+        user: &User,
+        code: &String,
+    ) -> UpdateResult<(), ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Disable a user's two-factor authentication, which deletes their secret and recovery codes,
+    /// in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose two-factor authentication is being disabled.
+    ///
+    /// `code` - A code from the user's authenticator app, or one of their recovery codes.
+    ///
+    /// `context` - The context two-factor authentication will be disabled in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If two-factor authentication was disabled, the Ok variant will be returned.
+    /// - If the user has not enabled two-factor authentication, the NotFound variant will be
+    /// returned.
+    /// - If the code is not correct, the Invalid variant will be returned with the validation
+    /// errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn disable_with_context(
+        &self,
+        user: &User,
+        code: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<(), ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Verify a user's second factor. Each code from the user's authenticator app and each recovery
+    /// code can only be used once.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user.
+    ///
+    /// `code` - A code from the user's authenticator app, or one of their recovery codes.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the verification was performed, the Ok variant will be returned with if the code was
+    /// accepted. Codes are never accepted for users that have not enabled two-factor
+    /// authentication.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn verify(&self, user_id: &u64, code: &String) -> Result<bool, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Create a challenge for a user that has entered their password, which they must send back
+    /// with their second factor to finish logging in.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user the challenge is being created for.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the challenge was created, the Ok variant will be returned with the secret challenge
+    /// token.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create_challenge(&self, user: &User) -> Result<String, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Query a challenge by the secret challenge token it was created for.
+    ///
+    /// # Arguments
+    ///
+    /// `challenge_token` - The secret challenge token.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a query result:
+    /// - If the challenge was found and has not expired, the Ok variant will be returned with it.
+    /// - If the challenge was not found or has expired, the NotFound variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_challenge(
+        &self,
+        challenge_token: &String,
+    ) -> QueryResult<TwoFactorChallenge, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Delete a challenge once the user has finished logging in, so that it can not be used again.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the challenge.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was performed, the Ok variant will be returned with if the challenge was
+    /// deleted. If it was not, another request already used it.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_challenge(&self, id: &u64) -> Result<bool, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Delete the challenges that have expired.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of
+    /// challenges deleted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn delete_expired_challenges(&self) -> Result<u64, Box<dyn Error>>;
+}
+
+/// A TwoFactorServiceImpl struct.
+#[derive(Component)]
+#[shaku(interface = TwoFactorService)]
+pub(crate) struct TwoFactorServiceImpl {
+    /// The crypto service that will be used to create secrets and verify codes.
+    #[shaku(inject)]
+    crypto_service: Arc<dyn CryptoService>,
+
+    /// The two-factor repository that will be used to manage persistent two-factor data.
+    #[shaku(inject)]
+    two_factor_repository: Arc<dyn TwoFactorRepository>,
+
+    /// The database connection factory that will be used to acquire database connections.
+    #[shaku(inject)]
+    connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The two-factor configuration.
+    two_factor_config: TwoFactorConfig,
+}
+
+/// A TwoFactorService implementation for the TwoFactorServiceImpl struct.
+#[async_trait(?Send)]
+impl TwoFactorService for TwoFactorServiceImpl {
+    async fn is_enabled(&self, user_id: &u64) -> Result<bool, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the user's credential.
+        let credential: Option<TwoFactorCredential> = __self
+            .two_factor_repository
+            .get_credential_by_user_id(user_id, &mut context)
+            .await?;
+
+        return Ok(credential.is_some_and(|credential| credential.enabled_at.is_some()));
+    }
+
+    async fn count_recovery_codes(&self, user_id: &u64) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Count the recovery codes.
+        return Ok(__self
+            .two_factor_repository
+            .count_recovery_codes(user_id, &mut context)
+            .await?);
+    }
+
+    async fn begin_enrollment(
+        &self,
+        user: &User,
+    ) -> InsertionResult<TwoFactorEnrollment, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Start the enrollment.
+        let insertion_result = self.begin_enrollment_with_context(user, &mut context).await;
+
+        // If the enrollment was started, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match insertion_result {
+            InsertionResult::Ok(_) => context.commit_if_transaction().await,
+            InsertionResult::Invalid(_) => context.rollback_if_transaction().await,
+            InsertionResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the insertion result, otherwise
+        // return the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => insertion_result,
+            Err(error) => InsertionResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn begin_enrollment_with_context(
+        &self,
+        user: &User,
+        context: &mut QueryContext,
+    ) -> InsertionResult<TwoFactorEnrollment, ValidationErrors, Box<dyn Error>> {
+        // Make sure the user has not already enabled two-factor authentication.
+        match __self
+            .two_factor_repository
+            .get_credential_by_user_id(&user.id, context)
+            .await
+        {
+            Ok(Some(credential)) if credential.enabled_at.is_some() => {
+                let mut validation_errors = ValidationErrors::new();
+                validation_errors.add("enrollment", ValidationError::new("already_enabled"));
+                return InsertionResult::Invalid(validation_errors);
+            }
+            Ok(_) => {}
+            Err(error) => return InsertionResult::Err(Box::new(error)),
+        };
+
+        // Replace the secret of an enrollment that was never confirmed.
+        if let Err(error) = __self
+            .two_factor_repository
+            .delete_credential(&user.id, context)
+            .await
+        {
+            return InsertionResult::Err(Box::new(error));
+        }
+
+        // Create the credential.
+        let credential: TwoFactorCredential = TwoFactorCredential {
+            user_id: user.id,
+            secret: __self.crypto_service.generate_totp_secret(),
+            credential_created_at: OffsetDateTime::now_utc(),
+            enabled_at: None,
+            last_used_step: None,
+        };
+        if let Err(error) = __self
+            .two_factor_repository
+            .insert_credential(&credential, context)
+            .await
+        {
+            return InsertionResult::Err(Box::new(error));
+        }
+
+        return InsertionResult::Ok(TwoFactorEnrollment {
+            uri: self.create_enrollment_uri(user, &credential.secret),
+            secret: credential.secret,
+        });
+    }
+
+    async fn confirm_enrollment(
+        &self,
+        user: &User,
+        code: &String,
+    ) -> UpdateResult<Vec<String>, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Confirm the enrollment.
+        let update_result = self
+            .confirm_enrollment_with_context(user, code, &mut context)
+            .await;
+
+        // If the enrollment was confirmed, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn confirm_enrollment_with_context(
+        &self,
+        user: &User,
+        code: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<Vec<String>, ValidationErrors, Box<dyn Error>> {
+        // Query the credential the user is enrolling with.
+        let credential: TwoFactorCredential = match __self
+            .two_factor_repository
+            .get_credential_by_user_id(&user.id, context)
+            .await
+        {
+            Ok(Some(credential)) => credential,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the enrollment has not already been confirmed.
+        let mut validation_errors = ValidationErrors::new();
+        if credential.enabled_at.is_some() {
+            validation_errors.add("enrollment", ValidationError::new("already_enabled"));
+            return UpdateResult::Invalid(validation_errors);
+        }
+
+        // Make sure the code is correct. Recovery codes are not accepted, since they have not been
+        // created yet.
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let step: i64 = match __self
+            .crypto_service
+            .verify_totp_code(&credential.secret, code, &now)
+        {
+            Ok(Some(step)) => step,
+            Ok(None) => {
+                validation_errors.add("code", ValidationError::new("invalid"));
+                return UpdateResult::Invalid(validation_errors);
+            }
+            Err(error) => return UpdateResult::Err(error),
+        };
+
+        // Enable two-factor authentication, and make sure the code can not be used again.
+        if let Err(error) = __self
+            .two_factor_repository
+            .enable_credential(&user.id, &now, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+        if let Err(error) = __self
+            .two_factor_repository
+            .update_last_used_step(&user.id, &step, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Create the user's recovery codes.
+        return match self.create_recovery_codes(&user.id, context).await {
+            Ok(recovery_codes) => UpdateResult::Ok(recovery_codes),
+            Err(error) => UpdateResult::Err(error),
+        };
+    }
+
+    async fn disable(
+        &self,
+        user: &User,
+        code: &String,
+    ) -> UpdateResult<(), ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Disable two-factor authentication.
+        let update_result = self.disable_with_context(user, code, &mut context).await;
+
+        // If two-factor authentication was disabled, commit the transaction, otherwise roll it
+        // back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn disable_with_context(
+        &self,
+        user: &User,
+        code: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<(), ValidationErrors, Box<dyn Error>> {
+        // Query the user's credential, and make sure two-factor authentication is enabled.
+        let credential: TwoFactorCredential = match __self
+            .two_factor_repository
+            .get_credential_by_user_id(&user.id, context)
+            .await
+        {
+            Ok(Some(credential)) if credential.enabled_at.is_some() => credential,
+            Ok(_) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Make sure the code is correct.
+        match self.verify_with_context(&credential, code, context).await {
+            Ok(true) => {}
+            Ok(false) => {
+                let mut validation_errors = ValidationErrors::new();
+                validation_errors.add("code", ValidationError::new("invalid"));
+                return UpdateResult::Invalid(validation_errors);
+            }
+            Err(error) => return UpdateResult::Err(error),
+        };
+
+        // Delete the user's credential and recovery codes.
+        if let Err(error) = __self
+            .two_factor_repository
+            .delete_credential(&user.id, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+        if let Err(error) = __self
+            .two_factor_repository
+            .delete_recovery_codes(&user.id, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        return UpdateResult::Ok(());
+    }
+
+    async fn verify(&self, user_id: &u64, code: &String) -> Result<bool, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the user's credential, and make sure two-factor authentication is enabled.
+        let credential: TwoFactorCredential = match __self
+            .two_factor_repository
+            .get_credential_by_user_id(user_id, &mut context)
+            .await?
+        {
+            Some(credential) if credential.enabled_at.is_some() => credential,
+            _ => return Ok(false),
+        };
+
+        // Verify the code.
+        return self
+            .verify_with_context(&credential, code, &mut context)
+            .await;
+    }
+
+    async fn create_challenge(&self, user: &User) -> Result<String, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Create a challenge token, and store its hash.
+        let challenge_token: String = __self.crypto_service.generate_secret_token();
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let challenge: TwoFactorChallenge = TwoFactorChallenge {
+            id: 0,
+            user_id: user.id,
+            challenge_hash: __self.crypto_service.hash_secret_token(&challenge_token),
+            challenge_created_at: now,
+            expires_at: now + Duration::seconds(__self.two_factor_config.challenge_lifetime as i64),
+        };
+        __self
+            .two_factor_repository
+            .insert_challenge(&challenge, &mut context)
+            .await?;
+
+        // Return the challenge token.
+        return Ok(challenge_token);
+    }
+
+    async fn get_challenge(
+        &self,
+        challenge_token: &String,
+    ) -> QueryResult<TwoFactorChallenge, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return QueryResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the challenge by its hash, and make sure it has not expired.
+        let challenge_hash: String = __self.crypto_service.hash_secret_token(challenge_token);
+        return match __self
+            .two_factor_repository
+            .get_challenge_by_hash(&challenge_hash, &mut context)
+            .await
+        {
+            Ok(Some(challenge)) if challenge.expires_at > OffsetDateTime::now_utc() => {
+                QueryResult::Ok(challenge)
+            }
+            Ok(_) => QueryResult::NotFound,
+            Err(error) => QueryResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn delete_challenge(&self, id: &u64) -> Result<bool, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the challenge.
+        let rows_deleted: u64 = __self
+            .two_factor_repository
+            .delete_challenge(id, &mut context)
+            .await?;
+
+        return Ok(rows_deleted > 0);
+    }
+
+    async fn delete_expired_challenges(&self) -> Result<u64, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Delete the expired challenges.
+        return Ok(__self
+            .two_factor_repository
+            .delete_expired_challenges(&OffsetDateTime::now_utc(), &mut context)
+            .await?);
+    }
+}
+
+/// An implementation for the TwoFactorServiceImpl struct.
+impl TwoFactorServiceImpl {
+    /// # Description
+    ///
+    /// Create two-factor service parameters that can be used to override the default values
+    /// injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `two_factor_config` - The two-factor configuration.
+    ///
+    /// # Returns
+    ///
+    /// The two-factor service parameters that were created.
+    pub(crate) fn create_parameters(
+        two_factor_config: TwoFactorConfig,
+    ) -> TwoFactorServiceImplParameters {
+        return TwoFactorServiceImplParameters { two_factor_config };
+    }
+
+    /// # Description
+    ///
+    /// Create the otpauth:// uri authenticator apps use to add a user's account.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user that is enrolling.
+    ///
+    /// `secret` - The base32 encoded secret.
+    ///
+    /// # Returns
+    ///
+    /// The uri that was created.
+    fn create_enrollment_uri(&self, user: &User, secret: &String) -> String {
+        let issuer: String = percent_encode(&self.two_factor_config.issuer);
+        return format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+            issuer,
+            percent_encode(&user.username),
+            secret,
+            issuer,
+            TOTP_DIGITS,
+            TOTP_PERIOD
+        );
+    }
+
+    /// # Description
+    ///
+    /// Verify a code against a user's credential in the context specified. Codes that only
+    /// contain digits are verified as codes from the user's authenticator app, and all other codes
+    /// are verified as recovery codes.
+    ///
+    /// # Arguments
+    ///
+    /// `credential` - The user's credential.
+    ///
+    /// `code` - A code from the user's authenticator app, or one of their recovery codes.
+    ///
+    /// `context` - The context the code will be verified in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the verification was performed, the Ok variant will be returned with if the code was
+    /// accepted.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn verify_with_context(
+        &self,
+        credential: &TwoFactorCredential,
+        code: &String,
+        context: &mut QueryContext<'_>,
+    ) -> Result<bool, Box<dyn Error>> {
+        let code: String = code.trim().to_owned();
+
+        // Recovery codes are used up when they are accepted.
+        if code.is_empty() || !code.chars().all(|character| character.is_ascii_digit()) {
+            let code_hash: String = self
+                .crypto_service
+                .hash_secret_token(&normalize_recovery_code(&code));
+            let rows_deleted: u64 = self
+                .two_factor_repository
+                .delete_recovery_code(&credential.user_id, &code_hash, context)
+                .await?;
+            return Ok(rows_deleted > 0);
+        }
+
+        // Codes from the user's authenticator app are only accepted if no code from the same or a
+        // later time step has been accepted, so that they can not be replayed.
+        let step: i64 = match self.crypto_service.verify_totp_code(
+            &credential.secret,
+            &code,
+            &OffsetDateTime::now_utc(),
+        )? {
+            Some(step) => step,
+            None => return Ok(false),
+        };
+        let rows_updated: u64 = self
+            .two_factor_repository
+            .update_last_used_step(&credential.user_id, &step, context)
+            .await?;
+        return Ok(rows_updated > 0);
+    }
+
+    /// # Description
+    ///
+    /// Replace a user's recovery codes with new ones, and store their hashes.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user.
+    ///
+    /// `context` - The context the recovery codes will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the recovery codes were created, the Ok variant will be returned with them.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create_recovery_codes(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext<'_>,
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        // Delete the user's existing recovery codes.
+        self.two_factor_repository
+            .delete_recovery_codes(user_id, context)
+            .await?;
+
+        // Create the recovery codes, and store their hashes.
+        let mut recovery_codes: Vec<String> = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        for _ in 0..RECOVERY_CODE_COUNT {
+            let recovery_code: String = format!(
+                "{}-{}",
+                generate_random_string(RECOVERY_CODE_HALF_LENGTH).to_lowercase(),
+                generate_random_string(RECOVERY_CODE_HALF_LENGTH).to_lowercase()
+            );
+            self.two_factor_repository
+                .insert_recovery_code(
+                    &RecoveryCode {
+                        id: 0,
+                        user_id: *user_id,
+                        code_hash: self
+                            .crypto_service
+                            .hash_secret_token(&normalize_recovery_code(&recovery_code)),
+                        code_created_at: OffsetDateTime::now_utc(),
+                    },
+                    context,
+                )
+                .await?;
+            recovery_codes.push(recovery_code);
+        }
+
+        // Return the recovery codes.
+        return Ok(recovery_codes);
+    }
+}
+
+/// # Description
+///
+/// Normalize a recovery code before it is hashed, so that it is accepted regardless of its case and
+/// whether the separator is included.
+///
+/// # Arguments
+///
+/// `recovery_code` - The recovery code.
+///
+/// # Returns
+///
+/// The normalized recovery code.
+fn normalize_recovery_code(recovery_code: &String) -> String {
+    return recovery_code
+        .chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .map(|character| character.to_ascii_lowercase())
+        .collect();
+}
@@ -0,0 +1,394 @@
+use crate::{
+    common::{
+        enumeration::{DeletionResult, InsertionResult, QueryResult, UpdateResult},
+        utility::generate_random_string,
+    },
+    config::Config,
+    feature::{
+        crypto::service::CryptoService,
+        two_factor::{model::TwoFactorEnrollment, service::TwoFactorService},
+        user::{model::User, service::UserService},
+    },
+    injector::DependencyInjector,
+};
+use shaku::HasComponent;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+/// # Description
+///
+/// A function to create a user that can be used for testing.
+///
+/// # Returns
+///
+/// The user that was created.
+fn create_test_user() -> User {
+    return User {
+        id: 0,
+        account_created_at: OffsetDateTime::now_utc(),
+        password_reset_at: OffsetDateTime::now_utc(),
+        profile_picture_url: format!(
+            "https://{}.com/{}.png",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        username: generate_random_string(8),
+        password: generate_random_string(8),
+        email: format!(
+            "{}@{}.com",
+            generate_random_string(8),
+            generate_random_string(8)
+        ),
+        email_is_verified: false,
+        password_reset_is_required: false,
+        account_is_locked: false,
+        account_unlocks_at: None,
+        account_is_banned: false,
+    };
+}
+
+/// # Description
+///
+/// Insert a test user with the user service.
+///
+/// # Arguments
+///
+/// `user_service` - The user service the user will be inserted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to insert the user.
+///
+/// # Returns
+///
+/// The user that was inserted.
+async fn insert_test_user(user_service: &Arc<dyn UserService>) -> User {
+    return match user_service.insert(&create_test_user()).await {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {}", error),
+    };
+}
+
+/// # Description
+///
+/// Delete a test user with the user service.
+///
+/// # Arguments
+///
+/// `user` - The user to delete.
+///
+/// `user_service` - The user service the user will be deleted with.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to delete the user.
+async fn delete_test_user(user: &User, user_service: &Arc<dyn UserService>) {
+    match user_service.delete(&user.id).await {
+        DeletionResult::Ok => {}
+        DeletionResult::NotFound => panic!("Failed to delete test user: User not found"),
+        DeletionResult::Err(error) => panic!("Failed to delete test user: {}", error),
+    }
+}
+
+/// # Description
+///
+/// Enroll a test user in two-factor authentication.
+///
+/// # Arguments
+///
+/// `user` - The user to enroll.
+///
+/// `two_factor_service` - The two-factor service the user will be enrolled with.
+///
+/// `crypto_service` - The crypto service that will be used to create the confirmation code.
+///
+/// # Panics
+///
+/// This function will panic if the user could not be enrolled.
+///
+/// # Returns
+///
+/// The user's enrollment and recovery codes.
+async fn enroll_test_user(
+    user: &User,
+    two_factor_service: &Arc<dyn TwoFactorService>,
+    crypto_service: &Arc<dyn CryptoService>,
+) -> (TwoFactorEnrollment, Vec<String>) {
+    let enrollment: TwoFactorEnrollment = match two_factor_service.begin_enrollment(user).await {
+        InsertionResult::Ok(enrollment) => enrollment,
+        InsertionResult::Invalid(details) => panic!("Failed to begin enrollment: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to begin enrollment: {}", error),
+    };
+    let code: String = crypto_service
+        .create_totp_code(&enrollment.secret, &OffsetDateTime::now_utc())
+        .expect("Failed to create totp code");
+    let recovery_codes: Vec<String> = match two_factor_service.confirm_enrollment(user, &code).await
+    {
+        UpdateResult::Ok(recovery_codes) => recovery_codes,
+        UpdateResult::NotFound => panic!("Failed to confirm enrollment: Enrollment not found"),
+        UpdateResult::Invalid(details) => panic!("Failed to confirm enrollment: {}", details),
+        UpdateResult::Err(error) => panic!("Failed to confirm enrollment: {}", error),
+    };
+    return (enrollment, recovery_codes);
+}
+
+/// # Description
+///
+/// A utility function to load the server's configuration file.
+///
+/// # Panics
+///
+/// This function will panic if an error occurs while attempting to load or parse the configuration
+/// file.
+///
+/// # Returns
+///
+/// The server's configuration.
+fn load_config() -> Config {
+    return Config::load_config(String::from("config.json")).expect("Failed to load config");
+}
+
+/// # Description
+///
+/// Create a dependency injector.
+///
+/// # Panics
+///
+/// This panics if there was an error creating the dependency injector.
+///
+/// # Returns
+///
+/// The dependency injector that was created.
+async fn create_dependency_injector() -> DependencyInjector {
+    // Load the config.
+    let config: Config = load_config();
+
+    return DependencyInjector::create_from_config(&config)
+        .await
+        .expect("Failed to load dependency injector");
+}
+
+/// # Description
+///
+/// Test enrolling a user in two-factor authentication, and make sure it is only enabled once the
+/// enrollment is confirmed with a correct code.
+#[actix_web::test]
+async fn enrollments_must_be_confirmed_with_a_code() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let two_factor_service: Arc<dyn TwoFactorService> = injector.resolve();
+
+    // Insert a test user.
+    let user: User = insert_test_user(&user_service).await;
+
+    // Make sure an enrollment can not be confirmed before it is started.
+    match two_factor_service
+        .confirm_enrollment(&user, &String::from("000000"))
+        .await
+    {
+        UpdateResult::NotFound => {}
+        _ => panic!("An enrollment that was never started was confirmed"),
+    };
+
+    // Start enrolling the user, and make sure two-factor authentication is not enabled yet.
+    let enrollment: TwoFactorEnrollment = match two_factor_service.begin_enrollment(&user).await {
+        InsertionResult::Ok(enrollment) => enrollment,
+        InsertionResult::Invalid(details) => panic!("Failed to begin enrollment: {}", details),
+        InsertionResult::Err(error) => panic!("Failed to begin enrollment: {}", error),
+    };
+    assert_eq!(32, enrollment.secret.len());
+    assert!(enrollment.uri.starts_with("otpauth://totp/"));
+    assert!(enrollment
+        .uri
+        .contains(&format!("secret={}", enrollment.secret)));
+    assert!(!two_factor_service
+        .is_enabled(&user.id)
+        .await
+        .expect("Failed to check if two-factor authentication is enabled"));
+
+    // Make sure the enrollment can not be confirmed with an incorrect code.
+    let incorrect_code: String = crypto_service
+        .create_totp_code(
+            &enrollment.secret,
+            &(OffsetDateTime::now_utc() - Duration::HOUR),
+        )
+        .expect("Failed to create totp code");
+    match two_factor_service
+        .confirm_enrollment(&user, &incorrect_code)
+        .await
+    {
+        UpdateResult::Invalid(_) => {}
+        _ => panic!("An enrollment was confirmed with an incorrect code"),
+    };
+
+    // Confirm the enrollment, and make sure the recovery codes were created.
+    let code: String = crypto_service
+        .create_totp_code(&enrollment.secret, &OffsetDateTime::now_utc())
+        .expect("Failed to create totp code");
+    let recovery_codes: Vec<String> =
+        match two_factor_service.confirm_enrollment(&user, &code).await {
+            UpdateResult::Ok(recovery_codes) => recovery_codes,
+            UpdateResult::NotFound => panic!("Failed to confirm enrollment: Enrollment not found"),
+            UpdateResult::Invalid(details) => panic!("Failed to confirm enrollment: {}", details),
+            UpdateResult::Err(error) => panic!("Failed to confirm enrollment: {}", error),
+        };
+    assert_eq!(10, recovery_codes.len());
+    assert_eq!(
+        10,
+        two_factor_service
+            .count_recovery_codes(&user.id)
+            .await
+            .expect("Failed to count recovery codes")
+    );
+    assert!(two_factor_service
+        .is_enabled(&user.id)
+        .await
+        .expect("Failed to check if two-factor authentication is enabled"));
+
+    // Make sure the user can not enroll again while two-factor authentication is enabled.
+    match two_factor_service.begin_enrollment(&user).await {
+        InsertionResult::Invalid(_) => {}
+        _ => panic!("An enrollment was started while two-factor authentication was enabled"),
+    };
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
+
+/// # Description
+///
+/// Test verifying second factors, and make sure codes and recovery codes can only be used once.
+#[actix_web::test]
+async fn codes_can_only_be_used_once() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+    let two_factor_service: Arc<dyn TwoFactorService> = injector.resolve();
+
+    // Insert a test user, and enroll them in two-factor authentication.
+    let user: User = insert_test_user(&user_service).await;
+    let (enrollment, recovery_codes) =
+        enroll_test_user(&user, &two_factor_service, &crypto_service).await;
+
+    // Make sure a code from the next period is only accepted once.
+    let code: String = crypto_service
+        .create_totp_code(
+            &enrollment.secret,
+            &(OffsetDateTime::now_utc() + Duration::seconds(30)),
+        )
+        .expect("Failed to create totp code");
+    for expected_result in [true, false] {
+        let result: bool = two_factor_service
+            .verify(&user.id, &code)
+            .await
+            .expect("Failed to verify code");
+        assert_eq!(expected_result, result);
+    }
+
+    // Make sure a recovery code is only accepted once, regardless of its case and separator.
+    for (recovery_code, expected_result) in [
+        (recovery_codes[0].to_uppercase().replace("-", " "), true),
+        (recovery_codes[0].clone(), false),
+        (generate_random_string(10), false),
+    ] {
+        let result: bool = two_factor_service
+            .verify(&user.id, &recovery_code)
+            .await
+            .expect("Failed to verify recovery code");
+        assert_eq!(expected_result, result);
+    }
+    assert_eq!(
+        9,
+        two_factor_service
+            .count_recovery_codes(&user.id)
+            .await
+            .expect("Failed to count recovery codes")
+    );
+
+    // Disable two-factor authentication with a recovery code, and make sure codes are no longer
+    // accepted.
+    match two_factor_service.disable(&user, &recovery_codes[0]).await {
+        UpdateResult::Invalid(_) => {}
+        _ => panic!("Two-factor authentication was disabled with a used recovery code"),
+    };
+    match two_factor_service.disable(&user, &recovery_codes[1]).await {
+        UpdateResult::Ok(()) => {}
+        UpdateResult::NotFound => panic!("Two-factor authentication was not enabled"),
+        UpdateResult::Invalid(details) => panic!("Failed to disable: {}", details),
+        UpdateResult::Err(error) => panic!("Failed to disable: {}", error),
+    };
+    assert!(!two_factor_service
+        .verify(&user.id, &recovery_codes[2])
+        .await
+        .expect("Failed to verify recovery code"));
+    assert_eq!(
+        0,
+        two_factor_service
+            .count_recovery_codes(&user.id)
+            .await
+            .expect("Failed to count recovery codes")
+    );
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
+
+/// # Description
+///
+/// Test creating a challenge, and make sure it can be queried by its token and only deleted once.
+#[actix_web::test]
+async fn challenges_are_queryable_by_their_token() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let two_factor_service: Arc<dyn TwoFactorService> = injector.resolve();
+
+    // Insert a test user, and create a challenge for them.
+    let user: User = insert_test_user(&user_service).await;
+    let challenge_token: String = two_factor_service
+        .create_challenge(&user)
+        .await
+        .expect("Failed to create challenge");
+
+    // Make sure the challenge can be queried by its token.
+    let challenge_id: u64 = match two_factor_service.get_challenge(&challenge_token).await {
+        QueryResult::Ok(challenge) => {
+            assert_eq!(user.id, challenge.user_id);
+            assert!(challenge.expires_at > OffsetDateTime::now_utc());
+            challenge.id
+        }
+        QueryResult::NotFound => panic!("The challenge could not be found"),
+        QueryResult::Err(error) => panic!("Failed to query challenge: {}", error),
+    };
+
+    // Make sure an unknown token does not match a challenge.
+    match two_factor_service
+        .get_challenge(&generate_random_string(48))
+        .await
+    {
+        QueryResult::NotFound => {}
+        _ => panic!("An unknown challenge token matched a challenge"),
+    };
+
+    // Delete the challenge, and make sure it can only be deleted once.
+    for expected_result in [true, false] {
+        let result: bool = two_factor_service
+            .delete_challenge(&challenge_id)
+            .await
+            .expect("Failed to delete challenge");
+        assert_eq!(expected_result, result);
+    }
+
+    // Delete the test user.
+    delete_test_user(&user, &user_service).await;
+}
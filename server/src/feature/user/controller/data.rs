@@ -28,6 +28,14 @@ impl Into<User> for CreateUserRequestBody {
     }
 }
 
+/// A verify email request body struct.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct VerifyEmailRequestBody {
+    /// The email verification token that was emailed to the user.
+    pub(super) token: String,
+}
+
 /// A get user response body struct.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
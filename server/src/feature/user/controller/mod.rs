@@ -1,20 +1,26 @@
 mod data;
 
-use self::data::{CreateUserRequestBody, UpdateUserRequestBody};
+use self::data::{CreateUserRequestBody, UpdateUserRequestBody, VerifyEmailRequestBody};
 use crate::{
-    common::enumeration::{
-        AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+    common::{
+        enumeration::{
+            AuthenticationResult, DeletionResult, InsertionResult, QueryResult, UpdateResult,
+        },
+        utility::{
+            create_authentication_cookies, create_authentication_failure_response,
+            get_client_ip_address, get_client_user_agent,
+        },
     },
     config::Config,
     feature::{
         auth::service::AuthService,
         crypto::service::CryptoService,
+        session::service::SessionService,
         user::{controller::data::GetUserResponseBody, model::User, service::UserService},
     },
     injector::DependencyInjector,
 };
 use actix_web::{
-    cookie::{Cookie, SameSite},
     delete, get, patch, post,
     web::{self, ServiceConfig},
     HttpRequest, HttpResponse,
@@ -32,6 +38,8 @@ pub(crate) fn configure(config: &mut ServiceConfig) {
     config.service(
         web::scope("/users")
             .service(create_user)
+            .service(verify_email)
+            .service(send_email_verification)
             .service(get_user_by_id)
             .service(get_user_by_username)
             .service(get_user_by_email)
@@ -46,6 +54,8 @@ pub(crate) fn configure(config: &mut ServiceConfig) {
 ///
 /// # Arguments
 ///
+/// `request` - The http request.
+///
 /// `body` - The request body which contains information about the account that is being created.
 ///
 /// `config` - The server's configuration data.
@@ -54,15 +64,19 @@ pub(crate) fn configure(config: &mut ServiceConfig) {
 ///
 /// `crypto_service` The crypto service that will be used to create a token for the user.
 ///
+/// `session_service` - The session service that will be used to create a session for the user.
+///
 /// # Returns
 ///
 /// An http response.
 #[post("")]
 async fn create_user(
+    request: HttpRequest,
     body: web::Json<CreateUserRequestBody>,
     config: web::Data<Config>,
     user_service: Inject<DependencyInjector, dyn UserService>,
     crypto_service: Inject<DependencyInjector, dyn CryptoService>,
+    session_service: Inject<DependencyInjector, dyn SessionService>,
 ) -> HttpResponse {
     // Convert the request body into a user.
     let mut user: User = body.into_inner().into();
@@ -77,23 +91,100 @@ async fn create_user(
         InsertionResult::Err(_) => return HttpResponse::InternalServerError().finish(),
     };
 
+    // Create a session for the user.
+    let (refresh_token, session) = match session_service
+        .create(
+            &created_user,
+            &get_client_user_agent(&request),
+            &get_client_ip_address(&request),
+        )
+        .await
+    {
+        Ok(result) => result,
+        Err(_) => return HttpResponse::InternalServerError().finish(),
+    };
+
     // Create an authentication token for the user.
-    let token: String = match crypto_service.create_token(&created_user) {
+    let token: String = match crypto_service.create_token(&created_user, &session.family_id) {
         Ok(token) => token,
         Err(_) => return HttpResponse::InternalServerError().finish(),
     };
 
-    // Create an authentication cookie for the user.
-    let auth_cookie: Cookie = Cookie::build("authorization", format!("Bearer {}", token))
-        .http_only(true)
-        .secure(true)
-        .same_site(SameSite::Strict)
-        .path("/")
-        .expires(None)
-        .finish();
+    // Send the response with the user's authentication cookies.
+    let mut response = HttpResponse::Ok();
+    for cookie in create_authentication_cookies(&token, &refresh_token) {
+        response.cookie(cookie);
+    }
+    return response.json(created_user);
+}
 
-    // Send the response.
-    return HttpResponse::Ok().cookie(auth_cookie).json(created_user);
+/// # Description
+///
+/// An api endpoint to verify the email address of a user account with the token that was emailed
+/// to the user.
+///
+/// # Arguments
+///
+/// `body` - The request body which contains the email verification token.
+///
+/// `user_service` - The user service that will be used to verify the email address.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/verify-email")]
+async fn verify_email(
+    body: web::Json<VerifyEmailRequestBody>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Verify the email address.
+    return match user_service.verify_email(&body.token).await {
+        UpdateResult::Ok(user) => HttpResponse::Ok().json(user),
+        UpdateResult::NotFound => HttpResponse::NotFound().finish(),
+        UpdateResult::Invalid(details) => HttpResponse::BadRequest().json(details),
+        UpdateResult::Err(_) => HttpResponse::InternalServerError().finish(),
+    };
+}
+
+/// # Description
+///
+/// An api endpoint to email the sending user a new link they can use to verify their email
+/// address.
+///
+/// # Arguments
+///
+/// `request` - The http request.
+///
+/// `auth_service` - The authentication service that will be used to authenticate the user sending
+/// the request.
+///
+/// `user_service` - The user service that will be used to send the verification email.
+///
+/// # Returns
+///
+/// An http response.
+#[post("/verify-email/resend")]
+async fn send_email_verification(
+    request: HttpRequest,
+    auth_service: Inject<DependencyInjector, dyn AuthService>,
+    user_service: Inject<DependencyInjector, dyn UserService>,
+) -> HttpResponse {
+    // Authenticate the user.
+    let user: User = match auth_service.authenticate_request(&request, None).await {
+        AuthenticationResult::Ok(user) => user,
+        result => return create_authentication_failure_response(result),
+    };
+
+    // Make sure the user's email address has not already been verified.
+    if user.email_is_verified {
+        return HttpResponse::Conflict().finish();
+    }
+
+    // Send the verification email.
+    return match user_service.send_email_verification(&user).await {
+        Ok(()) => HttpResponse::Accepted().finish(),
+        Err(_) => HttpResponse::InternalServerError().finish(),
+    };
 }
 
 /// # Description
@@ -122,10 +213,9 @@ async fn get_user_by_id(
     user_service: Inject<DependencyInjector, dyn UserService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    match auth_service.authenticate_request(&request).await {
+    match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(_) => {}
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Query the target user, and if found, convert the user into the response body format.
@@ -165,10 +255,9 @@ async fn get_user_by_username(
     user_service: Inject<DependencyInjector, dyn UserService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    match auth_service.authenticate_request(&request).await {
+    match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(_) => {}
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Query the target user, and if found, convert the user into the response body format.
@@ -208,10 +297,9 @@ async fn get_user_by_email(
     user_service: Inject<DependencyInjector, dyn UserService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    match auth_service.authenticate_request(&request).await {
+    match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(_) => {}
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Query the target user, and if found, convert the user into the response body format.
@@ -251,10 +339,9 @@ async fn update_user(
     user_service: Inject<DependencyInjector, dyn UserService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    let mut user: User = match auth_service.authenticate_request(&request).await {
+    let mut user: User = match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Apply the update to the user.
@@ -292,10 +379,9 @@ async fn delete_user(
     user_service: Inject<DependencyInjector, dyn UserService>,
 ) -> HttpResponse {
     // Authenticate the user.
-    let user: User = match auth_service.authenticate_request(&request).await {
+    let user: User = match auth_service.authenticate_request(&request, None).await {
         AuthenticationResult::Ok(user) => user,
-        AuthenticationResult::NotAuthenticated => return HttpResponse::Unauthorized().finish(),
-        AuthenticationResult::Err(_) => return HttpResponse::InternalServerError().finish(),
+        result => return create_authentication_failure_response(result),
     };
 
     // Delete the user.
@@ -44,6 +44,12 @@ pub(crate) struct User {
     /// If the user's account has been locked.
     pub(crate) account_is_locked: bool,
 
+    /// The date and time the user's account is automatically unlocked at, if it was locked
+    /// temporarily. Accounts that are locked without an unlock time stay locked until they are
+    /// unlocked.
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub(crate) account_unlocks_at: Option<OffsetDateTime>,
+
     /// If the user's account has been banned.
     pub(crate) account_is_banned: bool,
 }
@@ -62,6 +68,7 @@ impl Default for User {
             email_is_verified: false,
             password_reset_is_required: false,
             account_is_locked: false,
+            account_unlocks_at: None,
             account_is_banned: false,
         };
     }
@@ -86,6 +93,12 @@ impl PartialEq for User {
             && self.email_is_verified == other.email_is_verified
             && self.password_reset_is_required == other.password_reset_is_required
             && self.account_is_locked == other.account_is_locked
+            && self
+                .account_unlocks_at
+                .map(|unlocks_at| unlocks_at.unix_timestamp())
+                == other
+                    .account_unlocks_at
+                    .map(|unlocks_at| unlocks_at.unix_timestamp())
             && self.account_is_banned == other.account_is_banned
     }
 
@@ -93,3 +106,135 @@ impl PartialEq for User {
         return !self.eq(other);
     }
 }
+
+/// A user token struct, which is a single-use secret that lets its holder perform an action on
+/// behalf of a user. Only a hash of the secret is stored.
+#[derive(Clone, Debug)]
+pub(crate) struct UserToken {
+    /// The user token's unique identifier.
+    pub(crate) id: u64,
+
+    /// The id of the user the token belongs to.
+    pub(crate) user_id: u64,
+
+    /// The action the token can be used to perform.
+    pub(crate) purpose: UserTokenPurpose,
+
+    /// The sha-256 hash of the token, encoded as hex.
+    pub(crate) token_hash: String,
+
+    /// The date and time the token was created at.
+    pub(crate) token_created_at: OffsetDateTime,
+
+    /// The date and time the token expires at.
+    pub(crate) expires_at: OffsetDateTime,
+}
+
+/// An enumeration of all of the actions a user token can be used to perform.
+#[derive(Clone, Copy, Debug, PartialEq, sqlx::Type)]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum UserTokenPurpose {
+    /// # Description
+    ///
+    /// The EmailVerification variant is used for tokens that verify a user's email address.
+    EmailVerification,
+
+    /// # Description
+    ///
+    /// The PasswordReset variant is used for tokens that reset a user's password.
+    PasswordReset,
+
+    /// # Description
+    ///
+    /// The AccountUnlock variant is used for tokens that unlock an account that was locked because
+    /// of failed logins.
+    AccountUnlock,
+}
+
+/// An enumeration of all of the roles a user can be granted. A user's roles are stored in a join
+/// table, and each role grants a fixed set of permissions.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(rename_all = "snake_case")]
+pub(crate) enum UserRole {
+    /// # Description
+    ///
+    /// The Admin variant is used for administrators, who can manage every user's account and
+    /// roles.
+    Admin,
+}
+
+/// An implementation for the UserRole enum.
+impl UserRole {
+    /// # Description
+    ///
+    /// Get the permissions the role grants.
+    ///
+    /// # Returns
+    ///
+    /// The permissions the role grants.
+    pub(crate) fn permissions(&self) -> &'static [Permission] {
+        return match self {
+            UserRole::Admin => &[
+                Permission::UsersRead,
+                Permission::UsersWrite,
+                Permission::RolesWrite,
+            ],
+        };
+    }
+}
+
+/// An enumeration of all of the permissions that can be granted by a role, which each allow a
+/// group of administrative endpoints to be used.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) enum Permission {
+    /// # Description
+    ///
+    /// The UsersRead variant allows every user's account to be listed, searched and read.
+    #[serde(rename = "users:read")]
+    UsersRead,
+
+    /// # Description
+    ///
+    /// The UsersWrite variant allows every user's account to be locked, unlocked, banned and
+    /// unbanned, and their password reset to be required.
+    #[serde(rename = "users:write")]
+    UsersWrite,
+
+    /// # Description
+    ///
+    /// The RolesWrite variant allows roles to be granted to and revoked from every user.
+    #[serde(rename = "roles:write")]
+    RolesWrite,
+}
+
+/// An enumeration of all of the actions an administrator can perform on a user's account.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum AccountAction {
+    /// # Description
+    ///
+    /// The Lock variant locks the account until it is unlocked by an administrator.
+    Lock,
+
+    /// # Description
+    ///
+    /// The Unlock variant unlocks the account, including accounts that were locked temporarily
+    /// because of failed logins.
+    Unlock,
+
+    /// # Description
+    ///
+    /// The Ban variant bans the account.
+    Ban,
+
+    /// # Description
+    ///
+    /// The Unban variant lifts the account's ban.
+    Unban,
+
+    /// # Description
+    ///
+    /// The RequirePasswordReset variant requires the user to reset their password before they can
+    /// use their account, and emails them a password reset link.
+    RequirePasswordReset,
+}
@@ -1,7 +1,10 @@
 #[cfg(test)]
 mod test;
 
-use crate::{common::enumeration::QueryContext, feature::user::model::User};
+use crate::{
+    common::enumeration::QueryContext,
+    feature::user::model::{User, UserRole, UserToken, UserTokenPurpose},
+};
 use async_trait::async_trait;
 use shaku::{Component, Interface};
 use sqlx::{Error, Row};
@@ -91,6 +94,36 @@ pub(crate) trait UserRepository: Interface {
         context: &mut QueryContext,
     ) -> Result<Option<User>, Error>;
 
+    /// # Description
+    ///
+    /// Query the users whose username or email address contains a search term from the user
+    /// repository, ordered by their id.
+    ///
+    /// # Arguments
+    ///
+    /// `search_term` - The term the users' usernames and email addresses are searched for. An
+    /// empty search term matches every user.
+    ///
+    /// `limit` - The maximum amount of users to query.
+    ///
+    /// `offset` - The amount of matching users to skip.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the users that match.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn search(
+        &self,
+        search_term: &String,
+        limit: &u32,
+        offset: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<User>, Error>;
+
     /// # Description
     ///
     /// Update a user in the user repository.
@@ -128,6 +161,155 @@ pub(crate) trait UserRepository: Interface {
     /// - If the deletion was not successful, the Err variant will be returned with the error that
     /// occurred.
     async fn delete(&self, id: &u64, context: &mut QueryContext) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Insert a user token into the user repository.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The user token to insert into the user repository.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the id of the user
+    /// token that was inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_token(
+        &self,
+        token: &UserToken,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query a user token from the user repository by its purpose and hash.
+    ///
+    /// # Arguments
+    ///
+    /// `purpose` - The purpose of the user token to query from the user repository.
+    ///
+    /// `token_hash` - The hash of the user token to query from the user repository.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with an optional user token.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_token_by_hash(
+        &self,
+        purpose: &UserTokenPurpose,
+        token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<UserToken>, Error>;
+
+    /// # Description
+    ///
+    /// Delete all of a user's tokens that have a specific purpose from the user repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user whose tokens will be deleted.
+    ///
+    /// `purpose` - The purpose of the user tokens that will be deleted.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_tokens(
+        &self,
+        user_id: &u64,
+        purpose: &UserTokenPurpose,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Query the roles that have been granted to a user from the user repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user whose roles will be queried.
+    ///
+    /// `context` - The query context the query will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the user's roles.
+    /// - If the query was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn get_roles(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<UserRole>, Error>;
+
+    /// # Description
+    ///
+    /// Grant a role to a user in the user repository. Granting a role the user already has does
+    /// nothing.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the role will be granted to.
+    ///
+    /// `role` - The role that will be granted.
+    ///
+    /// `context` - The query context the insertion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the insertion was successful, the Ok variant will be returned with the amount of
+    /// records inserted.
+    /// - If the insertion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn insert_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
+
+    /// # Description
+    ///
+    /// Revoke a role from a user in the user repository.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the role will be revoked from.
+    ///
+    /// `role` - The role that will be revoked.
+    ///
+    /// `context` - The query context the deletion will be executed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the deletion was successful, the Ok variant will be returned with the amount of records
+    /// deleted.
+    /// - If the deletion was not successful, the Err variant will be returned with the error that
+    /// occurred.
+    async fn delete_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error>;
 }
 
 /// A UserRepositoryImpl struct.
@@ -153,6 +335,7 @@ impl UserRepository for UserRepositoryImpl {
                     `email_is_verified`,
                     `password_reset_is_required`,
                     `account_is_locked`,
+                    `account_unlocks_at`,
                     `account_is_banned`
                 ) VALUES (
                     ?,
@@ -165,6 +348,7 @@ impl UserRepository for UserRepositoryImpl {
                     ?,
                     ?,
                     ?,
+                    ?,
                     ?
                 ) RETURNING `id`;
             "#,
@@ -178,6 +362,7 @@ impl UserRepository for UserRepositoryImpl {
             user.email_is_verified,
             user.password_reset_is_required,
             user.account_is_locked,
+            user.account_unlocks_at,
             user.account_is_banned
         );
 
@@ -206,6 +391,7 @@ impl UserRepository for UserRepositoryImpl {
                     `email_is_verified` AS `email_is_verified: bool`,
                     `password_reset_is_required` AS `password_reset_is_required: bool`,
                     `account_is_locked` AS `account_is_locked: bool`,
+                    `account_unlocks_at` AS `account_unlocks_at: OffsetDateTime`,
                     `account_is_banned` AS `account_is_banned: bool`
                 FROM
                     `users`
@@ -242,6 +428,7 @@ impl UserRepository for UserRepositoryImpl {
                     `email_is_verified` AS `email_is_verified: bool`,
                     `password_reset_is_required` AS `password_reset_is_required: bool`,
                     `account_is_locked` AS `account_is_locked: bool`,
+                    `account_unlocks_at` AS `account_unlocks_at: OffsetDateTime`,
                     `account_is_banned` AS `account_is_banned: bool`
                 FROM
                     `users`
@@ -278,6 +465,7 @@ impl UserRepository for UserRepositoryImpl {
                     `email_is_verified` AS `email_is_verified: bool`,
                     `password_reset_is_required` AS `password_reset_is_required: bool`,
                     `account_is_locked` AS `account_is_locked: bool`,
+                    `account_unlocks_at` AS `account_unlocks_at: OffsetDateTime`,
                     `account_is_banned` AS `account_is_banned: bool`
                 FROM
                     `users`
@@ -294,6 +482,53 @@ impl UserRepository for UserRepositoryImpl {
         };
     }
 
+    async fn search(
+        &self,
+        search_term: &String,
+        limit: &u32,
+        offset: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<User>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            User,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `account_created_at` AS `account_created_at: OffsetDateTime`,
+                    `password_reset_at` AS `password_reset_at: OffsetDateTime`,
+                    `profile_picture_url` AS `profile_picture_url: String`,
+                    `username` AS `username: String`,
+                    `password` AS `password: String`,
+                    `email` AS `email: String`,
+                    `email_is_verified` AS `email_is_verified: bool`,
+                    `password_reset_is_required` AS `password_reset_is_required: bool`,
+                    `account_is_locked` AS `account_is_locked: bool`,
+                    `account_unlocks_at` AS `account_unlocks_at: OffsetDateTime`,
+                    `account_is_banned` AS `account_is_banned: bool`
+                FROM
+                    `users`
+                WHERE
+                    INSTR(`username`, ?) > 0
+                    OR INSTR(`email`, ?) > 0
+                ORDER BY
+                    `id` ASC
+                LIMIT ?
+                OFFSET ?;
+            "#,
+            search_term,
+            search_term,
+            limit,
+            offset
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        };
+    }
+
     async fn update(&self, user: &User, context: &mut QueryContext) -> Result<u64, Error> {
         // Prepare the query.
         let query = sqlx::query!(
@@ -310,6 +545,7 @@ impl UserRepository for UserRepositoryImpl {
                     `email_is_verified` = ?,
                     `password_reset_is_required` = ?,
                     `account_is_locked` = ?,
+                    `account_unlocks_at` = ?,
                     `account_is_banned` = ?
                 WHERE
                     `id` = ?;
@@ -323,6 +559,7 @@ impl UserRepository for UserRepositoryImpl {
             user.email_is_verified,
             user.password_reset_is_required,
             user.account_is_locked,
+            user.account_unlocks_at,
             user.account_is_banned,
             user.id
         );
@@ -356,4 +593,191 @@ impl UserRepository for UserRepositoryImpl {
 
         return Ok(result.rows_affected());
     }
+
+    async fn insert_token(
+        &self,
+        token: &UserToken,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT INTO `user_tokens` (
+                    `id`,
+                    `user_id`,
+                    `purpose`,
+                    `token_hash`,
+                    `token_created_at`,
+                    `expires_at`
+                ) VALUES (
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?,
+                    ?
+                ) RETURNING `id`;
+            "#,
+            token.id,
+            token.user_id,
+            token.purpose,
+            token.token_hash,
+            token.token_created_at,
+            token.expires_at
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.fetch_one(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_one(transaction.as_mut()).await,
+        }?;
+
+        return result.try_get(0);
+    }
+
+    async fn get_token_by_hash(
+        &self,
+        purpose: &UserTokenPurpose,
+        token_hash: &String,
+        context: &mut QueryContext,
+    ) -> Result<Option<UserToken>, Error> {
+        // Prepare the query.
+        let query = sqlx::query_as!(
+            UserToken,
+            r#"
+                SELECT
+                    `id` AS `id: u64`,
+                    `user_id` AS `user_id: u64`,
+                    `purpose` AS `purpose: UserTokenPurpose`,
+                    `token_hash` AS `token_hash: String`,
+                    `token_created_at` AS `token_created_at: OffsetDateTime`,
+                    `expires_at` AS `expires_at: OffsetDateTime`
+                FROM
+                    `user_tokens`
+                WHERE
+                    `purpose` = ? AND `token_hash` = ?;
+            "#,
+            purpose,
+            token_hash
+        );
+
+        // Execute the query.
+        return match context {
+            QueryContext::Connection(connection) => query.fetch_optional(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_optional(transaction.as_mut()).await,
+        };
+    }
+
+    async fn delete_tokens(
+        &self,
+        user_id: &u64,
+        purpose: &UserTokenPurpose,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `user_tokens`
+                WHERE
+                    `user_id` = ? AND `purpose` = ?;
+            "#,
+            user_id,
+            purpose
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn get_roles(
+        &self,
+        user_id: &u64,
+        context: &mut QueryContext,
+    ) -> Result<Vec<UserRole>, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                SELECT
+                    `role` AS `role: UserRole`
+                FROM
+                    `user_roles`
+                WHERE
+                    `user_id` = ?
+                ORDER BY
+                    `role_granted_at` ASC;
+            "#,
+            user_id
+        );
+
+        // Execute the query.
+        let records = match context {
+            QueryContext::Connection(connection) => query.fetch_all(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.fetch_all(transaction.as_mut()).await,
+        }?;
+
+        return Ok(records.into_iter().map(|record| record.role).collect());
+    }
+
+    async fn insert_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                INSERT IGNORE INTO `user_roles` (
+                    `user_id`,
+                    `role`
+                ) VALUES (
+                    ?,
+                    ?
+                );
+            "#,
+            user_id,
+            role
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
+
+    async fn delete_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+        context: &mut QueryContext,
+    ) -> Result<u64, Error> {
+        // Prepare the query.
+        let query = sqlx::query!(
+            r#"
+                DELETE FROM
+                    `user_roles`
+                WHERE
+                    `user_id` = ? AND `role` = ?;
+            "#,
+            user_id,
+            role
+        );
+
+        // Execute the query.
+        let result = match context {
+            QueryContext::Connection(connection) => query.execute(connection.as_mut()).await,
+            QueryContext::Transaction(transaction) => query.execute(transaction.as_mut()).await,
+        }?;
+
+        return Ok(result.rows_affected());
+    }
 }
@@ -1,14 +1,17 @@
 use crate::{
     common::{enumeration::QueryContext, utility::generate_random_string},
     config::Config,
-    feature::user::{model::User, repository::UserRepository},
+    feature::user::{
+        model::{User, UserRole, UserToken, UserTokenPurpose},
+        repository::UserRepository,
+    },
     injector::DependencyInjector,
 };
 use shaku::HasComponent;
 use sqlx::Connection;
 use sqlx::{mysql::MySqlPoolOptions, pool::PoolConnection, MySql, Pool};
 use std::sync::Arc;
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 
 /// # Description
 ///
@@ -37,6 +40,7 @@ fn create_test_user() -> User {
         email_is_verified: false,
         password_reset_is_required: false,
         account_is_locked: false,
+        account_unlocks_at: None,
         account_is_banned: false,
     };
 }
@@ -595,6 +599,8 @@ async fn updating_a_user_that_does_exist_modifies_their_record() {
     user_with_updated_information.password_reset_is_required =
         !test_user.password_reset_is_required;
     user_with_updated_information.account_is_locked = !test_user.account_is_locked;
+    user_with_updated_information.account_unlocks_at =
+        Some(OffsetDateTime::now_utc() + Duration::hours(1));
     user_with_updated_information.account_is_banned = !test_user.account_is_banned;
 
     // Make sure the updated information is different from the original.
@@ -759,3 +765,230 @@ async fn users_cannot_be_queried_after_being_deleted() {
     }
     .expect("Failed to roll transaction back");
 }
+
+/// # Description
+///
+/// Test inserting a user token, and make sure it can only be queried by its hash until the user's
+/// tokens are deleted.
+#[actix_web::test]
+async fn user_tokens_can_be_queried_by_their_hash_until_deleted() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a user repository instance.
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection().await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user.
+    let user_id = user_repository
+        .insert(&create_test_user(), &mut context)
+        .await
+        .expect("Failed to insert user");
+
+    // Insert a token for the user.
+    let token: UserToken = UserToken {
+        id: 0,
+        user_id,
+        purpose: UserTokenPurpose::EmailVerification,
+        token_hash: generate_random_string(64),
+        token_created_at: OffsetDateTime::now_utc(),
+        expires_at: OffsetDateTime::now_utc() + Duration::hours(1),
+    };
+    let token_id = user_repository
+        .insert_token(&token, &mut context)
+        .await
+        .expect("Failed to insert user token");
+
+    // Make sure the token can be queried by its hash.
+    let queried_token = user_repository
+        .get_token_by_hash(&token.purpose, &token.token_hash, &mut context)
+        .await
+        .expect("Failed to query user token")
+        .expect("Query did not return user token");
+
+    assert_eq!(queried_token.id, token_id);
+    assert_eq!(queried_token.user_id, user_id);
+
+    // Make sure a token with a different hash is not found.
+    let unknown_token = user_repository
+        .get_token_by_hash(&token.purpose, &generate_random_string(64), &mut context)
+        .await
+        .expect("Failed to query user token");
+
+    assert!(unknown_token.is_none());
+
+    // Delete the user's tokens.
+    let rows_deleted = user_repository
+        .delete_tokens(&user_id, &token.purpose, &mut context)
+        .await
+        .expect("Failed to delete user tokens");
+
+    assert_eq!(rows_deleted, 1);
+
+    // Make sure the token can no longer be queried.
+    let deleted_token = user_repository
+        .get_token_by_hash(&token.purpose, &token.token_hash, &mut context)
+        .await
+        .expect("Failed to query user token");
+
+    assert!(deleted_token.is_none());
+
+    // Rollback the transaction.
+    match context {
+        QueryContext::Transaction(transaction) => transaction.rollback().await,
+        QueryContext::Connection(_) => unreachable!(),
+    }
+    .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test granting a role to a user, and make sure it can be queried until it is revoked.
+#[actix_web::test]
+async fn user_roles_can_be_queried_until_revoked() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a user repository instance.
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection().await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user.
+    let user_id = user_repository
+        .insert(&create_test_user(), &mut context)
+        .await
+        .expect("Failed to insert user");
+
+    // Make sure the user does not have any roles.
+    let roles = user_repository
+        .get_roles(&user_id, &mut context)
+        .await
+        .expect("Failed to query user roles");
+
+    assert!(roles.is_empty());
+
+    // Grant the user a role twice, and make sure it is only stored once.
+    let rows_inserted = user_repository
+        .insert_role(&user_id, &UserRole::Admin, &mut context)
+        .await
+        .expect("Failed to insert user role");
+
+    assert_eq!(rows_inserted, 1);
+
+    let rows_inserted = user_repository
+        .insert_role(&user_id, &UserRole::Admin, &mut context)
+        .await
+        .expect("Failed to insert user role");
+
+    assert_eq!(rows_inserted, 0);
+
+    let roles = user_repository
+        .get_roles(&user_id, &mut context)
+        .await
+        .expect("Failed to query user roles");
+
+    assert_eq!(roles, vec![UserRole::Admin]);
+
+    // Revoke the role, and make sure it can no longer be queried.
+    let rows_deleted = user_repository
+        .delete_role(&user_id, &UserRole::Admin, &mut context)
+        .await
+        .expect("Failed to delete user role");
+
+    assert_eq!(rows_deleted, 1);
+
+    let roles = user_repository
+        .get_roles(&user_id, &mut context)
+        .await
+        .expect("Failed to query user roles");
+
+    assert!(roles.is_empty());
+
+    // Rollback the transaction.
+    match context {
+        QueryContext::Transaction(transaction) => transaction.rollback().await,
+        QueryContext::Connection(_) => unreachable!(),
+    }
+    .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test searching for users, and make sure users are matched by their username and email address.
+#[actix_web::test]
+async fn users_can_be_searched_by_their_username_and_email() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get a user repository instance.
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+
+    // Acquire a database connection.
+    let mut connection = get_database_connection().await;
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user.
+    let test_user: User = create_test_user();
+    let user_id = user_repository
+        .insert(&test_user, &mut context)
+        .await
+        .expect("Failed to insert user");
+
+    // Make sure the user is found by part of their username, and by part of their email address.
+    for search_term in [
+        test_user.username[1..7].to_owned(),
+        test_user.email[..8].to_uppercase(),
+    ] {
+        let users = user_repository
+            .search(&search_term, &100, &0, &mut context)
+            .await
+            .expect("Failed to search users");
+
+        assert!(users.iter().any(|user| user.id == user_id));
+    }
+
+    // Make sure the user is not found by a term that they do not match.
+    let users = user_repository
+        .search(&generate_random_string(32), &100, &0, &mut context)
+        .await
+        .expect("Failed to search users");
+
+    assert!(users.iter().all(|user| user.id != user_id));
+
+    // Rollback the transaction.
+    match context {
+        QueryContext::Transaction(transaction) => transaction.rollback().await,
+        QueryContext::Connection(_) => unreachable!(),
+    }
+    .expect("Failed to roll transaction back");
+}
@@ -6,10 +6,22 @@ use crate::{
         enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
         utility::create_value_validation_error,
     },
+    config::AccountConfig,
     database::DatabaseConnectionFactory,
     feature::{
         crypto::service::CryptoService,
-        user::{model::User, repository::UserRepository},
+        mail::{
+            model::Email,
+            service::MailService,
+            template::{
+                create_account_unlock_email, create_email_verification_email,
+                create_password_reset_email,
+            },
+        },
+        user::{
+            model::{AccountAction, User, UserRole, UserToken, UserTokenPurpose},
+            repository::UserRepository,
+        },
     },
 };
 use async_trait::async_trait;
@@ -17,8 +29,8 @@ use nameof::name_of;
 use shaku::{Component, Interface};
 use sqlx::Acquire;
 use std::{error::Error, io, sync::Arc};
-use time::OffsetDateTime;
-use validator::{Validate, ValidationErrors};
+use time::{Duration, OffsetDateTime};
+use validator::{Validate, ValidationError, ValidationErrors};
 
 /// A user service trait.
 #[async_trait(?Send)]
@@ -264,6 +276,394 @@ pub(crate) trait UserService: Interface {
         id: &u64,
         context: &mut QueryContext,
     ) -> DeletionResult<Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Verify a user's email address with an email verification token. Tokens can only be used
+    /// once.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The email verification token that was emailed to the user.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the email address was verified, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn verify_email(
+        &self,
+        token: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Verify a user's email address with an email verification token, in the context specified.
+    /// Tokens can only be used once.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The email verification token that was emailed to the user.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the email address was verified, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn verify_email_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Email a user a link they can use to verify their email address. Any verification links that
+    /// were previously sent to the user stop working.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose email address will be verified.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was queued, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_email_verification(&self, user: &User) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Email a user a link they can use to verify their email address, in the context specified, so
+    /// that the email is only sent if the context's transaction is committed. Any verification links
+    /// that were previously sent to the user stop working.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose email address will be verified.
+    ///
+    /// `context` - The context the verification token will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was queued, the Ok variant will be returned.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_email_verification_with_context(
+        &self,
+        user: &User,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Email the user with an email address a link they can use to reset their password. Any
+    /// password reset links that were previously sent to the user stop working.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email address of the user whose password will be reset.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was queued or no user has the email address, the Ok variant will be returned,
+    /// so that callers can not reveal which email addresses are in use.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_password_reset(&self, email: &String) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Email the user with an email address a link they can use to reset their password, in the
+    /// context specified, so that the email is only sent if the context's transaction is committed.
+    /// Any password reset links that were previously sent to the user stop working.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email address of the user whose password will be reset.
+    ///
+    /// `context` - The context the password reset token will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was queued or no user has the email address, the Ok variant will be returned,
+    /// so that callers can not reveal which email addresses are in use.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn send_password_reset_with_context(
+        &self,
+        email: &String,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Reset a user's password with a password reset token. Tokens can only be used once, and
+    /// every authentication token that was issued to the user before the reset stops working.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The password reset token that was emailed to the user.
+    ///
+    /// `password` - The user's new plain-text password.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the password was reset, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, or the new password is
+    /// invalid, the Invalid variant will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn reset_password(
+        &self,
+        token: &String,
+        password: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Reset a user's password with a password reset token, in the context specified. Tokens can
+    /// only be used once, and every authentication token that was issued to the user before the
+    /// reset stops working.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The password reset token that was emailed to the user.
+    ///
+    /// `password` - The user's new plain-text password.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the password was reset, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, or the new password is
+    /// invalid, the Invalid variant will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn reset_password_with_context(
+        &self,
+        token: &String,
+        password: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Lock a user's account temporarily because of failed logins, and email the user a link they
+    /// can use to unlock it before the lock expires, in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user whose account will be locked.
+    ///
+    /// `lockout_duration` - The amount of time (in seconds) the account will be locked for.
+    ///
+    /// `context` - The context the account will be locked in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the account was locked and the email was queued, the Ok variant will be returned with
+    /// the locked user.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn lock_account_with_context(
+        &self,
+        user: &User,
+        lockout_duration: u64,
+        context: &mut QueryContext,
+    ) -> Result<User, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Unlock a user's account with the account unlock token that was emailed to them when it was
+    /// locked. Tokens can only be used once.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The account unlock token that was emailed to the user.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the account was unlocked, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn unlock_account(
+        &self,
+        token: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Unlock a user's account with the account unlock token that was emailed to them when it was
+    /// locked, in the context specified. Tokens can only be used once.
+    ///
+    /// # Arguments
+    ///
+    /// `token` - The account unlock token that was emailed to the user.
+    ///
+    /// `context` - The context the update will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the account was unlocked, the Ok variant will be returned with the updated user.
+    /// - If the user the token belongs to no longer exists, the NotFound variant will be returned.
+    /// - If the token does not exist, has already been used or has expired, the Invalid variant
+    /// will be returned with the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn unlock_account_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Search for the users whose username or email address contains a search term.
+    ///
+    /// # Arguments
+    ///
+    /// `search_term` - The term the users' usernames and email addresses are searched for. An
+    /// empty search term matches every user.
+    ///
+    /// `limit` - The maximum amount of users to return.
+    ///
+    /// `offset` - The amount of matching users to skip.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the search was successful, the Ok variant will be returned with the users that match,
+    /// ordered by their id.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn search(
+        &self,
+        search_term: &String,
+        limit: &u32,
+        offset: &u64,
+    ) -> Result<Vec<User>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Get the roles that have been granted to a user.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user whose roles are being retrieved.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with the user's roles.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_roles(&self, user_id: &u64) -> Result<Vec<UserRole>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Grant a role to a user. Granting a role the user already has does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the role will be granted to.
+    ///
+    /// `role` - The role that will be granted.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the role was granted, the Ok variant will be returned with the user's roles.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn grant_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+    ) -> Result<Vec<UserRole>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Revoke a role from a user. Revoking a role the user does not have does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// `user_id` - The id of the user the role will be revoked from.
+    ///
+    /// `role` - The role that will be revoked.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the role was revoked, the Ok variant will be returned with the user's remaining roles.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn revoke_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+    ) -> Result<Vec<UserRole>, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Perform an administrative action on a user's account.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the user whose account the action will be performed on.
+    ///
+    /// `action` - The action that will be performed.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the action was performed, the Ok variant will be returned with the updated user.
+    /// - If the user does not exist, the NotFound variant will be returned.
+    /// - If the user's details do not pass validation, the Invalid variant will be returned with
+    /// the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn perform_account_action(
+        &self,
+        id: &u64,
+        action: &AccountAction,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
+
+    /// # Description
+    ///
+    /// Perform an administrative action on a user's account, in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `id` - The id of the user whose account the action will be performed on.
+    ///
+    /// `action` - The action that will be performed.
+    ///
+    /// `context` - The context the action will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns an update result:
+    /// - If the action was performed, the Ok variant will be returned with the updated user.
+    /// - If the user does not exist, the NotFound variant will be returned.
+    /// - If the user's details do not pass validation, the Invalid variant will be returned with
+    /// the validation errors that were detected.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn perform_account_action_with_context(
+        &self,
+        id: &u64,
+        action: &AccountAction,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>>;
 }
 
 /// A UserServiceImpl struct.
@@ -278,9 +678,17 @@ pub(crate) struct UserServiceImpl {
     #[shaku(inject)]
     user_repository: Arc<dyn UserRepository>,
 
+    /// The mail service that will be used to email users.
+    #[shaku(inject)]
+    mail_service: Arc<dyn MailService>,
+
     /// The database connection factory that will be used to acquire database connections.
     #[shaku(inject)]
     connection_factory: Arc<dyn DatabaseConnectionFactory>,
+
+    /// The account configuration that determines how email addresses are verified, passwords are
+    /// reset and locked accounts are unlocked.
+    account_config: AccountConfig,
 }
 
 /// A UserService implementation for the UserServiceImpl struct.
@@ -400,14 +808,29 @@ impl UserService for UserServiceImpl {
                 Err(error) => return InsertionResult::Err(Box::new(error)),
             };
 
-        // If the user was found, return the user, otherwise return an error.
-        return match inserted_user_option {
-            Some(inserted_user) => InsertionResult::Ok(inserted_user),
-            None => InsertionResult::Err(Box::new(io::Error::new(
-                io::ErrorKind::NotFound,
-                "User could not be found after insertion",
-            ))),
+        // Make sure the user was found.
+        let inserted_user: User = match inserted_user_option {
+            Some(inserted_user) => inserted_user,
+            None => {
+                return InsertionResult::Err(Box::new(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "User could not be found after insertion",
+                )))
+            }
         };
+
+        // If the user's email address has not been verified, ask them to verify it.
+        if !inserted_user.email_is_verified {
+            if let Err(error) = self
+                .send_email_verification_with_context(&inserted_user, context)
+                .await
+            {
+                return InsertionResult::Err(error);
+            }
+        }
+
+        // Return the user.
+        return InsertionResult::Ok(inserted_user);
     }
 
     async fn get_by_id(&self, id: &u64) -> QueryResult<User, Box<dyn Error>> {
@@ -619,6 +1042,13 @@ impl UserService for UserServiceImpl {
         // so we must make a copy of it.
         let mut user = user.clone();
 
+        // If the user is changing their email address, the new email address must be verified.
+        let email_is_changing: bool =
+            user.email.to_lowercase() != existing_user.email.to_lowercase();
+        if email_is_changing {
+            user.email_is_verified = false;
+        }
+
         // If the user is updating their password, hash it.
         if &user.password != &existing_user.password {
             user.password_reset_at = OffsetDateTime::now_utc();
@@ -653,14 +1083,29 @@ impl UserService for UserServiceImpl {
             Err(error) => return UpdateResult::Err(Box::new(error)),
         };
 
-        // Make sure the user was found, and return the updated user.
-        return match updated_user_option {
-            Some(updated_user) => UpdateResult::Ok(updated_user),
-            None => UpdateResult::Err(Box::new(io::Error::new(
-                io::ErrorKind::NotFound,
-                "User could not be found after update",
-            ))),
+        // Make sure the user was found.
+        let updated_user: User = match updated_user_option {
+            Some(updated_user) => updated_user,
+            None => {
+                return UpdateResult::Err(Box::new(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    "User could not be found after update",
+                )))
+            }
         };
+
+        // If the user changed their email address, ask them to verify the new one.
+        if email_is_changing {
+            if let Err(error) = self
+                .send_email_verification_with_context(&updated_user, context)
+                .await
+            {
+                return UpdateResult::Err(error);
+            }
+        }
+
+        // Return the updated user.
+        return UpdateResult::Ok(updated_user);
     }
 
     async fn delete(&self, id: &u64) -> DeletionResult<Box<dyn Error>> {
@@ -695,4 +1140,737 @@ impl UserService for UserServiceImpl {
             return DeletionResult::NotFound;
         }
     }
+
+    async fn verify_email(
+        &self,
+        token: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the verification.
+        let update_result = self.verify_email_with_context(token, &mut context).await;
+
+        // If the verification was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn verify_email_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Query the token, and make sure it is valid.
+        let user_token: UserToken = match self
+            .get_user_token(&UserTokenPurpose::EmailVerification, token, context)
+            .await
+        {
+            Ok(Ok(user_token)) => user_token,
+            Ok(Err(validation_errors)) => return UpdateResult::Invalid(validation_errors),
+            Err(error) => return UpdateResult::Err(error),
+        };
+
+        // Query the user the token belongs to.
+        let mut user: User = match __self
+            .user_repository
+            .get_by_id(&user_token.user_id, context)
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Mark the user's email address as verified.
+        user.email_is_verified = true;
+        if let Err(error) = __self.user_repository.update(&user, context).await {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Delete the user's email verification tokens, so that they can not be used again.
+        if let Err(error) = __self
+            .user_repository
+            .delete_tokens(&user.id, &UserTokenPurpose::EmailVerification, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Return the updated user.
+        return UpdateResult::Ok(user);
+    }
+
+    async fn send_email_verification(&self, user: &User) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = __self.connection_factory.get_connection().await?;
+
+        // Start a transaction.
+        let transaction = connection.begin().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Create the token and queue the email.
+        let result = self
+            .send_email_verification_with_context(user, &mut context)
+            .await;
+
+        // If the email was queued, commit the transaction, otherwise roll it back.
+        match result {
+            Ok(()) => context.commit_if_transaction().await?,
+            Err(_) => context.rollback_if_transaction().await?,
+        };
+
+        // Return the result.
+        return result;
+    }
+
+    async fn send_email_verification_with_context(
+        &self,
+        user: &User,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>> {
+        // Create the token, which replaces the tokens of any verification links that were
+        // previously sent.
+        let (token, user_token) = self
+            .create_user_token(
+                user,
+                UserTokenPurpose::EmailVerification,
+                __self.account_config.email_verification_lifetime,
+                context,
+            )
+            .await?;
+
+        // Queue the email that contains the verification link.
+        let link: String = format!(
+            "{}?token={}",
+            __self.account_config.email_verification_url, token
+        );
+        let email = create_email_verification_email(
+            &user.email,
+            &user.username,
+            &link,
+            &user_token.expires_at,
+        );
+        return self.queue_email(&email, context).await;
+    }
+
+    async fn send_password_reset(&self, email: &String) -> Result<(), Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = __self.connection_factory.get_connection().await?;
+
+        // Start a transaction.
+        let transaction = connection.begin().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Create the token and queue the email.
+        let result = self
+            .send_password_reset_with_context(email, &mut context)
+            .await;
+
+        // If the email was queued, commit the transaction, otherwise roll it back.
+        match result {
+            Ok(()) => context.commit_if_transaction().await?,
+            Err(_) => context.rollback_if_transaction().await?,
+        };
+
+        // Return the result.
+        return result;
+    }
+
+    async fn send_password_reset_with_context(
+        &self,
+        email: &String,
+        context: &mut QueryContext,
+    ) -> Result<(), Box<dyn Error>> {
+        // If no user has the email address, there is nobody to send the email to.
+        let user: User = match __self.user_repository.get_by_email(email, context).await? {
+            Some(user) => user,
+            None => return Ok(()),
+        };
+
+        // Create the token, which replaces the tokens of any password reset links that were
+        // previously sent.
+        let (token, user_token) = self
+            .create_user_token(
+                &user,
+                UserTokenPurpose::PasswordReset,
+                __self.account_config.password_reset_lifetime,
+                context,
+            )
+            .await?;
+
+        // Queue the email that contains the password reset link.
+        let link: String = format!(
+            "{}?token={}",
+            __self.account_config.password_reset_url, token
+        );
+        let email =
+            create_password_reset_email(&user.email, &user.username, &link, &user_token.expires_at);
+        return self.queue_email(&email, context).await;
+    }
+
+    async fn reset_password(
+        &self,
+        token: &String,
+        password: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the password reset.
+        let update_result = self
+            .reset_password_with_context(token, password, &mut context)
+            .await;
+
+        // If the password reset was successful, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn reset_password_with_context(
+        &self,
+        token: &String,
+        password: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Query the token, and make sure it is valid.
+        let user_token: UserToken = match self
+            .get_user_token(&UserTokenPurpose::PasswordReset, token, context)
+            .await
+        {
+            Ok(Ok(user_token)) => user_token,
+            Ok(Err(validation_errors)) => return UpdateResult::Invalid(validation_errors),
+            Err(error) => return UpdateResult::Err(error),
+        };
+
+        // Query the user the token belongs to.
+        let mut user: User = match __self
+            .user_repository
+            .get_by_id(&user_token.user_id, context)
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Update the user's password. Since this changes when the password was last reset, every
+        // authentication token that was issued before the reset stops working.
+        user.password = password.clone();
+        user.password_reset_is_required = false;
+        let updated_user: User = match self.update_with_context(&user, context).await {
+            UpdateResult::Ok(updated_user) => updated_user,
+            other => return other,
+        };
+
+        // Delete the user's password reset tokens, so that they can not be used again.
+        if let Err(error) = __self
+            .user_repository
+            .delete_tokens(&user.id, &UserTokenPurpose::PasswordReset, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Return the updated user.
+        return UpdateResult::Ok(updated_user);
+    }
+
+    async fn lock_account_with_context(
+        &self,
+        user: &User,
+        lockout_duration: u64,
+        context: &mut QueryContext,
+    ) -> Result<User, Box<dyn Error>> {
+        // Lock the account until the lockout ends.
+        let mut user: User = user.clone();
+        user.account_is_locked = true;
+        user.account_unlocks_at =
+            Some(OffsetDateTime::now_utc() + Duration::seconds(lockout_duration as i64));
+        __self.user_repository.update(&user, context).await?;
+
+        // Create the token, which is only needed until the account is unlocked automatically.
+        let (token, user_token) = self
+            .create_user_token(
+                &user,
+                UserTokenPurpose::AccountUnlock,
+                lockout_duration,
+                context,
+            )
+            .await?;
+
+        // Queue the email that contains the account unlock link.
+        let link: String = format!(
+            "{}?token={}",
+            __self.account_config.account_unlock_url, token
+        );
+        let email =
+            create_account_unlock_email(&user.email, &user.username, &link, &user_token.expires_at);
+        self.queue_email(&email, context).await?;
+
+        // Return the locked user.
+        return Ok(user);
+    }
+
+    async fn unlock_account(
+        &self,
+        token: &String,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Unlock the account.
+        let update_result = self.unlock_account_with_context(token, &mut context).await;
+
+        // If the account was unlocked, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn unlock_account_with_context(
+        &self,
+        token: &String,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Query the token, and make sure it is valid.
+        let user_token: UserToken = match self
+            .get_user_token(&UserTokenPurpose::AccountUnlock, token, context)
+            .await
+        {
+            Ok(Ok(user_token)) => user_token,
+            Ok(Err(validation_errors)) => return UpdateResult::Invalid(validation_errors),
+            Err(error) => return UpdateResult::Err(error),
+        };
+
+        // Query the user the token belongs to.
+        let mut user: User = match __self
+            .user_repository
+            .get_by_id(&user_token.user_id, context)
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Unlock the account.
+        user.account_is_locked = false;
+        user.account_unlocks_at = None;
+        if let Err(error) = __self.user_repository.update(&user, context).await {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Delete the user's account unlock tokens, so that they can not be used again.
+        if let Err(error) = __self
+            .user_repository
+            .delete_tokens(&user.id, &UserTokenPurpose::AccountUnlock, context)
+            .await
+        {
+            return UpdateResult::Err(Box::new(error));
+        }
+
+        // Return the updated user.
+        return UpdateResult::Ok(user);
+    }
+
+    async fn search(
+        &self,
+        search_term: &String,
+        limit: &u32,
+        offset: &u64,
+    ) -> Result<Vec<User>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Perform the search.
+        return Ok(__self
+            .user_repository
+            .search(search_term, limit, offset, &mut context)
+            .await?);
+    }
+
+    async fn get_roles(&self, user_id: &u64) -> Result<Vec<UserRole>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Query the user's roles.
+        return Ok(__self
+            .user_repository
+            .get_roles(user_id, &mut context)
+            .await?);
+    }
+
+    async fn grant_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+    ) -> Result<Vec<UserRole>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Grant the role, and return the user's roles.
+        __self
+            .user_repository
+            .insert_role(user_id, role, &mut context)
+            .await?;
+        return Ok(__self
+            .user_repository
+            .get_roles(user_id, &mut context)
+            .await?);
+    }
+
+    async fn revoke_role(
+        &self,
+        user_id: &u64,
+        role: &UserRole,
+    ) -> Result<Vec<UserRole>, Box<dyn Error>> {
+        // Acquire a database connection.
+        let connection = __self.connection_factory.get_connection().await?;
+
+        // Create the query context.
+        let mut context = QueryContext::Connection(connection);
+
+        // Revoke the role, and return the user's remaining roles.
+        __self
+            .user_repository
+            .delete_role(user_id, role, &mut context)
+            .await?;
+        return Ok(__self
+            .user_repository
+            .get_roles(user_id, &mut context)
+            .await?);
+    }
+
+    async fn perform_account_action(
+        &self,
+        id: &u64,
+        action: &AccountAction,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Acquire a database connection.
+        let mut connection = match __self.connection_factory.get_connection().await {
+            Ok(connection) => connection,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Start a transaction.
+        let transaction = match connection.begin().await {
+            Ok(transaction) => transaction,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Create the query context.
+        let mut context = QueryContext::Transaction(transaction);
+
+        // Perform the action.
+        let update_result = self
+            .perform_account_action_with_context(id, action, &mut context)
+            .await;
+
+        // If the action was performed, commit the transaction, otherwise roll it back.
+        let transaction_completion_result = match update_result {
+            UpdateResult::Ok(_) => context.commit_if_transaction().await,
+            UpdateResult::NotFound => context.rollback_if_transaction().await,
+            UpdateResult::Invalid(_) => context.rollback_if_transaction().await,
+            UpdateResult::Err(_) => context.rollback_if_transaction().await,
+        };
+
+        // If the transaction completion was successful, return the update result, otherwise return
+        // the transaction completion error.
+        return match transaction_completion_result {
+            Ok(()) => update_result,
+            Err(error) => UpdateResult::Err(Box::new(error)),
+        };
+    }
+
+    async fn perform_account_action_with_context(
+        &self,
+        id: &u64,
+        action: &AccountAction,
+        context: &mut QueryContext,
+    ) -> UpdateResult<User, ValidationErrors, Box<dyn Error>> {
+        // Query the user.
+        let mut user: User = match __self.user_repository.get_by_id(id, context).await {
+            Ok(Some(user)) => user,
+            Ok(None) => return UpdateResult::NotFound,
+            Err(error) => return UpdateResult::Err(Box::new(error)),
+        };
+
+        // Apply the action to the user's account. Accounts locked by an administrator have no
+        // unlock time, so they stay locked until an administrator unlocks them.
+        match action {
+            AccountAction::Lock => {
+                user.account_is_locked = true;
+                user.account_unlocks_at = None;
+            }
+            AccountAction::Unlock => {
+                user.account_is_locked = false;
+                user.account_unlocks_at = None;
+            }
+            AccountAction::Ban => user.account_is_banned = true,
+            AccountAction::Unban => user.account_is_banned = false,
+            AccountAction::RequirePasswordReset => user.password_reset_is_required = true,
+        };
+        let updated_user: User = match self.update_with_context(&user, context).await {
+            UpdateResult::Ok(updated_user) => updated_user,
+            other => return other,
+        };
+
+        // Delete the account unlock tokens that were emailed because of failed logins, so that
+        // they can not be used to unlock an account an administrator has locked.
+        if let AccountAction::Lock | AccountAction::Unlock = action {
+            if let Err(error) = __self
+                .user_repository
+                .delete_tokens(&updated_user.id, &UserTokenPurpose::AccountUnlock, context)
+                .await
+            {
+                return UpdateResult::Err(Box::new(error));
+            }
+        }
+
+        // Email the user a link they can use to reset their password.
+        if let AccountAction::RequirePasswordReset = action {
+            if let Err(error) = self
+                .send_password_reset_with_context(&updated_user.email, context)
+                .await
+            {
+                return UpdateResult::Err(error);
+            }
+        }
+
+        // Return the updated user.
+        return UpdateResult::Ok(updated_user);
+    }
+}
+
+/// An implementation for the UserServiceImpl struct.
+impl UserServiceImpl {
+    /// # Description
+    ///
+    /// Create user service parameters that can be used to override the default values injected.
+    ///
+    /// ### Note: This is necessary as the Shaku macro does not make them accessible outside of this
+    /// file.
+    ///
+    /// # Arguments
+    ///
+    /// `account_config` - The account configuration that determines how email addresses are
+    /// verified, passwords are reset and locked accounts are unlocked.
+    ///
+    /// # Returns
+    ///
+    /// The user service parameters that were created.
+    pub(crate) fn create_parameters(account_config: AccountConfig) -> UserServiceImplParameters {
+        return UserServiceImplParameters { account_config };
+    }
+
+    /// # Description
+    ///
+    /// Create a user token, and store its hash. Any tokens the user already has for the same
+    /// purpose are deleted, so that only the newest token can be used.
+    ///
+    /// # Arguments
+    ///
+    /// `user` - The user the token is being created for.
+    ///
+    /// `purpose` - The action the token can be used to perform.
+    ///
+    /// `lifetime` - The amount of time (in seconds) the token is valid for.
+    ///
+    /// `context` - The context the token will be created in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the token was created, the Ok variant will be returned with the secret token and the
+    /// user token that was stored.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn create_user_token(
+        &self,
+        user: &User,
+        purpose: UserTokenPurpose,
+        lifetime: u64,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(String, UserToken), Box<dyn Error>> {
+        // Delete the user's existing tokens.
+        self.user_repository
+            .delete_tokens(&user.id, &purpose, context)
+            .await?;
+
+        // Create a token, and store its hash.
+        let token: String = self.crypto_service.generate_secret_token();
+        let now: OffsetDateTime = OffsetDateTime::now_utc();
+        let user_token: UserToken = UserToken {
+            id: 0,
+            user_id: user.id,
+            purpose,
+            token_hash: self.crypto_service.hash_secret_token(&token),
+            token_created_at: now,
+            expires_at: now + Duration::seconds(lifetime as i64),
+        };
+        self.user_repository
+            .insert_token(&user_token, context)
+            .await?;
+
+        // Return the token.
+        return Ok((token, user_token));
+    }
+
+    /// # Description
+    ///
+    /// Query a user token by the secret token it was created for, and make sure it has not expired.
+    ///
+    /// # Arguments
+    ///
+    /// `purpose` - The action the token is being used to perform.
+    ///
+    /// `token` - The secret token.
+    ///
+    /// `context` - The context the query will be performed in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the query was successful, the Ok variant will be returned with either the user token, or
+    /// the validation errors that describe why the token can not be used. The token itself is not
+    /// included in the validation errors, since it is a secret.
+    /// - If an unexpected error occurs, the Err variant will be returned with the error that occurred.
+    async fn get_user_token(
+        &self,
+        purpose: &UserTokenPurpose,
+        token: &String,
+        context: &mut QueryContext<'_>,
+    ) -> Result<Result<UserToken, ValidationErrors>, Box<dyn Error>> {
+        // Query the token by its hash.
+        let token_hash: String = self.crypto_service.hash_secret_token(token);
+        let user_token_option = self
+            .user_repository
+            .get_token_by_hash(purpose, &token_hash, context)
+            .await?;
+
+        // Make sure the token exists and has not expired.
+        let mut validation_errors = ValidationErrors::new();
+        return match user_token_option {
+            Some(user_token) if user_token.expires_at > OffsetDateTime::now_utc() => {
+                Ok(Ok(user_token))
+            }
+            Some(_) => {
+                validation_errors.add("token", ValidationError::new("expired"));
+                Ok(Err(validation_errors))
+            }
+            None => {
+                validation_errors.add("token", ValidationError::new("invalid"));
+                Ok(Err(validation_errors))
+            }
+        };
+    }
+
+    /// # Description
+    ///
+    /// Queue an email to be sent by the mail worker, in the context specified.
+    ///
+    /// # Arguments
+    ///
+    /// `email` - The email that will be queued.
+    ///
+    /// `context` - The context the email will be queued in.
+    ///
+    /// # Returns
+    ///
+    /// This function returns a result:
+    /// - If the email was queued, the Ok variant will be returned.
+    /// - If the email was invalid or an unexpected error occurs, the Err variant will be returned
+    /// with the error that occurred.
+    async fn queue_email(
+        &self,
+        email: &Email,
+        context: &mut QueryContext<'_>,
+    ) -> Result<(), Box<dyn Error>> {
+        return match self.mail_service.queue_with_context(email, context).await {
+            InsertionResult::Ok(_) => Ok(()),
+            InsertionResult::Invalid(errors) => Err(Box::new(errors)),
+            InsertionResult::Err(error) => Err(error),
+        };
+    }
 }
@@ -1,17 +1,21 @@
 use crate::{
     common::{
-        enumeration::{DeletionResult, InsertionResult, QueryResult, UpdateResult},
+        enumeration::{DeletionResult, InsertionResult, QueryContext, QueryResult, UpdateResult},
         utility::generate_random_string,
     },
     config::Config,
-    feature::user::model::User,
+    database::DatabaseConnectionFactory,
+    feature::crypto::service::CryptoService,
+    feature::user::model::{AccountAction, User, UserToken, UserTokenPurpose},
+    feature::user::repository::UserRepository,
     feature::user::service::UserService,
     injector::DependencyInjector,
 };
 use core::panic;
 use shaku::HasComponent;
+use sqlx::Acquire;
 use std::sync::Arc;
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 
 /// # Description
 ///
@@ -40,6 +44,7 @@ fn create_test_user() -> User {
         email_is_verified: false,
         password_reset_is_required: false,
         account_is_locked: false,
+        account_unlocks_at: None,
         account_is_banned: false,
     };
 }
@@ -640,6 +645,7 @@ async fn updating_a_user_with_valid_information_should_succeed() {
     updated_user.email_is_verified = !user.email_is_verified;
     updated_user.password_reset_is_required = !user.password_reset_is_required;
     updated_user.account_is_locked = !user.account_is_locked;
+    updated_user.account_unlocks_at = Some(OffsetDateTime::now_utc() + Duration::hours(1));
     updated_user.account_is_banned = !user.account_is_banned;
 
     // Perform the update.
@@ -659,6 +665,10 @@ async fn updating_a_user_with_valid_information_should_succeed() {
     assert_ne!(&updated_user.password, &returned_updated_user.password);
     updated_user.password = returned_updated_user.password.clone();
 
+    // Make sure the user's new email address must be verified again.
+    assert!(!returned_updated_user.email_is_verified);
+    updated_user.email_is_verified = false;
+
     // Make sure the returned update user has the correct information.
     assert_eq!(updated_user, returned_updated_user);
 
@@ -741,3 +751,422 @@ async fn deleted_users_cannot_be_queried_after_being_deleted() {
         QueryResult::Err(error) => panic!("Failed to execute deletion: {:?}", error),
     };
 }
+
+/// # Description
+///
+/// Test verifying a user's email address, and make sure the token can only be used once and
+/// expired or unknown tokens are rejected.
+#[actix_web::test]
+async fn email_verification_tokens_can_only_be_used_once() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service and repository instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+    let mut connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), &mut context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {:?}", error),
+    };
+    assert!(!user.email_is_verified);
+
+    // Store a verification token and an expired verification token for the user.
+    let token: String = crypto_service.generate_secret_token();
+    let expired_token: String = crypto_service.generate_secret_token();
+    for (secret, expires_at) in [
+        (&token, OffsetDateTime::now_utc() + Duration::hours(1)),
+        (
+            &expired_token,
+            OffsetDateTime::now_utc() - Duration::hours(1),
+        ),
+    ] {
+        let user_token: UserToken = UserToken {
+            id: 0,
+            user_id: user.id,
+            purpose: UserTokenPurpose::EmailVerification,
+            token_hash: crypto_service.hash_secret_token(secret),
+            token_created_at: OffsetDateTime::now_utc(),
+            expires_at,
+        };
+        user_repository
+            .insert_token(&user_token, &mut context)
+            .await
+            .expect("Failed to insert user token");
+    }
+
+    // Make sure unknown and expired tokens are rejected.
+    for (secret, code) in [
+        (&generate_random_string(48), "invalid"),
+        (&expired_token, "expired"),
+    ] {
+        match user_service
+            .verify_email_with_context(secret, &mut context)
+            .await
+        {
+            UpdateResult::Invalid(errors) => {
+                assert_eq!(errors.field_errors()["token"][0].code, code)
+            }
+            _ => panic!("Verification succeeded when it should have failed"),
+        };
+    }
+
+    // Make sure the token verifies the user's email address.
+    match user_service
+        .verify_email_with_context(&token, &mut context)
+        .await
+    {
+        UpdateResult::Ok(verified_user) => assert!(verified_user.email_is_verified),
+        UpdateResult::NotFound => panic!("Failed to verify email: user was not found"),
+        UpdateResult::Invalid(errors) => panic!("Failed to verify email: {:?}", errors),
+        UpdateResult::Err(error) => panic!("Failed to verify email: {:?}", error),
+    };
+
+    // Make sure the token can not be used again.
+    match user_service
+        .verify_email_with_context(&token, &mut context)
+        .await
+    {
+        UpdateResult::Invalid(errors) => {
+            assert_eq!(errors.field_errors()["token"][0].code, "invalid")
+        }
+        _ => panic!("Verification succeeded when the token should have been used"),
+    };
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test resetting a user's password, and make sure the new password is hashed, the token can only
+/// be used once and unknown email addresses are not revealed.
+#[actix_web::test]
+async fn password_reset_tokens_reset_the_password_once() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service and repository instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+    let mut connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Make sure requesting a password reset for an unknown email address succeeds.
+    user_service
+        .send_password_reset_with_context(
+            &format!("{}@example.com", generate_random_string(16)),
+            &mut context,
+        )
+        .await
+        .expect("Failed to request a password reset");
+
+    // Insert a test user, and request a password reset for them.
+    let mut test_user: User = create_test_user();
+    test_user.password_reset_at = OffsetDateTime::now_utc() - Duration::hours(1);
+    let user: User = match user_service
+        .insert_with_context(&test_user, &mut context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {:?}", error),
+    };
+    user_service
+        .send_password_reset_with_context(&user.email, &mut context)
+        .await
+        .expect("Failed to request a password reset");
+
+    // Store a password reset token with a known secret, which replaces the one that was emailed.
+    let token: String = crypto_service.generate_secret_token();
+    let user_token: UserToken = UserToken {
+        id: 0,
+        user_id: user.id,
+        purpose: UserTokenPurpose::PasswordReset,
+        token_hash: crypto_service.hash_secret_token(&token),
+        token_created_at: OffsetDateTime::now_utc(),
+        expires_at: OffsetDateTime::now_utc() + Duration::hours(1),
+    };
+    user_repository
+        .insert_token(&user_token, &mut context)
+        .await
+        .expect("Failed to insert user token");
+
+    // Make sure the token resets the user's password.
+    let password: String = generate_random_string(16);
+    let updated_user: User = match user_service
+        .reset_password_with_context(&token, &password, &mut context)
+        .await
+    {
+        UpdateResult::Ok(updated_user) => updated_user,
+        UpdateResult::NotFound => panic!("Failed to reset password: user was not found"),
+        UpdateResult::Invalid(errors) => panic!("Failed to reset password: {:?}", errors),
+        UpdateResult::Err(error) => panic!("Failed to reset password: {:?}", error),
+    };
+    crypto_service
+        .verify_password(&password, &updated_user.password)
+        .expect("The new password was not set");
+    assert!(updated_user.password_reset_at > user.password_reset_at);
+
+    // Make sure the token can not be used again.
+    match user_service
+        .reset_password_with_context(&token, &password, &mut context)
+        .await
+    {
+        UpdateResult::Invalid(errors) => {
+            assert_eq!(errors.field_errors()["token"][0].code, "invalid")
+        }
+        _ => panic!("Password reset succeeded when the token should have been used"),
+    };
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test locking a user's account and unlocking it with an account unlock token, and make sure the
+/// token can only be used once.
+#[actix_web::test]
+async fn account_unlock_tokens_unlock_the_account_once() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service and repository instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+    let mut connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and lock their account.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), &mut context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {:?}", error),
+    };
+    let locked_user: User = user_service
+        .lock_account_with_context(&user, 3600, &mut context)
+        .await
+        .expect("Failed to lock the account");
+    assert!(locked_user.account_is_locked);
+    assert!(locked_user
+        .account_unlocks_at
+        .is_some_and(|unlocks_at| unlocks_at > OffsetDateTime::now_utc()));
+
+    // Store an account unlock token with a known secret, which replaces the one that was emailed.
+    let token: String = crypto_service.generate_secret_token();
+    let user_token: UserToken = UserToken {
+        id: 0,
+        user_id: user.id,
+        purpose: UserTokenPurpose::AccountUnlock,
+        token_hash: crypto_service.hash_secret_token(&token),
+        token_created_at: OffsetDateTime::now_utc(),
+        expires_at: OffsetDateTime::now_utc() + Duration::hours(1),
+    };
+    user_repository
+        .insert_token(&user_token, &mut context)
+        .await
+        .expect("Failed to insert user token");
+
+    // Make sure the token unlocks the account.
+    let unlocked_user: User = match user_service
+        .unlock_account_with_context(&token, &mut context)
+        .await
+    {
+        UpdateResult::Ok(unlocked_user) => unlocked_user,
+        UpdateResult::NotFound => panic!("Failed to unlock account: user was not found"),
+        UpdateResult::Invalid(errors) => panic!("Failed to unlock account: {:?}", errors),
+        UpdateResult::Err(error) => panic!("Failed to unlock account: {:?}", error),
+    };
+    assert!(!unlocked_user.account_is_locked);
+    assert!(unlocked_user.account_unlocks_at.is_none());
+
+    // Make sure the token can not be used again.
+    match user_service
+        .unlock_account_with_context(&token, &mut context)
+        .await
+    {
+        UpdateResult::Invalid(errors) => {
+            assert_eq!(errors.field_errors()["token"][0].code, "invalid")
+        }
+        _ => panic!("Account unlock succeeded when the token should have been used"),
+    };
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
+
+/// # Description
+///
+/// Test performing administrative actions on a user's account, and make sure an account locked by
+/// an administrator can not be unlocked with an account unlock token.
+#[actix_web::test]
+async fn administrative_actions_update_the_account() {
+    // Create a dependency injector.
+    let injector: DependencyInjector = create_dependency_injector().await;
+
+    // Get the service and repository instances.
+    let user_service: Arc<dyn UserService> = injector.resolve();
+    let user_repository: Arc<dyn UserRepository> = injector.resolve();
+    let crypto_service: Arc<dyn CryptoService> = injector.resolve();
+
+    // Acquire a database connection.
+    let connection_factory: Arc<dyn DatabaseConnectionFactory> = injector.resolve();
+    let mut connection = connection_factory
+        .get_connection()
+        .await
+        .expect("Failed to acquire a database connection");
+
+    // Start a transaction.
+    let transaction = connection
+        .begin()
+        .await
+        .expect("Failed to start transaction");
+
+    // Create a query context.
+    let mut context: QueryContext = QueryContext::Transaction(transaction);
+
+    // Insert a test user, and store an account unlock token for them.
+    let user: User = match user_service
+        .insert_with_context(&create_test_user(), &mut context)
+        .await
+    {
+        InsertionResult::Ok(user) => user,
+        InsertionResult::Invalid(details) => panic!("Failed to insert user: {:?}", details),
+        InsertionResult::Err(error) => panic!("Failed to insert user: {:?}", error),
+    };
+    let token: String = crypto_service.generate_secret_token();
+    let user_token: UserToken = UserToken {
+        id: 0,
+        user_id: user.id,
+        purpose: UserTokenPurpose::AccountUnlock,
+        token_hash: crypto_service.hash_secret_token(&token),
+        token_created_at: OffsetDateTime::now_utc(),
+        expires_at: OffsetDateTime::now_utc() + Duration::hours(1),
+    };
+    user_repository
+        .insert_token(&user_token, &mut context)
+        .await
+        .expect("Failed to insert user token");
+
+    // Perform each action, and make sure it updates the account.
+    for action in [
+        AccountAction::Lock,
+        AccountAction::Ban,
+        AccountAction::RequirePasswordReset,
+        AccountAction::Unlock,
+        AccountAction::Unban,
+    ] {
+        let updated_user: User = match user_service
+            .perform_account_action_with_context(&user.id, &action, &mut context)
+            .await
+        {
+            UpdateResult::Ok(updated_user) => updated_user,
+            UpdateResult::NotFound => panic!("Failed to perform action: user was not found"),
+            UpdateResult::Invalid(errors) => panic!("Failed to perform action: {:?}", errors),
+            UpdateResult::Err(error) => panic!("Failed to perform action: {:?}", error),
+        };
+        match action {
+            AccountAction::Lock => {
+                assert!(updated_user.account_is_locked);
+                assert!(updated_user.account_unlocks_at.is_none());
+            }
+            AccountAction::Unlock => assert!(!updated_user.account_is_locked),
+            AccountAction::Ban => assert!(updated_user.account_is_banned),
+            AccountAction::Unban => assert!(!updated_user.account_is_banned),
+            AccountAction::RequirePasswordReset => {
+                assert!(updated_user.password_reset_is_required)
+            }
+        };
+    }
+
+    // Make sure the account unlock token was deleted when the account was locked.
+    match user_service
+        .unlock_account_with_context(&token, &mut context)
+        .await
+    {
+        UpdateResult::Invalid(errors) => {
+            assert_eq!(errors.field_errors()["token"][0].code, "invalid")
+        }
+        _ => panic!("Account unlock succeeded after an administrator locked the account"),
+    };
+
+    // Make sure actions can not be performed on a user that does not exist.
+    match user_service
+        .perform_account_action_with_context(&0, &AccountAction::Ban, &mut context)
+        .await
+    {
+        UpdateResult::NotFound => {}
+        _ => panic!("An action was performed on a user that does not exist"),
+    };
+
+    // Rollback the transaction.
+    context
+        .rollback_if_transaction()
+        .await
+        .expect("Failed to roll transaction back");
+}
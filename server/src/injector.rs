@@ -2,16 +2,31 @@ use crate::{
     config::Config,
     database::DatabaseConnectionFactoryImpl,
     feature::{
-        auth::service::AuthServiceImpl,
+        api_token::{repository::ApiTokenRepositoryImpl, service::ApiTokenServiceImpl},
+        auth::{repository::FailedLoginRepositoryImpl, service::AuthServiceImpl},
+        check::{repository::CheckResultRepositoryImpl, service::CheckResultServiceImpl},
         crypto::service::CryptoServiceImpl,
         file::{repository::FileRepositoryImpl, service::FileServiceImpl},
+        heartbeat::{repository::HeartbeatRepositoryImpl, service::HeartbeatServiceImpl},
+        incident::{repository::IncidentRepositoryImpl, service::IncidentServiceImpl},
+        mail::{mailer::MailerImpl, repository::EmailRepositoryImpl, service::MailServiceImpl},
+        maintenance::{
+            repository::MaintenanceWindowRepositoryImpl, service::MaintenanceWindowServiceImpl,
+        },
+        monitor::{repository::MonitorRepositoryImpl, service::MonitorServiceImpl},
+        notification::{
+            repository::NotificationChannelRepositoryImpl, service::NotificationChannelServiceImpl,
+        },
+        oidc::{repository::OidcRepositoryImpl, service::OidcServiceImpl},
+        session::{repository::SessionRepositoryImpl, service::SessionServiceImpl},
+        status_page::{repository::StatusPageRepositoryImpl, service::StatusPageServiceImpl},
+        two_factor::{repository::TwoFactorRepositoryImpl, service::TwoFactorServiceImpl},
         user::{repository::UserRepositoryImpl, service::UserServiceImpl},
     },
 };
-use jsonwebtoken::{DecodingKey, EncodingKey};
 use shaku::module;
 use sqlx::{mysql::MySqlPoolOptions, MySql, Pool};
-use std::{error::Error, fs};
+use std::error::Error;
 
 // Create the dependency injector module.
 module! {
@@ -31,7 +46,57 @@ module! {
             FileRepositoryImpl,
             FileServiceImpl,
 
+            // Monitor
+            MonitorRepositoryImpl,
+            MonitorServiceImpl,
+
+            // Maintenance
+            MaintenanceWindowRepositoryImpl,
+            MaintenanceWindowServiceImpl,
+
+            // Mail
+            MailerImpl,
+            EmailRepositoryImpl,
+            MailServiceImpl,
+
+            // Notification
+            NotificationChannelRepositoryImpl,
+            NotificationChannelServiceImpl,
+
+            // Check
+            CheckResultRepositoryImpl,
+            CheckResultServiceImpl,
+
+            // Heartbeat
+            HeartbeatRepositoryImpl,
+            HeartbeatServiceImpl,
+
+            // Incident
+            IncidentRepositoryImpl,
+            IncidentServiceImpl,
+
+            // Status Page
+            StatusPageRepositoryImpl,
+            StatusPageServiceImpl,
+
+            // Session
+            SessionRepositoryImpl,
+            SessionServiceImpl,
+
+            // Two-Factor
+            TwoFactorRepositoryImpl,
+            TwoFactorServiceImpl,
+
+            // OIDC
+            OidcRepositoryImpl,
+            OidcServiceImpl,
+
+            // API Token
+            ApiTokenRepositoryImpl,
+            ApiTokenServiceImpl,
+
             // Authentication
+            FailedLoginRepositoryImpl,
             AuthServiceImpl
         ],
         providers = []
@@ -62,22 +127,39 @@ impl DependencyInjector {
             .connect(&config.database.connection_string)
             .await?;
 
-        // Load the jwt private key.
-        let encoding_key: EncodingKey =
-            EncodingKey::from_rsa_pem(&fs::read(&config.jwt.private_key_path)?)?;
-
-        // Load the jwt public key.
-        let decoding_key: DecodingKey =
-            DecodingKey::from_rsa_pem(&fs::read(&config.jwt.public_key_path)?)?;
-
         // Create the injector.
         let injector: DependencyInjector = DependencyInjector::builder()
             .with_component_parameters::<DatabaseConnectionFactoryImpl>(
                 DatabaseConnectionFactoryImpl::create_parameters(database_connection_pool),
             )
             .with_component_parameters::<CryptoServiceImpl>(CryptoServiceImpl::create_parameters(
-                encoding_key,
-                decoding_key,
+                &config.jwt,
+            )?)
+            .with_component_parameters::<UserServiceImpl>(UserServiceImpl::create_parameters(
+                config.account.clone(),
+            ))
+            .with_component_parameters::<CheckResultServiceImpl>(
+                CheckResultServiceImpl::create_parameters(config.retention.clone()),
+            )
+            .with_component_parameters::<MailerImpl>(MailerImpl::create_parameters(&config.mail)?)
+            .with_component_parameters::<MailServiceImpl>(MailServiceImpl::create_parameters(
+                config.mail.clone(),
+            ))
+            .with_component_parameters::<NotificationChannelServiceImpl>(
+                NotificationChannelServiceImpl::create_parameters(config.notification.clone()),
+            )
+            .with_component_parameters::<SessionServiceImpl>(SessionServiceImpl::create_parameters(
+                config.jwt.refresh_token_lifetime,
+            ))
+            .with_component_parameters::<TwoFactorServiceImpl>(
+                TwoFactorServiceImpl::create_parameters(config.two_factor.clone()),
+            )
+            .with_component_parameters::<OidcServiceImpl>(OidcServiceImpl::create_parameters(
+                config.oidc.clone(),
+                &config.default,
+            )?)
+            .with_component_parameters::<AuthServiceImpl>(AuthServiceImpl::create_parameters(
+                config.login.clone(),
             ))
             .build();
 
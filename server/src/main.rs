@@ -4,7 +4,16 @@ pub(crate) mod database;
 pub(crate) mod feature;
 pub(crate) mod injector;
 
-use crate::{common::utility::get_config_path, config::Config, injector::DependencyInjector};
+use crate::{
+    common::utility::get_config_path,
+    config::Config,
+    feature::{
+        check::{retention::RetentionWorker, scheduler::CheckScheduler},
+        mail::delivery::MailDeliveryWorker,
+        notification::delivery::DeliveryWorker,
+    },
+    injector::DependencyInjector,
+};
 use actix_cors::Cors;
 use actix_web::{http::Method, middleware::Logger, web::Data, App, HttpServer};
 use common::utility::map_to_owned;
@@ -61,6 +70,30 @@ async fn main() -> std::io::Result<()> {
             .expect("Failed to create the dependency injector"),
     );
 
+    // Create the check scheduler, and start it in the background.
+    let check_scheduler: CheckScheduler =
+        CheckScheduler::new(Arc::clone(&dependency_injector), config.scheduler.clone())
+            .expect("Failed to create the check scheduler");
+    actix_web::rt::spawn(check_scheduler.run());
+
+    // Create the retention worker, and start it in the background.
+    let retention_worker: RetentionWorker =
+        RetentionWorker::new(Arc::clone(&dependency_injector), config.retention.clone());
+    actix_web::rt::spawn(retention_worker.run());
+
+    // Create the notification delivery worker, and start it in the background.
+    let delivery_worker: DeliveryWorker = DeliveryWorker::new(
+        Arc::clone(&dependency_injector),
+        config.notification.clone(),
+    )
+    .expect("Failed to create the notification delivery worker");
+    actix_web::rt::spawn(delivery_worker.run());
+
+    // Create the mail delivery worker, and start it in the background.
+    let mail_delivery_worker: MailDeliveryWorker =
+        MailDeliveryWorker::new(Arc::clone(&dependency_injector), config.mail.clone());
+    actix_web::rt::spawn(mail_delivery_worker.run());
+
     // Define an actix application factory closure.
     let app_factory = move || {
         App::new()
@@ -70,7 +103,18 @@ async fn main() -> std::io::Result<()> {
             .wrap(Logger::default())
             .configure(crate::feature::user::controller::configure)
             .configure(crate::feature::auth::controller::configure)
+            .configure(crate::feature::crypto::controller::configure)
+            .configure(crate::feature::session::controller::configure)
+            .configure(crate::feature::two_factor::controller::configure)
+            .configure(crate::feature::api_token::controller::configure)
+            .configure(crate::feature::admin::controller::configure)
             .configure(crate::feature::file::controller::configure)
+            .configure(crate::feature::monitor::controller::configure)
+            .configure(crate::feature::heartbeat::controller::configure)
+            .configure(crate::feature::incident::controller::configure)
+            .configure(crate::feature::status_page::controller::configure)
+            .configure(crate::feature::maintenance::controller::configure)
+            .configure(crate::feature::notification::controller::configure)
     };
 
     // Log the address the server will be bound to.